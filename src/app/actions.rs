@@ -24,13 +24,15 @@ pub(super) fn execute_editor_action<S: CaptureStorage, C: ClipboardBackend>(
                 })?;
             Ok(EditorEvent::Save { capture_id })
         }
-        EditorAction::Copy => {
-            clipboard.copy(&artifact.temp_path).map_err(|err| {
-                EditorActionError::ClipboardError {
-                    operation: "copy",
-                    capture_id: capture_id.clone(),
-                    source: err,
-                }
+        EditorAction::Copy | EditorAction::CopyAs(_) => {
+            let copied = match action {
+                EditorAction::CopyAs(profile) => clipboard.copy_as(&artifact.temp_path, profile),
+                _ => clipboard.copy(&artifact.temp_path),
+            };
+            copied.map_err(|err| EditorActionError::ClipboardError {
+                operation: "copy",
+                capture_id: capture_id.clone(),
+                source: err,
             })?;
             Ok(EditorEvent::Copy { capture_id })
         }
@@ -56,26 +58,31 @@ pub(super) fn execute_preview_action<S: CaptureStorage, C: ClipboardBackend>(
                 })?;
             Ok(PreviewEvent::Save { capture_id })
         }
-        PreviewAction::Copy => {
-            clipboard.copy(&artifact.temp_path).map_err(|err| {
-                PreviewActionError::ClipboardError {
-                    operation: "copy",
-                    capture_id: capture_id.clone(),
-                    source: err,
-                }
+        PreviewAction::Copy | PreviewAction::CopyAs(_) => {
+            let copied = match action {
+                PreviewAction::CopyAs(profile) => clipboard.copy_as(&artifact.temp_path, profile),
+                _ => clipboard.copy(&artifact.temp_path),
+            };
+            copied.map_err(|err| PreviewActionError::ClipboardError {
+                operation: "copy",
+                capture_id: capture_id.clone(),
+                source: err,
             })?;
             Ok(PreviewEvent::Copy { capture_id })
         }
         PreviewAction::Edit => Ok(PreviewEvent::Edit { capture_id }),
         PreviewAction::Delete => {
-            storage
-                .discard_session_artifacts(&capture_id)
-                .map_err(|err| PreviewActionError::StorageError {
+            let trashed = storage.trash_capture(&capture_id).map_err(|err| {
+                PreviewActionError::StorageError {
                     operation: "delete",
                     capture_id: capture_id.clone(),
                     source: err,
-                })?;
-            Ok(PreviewEvent::Delete { capture_id })
+                }
+            })?;
+            Ok(PreviewEvent::Delete {
+                capture_id,
+                trashed,
+            })
         }
         PreviewAction::Close => Ok(PreviewEvent::Close { capture_id }),
     }
@@ -86,7 +93,7 @@ mod tests {
     use std::cell::RefCell;
     use std::path::{Path, PathBuf};
 
-    use crate::clipboard::ClipboardBackend;
+    use crate::clipboard::{ClipboardBackend, CopyProfile};
     use crate::storage::CaptureStorage;
 
     use super::*;
@@ -94,6 +101,7 @@ mod tests {
     #[derive(Default)]
     struct FakeClipboard {
         copied_paths: RefCell<Vec<PathBuf>>,
+        copied_profiles: RefCell<Vec<CopyProfile>>,
     }
 
     impl ClipboardBackend for FakeClipboard {
@@ -101,12 +109,22 @@ mod tests {
             self.copied_paths.borrow_mut().push(path.to_path_buf());
             Ok(())
         }
+
+        fn copy_as(
+            &self,
+            path: &Path,
+            profile: CopyProfile,
+        ) -> crate::clipboard::ClipboardResult<()> {
+            self.copied_profiles.borrow_mut().push(profile);
+            self.copy(path)
+        }
     }
 
     #[derive(Default)]
     struct FakeStorage {
         save_requests: RefCell<Vec<String>>,
         discarded: RefCell<Vec<String>>,
+        trashed: RefCell<Vec<String>>,
     }
 
     impl CaptureStorage for FakeStorage {
@@ -124,6 +142,14 @@ mod tests {
             self.discarded.borrow_mut().push(capture_id.to_string());
             Ok(())
         }
+
+        fn trash_capture(
+            &self,
+            capture_id: &str,
+        ) -> crate::storage::StorageResult<crate::storage::TrashedCapture> {
+            self.trashed.borrow_mut().push(capture_id.to_string());
+            Ok(crate::storage::TrashedCapture::default())
+        }
     }
 
     fn artifact(id: &str) -> CaptureArtifact {
@@ -249,6 +275,59 @@ mod tests {
         assert!(storage.save_requests.borrow().is_empty());
     }
 
+    #[test]
+    fn preview_action_copy_as_passes_profile_to_clipboard() {
+        let storage = FakeStorage::default();
+        let clipboard = FakeClipboard::default();
+        let current = artifact("capture-copy-as");
+
+        let event = execute_preview_action(
+            &current,
+            PreviewAction::CopyAs(CopyProfile::Markdown),
+            &storage,
+            &clipboard,
+        )
+        .expect("copy as should succeed");
+
+        assert_eq!(
+            event,
+            PreviewEvent::Copy {
+                capture_id: "capture-copy-as".to_string()
+            }
+        );
+        assert_eq!(
+            clipboard.copied_profiles.borrow().as_slice(),
+            &[CopyProfile::Markdown]
+        );
+    }
+
+    #[test]
+    fn editor_action_copy_as_passes_profile_to_clipboard() {
+        let storage = FakeStorage::default();
+        let clipboard = FakeClipboard::default();
+        let current = artifact("editor-copy-as");
+
+        let event = execute_editor_action(
+            &current,
+            EditorAction::CopyAs(CopyProfile::Jpeg),
+            &storage,
+            &clipboard,
+        )
+        .expect("copy as should succeed");
+
+        assert_eq!(
+            event,
+            EditorEvent::Copy {
+                capture_id: "editor-copy-as".to_string()
+            }
+        );
+        assert_eq!(
+            clipboard.copied_profiles.borrow().as_slice(),
+            &[CopyProfile::Jpeg]
+        );
+        assert!(storage.save_requests.borrow().is_empty());
+    }
+
     #[test]
     fn preview_action_edit_and_close_no_side_effects() {
         let storage = FakeStorage::default();
@@ -280,7 +359,7 @@ mod tests {
     }
 
     #[test]
-    fn preview_action_delete_discards_artifact() {
+    fn preview_action_delete_moves_artifact_to_trash() {
         let storage = FakeStorage::default();
         let clipboard = FakeClipboard::default();
         let current = artifact("capture-delete");
@@ -291,13 +370,15 @@ mod tests {
         assert_eq!(
             event,
             PreviewEvent::Delete {
-                capture_id: "capture-delete".to_string()
+                capture_id: "capture-delete".to_string(),
+                trashed: crate::storage::TrashedCapture::default(),
             }
         );
         assert_eq!(
-            storage.discarded.borrow().as_slice(),
+            storage.trashed.borrow().as_slice(),
             &["capture-delete".to_string()]
         );
+        assert!(storage.discarded.borrow().is_empty());
         assert!(storage.save_requests.borrow().is_empty());
         assert!(clipboard.copied_paths.borrow().is_empty());
     }
@@ -0,0 +1,218 @@
+use crate::barcode::{BarcodeResult, DecodedBarcode};
+use crate::input::{resolve_shortcut, InputContext, InputMode, ShortcutAction};
+use crate::ui::{icon_button, LAYOUT_TOKENS};
+
+use gtk4::prelude::*;
+use gtk4::{Align, Box as GtkBox, Dialog, Label, Orientation, ResponseType};
+
+use super::input_bridge::{normalize_shortcut_key, shortcut_modifiers};
+use super::jobs::{JobHandle, JobKey, JobOutcome, JobQueue};
+use super::launchpad_actions::{set_status, SharedStatusLog};
+
+pub(super) enum BarcodeInput {
+    Image(image::DynamicImage),
+    File(std::path::PathBuf),
+}
+
+/// Job key for decoding a capture; `region` is `None` for the whole capture.
+pub(super) fn barcode_decode_key(capture_id: &str, region: Option<(u32, u32, u32, u32)>) -> JobKey {
+    match region {
+        Some((x, y, width, height)) => JobKey::for_capture(
+            capture_id,
+            format!("decode-region:{x},{y},{width},{height}"),
+        ),
+        None => JobKey::for_capture(capture_id, "decode"),
+    }
+}
+
+/// Queues decoding of `input` under `key`. Returns `None` without queueing
+/// when the same decode is still pending, so repeated triggers end in one
+/// result dialog. `on_done` runs on the main thread and gets
+/// [`JobOutcome::Cancelled`] when the capture's jobs were cancelled.
+pub(super) fn queue_barcode_decode(
+    jobs: &JobQueue,
+    key: JobKey,
+    input: BarcodeInput,
+    on_done: impl FnOnce(JobOutcome<BarcodeResult<Vec<DecodedBarcode>>>) + 'static,
+) -> Option<JobHandle> {
+    if jobs.is_pending(&key) {
+        return None;
+    }
+    Some(jobs.submit(
+        key,
+        move |_context| match input {
+            BarcodeInput::Image(image) => crate::barcode::decode_image(&image),
+            BarcodeInput::File(path) => crate::barcode::decode_image_file(&path),
+        },
+        |_, _| {},
+        on_done,
+    ))
+}
+
+/// Handle a finished decode on the **main thread**: report errors and empty
+/// results, otherwise list the payloads in a dialog over `parent`.
+pub(super) fn handle_barcode_result(
+    parent: Option<gtk4::Window>,
+    status_log: &SharedStatusLog,
+    result: BarcodeResult<Vec<DecodedBarcode>>,
+) {
+    match result {
+        Ok(decoded) if decoded.is_empty() => {
+            set_status(status_log, "decode: no QR code or barcode found");
+            crate::notification::send("No QR code or barcode found");
+        }
+        Ok(decoded) => {
+            set_status(status_log, decoded_status(&decoded));
+            show_barcode_results_dialog(parent.as_ref(), &decoded, status_log);
+        }
+        Err(err) => {
+            set_status(status_log, format!("decode failed: {err}"));
+            crate::notification::send(format!("Decode failed: {err}"));
+        }
+    }
+}
+
+fn decoded_status(decoded: &[DecodedBarcode]) -> String {
+    match decoded {
+        [single] => format!("decoded {}", single.format.label()),
+        _ => format!("decoded {} codes", decoded.len()),
+    }
+}
+
+fn show_barcode_results_dialog(
+    parent: Option<&gtk4::Window>,
+    decoded: &[DecodedBarcode],
+    status_log: &SharedStatusLog,
+) {
+    let style_tokens = LAYOUT_TOKENS;
+    let dialog = Dialog::new();
+    dialog.add_css_class("chalkak-root");
+    dialog.set_title(Some("Decoded codes"));
+    dialog.set_transient_for(parent);
+    dialog.set_modal(true);
+    dialog.set_destroy_with_parent(true);
+    dialog.add_button("Close", ResponseType::Close);
+    dialog.set_default_response(ResponseType::Close);
+
+    let dialog_content = GtkBox::new(Orientation::Vertical, style_tokens.spacing_12);
+    dialog_content.set_margin_top(style_tokens.spacing_12);
+    dialog_content.set_margin_bottom(style_tokens.spacing_12);
+    dialog_content.set_margin_start(style_tokens.spacing_12);
+    dialog_content.set_margin_end(style_tokens.spacing_12);
+    for barcode in decoded {
+        dialog_content.append(&barcode_result_row(barcode, status_log));
+    }
+    dialog.content_area().append(&dialog_content);
+
+    {
+        let dialog_for_key = dialog.clone();
+        let key_controller = gtk4::EventControllerKey::new();
+        key_controller.connect_key_pressed(move |_, key, keycode, modifier| {
+            let Some(shortcut_key) = normalize_shortcut_key(key, keycode) else {
+                return gtk4::glib::Propagation::Proceed;
+            };
+            let shortcut = resolve_shortcut(
+                shortcut_key,
+                shortcut_modifiers(modifier),
+                InputContext {
+                    mode: InputMode::Dialog,
+                },
+            );
+            match shortcut {
+                Some(ShortcutAction::DialogConfirm | ShortcutAction::DialogCancel) => {
+                    dialog_for_key.response(ResponseType::Close);
+                    gtk4::glib::Propagation::Stop
+                }
+                _ => gtk4::glib::Propagation::Proceed,
+            }
+        });
+        dialog.add_controller(key_controller);
+    }
+
+    dialog.connect_response(|dialog, _| {
+        dialog.close();
+    });
+    dialog.present();
+}
+
+fn barcode_result_row(barcode: &DecodedBarcode, status_log: &SharedStatusLog) -> GtkBox {
+    let style_tokens = LAYOUT_TOKENS;
+    let format_label = Label::new(Some(barcode.format.label()));
+    format_label.add_css_class("barcode-result-format");
+    format_label.set_halign(Align::Start);
+    format_label.set_xalign(0.0);
+
+    let payload_label = Label::new(Some(&barcode.text));
+    payload_label.add_css_class("barcode-result-payload");
+    payload_label.set_halign(Align::Start);
+    payload_label.set_xalign(0.0);
+    payload_label.set_wrap(true);
+    payload_label.set_wrap_mode(gtk4::pango::WrapMode::WordChar);
+    payload_label.set_selectable(true);
+    payload_label.set_max_width_chars(48);
+
+    let text_column = GtkBox::new(Orientation::Vertical, style_tokens.spacing_4);
+    text_column.set_hexpand(true);
+    text_column.append(&format_label);
+    text_column.append(&payload_label);
+
+    let row = GtkBox::new(Orientation::Horizontal, style_tokens.spacing_8);
+    row.add_css_class("barcode-result-row");
+    row.append(&text_column);
+
+    let control_size = style_tokens.control_size as i32;
+    let copy_button = icon_button("copy-symbolic", "Copy", control_size, &[]);
+    copy_button.set_valign(Align::Center);
+    {
+        let text = barcode.text.clone();
+        let label = barcode.format.label();
+        let status_log = status_log.clone();
+        copy_button.connect_clicked(move |button| {
+            button.clipboard().set_text(&text);
+            set_status(&status_log, format!("copied {label} payload"));
+        });
+    }
+    row.append(&copy_button);
+
+    if let Some(url) = barcode.url() {
+        let open_button = icon_button("arrow-up-right-symbolic", "Open link", control_size, &[]);
+        open_button.set_valign(Align::Center);
+        let url = url.to_string();
+        let status_log = status_log.clone();
+        open_button.connect_clicked(move |_| {
+            match gtk4::gio::AppInfo::launch_default_for_uri(
+                &url,
+                None::<&gtk4::gio::AppLaunchContext>,
+            ) {
+                Ok(()) => set_status(&status_log, format!("opened {url}")),
+                Err(err) => {
+                    set_status(&status_log, format!("open link failed: {err}"));
+                    crate::notification::send(format!("Could not open link: {err}"));
+                }
+            }
+        });
+        row.append(&open_button);
+    }
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::barcode::BarcodeFormat;
+
+    #[test]
+    fn decoded_status_names_single_format_and_counts_several() {
+        let qr = DecodedBarcode {
+            format: BarcodeFormat::QrCode,
+            text: "https://example.com".to_string(),
+        };
+        let ean = DecodedBarcode {
+            format: BarcodeFormat::Ean13,
+            text: "4006381333931".to_string(),
+        };
+
+        assert_eq!(decoded_status(std::slice::from_ref(&qr)), "decoded QR code");
+        assert_eq!(decoded_status(&[qr, ean]), "decoded 2 codes");
+    }
+}
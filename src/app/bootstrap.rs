@@ -1,5 +1,5 @@
 use crate::config::load_app_config;
-use crate::editor::tools::Color;
+use crate::editor::tools::{BlurMode, Color};
 use crate::input::{load_editor_navigation_bindings, EditorNavigationBindings};
 use crate::storage::prune_stale_temp_files;
 use crate::theme::{
@@ -24,7 +24,11 @@ pub(super) struct ResolvedThemeRuntime {
     pub(super) text_input_palette: EditorTextInputPalette,
     pub(super) editor_theme_overrides: EditorThemeOverrides,
     pub(super) editor_tool_option_presets: EditorToolOptionPresets,
-    pub(super) ocr_language: crate::ocr::OcrLanguage,
+    pub(super) ocr_languages: Vec<crate::ocr::OcrLanguage>,
+    pub(super) ocr_installed_languages: Vec<crate::ocr::OcrLanguage>,
+    pub(super) copy_profile: crate::clipboard::CopyProfile,
+    pub(super) redaction_rules: crate::ocr::RedactionRules,
+    pub(super) default_blur_mode: BlurMode,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -164,7 +168,18 @@ pub(super) fn resolve_theme_runtime(
     });
 
     let app_config = load_app_config();
-    let ocr_language = crate::ocr::resolve_ocr_language(app_config.ocr_language.as_deref());
+    let ocr_language_names = app_config
+        .ocr_language
+        .as_ref()
+        .map(crate::config::OcrLanguageConfig::names)
+        .unwrap_or_default();
+    let ocr_languages = crate::ocr::resolve_ocr_languages(&ocr_language_names);
+    let ocr_installed_languages =
+        crate::ocr::installed_languages(&crate::ocr::resolve_model_dirs());
+    let ocr_languages = retain_installed_ocr_languages(&ocr_languages, &ocr_installed_languages);
+    let copy_profile = crate::clipboard::resolve_copy_profile(app_config.copy_profile.as_deref());
+    let redaction_rules = crate::ocr::resolve_redaction_rules(app_config.auto_redact.as_ref());
+    let default_blur_mode = resolve_default_blur_mode(app_config.blur_mode.as_deref());
 
     ResolvedThemeRuntime {
         style_tokens,
@@ -172,10 +187,40 @@ pub(super) fn resolve_theme_runtime(
         text_input_palette,
         editor_theme_overrides,
         editor_tool_option_presets,
-        ocr_language,
+        ocr_languages,
+        ocr_installed_languages,
+        copy_profile,
+        redaction_rules,
+        default_blur_mode,
     }
 }
 
+fn resolve_default_blur_mode(config_value: Option<&str>) -> BlurMode {
+    let Some(value) = config_value else {
+        return BlurMode::default();
+    };
+    BlurMode::from_config_name(value).unwrap_or_else(|| {
+        tracing::warn!(value, "unknown blur_mode in config.json; using gaussian");
+        BlurMode::default()
+    })
+}
+
+fn retain_installed_ocr_languages(
+    languages: &[crate::ocr::OcrLanguage],
+    installed: &[crate::ocr::OcrLanguage],
+) -> Vec<crate::ocr::OcrLanguage> {
+    let retained = crate::ocr::models::retain_installed(languages, installed);
+    for language in languages {
+        if !retained.contains(language) {
+            tracing::warn!(
+                language = language.as_str(),
+                "ocr_language model is not installed; skipping (see `chalkak models list`)"
+            );
+        }
+    }
+    retained
+}
+
 fn prune_stale_capture_temp_files() {
     match prune_stale_temp_files(24) {
         Ok(report) if report.removed_files > 0 => {
@@ -439,6 +484,16 @@ mod tests {
         assert_eq!(parse_hex_rgb("12AB34"), Some(Color::new(0x12, 0xab, 0x34)));
     }
 
+    #[test]
+    fn resolve_default_blur_mode_falls_back_to_gaussian() {
+        assert_eq!(resolve_default_blur_mode(None), BlurMode::Gaussian);
+        assert_eq!(resolve_default_blur_mode(Some("secure")), BlurMode::Secure);
+        assert_eq!(
+            resolve_default_blur_mode(Some("opaque")),
+            BlurMode::Gaussian
+        );
+    }
+
     #[test]
     fn parse_hex_rgb_rejects_invalid_values() {
         assert_eq!(parse_hex_rgb("#fff"), None);
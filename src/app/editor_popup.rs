@@ -1,9 +1,10 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
+use std::sync::Arc;
 
 use crate::capture;
-use crate::editor::tools::{CropElement, ImageBounds, ToolPoint};
+use crate::editor::tools::{CropElement, ImageBounds, LineDash, MagnifierPart, ToolPoint};
 use crate::editor::{self, EditorAction, ToolKind};
 use crate::storage::StorageService;
 use crate::theme::ThemeMode;
@@ -39,7 +40,12 @@ pub(super) enum RectangleHandle {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(super) enum ResizableObjectKind {
     Rectangle,
+    Ellipse,
     Blur,
+    SpotlightHole(usize),
+    MagnifierSource,
+    MagnifierCallout,
+    Image,
     Crop,
 }
 
@@ -49,6 +55,19 @@ pub(super) enum ObjectDragState {
         object_ids: Vec<u64>,
         last: ToolPoint,
     },
+    MoveSpotlightHole {
+        object_id: u64,
+        hole_index: usize,
+        last: ToolPoint,
+    },
+    MoveMagnifierPart {
+        object_id: u64,
+        part: MagnifierPart,
+        last: ToolPoint,
+    },
+    MoveCalloutTail {
+        object_id: u64,
+    },
     ResizeObject {
         object_id: u64,
         kind: ResizableObjectKind,
@@ -86,7 +105,7 @@ impl TextCaretLayout {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(super) struct BlurRenderKey {
     source_width: i32,
     source_height: i32,
@@ -94,7 +113,8 @@ pub(super) struct BlurRenderKey {
     y: i32,
     width: u32,
     height: u32,
-    intensity: u8,
+    options: editor::tools::BlurOptions,
+    noise_seed: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -108,6 +128,17 @@ pub(super) struct BlurRenderCache {
     entries: HashMap<u64, BlurRenderEntry>,
 }
 
+#[derive(Debug, Clone)]
+pub(super) struct ImageRenderEntry {
+    data: Arc<[u8]>,
+    surface: gtk4::cairo::ImageSurface,
+}
+
+#[derive(Debug, Default)]
+pub(super) struct ImageRenderCache {
+    entries: HashMap<u64, ImageRenderEntry>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(super) struct ArrowDrawStyle {
     pub(super) color_r: u8,
@@ -116,6 +147,7 @@ pub(super) struct ArrowDrawStyle {
     pub(super) opacity_percent: u8,
     pub(super) thickness: u8,
     pub(super) head_size: u8,
+    pub(super) dash: LineDash,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -213,6 +245,7 @@ pub(super) struct ToolRenderContext<'a> {
     pub(super) active_text_id: Option<u64>,
     pub(super) active_text_preedit: Option<&'a TextPreeditState>,
     pub(super) blur_cache: Option<&'a Rc<RefCell<BlurRenderCache>>>,
+    pub(super) image_cache: Option<&'a Rc<RefCell<ImageRenderCache>>>,
 }
 
 pub(super) struct EditorOutputActionContext<'a> {
@@ -222,6 +255,7 @@ pub(super) struct EditorOutputActionContext<'a> {
     pub(super) pending_crop: Option<CropElement>,
     pub(super) source_pixbuf: &'a gtk4::gdk_pixbuf::Pixbuf,
     pub(super) storage_service: &'a StorageService,
+    pub(super) copy_profile: crate::clipboard::CopyProfile,
     pub(super) status_log: &'a Rc<RefCell<String>>,
     pub(super) editor_toast: &'a ToastRuntime,
     pub(super) toast_duration_ms: u32,
@@ -1,5 +1,6 @@
 use crate::editor::tools::{
-    CropElement, ImageBounds, RectangleElement, TextElement, ToolBounds, ToolPoint,
+    CalloutElement, CropElement, ImageBounds, ImageElement, MagnifierElement, RectangleElement,
+    TextElement, ToolBounds, ToolPoint,
 };
 use crate::editor::{self, ToolObject};
 use gtk4::prelude::*;
@@ -155,6 +156,33 @@ pub(in crate::app) fn text_dimensions(text: &TextElement) -> (u32, u32) {
     })
 }
 
+pub(in crate::app) fn callout_bubble_bounds(callout: &CalloutElement) -> ToolBounds {
+    callout.bubble_bounds(text_dimensions(&callout.text))
+}
+
+/// The tail tip doubles as the handle that re-aims it.
+pub(in crate::app) fn callout_tail_at_point(callout: &CalloutElement, point: ToolPoint) -> bool {
+    (point.x - callout.tail.x).abs() <= 8 && (point.y - callout.tail.y).abs() <= 8
+}
+
+fn point_list_bounds(points: &[ToolPoint]) -> Option<(i32, i32, u32, u32)> {
+    let first = points.first()?;
+    let (min_x, min_y, max_x, max_y) = points.iter().fold(
+        (first.x, first.y, first.x, first.y),
+        |(min_x, min_y, max_x, max_y), point| {
+            (
+                min_x.min(point.x),
+                min_y.min(point.y),
+                max_x.max(point.x),
+                max_y.max(point.y),
+            )
+        },
+    );
+    let width = u32::try_from(max_x.saturating_sub(min_x).saturating_add(1)).ok()?;
+    let height = u32::try_from(max_y.saturating_sub(min_y).saturating_add(1)).ok()?;
+    Some((min_x, min_y, width, height))
+}
+
 pub(in crate::app) fn object_bounds(object: &ToolObject) -> Option<(i32, i32, u32, u32)> {
     match object {
         ToolObject::Blur(blur) => Some((
@@ -188,9 +216,64 @@ pub(in crate::app) fn object_bounds(object: &ToolObject) -> Option<(i32, i32, u3
             let height = u32::try_from(max_y.saturating_sub(min_y).saturating_add(1)).ok()?;
             Some((min_x, min_y, width, height))
         }
+        ToolObject::Highlighter(stroke) => {
+            // Include the nib so the wide marker band is selectable, not
+            // just its centre line.
+            let (x, y, width, height) = point_list_bounds(&stroke.points)?;
+            let half = stroke.options.thickness / 2;
+            let grow = u32::from(half) * 2;
+            Some((
+                x.saturating_sub(i32::from(half)),
+                y.saturating_sub(i32::from(half)),
+                width.saturating_add(grow),
+                height.saturating_add(grow),
+            ))
+        }
+        ToolObject::Line(line) => point_list_bounds(&line.points),
         ToolObject::Rectangle(rectangle) => {
             Some((rectangle.x, rectangle.y, rectangle.width, rectangle.height))
         }
+        ToolObject::Ellipse(ellipse) => Some((ellipse.x, ellipse.y, ellipse.width, ellipse.height)),
+        ToolObject::Badge(badge) => {
+            let radius = i32::from(badge.options.size / 2);
+            let diameter = u32::from(badge.options.size / 2) * 2 + 1;
+            Some((
+                badge.center.x.saturating_sub(radius),
+                badge.center.y.saturating_sub(radius),
+                diameter,
+                diameter,
+            ))
+        }
+        ToolObject::Spotlight(spotlight) => spotlight
+            .bounds()
+            .map(|bounds| (bounds.x, bounds.y, bounds.width, bounds.height)),
+        ToolObject::Magnifier(magnifier) => {
+            let bounds = magnifier.bounds();
+            Some((bounds.x, bounds.y, bounds.width, bounds.height))
+        }
+        ToolObject::Callout(callout) => {
+            let bubble = callout_bubble_bounds(callout);
+            let right = bubble
+                .x
+                .saturating_add(i32::try_from(bubble.width).unwrap_or(i32::MAX))
+                .max(callout.tail.x);
+            let bottom = bubble
+                .y
+                .saturating_add(i32::try_from(bubble.height).unwrap_or(i32::MAX))
+                .max(callout.tail.y);
+            let left = bubble.x.min(callout.tail.x);
+            let top = bubble.y.min(callout.tail.y);
+            Some((
+                left,
+                top,
+                u32::try_from(right.saturating_sub(left)).ok()?,
+                u32::try_from(bottom.saturating_sub(top)).ok()?,
+            ))
+        }
+        ToolObject::Image(image) => {
+            let bounds = image.bounds();
+            Some((bounds.x, bounds.y, bounds.width, bounds.height))
+        }
         ToolObject::Crop(crop) => Some((crop.x, crop.y, crop.width, crop.height)),
         ToolObject::Text(text) => {
             let (width, height) = text_dimensions(text);
@@ -199,40 +282,37 @@ pub(in crate::app) fn object_bounds(object: &ToolObject) -> Option<(i32, i32, u3
     }
 }
 
-pub(in crate::app) const fn object_is_blur(object: &ToolObject) -> bool {
-    matches!(object, ToolObject::Blur(_))
+/// Blurs sit directly on the image and the spotlight dims it, so both stay
+/// below the annotations whenever they were added.
+const fn object_layer(object: &ToolObject) -> u8 {
+    match object {
+        ToolObject::Blur(_) => 0,
+        ToolObject::Spotlight(_) => 1,
+        _ => 2,
+    }
 }
 
 pub(in crate::app) fn objects_in_draw_order<'a>(
     tools: &'a editor::EditorTools,
 ) -> impl Iterator<Item = &'a ToolObject> + 'a {
-    tools
-        .objects()
-        .iter()
-        .filter(|object| object_is_blur(object))
-        .chain(
-            tools
-                .objects()
-                .iter()
-                .filter(|object| !object_is_blur(object)),
-        )
+    (0..=2).flat_map(move |layer| {
+        tools
+            .objects()
+            .iter()
+            .filter(move |object| object_layer(object) == layer)
+    })
 }
 
 pub(in crate::app) fn objects_in_hit_test_order<'a>(
     tools: &'a editor::EditorTools,
 ) -> impl Iterator<Item = &'a ToolObject> + 'a {
-    tools
-        .objects()
-        .iter()
-        .rev()
-        .filter(|object| !object_is_blur(object))
-        .chain(
-            tools
-                .objects()
-                .iter()
-                .rev()
-                .filter(|object| object_is_blur(object)),
-        )
+    (0..=2).rev().flat_map(move |layer| {
+        tools
+            .objects()
+            .iter()
+            .rev()
+            .filter(move |object| object_layer(object) == layer)
+    })
 }
 
 pub(in crate::app) fn point_in_bounds(
@@ -252,18 +332,37 @@ pub(in crate::app) fn point_in_bounds(
     point.x >= left && point.x <= right && point.y >= top && point.y <= bottom
 }
 
+/// A magnifier only reacts on its two boxes, a callout on its bubble and
+/// tail tip, and a rotated image on its own frame, so the empty space they
+/// span does not cover the annotations underneath.
+fn object_hit_at_point(object: &ToolObject, point: ToolPoint) -> bool {
+    match object {
+        ToolObject::Magnifier(magnifier) => {
+            return [magnifier.source_bounds(), magnifier.callout_bounds()]
+                .into_iter()
+                .any(|bounds| {
+                    point_in_bounds(point, bounds.x, bounds.y, bounds.width, bounds.height, 4)
+                });
+        }
+        ToolObject::Callout(callout) => {
+            let bubble = callout_bubble_bounds(callout);
+            return callout_tail_at_point(callout, point)
+                || point_in_bounds(point, bubble.x, bubble.y, bubble.width, bubble.height, 4);
+        }
+        ToolObject::Image(image) => return image.contains(point),
+        _ => {}
+    }
+    object_bounds(object)
+        .is_some_and(|(x, y, width, height)| point_in_bounds(point, x, y, width, height, 4))
+}
+
 pub(in crate::app) fn top_object_id_at_point(
     tools: &editor::EditorTools,
     point: ToolPoint,
 ) -> Option<u64> {
-    for object in objects_in_hit_test_order(tools) {
-        if let Some((x, y, width, height)) = object_bounds(object) {
-            if point_in_bounds(point, x, y, width, height, 4) {
-                return Some(object.id());
-            }
-        }
-    }
-    None
+    objects_in_hit_test_order(tools)
+        .find(|object| object_hit_at_point(object, point))
+        .map(ToolObject::id)
 }
 
 pub(in crate::app) fn bounds_intersect(a: ToolBounds, b: ToolBounds) -> bool {
@@ -315,11 +414,20 @@ pub(in crate::app) fn top_text_id_at_point(
     point: ToolPoint,
 ) -> Option<u64> {
     for object in objects_in_hit_test_order(tools) {
-        if let ToolObject::Text(text) = object {
-            let (width, height) = text_dimensions(text);
-            if point_in_bounds(point, text.x, text.y, width, height, 4) {
-                return Some(text.id);
+        match object {
+            ToolObject::Text(text) => {
+                let (width, height) = text_dimensions(text);
+                if point_in_bounds(point, text.x, text.y, width, height, 4) {
+                    return Some(text.id);
+                }
             }
+            ToolObject::Callout(callout) => {
+                let bubble = callout_bubble_bounds(callout);
+                if point_in_bounds(point, bubble.x, bubble.y, bubble.width, bubble.height, 0) {
+                    return Some(callout.id);
+                }
+            }
+            _ => {}
         }
     }
     None
@@ -385,6 +493,10 @@ pub(in crate::app) fn resizable_object_handle_at_point(
     match object {
         ToolObject::Rectangle(rectangle) => rectangle_handle_at_point(rectangle, point)
             .map(|handle| (ResizableObjectKind::Rectangle, handle)),
+        ToolObject::Ellipse(ellipse) => {
+            handle_at_point_for_bounds(ellipse.x, ellipse.y, ellipse.width, ellipse.height, point)
+                .map(|handle| (ResizableObjectKind::Ellipse, handle))
+        }
         ToolObject::Blur(blur) => handle_at_point_for_bounds(
             blur.region.x,
             blur.region.y,
@@ -393,6 +505,38 @@ pub(in crate::app) fn resizable_object_handle_at_point(
             point,
         )
         .map(|handle| (ResizableObjectKind::Blur, handle)),
+        ToolObject::Spotlight(spotlight) => {
+            spotlight
+                .holes
+                .iter()
+                .enumerate()
+                .rev()
+                .find_map(|(index, hole)| {
+                    handle_at_point_for_bounds(hole.x, hole.y, hole.width, hole.height, point)
+                        .map(|handle| (ResizableObjectKind::SpotlightHole(index), handle))
+                })
+        }
+        ToolObject::Magnifier(magnifier) => {
+            let callout = magnifier.callout_bounds();
+            let source = magnifier.source_bounds();
+            handle_at_point_for_bounds(callout.x, callout.y, callout.width, callout.height, point)
+                .map(|handle| (ResizableObjectKind::MagnifierCallout, handle))
+                .or_else(|| {
+                    handle_at_point_for_bounds(
+                        source.x,
+                        source.y,
+                        source.width,
+                        source.height,
+                        point,
+                    )
+                    .map(|handle| (ResizableObjectKind::MagnifierSource, handle))
+                })
+        }
+        ToolObject::Image(image) => {
+            let bounds = image.bounds();
+            handle_at_point_for_bounds(bounds.x, bounds.y, bounds.width, bounds.height, point)
+                .map(|handle| (ResizableObjectKind::Image, handle))
+        }
         ToolObject::Crop(crop) => {
             handle_at_point_for_bounds(crop.x, crop.y, crop.width, crop.height, point)
                 .map(|handle| (ResizableObjectKind::Crop, handle))
@@ -516,6 +660,67 @@ pub(in crate::app) fn resized_crop_from_handle(
     Some(CropElement::new(crop.id, x, y, width, height, crop.options))
 }
 
+/// Dragging a callout corner changes the zoom: the callout keeps the source
+/// aspect and its opposite corner stays put.
+pub(in crate::app) fn resized_magnifier_callout_from_handle(
+    magnifier: &MagnifierElement,
+    handle: RectangleHandle,
+    point: ToolPoint,
+    image_bounds: ImageBounds,
+) -> Option<(ToolPoint, u16)> {
+    let callout = magnifier.callout_bounds();
+    let (anchor_x, anchor_y) = opposite_corner(callout, handle)?;
+    let raw = resized_bounds_from_handle(callout, handle, point, image_bounds)?;
+    let zoom_percent = magnifier.zoom_for_callout_size(raw.width, raw.height);
+    let (width, height) = magnifier.callout_size_at(zoom_percent);
+    let width = i32::try_from(width).ok()?;
+    let height = i32::try_from(height).ok()?;
+    let origin = match handle {
+        RectangleHandle::TopLeft => ToolPoint::new(
+            anchor_x.saturating_sub(width),
+            anchor_y.saturating_sub(height),
+        ),
+        RectangleHandle::TopRight => ToolPoint::new(anchor_x, anchor_y.saturating_sub(height)),
+        RectangleHandle::BottomLeft => ToolPoint::new(anchor_x.saturating_sub(width), anchor_y),
+        RectangleHandle::BottomRight => ToolPoint::new(anchor_x, anchor_y),
+    };
+    Some((origin, zoom_percent))
+}
+
+/// Image handles sit on the rotated outline's box. Unless the aspect is
+/// unlocked at a quarter turn the image scales uniformly, so the dragged
+/// box keeps its ratio and stays pinned at the opposite corner.
+pub(in crate::app) fn resized_image_from_handle(
+    image: &ImageElement,
+    handle: RectangleHandle,
+    point: ToolPoint,
+    image_bounds: ImageBounds,
+) -> Option<ToolBounds> {
+    let bounds = image.bounds();
+    let raw = resized_bounds_from_handle(bounds, handle, point, image_bounds)?;
+    let quarter_turn = image.options.rotation % 90 == 0;
+    if !image.options.lock_aspect && quarter_turn {
+        return Some(raw);
+    }
+    let (anchor_x, anchor_y) = opposite_corner(bounds, handle)?;
+    let (width, height) = adjust_ratio_to_fit(raw.width, raw.height, bounds.width, bounds.height);
+    if width < editor::tools::IMAGE_MIN_SIZE || height < editor::tools::IMAGE_MIN_SIZE {
+        return None;
+    }
+    let width_i32 = i32::try_from(width).ok()?;
+    let height_i32 = i32::try_from(height).ok()?;
+    let (x, y) = match handle {
+        RectangleHandle::TopLeft => (
+            anchor_x.saturating_sub(width_i32),
+            anchor_y.saturating_sub(height_i32),
+        ),
+        RectangleHandle::TopRight => (anchor_x, anchor_y.saturating_sub(height_i32)),
+        RectangleHandle::BottomLeft => (anchor_x.saturating_sub(width_i32), anchor_y),
+        RectangleHandle::BottomRight => (anchor_x, anchor_y),
+    };
+    Some(ToolBounds::new(x, y, width, height))
+}
+
 pub(in crate::app) fn resize_object_from_handle(
     tools: &mut editor::EditorTools,
     object_id: u64,
@@ -542,6 +747,23 @@ pub(in crate::app) fn resize_object_from_handle(
                 .resize_rectangle(object_id, bounds, image_bounds)
                 .is_ok()
         }
+        ResizableObjectKind::Ellipse => {
+            let ellipse = match tools.object(object_id) {
+                Some(ToolObject::Ellipse(ellipse)) => *ellipse,
+                _ => return false,
+            };
+            let Some(bounds) = resized_bounds_from_handle(
+                ToolBounds::new(ellipse.x, ellipse.y, ellipse.width, ellipse.height),
+                handle,
+                point,
+                image_bounds,
+            ) else {
+                return false;
+            };
+            tools
+                .resize_ellipse(object_id, bounds, image_bounds)
+                .is_ok()
+        }
         ResizableObjectKind::Blur => {
             let blur = match tools.object(object_id) {
                 Some(ToolObject::Blur(blur)) => blur.clone(),
@@ -562,6 +784,58 @@ pub(in crate::app) fn resize_object_from_handle(
             };
             tools.resize_blur(object_id, bounds, image_bounds).is_ok()
         }
+        ResizableObjectKind::SpotlightHole(hole_index) => {
+            let hole = match tools.object(object_id) {
+                Some(ToolObject::Spotlight(spotlight)) => spotlight.holes.get(hole_index).copied(),
+                _ => None,
+            };
+            let Some(bounds) = hole.and_then(|hole| {
+                resized_bounds_from_handle(hole.bounds(), handle, point, image_bounds)
+            }) else {
+                return false;
+            };
+            tools
+                .resize_spotlight_hole(object_id, hole_index, bounds, image_bounds)
+                .is_ok()
+        }
+        ResizableObjectKind::MagnifierSource => {
+            let source = match tools.object(object_id) {
+                Some(ToolObject::Magnifier(magnifier)) => magnifier.source_bounds(),
+                _ => return false,
+            };
+            let Some(bounds) = resized_bounds_from_handle(source, handle, point, image_bounds)
+            else {
+                return false;
+            };
+            tools
+                .resize_magnifier_source(object_id, bounds, image_bounds)
+                .is_ok()
+        }
+        ResizableObjectKind::MagnifierCallout => {
+            let magnifier = match tools.object(object_id) {
+                Some(ToolObject::Magnifier(magnifier)) => *magnifier,
+                _ => return false,
+            };
+            let Some((origin, zoom_percent)) =
+                resized_magnifier_callout_from_handle(&magnifier, handle, point, image_bounds)
+            else {
+                return false;
+            };
+            tools
+                .resize_magnifier_callout(object_id, origin, zoom_percent, image_bounds)
+                .is_ok()
+        }
+        ResizableObjectKind::Image => {
+            let image = match tools.object(object_id) {
+                Some(ToolObject::Image(image)) => image.clone(),
+                _ => return false,
+            };
+            let Some(bounds) = resized_image_from_handle(&image, handle, point, image_bounds)
+            else {
+                return false;
+            };
+            tools.resize_image(object_id, bounds).is_ok()
+        }
         ResizableObjectKind::Crop => {
             let crop = match tools.object(object_id) {
                 Some(ToolObject::Crop(crop)) => *crop,
@@ -609,7 +883,12 @@ pub(in crate::app) fn draw_resize_handles_for_bounds(
 pub(in crate::app) const fn resize_status_label(kind: ResizableObjectKind) -> &'static str {
     match kind {
         ResizableObjectKind::Rectangle => "rectangle resized",
+        ResizableObjectKind::Ellipse => "ellipse resized",
         ResizableObjectKind::Blur => "blur region resized",
+        ResizableObjectKind::SpotlightHole(_) => "spotlight hole resized",
+        ResizableObjectKind::MagnifierSource => "magnifier source resized",
+        ResizableObjectKind::MagnifierCallout => "magnifier zoom changed",
+        ResizableObjectKind::Image => "image resized",
         ResizableObjectKind::Crop => "crop frame resized",
     }
 }
@@ -712,4 +991,191 @@ mod tests {
             vec![blur_id, text_id]
         );
     }
+
+    #[test]
+    fn spotlight_draws_between_blurs_and_annotations() {
+        let mut tools = editor::EditorTools::new();
+        let arrow_id = tools
+            .add_arrow(ToolPoint::new(0, 0), ToolPoint::new(30, 30))
+            .expect("arrow should be inserted");
+        let spotlight_id = tools
+            .add_spotlight_hole(ToolPoint::new(10, 10), ToolPoint::new(60, 60))
+            .expect("spotlight should be inserted");
+        let blur_id = tools
+            .add_blur(editor::tools::BlurRegion::new(40, 40, 10, 10))
+            .expect("blur should be inserted");
+
+        assert_eq!(
+            objects_in_draw_order(&tools)
+                .map(ToolObject::id)
+                .collect::<Vec<_>>(),
+            vec![blur_id, spotlight_id, arrow_id]
+        );
+        assert_eq!(
+            top_object_id_at_point(&tools, ToolPoint::new(20, 20)),
+            Some(arrow_id)
+        );
+        assert_eq!(
+            top_object_id_at_point(&tools, ToolPoint::new(45, 45)),
+            Some(spotlight_id)
+        );
+    }
+
+    #[test]
+    fn callout_hits_its_bubble_and_tail_tip_but_not_the_gap() {
+        let mut tools = editor::EditorTools::new();
+        let callout_id = tools.add_callout(ToolPoint::new(20, 200), ToolPoint::new(100, 40));
+        let callout = match tools.object(callout_id) {
+            Some(ToolObject::Callout(callout)) => callout.clone(),
+            _ => panic!("callout should exist"),
+        };
+        let bubble = callout_bubble_bounds(&callout);
+        assert_eq!((bubble.x, bubble.y), (90, 30));
+
+        assert_eq!(
+            top_object_id_at_point(&tools, ToolPoint::new(95, 35)),
+            Some(callout_id)
+        );
+        assert_eq!(
+            top_object_id_at_point(&tools, ToolPoint::new(25, 195)),
+            Some(callout_id)
+        );
+        assert_eq!(top_object_id_at_point(&tools, ToolPoint::new(40, 60)), None);
+        assert_eq!(
+            top_text_id_at_point(&tools, ToolPoint::new(95, 35)),
+            Some(callout_id)
+        );
+        assert_eq!(top_text_id_at_point(&tools, ToolPoint::new(25, 195)), None);
+    }
+
+    #[test]
+    fn spotlight_handles_resolve_to_their_hole() {
+        let mut tools = editor::EditorTools::new();
+        let spotlight_id = tools
+            .add_spotlight_hole(ToolPoint::new(10, 10), ToolPoint::new(40, 40))
+            .expect("spotlight should be inserted");
+        tools
+            .add_spotlight_hole(ToolPoint::new(60, 10), ToolPoint::new(90, 40))
+            .expect("hole should be added");
+        let spotlight = tools.object(spotlight_id).expect("spotlight should exist");
+
+        assert_eq!(
+            resizable_object_handle_at_point(spotlight, ToolPoint::new(91, 41)),
+            Some((
+                ResizableObjectKind::SpotlightHole(1),
+                RectangleHandle::BottomRight
+            ))
+        );
+        assert_eq!(
+            resizable_object_handle_at_point(spotlight, ToolPoint::new(10, 10)),
+            Some((
+                ResizableObjectKind::SpotlightHole(0),
+                RectangleHandle::TopLeft
+            ))
+        );
+        assert_eq!(
+            resizable_object_handle_at_point(spotlight, ToolPoint::new(50, 25)),
+            None
+        );
+    }
+
+    #[test]
+    fn magnifier_hits_its_boxes_and_zooms_from_callout_corners() {
+        let mut tools = editor::EditorTools::new();
+        let image = ImageBounds::new(400, 300);
+        tools.set_magnifier_shape(editor::tools::MagnifierShape::Rectangle);
+        let magnifier_id = tools
+            .add_magnifier(ToolPoint::new(20, 100), ToolPoint::new(60, 140), image)
+            .expect("magnifier should be inserted");
+        let magnifier = match tools.object(magnifier_id) {
+            Some(ToolObject::Magnifier(magnifier)) => *magnifier,
+            _ => panic!("magnifier should exist"),
+        };
+        assert_eq!(magnifier.callout_bounds(), ToolBounds::new(84, 80, 80, 80));
+
+        assert_eq!(
+            top_object_id_at_point(&tools, ToolPoint::new(100, 100)),
+            Some(magnifier_id)
+        );
+        assert_eq!(
+            top_object_id_at_point(&tools, ToolPoint::new(72, 60)),
+            None,
+            "the gap between source and callout stays click-through"
+        );
+        assert_eq!(
+            resizable_object_handle_at_point(
+                tools.object(magnifier_id).expect("magnifier should exist"),
+                ToolPoint::new(164, 160)
+            ),
+            Some((
+                ResizableObjectKind::MagnifierCallout,
+                RectangleHandle::BottomRight
+            ))
+        );
+
+        assert_eq!(
+            resized_magnifier_callout_from_handle(
+                &magnifier,
+                RectangleHandle::TopLeft,
+                ToolPoint::new(40, 50),
+                image
+            ),
+            Some((ToolPoint::new(40, 36), 310))
+        );
+    }
+
+    #[test]
+    fn image_hits_its_rotated_frame_and_resizes_from_the_outline_corners() {
+        let mut tools = editor::EditorTools::new();
+        let image = ImageBounds::new(400, 300);
+        tools.set_image_rotation(45);
+        let image_id = tools
+            .add_stamp(ToolPoint::new(100, 100), ToolPoint::new(200, 200), image)
+            .expect("stamp should be placed");
+        let element = match tools.object(image_id) {
+            Some(ToolObject::Image(element)) => element.clone(),
+            _ => panic!("image should exist"),
+        };
+        let outline = element.bounds();
+        assert_eq!(
+            object_bounds(tools.object(image_id).expect("image should exist")),
+            Some((outline.x, outline.y, outline.width, outline.height))
+        );
+
+        assert_eq!(
+            top_object_id_at_point(&tools, ToolPoint::new(150, 150)),
+            Some(image_id)
+        );
+        assert_eq!(
+            top_object_id_at_point(&tools, ToolPoint::new(outline.x + 2, outline.y + 2)),
+            None,
+            "the corners of the outline box outside the tilted frame stay click-through"
+        );
+        assert_eq!(
+            resizable_object_handle_at_point(
+                tools.object(image_id).expect("image should exist"),
+                ToolPoint::new(outline.x, outline.y)
+            ),
+            Some((ResizableObjectKind::Image, RectangleHandle::TopLeft))
+        );
+
+        let resized = resized_image_from_handle(
+            &element,
+            RectangleHandle::BottomRight,
+            ToolPoint::new(outline.x + 71, outline.y + 200),
+            image,
+        )
+        .expect("image should resize");
+        assert_eq!((resized.x, resized.y), (outline.x, outline.y));
+        assert_eq!(resized.width, resized.height);
+        assert!(resize_object_from_handle(
+            &mut tools,
+            image_id,
+            ResizableObjectKind::Image,
+            RectangleHandle::BottomRight,
+            ToolPoint::new(outline.x + 71, outline.y + 200),
+            400,
+            300,
+        ));
+    }
 }
@@ -23,6 +23,78 @@ pub(in crate::app) fn blur_region_for_preview(region: &RgbaImage, sigma: f32) ->
     imageops::resize(&blurred, width, height, imageops::FilterType::Triangle)
 }
 
+/// Replaces each `block_size` square (anchored at the region's top-left) with
+/// its average color.
+pub(in crate::app) fn pixelate_region(region: &RgbaImage, block_size: u32) -> RgbaImage {
+    let block_size = block_size.max(1);
+    let mut pixelated = region.clone();
+    for block_y in (0..region.height()).step_by(block_size as usize) {
+        for block_x in (0..region.width()).step_by(block_size as usize) {
+            let block_width = block_size.min(region.width() - block_x);
+            let block_height = block_size.min(region.height() - block_y);
+            let mut sums = [0_u64; 4];
+            for y in block_y..block_y + block_height {
+                for x in block_x..block_x + block_width {
+                    let pixel = region.get_pixel(x, y);
+                    for (sum, channel) in sums.iter_mut().zip(pixel.0) {
+                        *sum += u64::from(channel);
+                    }
+                }
+            }
+            let count = u64::from(block_width) * u64::from(block_height);
+            let average = image::Rgba(sums.map(|sum| (sum / count) as u8));
+            for y in block_y..block_y + block_height {
+                for x in block_x..block_x + block_width {
+                    pixelated.put_pixel(x, y, average);
+                }
+            }
+        }
+    }
+    pixelated
+}
+
+pub(in crate::app) fn solid_fill_region(
+    width: u32,
+    height: u32,
+    (r, g, b): (u8, u8, u8),
+) -> RgbaImage {
+    RgbaImage::from_pixel(width, height, image::Rgba([r, g, b, 255]))
+}
+
+const SECURE_BLOCK_NOISE: i16 = 48;
+const SECURE_GRAIN_NOISE: i16 = 20;
+
+/// Perturbs an already pixelated region: every block gets its own color
+/// shift and every pixel some grain, both derived from `seed`.
+pub(in crate::app) fn add_secure_noise(region: &mut RgbaImage, block_size: u32, seed: u64) {
+    let block_size = block_size.max(1);
+    for (x, y, pixel) in region.enumerate_pixels_mut() {
+        let (block_x, block_y) = (x / block_size, y / block_size);
+        let grain = signed_noise(noise_hash(seed, x, y, 3), SECURE_GRAIN_NOISE);
+        for (channel, value) in pixel.0.iter_mut().take(3).enumerate() {
+            let shift = signed_noise(
+                noise_hash(seed, block_x, block_y, channel as u32),
+                SECURE_BLOCK_NOISE,
+            );
+            *value = (i16::from(*value) + shift + grain).clamp(0, 255) as u8;
+        }
+    }
+}
+
+fn noise_hash(seed: u64, a: u32, b: u32, salt: u32) -> u64 {
+    let mut value = seed
+        ^ ((u64::from(a) << 32) | u64::from(b)).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ u64::from(salt).wrapping_mul(0xD1B5_4A32_D192_ED03);
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    value ^ (value >> 31)
+}
+
+fn signed_noise(hash: u64, amplitude: i16) -> i16 {
+    let span = u64::from(amplitude.unsigned_abs()) * 2 + 1;
+    (hash % span) as i16 - amplitude
+}
+
 pub(in crate::app) fn bounded_region_for_blur(
     x: i32,
     y: i32,
@@ -210,4 +282,45 @@ mod tests {
         let blurred = blur_region_for_preview(&region, 11.0);
         assert_eq!(blurred.dimensions(), region.dimensions());
     }
+
+    #[test]
+    fn pixelate_region_averages_each_block() {
+        let mut region = RgbaImage::new(4, 2);
+        region.put_pixel(0, 0, image::Rgba([0, 0, 0, 255]));
+        region.put_pixel(1, 0, image::Rgba([100, 40, 20, 255]));
+        region.put_pixel(0, 1, image::Rgba([100, 40, 20, 255]));
+        region.put_pixel(1, 1, image::Rgba([0, 0, 0, 255]));
+        for x in 2..4 {
+            for y in 0..2 {
+                region.put_pixel(x, y, image::Rgba([200, 200, 200, 255]));
+            }
+        }
+        let pixelated = pixelate_region(&region, 2);
+        assert_eq!(pixelated.get_pixel(1, 1).0, [50, 20, 10, 255]);
+        assert_eq!(pixelated.get_pixel(0, 0).0, [50, 20, 10, 255]);
+        assert_eq!(pixelated.get_pixel(3, 0).0, [200, 200, 200, 255]);
+    }
+
+    #[test]
+    fn pixelate_region_handles_partial_edge_blocks() {
+        let region = RgbaImage::from_pixel(5, 3, image::Rgba([9, 8, 7, 255]));
+        let pixelated = pixelate_region(&region, 4);
+        assert_eq!(pixelated.dimensions(), (5, 3));
+        assert_eq!(pixelated.get_pixel(4, 2).0, [9, 8, 7, 255]);
+    }
+
+    #[test]
+    fn secure_noise_is_deterministic_per_seed() {
+        let region = RgbaImage::from_pixel(24, 24, image::Rgba([128, 128, 128, 255]));
+        let mut first = region.clone();
+        let mut second = region.clone();
+        let mut other_seed = region.clone();
+        add_secure_noise(&mut first, 8, 42);
+        add_secure_noise(&mut second, 8, 42);
+        add_secure_noise(&mut other_seed, 8, 43);
+        assert_eq!(first, second);
+        assert_ne!(first, other_seed);
+        assert_ne!(first, region);
+        assert!(first.pixels().all(|pixel| pixel.0[3] == 255));
+    }
 }
@@ -11,11 +11,20 @@ pub(in crate::app) fn tool_kind_label(tool: ToolKind) -> &'static str {
         ToolKind::Pan => "Pan",
         ToolKind::Blur => "Blur",
         ToolKind::Pen => "Pen",
+        ToolKind::Highlighter => "Highlighter",
         ToolKind::Arrow => "Arrow",
+        ToolKind::Line => "Line",
         ToolKind::Rectangle => "Rect",
+        ToolKind::Ellipse => "Ellipse",
+        ToolKind::Badge => "Badge",
+        ToolKind::Spotlight => "Spotlight",
+        ToolKind::Magnifier => "Magnifier",
+        ToolKind::Callout => "Callout",
+        ToolKind::Image => "Image",
         ToolKind::Crop => "Crop",
         ToolKind::Text => "Text",
         ToolKind::Ocr => "OCR",
+        ToolKind::Decode => "Decode",
     }
 }
 
@@ -41,6 +41,7 @@ pub(in crate::app) fn render_editor_output_png(
             active_text_id: None,
             active_text_preedit: None,
             blur_cache: None,
+            image_cache: None,
         },
     );
 
@@ -94,7 +95,7 @@ pub(in crate::app) fn render_editor_output_png(
 fn action_metadata(action: EditorAction) -> Option<(&'static str, &'static str)> {
     match action {
         EditorAction::Save => Some(("save", "Save")),
-        EditorAction::Copy => Some(("copy", "Copy")),
+        EditorAction::Copy | EditorAction::CopyAs(_) => Some(("copy", "Copy")),
         EditorAction::CloseRequested => None,
     }
 }
@@ -136,7 +137,7 @@ pub(in crate::app) fn execute_editor_output_action(ctx: EditorOutputActionContex
         ctx.active_capture,
         ctx.action,
         ctx.storage_service,
-        &WlCopyBackend,
+        &WlCopyBackend::with_profile(ctx.copy_profile),
     ) {
         Ok(EditorEvent::Save { capture_id }) if ctx.action == EditorAction::Save => {
             *ctx.editor_has_unsaved_changes.borrow_mut() = false;
@@ -144,7 +145,9 @@ pub(in crate::app) fn execute_editor_output_action(ctx: EditorOutputActionContex
             crate::notification::send(format!("Saved {capture_id}"));
             true
         }
-        Ok(EditorEvent::Copy { capture_id }) if ctx.action == EditorAction::Copy => {
+        Ok(EditorEvent::Copy { capture_id })
+            if matches!(ctx.action, EditorAction::Copy | EditorAction::CopyAs(_)) =>
+        {
             *ctx.status_log.borrow_mut() = format!("editor copied capture {capture_id}");
             crate::notification::send(format!("Copied {capture_id}"));
             true
@@ -1,33 +1,52 @@
 use std::cell::RefCell;
 use std::collections::HashSet;
 use std::rc::Rc;
+use std::sync::Arc;
 
 use crate::editor;
+use crate::editor::tools::{
+    BadgeOptions, BlurMode, BlurOptions, CalloutElement, EmbeddedImage, HighlighterStroke,
+    ImageBounds, ImageElement, ImageSource, LineCap, LineDash, LineOptions, MagnifierElement,
+    MagnifierShape, PenStroke, SpotlightElement, SpotlightHole, SpotlightShape, Stamp, TextElement,
+    ToolBounds, ToolPoint, CALLOUT_CORNER_RADIUS,
+};
 use crate::editor::{ToolKind, ToolObject};
-use image::imageops;
+use gtk4::gdk::prelude::GdkCairoContextExt;
+use image::{imageops, RgbaImage};
 
 use super::image_processing::{
-    blur_region_for_preview, bounded_region_for_blur, pixbuf_region_to_rgba_image,
-    rgba_image_to_cairo_surface,
+    add_secure_noise, blur_region_for_preview, bounded_region_for_blur,
+    pixbuf_region_to_rgba_image, pixelate_region, rgba_image_to_cairo_surface, solid_fill_region,
 };
 use super::{
-    adjust_ratio_to_fit, draw_resize_handles_for_bounds, is_object_selected, normalize_tool_box,
-    object_bounds, objects_in_draw_order, text_baseline_y, text_line_height, text_lines_for_render,
-    ArrowDrawStyle, BlurRenderCache, BlurRenderEntry, BlurRenderKey, EditorSelectionPalette,
-    RgbaColor, TextCaretLayout, ToolDragPreview, ToolRenderContext,
+    adjust_ratio_to_fit, callout_bubble_bounds, draw_resize_handles_for_bounds, is_object_selected,
+    normalize_tool_box, object_bounds, objects_in_draw_order, text_baseline_y, text_line_height,
+    text_lines_for_render, ArrowDrawStyle, BlurRenderCache, BlurRenderEntry, BlurRenderKey,
+    EditorSelectionPalette, ImageRenderCache, ImageRenderEntry, RgbaColor, TextCaretLayout,
+    ToolDragPreview, ToolRenderContext,
 };
 
+/// Gaussian strength of the backdrop behind a spotlight with blur enabled.
+const SPOTLIGHT_BLUR_INTENSITY: u8 = 45;
+
+type RedactedSurface = (gtk4::cairo::ImageSurface, i32, i32, u32, u32);
+
 impl BlurRenderCache {
-    fn surface_for_blur(
+    /// Cached redaction of `region` keyed by the owning object; blurs and
+    /// blurred spotlight backdrops share the cache.
+    fn surface_for_region(
         &mut self,
+        object_id: u64,
         source_pixbuf: &gtk4::gdk_pixbuf::Pixbuf,
-        blur: &editor::tools::BlurElement,
-    ) -> Option<(gtk4::cairo::ImageSurface, i32, i32, u32, u32)> {
+        region: (i32, i32, u32, u32),
+        options: BlurOptions,
+        noise_seed: u64,
+    ) -> Option<RedactedSurface> {
         let (x, y, width, height) = bounded_region_for_blur(
-            blur.region.x,
-            blur.region.y,
-            blur.region.width,
-            blur.region.height,
+            region.0,
+            region.1,
+            region.2,
+            region.3,
             source_pixbuf.width(),
             source_pixbuf.height(),
         )?;
@@ -38,20 +57,20 @@ impl BlurRenderCache {
             y,
             width,
             height,
-            intensity: blur.options.intensity,
+            options,
+            noise_seed,
         };
-        if let Some(entry) = self.entries.get(&blur.id) {
+        if let Some(entry) = self.entries.get(&object_id) {
             if entry.key == key {
                 return Some((entry.surface.clone(), x, y, width, height));
             }
         }
 
         let region = pixbuf_region_to_rgba_image(source_pixbuf, x, y, width, height)?;
-        let sigma = blur_sigma_from_intensity(blur.options.intensity);
-        let blurred = blur_region_for_preview(&region, sigma);
+        let blurred = redact_region(&region, options, noise_seed, blur_region_for_preview);
         let surface = rgba_image_to_cairo_surface(&blurred)?;
         self.entries.insert(
-            blur.id,
+            object_id,
             BlurRenderEntry {
                 key,
                 surface: surface.clone(),
@@ -67,6 +86,42 @@ impl BlurRenderCache {
     }
 }
 
+impl ImageRenderCache {
+    /// Decoded picture for an image object, reused for as long as the object
+    /// carries the same encoded bytes.
+    fn surface_for_picture(
+        &mut self,
+        object_id: u64,
+        picture: &EmbeddedImage,
+    ) -> Option<gtk4::cairo::ImageSurface> {
+        if let Some(entry) = self.entries.get(&object_id) {
+            if Arc::ptr_eq(&entry.data, picture.data()) {
+                return Some(entry.surface.clone());
+            }
+        }
+        let surface = decode_picture_surface(picture)?;
+        self.entries.insert(
+            object_id,
+            ImageRenderEntry {
+                data: picture.data().clone(),
+                surface: surface.clone(),
+            },
+        );
+        Some(surface)
+    }
+
+    fn retain_visible_pictures(&mut self, visible_picture_ids: &[u64]) {
+        let visible = visible_picture_ids.iter().copied().collect::<HashSet<_>>();
+        self.entries
+            .retain(|object_id, _| visible.contains(object_id));
+    }
+}
+
+fn decode_picture_surface(picture: &EmbeddedImage) -> Option<gtk4::cairo::ImageSurface> {
+    let decoded = image::load_from_memory(picture.data()).ok()?.to_rgba8();
+    rgba_image_to_cairo_surface(&decoded)
+}
+
 pub(in crate::app) fn preedit_cursor_char_index(preedit: &str, cursor_byte_index: i32) -> usize {
     let cursor_byte_index = usize::try_from(cursor_byte_index.max(0)).unwrap_or(usize::MAX);
     preedit
@@ -244,11 +299,14 @@ pub(in crate::app) fn draw_arrow_segment(
     );
 
     // Draw shaft from start to the base of the head.
+    context.save().ok();
     context.set_line_width(stroke_width);
     context.set_line_cap(gtk4::cairo::LineCap::Round);
+    apply_line_dash(context, style.dash, style.thickness);
     context.move_to(f64::from(start.x), f64::from(start.y));
     context.line_to(base_x, base_y);
     let _ = context.stroke();
+    context.restore().ok();
 
     // Draw filled triangle arrow head.
     context.move_to(tip_x, tip_y);
@@ -258,6 +316,253 @@ pub(in crate::app) fn draw_arrow_segment(
     let _ = context.fill();
 }
 
+/// Sets the dash pattern for the next stroke. Dotted patterns are made of
+/// zero-length dashes, so butt caps are swapped for round ones to keep the
+/// dots visible. Callers wrap this in `save`/`restore`.
+fn apply_line_dash(context: &gtk4::cairo::Context, dash: LineDash, thickness: u8) {
+    context.set_dash(&dash.pattern(thickness), 0.0);
+    if dash == LineDash::Dotted && context.line_cap() == gtk4::cairo::LineCap::Butt {
+        context.set_line_cap(gtk4::cairo::LineCap::Round);
+    }
+}
+
+/// Draws a step badge: an optional leader arrow, then a filled circle with
+/// the label centred in a colour that contrasts with the fill.
+fn draw_badge(
+    context: &gtk4::cairo::Context,
+    center: ToolPoint,
+    leader: Option<ToolPoint>,
+    options: BadgeOptions,
+    label: &str,
+    opacity_percent: u8,
+) {
+    let diameter = f64::from(options.size.max(1));
+    let radius = diameter / 2.0;
+    if let Some(target) = leader {
+        draw_arrow_segment(
+            context,
+            center,
+            target,
+            ArrowDrawStyle {
+                color_r: options.color.r,
+                color_g: options.color.g,
+                color_b: options.color.b,
+                opacity_percent,
+                thickness: (options.size / 10).max(2),
+                head_size: 8,
+                dash: LineDash::Solid,
+            },
+        );
+    }
+
+    let (center_x, center_y) = (f64::from(center.x), f64::from(center.y));
+    set_source_rgb_u8(
+        context,
+        options.color.r,
+        options.color.g,
+        options.color.b,
+        opacity_percent,
+    );
+    context.arc(center_x, center_y, radius, 0.0, std::f64::consts::TAU);
+    let _ = context.fill();
+
+    context.save().ok();
+    context.select_font_face(
+        "Sans",
+        gtk4::cairo::FontSlant::Normal,
+        gtk4::cairo::FontWeight::Bold,
+    );
+    // Long labels (e.g. "XVIII") shrink to stay inside the circle.
+    context.set_font_size(diameter * 0.5);
+    if let Ok(extents) = context.text_extents(label) {
+        let max_width = diameter * 0.78;
+        if extents.width() > max_width {
+            context.set_font_size(diameter * 0.5 * max_width / extents.width());
+        }
+    }
+    if let Ok(extents) = context.text_extents(label) {
+        let (text_r, text_g, text_b) = badge_label_rgb(options);
+        set_source_rgb_u8(context, text_r, text_g, text_b, opacity_percent);
+        context.move_to(
+            center_x - extents.x_bearing() - extents.width() / 2.0,
+            center_y - extents.y_bearing() - extents.height() / 2.0,
+        );
+        let _ = context.show_text(label);
+    }
+    context.restore().ok();
+}
+
+/// White on dark fills, near-black on light ones.
+fn badge_label_rgb(options: BadgeOptions) -> (u8, u8, u8) {
+    let color = options.color;
+    let luma = 0.299 * f64::from(color.r) + 0.587 * f64::from(color.g) + 0.114 * f64::from(color.b);
+    if luma > 160.0 {
+        (24, 26, 32)
+    } else {
+        (255, 255, 255)
+    }
+}
+
+/// Draws a pen stroke. Strokes with stylus pressure vary their width along
+/// the path and are composited as one group at the stroke opacity.
+fn draw_pen_stroke(context: &gtk4::cairo::Context, stroke: &PenStroke) {
+    let Some(first) = stroke.points.first() else {
+        return;
+    };
+    let options = stroke.options;
+    context.set_line_cap(gtk4::cairo::LineCap::Round);
+    context.set_line_join(gtk4::cairo::LineJoin::Round);
+    if !stroke.has_varying_width() {
+        set_source_rgb_u8(
+            context,
+            options.color.r,
+            options.color.g,
+            options.color.b,
+            options.opacity,
+        );
+        context.set_line_width(f64::from(options.thickness.max(1)));
+        context.move_to(f64::from(first.x), f64::from(first.y));
+        for point in &stroke.points[1..] {
+            context.line_to(f64::from(point.x), f64::from(point.y));
+        }
+        let _ = context.stroke();
+        return;
+    }
+
+    // Pressure strokes are drawn segment by segment, each with its own
+    // width. Compositing them as one group keeps the overlapping round caps
+    // from darkening translucent strokes.
+    context.save().ok();
+    context.push_group();
+    set_source_rgb_u8(
+        context,
+        options.color.r,
+        options.color.g,
+        options.color.b,
+        100,
+    );
+    if stroke.points.len() == 1 {
+        context.set_line_width(options.width_at(*first));
+        context.move_to(f64::from(first.x), f64::from(first.y));
+        context.line_to(f64::from(first.x), f64::from(first.y));
+        let _ = context.stroke();
+    }
+    for pair in stroke.points.windows(2) {
+        context.set_line_width((options.width_at(pair[0]) + options.width_at(pair[1])) / 2.0);
+        context.move_to(f64::from(pair[0].x), f64::from(pair[0].y));
+        context.line_to(f64::from(pair[1].x), f64::from(pair[1].y));
+        let _ = context.stroke();
+    }
+    let _ = context.pop_group_to_source();
+    let _ = context.paint_with_alpha(f64::from(options.opacity.min(100)) / 100.0);
+    context.restore().ok();
+}
+
+/// Horizontal scale applied to the highlighter pen so its square nib
+/// becomes a narrow, tall chisel tip.
+const HIGHLIGHTER_NIB_ASPECT: f64 = 0.35;
+
+/// Draws a marker stroke that tints the image underneath. The stroke is
+/// rendered opaque into a group first, so self-overlapping segments do not
+/// darken twice, and the group is then multiplied onto the canvas at the
+/// stroke opacity.
+fn draw_highlighter_stroke(context: &gtk4::cairo::Context, stroke: &HighlighterStroke) {
+    let Some(first) = stroke.points.first() else {
+        return;
+    };
+    let options = stroke.options;
+    context.save().ok();
+    context.push_group();
+    set_source_rgb_u8(
+        context,
+        options.color.r,
+        options.color.g,
+        options.color.b,
+        100,
+    );
+    context.move_to(f64::from(first.x), f64::from(first.y));
+    if stroke.points.len() == 1 {
+        context.line_to(f64::from(first.x), f64::from(first.y));
+    }
+    for point in &stroke.points[1..] {
+        context.line_to(f64::from(point.x), f64::from(point.y));
+    }
+    // The path is already in user space; scaling afterwards only changes
+    // the pen shape, which gives the chisel nib.
+    context.save().ok();
+    context.scale(HIGHLIGHTER_NIB_ASPECT, 1.0);
+    context.set_line_width(f64::from(options.thickness.max(1)));
+    context.set_line_cap(gtk4::cairo::LineCap::Square);
+    context.set_line_join(gtk4::cairo::LineJoin::Bevel);
+    let _ = context.stroke();
+    context.restore().ok();
+    let _ = context.pop_group_to_source();
+    context.set_operator(gtk4::cairo::Operator::Multiply);
+    let _ = context.paint_with_alpha(f64::from(options.opacity.min(100)) / 100.0);
+    context.restore().ok();
+}
+
+fn draw_polyline(context: &gtk4::cairo::Context, points: &[ToolPoint], options: LineOptions) {
+    let Some(first) = points.first() else {
+        return;
+    };
+    let width = f64::from(options.thickness.max(1));
+    set_source_rgb_u8(
+        context,
+        options.color.r,
+        options.color.g,
+        options.color.b,
+        100,
+    );
+    if points.len() == 1 {
+        // A polyline still waiting for its second vertex shows as a dot.
+        context.arc(
+            f64::from(first.x),
+            f64::from(first.y),
+            width / 2.0,
+            0.0,
+            std::f64::consts::TAU,
+        );
+        let _ = context.fill();
+        return;
+    }
+    context.save().ok();
+    context.set_line_width(width);
+    match options.cap {
+        LineCap::Round => {
+            context.set_line_cap(gtk4::cairo::LineCap::Round);
+            context.set_line_join(gtk4::cairo::LineJoin::Round);
+        }
+        LineCap::Square => {
+            context.set_line_cap(gtk4::cairo::LineCap::Square);
+            context.set_line_join(gtk4::cairo::LineJoin::Miter);
+        }
+    }
+    apply_line_dash(context, options.dash, options.thickness);
+    context.move_to(f64::from(first.x), f64::from(first.y));
+    for point in &points[1..] {
+        context.line_to(f64::from(point.x), f64::from(point.y));
+    }
+    let _ = context.stroke();
+    context.restore().ok();
+}
+
+fn append_ellipse_path(context: &gtk4::cairo::Context, x: i32, y: i32, width: u32, height: u32) {
+    if width == 0 || height == 0 {
+        return;
+    }
+    let radius_x = f64::from(width) / 2.0;
+    let radius_y = f64::from(height) / 2.0;
+    // Scale a unit circle; restoring afterwards keeps the stroke width uniform.
+    context.save().ok();
+    context.translate(f64::from(x) + radius_x, f64::from(y) + radius_y);
+    context.scale(radius_x, radius_y);
+    context.new_sub_path();
+    context.arc(0.0, 0.0, 1.0, 0.0, std::f64::consts::TAU);
+    context.close_path();
+    context.restore().ok();
+}
+
 fn effective_rectangle_corner_radius(width: u32, height: u32, border_radius: u16) -> f64 {
     let max_radius = f64::from(width.min(height)) / 2.0;
     f64::from(border_radius).clamp(0.0, max_radius)
@@ -371,6 +676,30 @@ pub(in crate::app) fn preview_blur_downsample_factor(width: u32, height: u32, si
     2
 }
 
+/// Applies the element's redaction mode to `region`. Only the gaussian mode
+/// differs between preview and export (`gaussian` may downsample); the other
+/// modes produce the same pixels everywhere.
+fn redact_region(
+    region: &RgbaImage,
+    options: BlurOptions,
+    noise_seed: u64,
+    gaussian: fn(&RgbaImage, f32) -> RgbaImage,
+) -> RgbaImage {
+    let block_size = u32::from(options.mosaic_block_size());
+    match options.mode {
+        BlurMode::Gaussian => gaussian(region, blur_sigma_from_intensity(options.intensity)),
+        BlurMode::Pixelate => pixelate_region(region, block_size),
+        BlurMode::SolidFill => {
+            solid_fill_region(region.width(), region.height(), options.fill_color.rgb())
+        }
+        BlurMode::Secure => {
+            let mut pixelated = pixelate_region(region, block_size);
+            add_secure_noise(&mut pixelated, block_size, noise_seed);
+            pixelated
+        }
+    }
+}
+
 pub(in crate::app) fn draw_real_blur_region(
     context: &gtk4::cairo::Context,
     source_pixbuf: &gtk4::gdk_pixbuf::Pixbuf,
@@ -389,8 +718,7 @@ pub(in crate::app) fn draw_real_blur_region(
     let Some(region) = pixbuf_region_to_rgba_image(source_pixbuf, x, y, width, height) else {
         return false;
     };
-    let sigma = blur_sigma_from_intensity(blur.options.intensity);
-    let blurred = imageops::blur(&region, sigma);
+    let blurred = redact_region(&region, blur.options, blur.noise_seed, imageops::blur);
     let Some(surface) = rgba_image_to_cairo_surface(&blurred) else {
         return false;
     };
@@ -417,10 +745,18 @@ pub(in crate::app) fn draw_real_blur_region_with_cache(
     blur: &editor::tools::BlurElement,
     blur_cache: &Rc<RefCell<BlurRenderCache>>,
 ) -> bool {
-    let Some((surface, x, y, width, height)) = blur_cache
-        .borrow_mut()
-        .surface_for_blur(source_pixbuf, blur)
-    else {
+    let Some((surface, x, y, width, height)) = blur_cache.borrow_mut().surface_for_region(
+        blur.id,
+        source_pixbuf,
+        (
+            blur.region.x,
+            blur.region.y,
+            blur.region.width,
+            blur.region.height,
+        ),
+        blur.options,
+        blur.noise_seed,
+    ) else {
         return false;
     };
 
@@ -440,6 +776,447 @@ pub(in crate::app) fn draw_real_blur_region_with_cache(
     true
 }
 
+/// Paints every blur object again on top of whatever was just drawn from the
+/// unredacted source, so derived views never expose redacted pixels.
+fn repaint_blur_redactions(
+    context: &gtk4::cairo::Context,
+    source_pixbuf: &gtk4::gdk_pixbuf::Pixbuf,
+    tools: &editor::EditorTools,
+    blur_cache: Option<&Rc<RefCell<BlurRenderCache>>>,
+) {
+    for object in tools.objects() {
+        if let ToolObject::Blur(blur) = object {
+            if let Some(cache) = blur_cache {
+                draw_real_blur_region_with_cache(context, source_pixbuf, blur, cache);
+            } else {
+                draw_real_blur_region(context, source_pixbuf, blur);
+            }
+        }
+    }
+}
+
+fn spotlight_backdrop_surface(
+    source_pixbuf: &gtk4::gdk_pixbuf::Pixbuf,
+    spotlight_id: u64,
+    blur_cache: Option<&Rc<RefCell<BlurRenderCache>>>,
+) -> Option<RedactedSurface> {
+    let width = u32::try_from(source_pixbuf.width()).ok()?;
+    let height = u32::try_from(source_pixbuf.height()).ok()?;
+    let options = BlurOptions {
+        intensity: SPOTLIGHT_BLUR_INTENSITY,
+        ..BlurOptions::default()
+    };
+    if let Some(cache) = blur_cache {
+        return cache.borrow_mut().surface_for_region(
+            spotlight_id,
+            source_pixbuf,
+            (0, 0, width, height),
+            options,
+            0,
+        );
+    }
+    let region = pixbuf_region_to_rgba_image(source_pixbuf, 0, 0, width, height)?;
+    let blurred = redact_region(&region, options, 0, imageops::blur);
+    let surface = rgba_image_to_cairo_surface(&blurred)?;
+    Some((surface, 0, 0, width, height))
+}
+
+fn append_spotlight_hole_path(context: &gtk4::cairo::Context, hole: &SpotlightHole) {
+    match hole.shape {
+        SpotlightShape::Rectangle => context.rectangle(
+            f64::from(hole.x),
+            f64::from(hole.y),
+            f64::from(hole.width),
+            f64::from(hole.height),
+        ),
+        SpotlightShape::Ellipse => {
+            append_ellipse_path(context, hole.x, hole.y, hole.width, hole.height)
+        }
+    }
+}
+
+/// Dims (and optionally blurs) the image, then punches the holes back out.
+/// Working in a group keeps the cleared holes from erasing the screenshot.
+/// The blurred backdrop comes from the raw source, so blur objects are
+/// repainted over it to keep redactions intact outside the holes.
+fn draw_spotlight(
+    context: &gtk4::cairo::Context,
+    spotlight: &SpotlightElement,
+    tools: &editor::EditorTools,
+    render: &ToolRenderContext<'_>,
+    backdrop: Option<&RedactedSurface>,
+) {
+    let width = f64::from(render.image_bounds.width.max(0));
+    let height = f64::from(render.image_bounds.height.max(0));
+    context.save().ok();
+    context.push_group();
+    if let Some((surface, x, y, surface_width, surface_height)) = backdrop {
+        if context
+            .set_source_surface(surface, f64::from(*x), f64::from(*y))
+            .is_ok()
+        {
+            context.rectangle(
+                f64::from(*x),
+                f64::from(*y),
+                f64::from(*surface_width),
+                f64::from(*surface_height),
+            );
+            let _ = context.fill();
+        }
+        if let Some(source) = render.source_pixbuf {
+            repaint_blur_redactions(context, source, tools, render.blur_cache);
+        }
+    }
+    context.set_source_rgba(0.0, 0.0, 0.0, f64::from(spotlight.options.opacity) / 100.0);
+    context.rectangle(0.0, 0.0, width, height);
+    let _ = context.fill();
+    context.set_operator(gtk4::cairo::Operator::Clear);
+    for hole in &spotlight.holes {
+        append_spotlight_hole_path(context, hole);
+        let _ = context.fill();
+    }
+    if context.pop_group_to_source().is_ok() {
+        let _ = context.paint();
+    }
+    context.restore().ok();
+}
+
+fn append_magnifier_shape_path(
+    context: &gtk4::cairo::Context,
+    shape: MagnifierShape,
+    bounds: ToolBounds,
+) {
+    match shape {
+        MagnifierShape::Rectangle => context.rectangle(
+            f64::from(bounds.x),
+            f64::from(bounds.y),
+            f64::from(bounds.width),
+            f64::from(bounds.height),
+        ),
+        MagnifierShape::Circle => {
+            append_ellipse_path(context, bounds.x, bounds.y, bounds.width, bounds.height)
+        }
+    }
+}
+
+/// Paints the source region scaled into the callout. Blurs are repainted
+/// inside the lens so a magnifier never reveals redacted pixels.
+fn draw_magnifier_lens(
+    context: &gtk4::cairo::Context,
+    magnifier: &MagnifierElement,
+    tools: &editor::EditorTools,
+    render: &ToolRenderContext<'_>,
+) {
+    let source = magnifier.source_bounds();
+    let callout = magnifier.callout_bounds();
+    context.save().ok();
+    append_magnifier_shape_path(context, magnifier.options.shape, callout);
+    context.clip();
+    let Some(pixbuf) = render.source_pixbuf else {
+        context.set_source_rgba(0.06, 0.12, 0.22, 0.26);
+        let _ = context.paint();
+        context.restore().ok();
+        return;
+    };
+    context.translate(f64::from(callout.x), f64::from(callout.y));
+    context.scale(
+        f64::from(callout.width) / f64::from(source.width.max(1)),
+        f64::from(callout.height) / f64::from(source.height.max(1)),
+    );
+    context.translate(-f64::from(source.x), -f64::from(source.y));
+    context.set_source_pixbuf(pixbuf, 0.0, 0.0);
+    context.source().set_filter(gtk4::cairo::Filter::Good);
+    let _ = context.paint();
+    repaint_blur_redactions(context, pixbuf, tools, render.blur_cache);
+    context.restore().ok();
+}
+
+fn draw_magnifier(
+    context: &gtk4::cairo::Context,
+    magnifier: &MagnifierElement,
+    tools: &editor::EditorTools,
+    render: &ToolRenderContext<'_>,
+) {
+    let options = magnifier.options;
+    context.save().ok();
+    set_source_rgb_u8(
+        context,
+        options.color.r,
+        options.color.g,
+        options.color.b,
+        100,
+    );
+    context.set_line_width(f64::from(options.thickness.max(1)));
+    context.set_line_cap(gtk4::cairo::LineCap::Round);
+    append_magnifier_shape_path(context, options.shape, magnifier.source_bounds());
+    let _ = context.stroke();
+    if let Some((from, to)) = magnifier.connector() {
+        context.move_to(f64::from(from.x), f64::from(from.y));
+        context.line_to(f64::from(to.x), f64::from(to.y));
+        let _ = context.stroke();
+    }
+    context.restore().ok();
+
+    draw_magnifier_lens(context, magnifier, tools, render);
+
+    context.save().ok();
+    set_source_rgb_u8(
+        context,
+        options.color.r,
+        options.color.g,
+        options.color.b,
+        100,
+    );
+    context.set_line_width(f64::from(options.thickness.max(1)));
+    append_magnifier_shape_path(context, options.shape, magnifier.callout_bounds());
+    let _ = context.stroke();
+    context.restore().ok();
+}
+
+fn draw_text_element(
+    context: &gtk4::cairo::Context,
+    text: &TextElement,
+    render: &ToolRenderContext<'_>,
+) {
+    let active_preedit = if render.active_text_id == Some(text.id) {
+        render
+            .active_text_preedit
+            .filter(|preedit| !preedit.content.is_empty())
+    } else {
+        None
+    };
+    let render_content = active_preedit.map(|preedit| text_content_with_preedit(text, preedit));
+    let weight = if text.options.weight >= 600 {
+        gtk4::cairo::FontWeight::Bold
+    } else {
+        gtk4::cairo::FontWeight::Normal
+    };
+    context.select_font_face(
+        text.options.family.cairo_font_name(),
+        gtk4::cairo::FontSlant::Normal,
+        weight,
+    );
+    context.set_font_size(f64::from(text.options.size.max(1)));
+    set_source_rgb_u8(
+        context,
+        text.options.color.r,
+        text.options.color.g,
+        text.options.color.b,
+        100,
+    );
+    let line_height = text_line_height(text);
+    let baseline = text_baseline_y(text);
+    let lines = match render_content.as_deref() {
+        Some("") => vec![""],
+        Some(content) => content.split('\n').collect::<Vec<_>>(),
+        None => text_lines_for_render(text),
+    };
+    for (index, line) in lines.iter().enumerate() {
+        if !line.is_empty() {
+            let line_y = baseline + (index as f64 * line_height);
+            context.move_to(f64::from(text.x), line_y);
+            let _ = context.show_text(line);
+        }
+    }
+    if render.active_text_id == Some(text.id) {
+        let preedit = active_preedit;
+        let caret = text_caret_layout(context, text, preedit);
+
+        if preedit.is_some() {
+            let preedit_start_x = caret.preedit_start_x.unwrap_or(caret.caret_x);
+            let preedit_end_x = caret.preedit_end_x.unwrap_or(preedit_start_x);
+            context.save().ok();
+            set_source_rgba_color(context, render.text_input_palette.preedit_underline);
+            context.set_line_width(1.0);
+            context.move_to(preedit_start_x, caret.baseline_y + 2.0);
+            context.line_to(preedit_end_x, caret.baseline_y + 2.0);
+            let _ = context.stroke();
+            context.restore().ok();
+        }
+
+        context.save().ok();
+        set_source_rgba_color(context, render.text_input_palette.caret);
+        context.set_line_width(1.4);
+        context.move_to(caret.caret_x, caret.caret_top);
+        context.line_to(caret.caret_x, caret.caret_bottom);
+        let _ = context.stroke();
+        context.restore().ok();
+    }
+}
+
+/// Bubble outline and tail as one shape; `tail_base` is `None` when the
+/// tip sits inside the bubble.
+fn append_callout_path(
+    context: &gtk4::cairo::Context,
+    bubble: ToolBounds,
+    tail_base: Option<[ToolPoint; 2]>,
+    tip: ToolPoint,
+) {
+    append_rectangle_path(
+        context,
+        bubble.x,
+        bubble.y,
+        bubble.width,
+        bubble.height,
+        u16::from(CALLOUT_CORNER_RADIUS),
+    );
+    if let Some([from, to]) = tail_base {
+        context.move_to(f64::from(from.x), f64::from(from.y));
+        context.line_to(f64::from(tip.x), f64::from(tip.y));
+        context.line_to(f64::from(to.x), f64::from(to.y));
+        context.close_path();
+    }
+}
+
+/// The outline is stroked at twice its width and the fill painted over the
+/// inner half, which hides the seam where the tail meets the bubble.
+fn draw_callout_bubble(
+    context: &gtk4::cairo::Context,
+    callout: &CalloutElement,
+    opacity_percent: u8,
+) {
+    let options = callout.options;
+    let bubble = callout_bubble_bounds(callout);
+    let tail_base = callout.tail_base(bubble);
+    context.save().ok();
+    context.set_line_join(gtk4::cairo::LineJoin::Round);
+    context.set_line_width(f64::from(options.thickness.max(1)) * 2.0);
+    set_source_rgb_u8(
+        context,
+        options.color.r,
+        options.color.g,
+        options.color.b,
+        opacity_percent,
+    );
+    append_callout_path(context, bubble, tail_base, callout.tail);
+    let _ = context.stroke();
+    set_source_rgb_u8(
+        context,
+        options.fill.r,
+        options.fill.g,
+        options.fill.b,
+        opacity_percent,
+    );
+    append_callout_path(context, bubble, tail_base, callout.tail);
+    let _ = context.fill();
+    context.restore().ok();
+}
+
+/// Side of the square stamps are drawn in before being scaled to their frame.
+const STAMP_UNITS: f64 = 100.0;
+
+fn draw_stamp(context: &gtk4::cairo::Context, stamp: Stamp) {
+    let center = STAMP_UNITS / 2.0;
+    context.set_line_cap(gtk4::cairo::LineCap::Round);
+    context.set_line_join(gtk4::cairo::LineJoin::Round);
+    context.set_line_width(10.0);
+    match stamp {
+        Stamp::Check => {
+            set_source_rgb_u8(context, 34, 168, 83, 100);
+            context.arc(center, center, center - 2.0, 0.0, std::f64::consts::TAU);
+            let _ = context.fill();
+            set_source_rgb_u8(context, 255, 255, 255, 100);
+            context.move_to(28.0, 52.0);
+            context.line_to(44.0, 68.0);
+            context.line_to(74.0, 36.0);
+            let _ = context.stroke();
+        }
+        Stamp::Cross => {
+            set_source_rgb_u8(context, 220, 53, 69, 100);
+            context.arc(center, center, center - 2.0, 0.0, std::f64::consts::TAU);
+            let _ = context.fill();
+            set_source_rgb_u8(context, 255, 255, 255, 100);
+            context.move_to(33.0, 33.0);
+            context.line_to(67.0, 67.0);
+            context.move_to(67.0, 33.0);
+            context.line_to(33.0, 67.0);
+            let _ = context.stroke();
+        }
+        Stamp::Warning => {
+            set_source_rgb_u8(context, 245, 159, 0, 100);
+            context.move_to(center, 8.0);
+            context.line_to(94.0, 88.0);
+            context.line_to(6.0, 88.0);
+            context.close_path();
+            let _ = context.fill_preserve();
+            let _ = context.stroke();
+            set_source_rgb_u8(context, 33, 33, 33, 100);
+            context.move_to(center, 36.0);
+            context.line_to(center, 60.0);
+            let _ = context.stroke();
+            context.arc(center, 76.0, 6.0, 0.0, std::f64::consts::TAU);
+            let _ = context.fill();
+        }
+        Stamp::Question => {
+            set_source_rgb_u8(context, 37, 99, 235, 100);
+            context.arc(center, center, center - 2.0, 0.0, std::f64::consts::TAU);
+            let _ = context.fill();
+            set_source_rgb_u8(context, 255, 255, 255, 100);
+            context.select_font_face(
+                "Sans",
+                gtk4::cairo::FontSlant::Normal,
+                gtk4::cairo::FontWeight::Bold,
+            );
+            context.set_font_size(64.0);
+            if let Ok(extents) = context.text_extents("?") {
+                context.move_to(
+                    center - extents.x_bearing() - extents.width() / 2.0,
+                    center - extents.y_bearing() - extents.height() / 2.0,
+                );
+                let _ = context.show_text("?");
+            }
+        }
+    }
+}
+
+/// Draws an image object's frame rotated about its center. Pictures that
+/// could not be decoded show as a grey placeholder. The content is composed
+/// in a group so the opacity applies to the object as a whole.
+fn draw_image_element(
+    context: &gtk4::cairo::Context,
+    image: &ImageElement,
+    picture: Option<&gtk4::cairo::ImageSurface>,
+    opacity_percent: u8,
+) {
+    let (center_x, center_y) = image.center();
+    let width = f64::from(image.width);
+    let height = f64::from(image.height);
+    context.save().ok();
+    context.translate(center_x, center_y);
+    context.rotate(f64::from(image.options.rotation).to_radians());
+    context.translate(-width / 2.0, -height / 2.0);
+    context.push_group();
+    context.save().ok();
+    match (&image.source, picture) {
+        (ImageSource::Stamp(stamp), _) => {
+            context.scale(width / STAMP_UNITS, height / STAMP_UNITS);
+            draw_stamp(context, *stamp);
+        }
+        (ImageSource::Picture(_), Some(surface)) => {
+            context.rectangle(0.0, 0.0, width, height);
+            context.clip();
+            context.scale(
+                width / f64::from(surface.width().max(1)),
+                height / f64::from(surface.height().max(1)),
+            );
+            if context.set_source_surface(surface, 0.0, 0.0).is_ok() {
+                context.source().set_filter(gtk4::cairo::Filter::Good);
+                let _ = context.paint();
+            }
+        }
+        (ImageSource::Picture(_), None) => {
+            context.set_source_rgba(0.5, 0.5, 0.5, 0.35);
+            context.rectangle(0.0, 0.0, width, height);
+            let _ = context.fill();
+        }
+    }
+    context.restore().ok();
+    let _ = context.pop_group_to_source();
+    let opacity = u16::from(image.options.opacity.min(100)) * u16::from(opacity_percent.min(100));
+    let _ = context.paint_with_alpha(f64::from(opacity) / 10_000.0);
+    context.restore().ok();
+}
+
 pub(in crate::app) fn draw_editor_tool_objects(
     context: &gtk4::cairo::Context,
     tools: &editor::EditorTools,
@@ -447,6 +1224,8 @@ pub(in crate::app) fn draw_editor_tool_objects(
 ) {
     let mut last_crop: Option<(i32, i32, u32, u32)> = None;
     let mut visible_blur_ids: Vec<u64> = Vec::new();
+    let mut visible_picture_ids: Vec<u64> = Vec::new();
+    let badge_labels = tools.badge_labels();
 
     for object in objects_in_draw_order(tools) {
         let object_id = object.id();
@@ -474,26 +1253,22 @@ pub(in crate::app) fn draw_editor_tool_objects(
                     let _ = context.fill();
                 }
             }
+            ToolObject::Spotlight(spotlight) => {
+                let backdrop = if spotlight.options.blur_outside {
+                    visible_blur_ids.push(spotlight.id);
+                    render.source_pixbuf.and_then(|source| {
+                        spotlight_backdrop_surface(source, spotlight.id, render.blur_cache)
+                    })
+                } else {
+                    None
+                };
+                draw_spotlight(context, spotlight, tools, &render, backdrop.as_ref());
+            }
+            ToolObject::Magnifier(magnifier) => {
+                draw_magnifier(context, magnifier, tools, &render);
+            }
             ToolObject::Pen(stroke) => {
-                if stroke.points.is_empty() {
-                    continue;
-                }
-                set_source_rgb_u8(
-                    context,
-                    stroke.options.color.r,
-                    stroke.options.color.g,
-                    stroke.options.color.b,
-                    stroke.options.opacity,
-                );
-                context.set_line_width(f64::from(stroke.options.thickness.max(1)));
-                context.set_line_cap(gtk4::cairo::LineCap::Round);
-                context.set_line_join(gtk4::cairo::LineJoin::Round);
-                let first = stroke.points[0];
-                context.move_to(f64::from(first.x), f64::from(first.y));
-                for point in &stroke.points[1..] {
-                    context.line_to(f64::from(point.x), f64::from(point.y));
-                }
-                let _ = context.stroke();
+                draw_pen_stroke(context, stroke);
             }
             ToolObject::Arrow(arrow) => {
                 draw_arrow_segment(
@@ -507,9 +1282,16 @@ pub(in crate::app) fn draw_editor_tool_objects(
                         opacity_percent: 100,
                         thickness: arrow.options.thickness,
                         head_size: arrow.options.head_size,
+                        dash: arrow.options.dash,
                     },
                 );
             }
+            ToolObject::Highlighter(stroke) => {
+                draw_highlighter_stroke(context, stroke);
+            }
+            ToolObject::Line(line) => {
+                draw_polyline(context, &line.points, line.options);
+            }
             ToolObject::Rectangle(rectangle) => {
                 if rectangle.options.fill_enabled {
                     set_source_rgb_u8(
@@ -536,7 +1318,9 @@ pub(in crate::app) fn draw_editor_tool_objects(
                     rectangle.options.color.b,
                     100,
                 );
+                context.save().ok();
                 context.set_line_width(f64::from(rectangle.options.thickness.max(1)));
+                apply_line_dash(context, rectangle.options.dash, rectangle.options.thickness);
                 append_rectangle_path(
                     context,
                     rectangle.x,
@@ -546,6 +1330,51 @@ pub(in crate::app) fn draw_editor_tool_objects(
                     rectangle.options.border_radius,
                 );
                 let _ = context.stroke();
+                context.restore().ok();
+            }
+            ToolObject::Ellipse(ellipse) => {
+                if ellipse.options.fill_enabled {
+                    set_source_rgb_u8(
+                        context,
+                        ellipse.options.color.r,
+                        ellipse.options.color.g,
+                        ellipse.options.color.b,
+                        24,
+                    );
+                    append_ellipse_path(
+                        context,
+                        ellipse.x,
+                        ellipse.y,
+                        ellipse.width,
+                        ellipse.height,
+                    );
+                    let _ = context.fill();
+                }
+                set_source_rgb_u8(
+                    context,
+                    ellipse.options.color.r,
+                    ellipse.options.color.g,
+                    ellipse.options.color.b,
+                    100,
+                );
+                context.set_line_width(f64::from(ellipse.options.thickness.max(1)));
+                append_ellipse_path(context, ellipse.x, ellipse.y, ellipse.width, ellipse.height);
+                let _ = context.stroke();
+            }
+            ToolObject::Badge(badge) => {
+                let label = badge_labels
+                    .iter()
+                    .find(|(id, _)| *id == badge.id)
+                    .map(|(_, label)| label.as_str())
+                    .unwrap_or_default();
+                draw_badge(
+                    context,
+                    badge.center,
+                    badge.leader,
+                    badge.options,
+                    label,
+                    100,
+                );
             }
             ToolObject::Crop(crop) => {
                 last_crop = Some((crop.x, crop.y, crop.width, crop.height));
@@ -561,72 +1390,27 @@ pub(in crate::app) fn draw_editor_tool_objects(
                     let _ = context.stroke();
                 }
             }
-            ToolObject::Text(text) => {
-                let active_preedit = if render.active_text_id == Some(object_id) {
-                    render
-                        .active_text_preedit
-                        .filter(|preedit| !preedit.content.is_empty())
-                } else {
-                    None
-                };
-                let render_content =
-                    active_preedit.map(|preedit| text_content_with_preedit(text, preedit));
-                let weight = if text.options.weight >= 600 {
-                    gtk4::cairo::FontWeight::Bold
-                } else {
-                    gtk4::cairo::FontWeight::Normal
-                };
-                context.select_font_face(
-                    text.options.family.cairo_font_name(),
-                    gtk4::cairo::FontSlant::Normal,
-                    weight,
-                );
-                context.set_font_size(f64::from(text.options.size.max(1)));
-                set_source_rgb_u8(
-                    context,
-                    text.options.color.r,
-                    text.options.color.g,
-                    text.options.color.b,
-                    100,
-                );
-                let line_height = text_line_height(text);
-                let baseline = text_baseline_y(text);
-                let lines = match render_content.as_deref() {
-                    Some("") => vec![""],
-                    Some(content) => content.split('\n').collect::<Vec<_>>(),
-                    None => text_lines_for_render(text),
-                };
-                for (index, line) in lines.iter().enumerate() {
-                    if !line.is_empty() {
-                        let line_y = baseline + (index as f64 * line_height);
-                        context.move_to(f64::from(text.x), line_y);
-                        let _ = context.show_text(line);
-                    }
-                }
-                if render.active_text_id == Some(object_id) {
-                    let preedit = active_preedit;
-                    let caret = text_caret_layout(context, text, preedit);
-
-                    if preedit.is_some() {
-                        let preedit_start_x = caret.preedit_start_x.unwrap_or(caret.caret_x);
-                        let preedit_end_x = caret.preedit_end_x.unwrap_or(preedit_start_x);
-                        context.save().ok();
-                        set_source_rgba_color(context, render.text_input_palette.preedit_underline);
-                        context.set_line_width(1.0);
-                        context.move_to(preedit_start_x, caret.baseline_y + 2.0);
-                        context.line_to(preedit_end_x, caret.baseline_y + 2.0);
-                        let _ = context.stroke();
-                        context.restore().ok();
+            ToolObject::Callout(callout) => {
+                draw_callout_bubble(context, callout, 100);
+                draw_text_element(context, &callout.text, &render);
+            }
+            ToolObject::Image(image) => {
+                let picture = match &image.source {
+                    ImageSource::Picture(picture) => {
+                        visible_picture_ids.push(image.id);
+                        match render.image_cache {
+                            Some(cache) => {
+                                cache.borrow_mut().surface_for_picture(image.id, picture)
+                            }
+                            None => decode_picture_surface(picture),
+                        }
                     }
-
-                    context.save().ok();
-                    set_source_rgba_color(context, render.text_input_palette.caret);
-                    context.set_line_width(1.4);
-                    context.move_to(caret.caret_x, caret.caret_top);
-                    context.line_to(caret.caret_x, caret.caret_bottom);
-                    let _ = context.stroke();
-                    context.restore().ok();
-                }
+                    ImageSource::Stamp(_) => None,
+                };
+                draw_image_element(context, image, picture.as_ref(), 100);
+            }
+            ToolObject::Text(text) => {
+                draw_text_element(context, text, &render);
             }
         }
 
@@ -656,6 +1440,16 @@ pub(in crate::app) fn draw_editor_tool_objects(
                         render.selection_palette.resize_handle_fill,
                     );
                 }
+                ToolObject::Ellipse(ellipse) => {
+                    draw_resize_handles_for_bounds(
+                        context,
+                        ellipse.x,
+                        ellipse.y,
+                        ellipse.width,
+                        ellipse.height,
+                        render.selection_palette.resize_handle_fill,
+                    );
+                }
                 ToolObject::Blur(blur) => {
                     draw_resize_handles_for_bounds(
                         context,
@@ -666,6 +1460,52 @@ pub(in crate::app) fn draw_editor_tool_objects(
                         render.selection_palette.resize_handle_fill,
                     );
                 }
+                ToolObject::Spotlight(spotlight) => {
+                    for hole in &spotlight.holes {
+                        draw_resize_handles_for_bounds(
+                            context,
+                            hole.x,
+                            hole.y,
+                            hole.width,
+                            hole.height,
+                            render.selection_palette.resize_handle_fill,
+                        );
+                    }
+                }
+                ToolObject::Magnifier(magnifier) => {
+                    for bounds in [magnifier.source_bounds(), magnifier.callout_bounds()] {
+                        draw_resize_handles_for_bounds(
+                            context,
+                            bounds.x,
+                            bounds.y,
+                            bounds.width,
+                            bounds.height,
+                            render.selection_palette.resize_handle_fill,
+                        );
+                    }
+                }
+                ToolObject::Callout(callout) => {
+                    // A zero-sized box collapses to a single handle on the tip.
+                    draw_resize_handles_for_bounds(
+                        context,
+                        callout.tail.x,
+                        callout.tail.y,
+                        0,
+                        0,
+                        render.selection_palette.resize_handle_fill,
+                    );
+                }
+                ToolObject::Image(image) => {
+                    let bounds = image.bounds();
+                    draw_resize_handles_for_bounds(
+                        context,
+                        bounds.x,
+                        bounds.y,
+                        bounds.width,
+                        bounds.height,
+                        render.selection_palette.resize_handle_fill,
+                    );
+                }
                 ToolObject::Crop(crop) => {
                     draw_resize_handles_for_bounds(
                         context,
@@ -684,6 +1524,11 @@ pub(in crate::app) fn draw_editor_tool_objects(
     if let Some(cache) = render.blur_cache {
         cache.borrow_mut().retain_visible_blurs(&visible_blur_ids);
     }
+    if let Some(cache) = render.image_cache {
+        cache
+            .borrow_mut()
+            .retain_visible_pictures(&visible_picture_ids);
+    }
 
     if render.show_crop_mask {
         if let Some((x, y, width, height)) = last_crop {
@@ -766,7 +1611,7 @@ pub(in crate::app) fn draw_drag_preview_overlay(
                 let _ = context.stroke();
             }
         }
-        ToolKind::Pen => {}
+        ToolKind::Pen | ToolKind::Highlighter | ToolKind::Line => {}
         ToolKind::Arrow => {
             let options = tools.arrow_options();
             draw_arrow_segment(
@@ -780,6 +1625,7 @@ pub(in crate::app) fn draw_drag_preview_overlay(
                     opacity_percent: 100,
                     thickness: options.thickness,
                     head_size: options.head_size,
+                    dash: options.dash,
                 },
             );
         }
@@ -805,9 +1651,98 @@ pub(in crate::app) fn draw_drag_preview_overlay(
                     options.color.b,
                     95,
                 );
+                context.save().ok();
                 context.set_line_width(f64::from(options.thickness.max(1)));
+                apply_line_dash(context, options.dash, options.thickness);
                 append_rectangle_path(context, x, y, width, height, options.border_radius);
                 let _ = context.stroke();
+                context.restore().ok();
+            }
+        }
+        ToolKind::Ellipse => {
+            if let Some((x, y, width, height)) = normalize_tool_box(preview.start, preview.current)
+            {
+                let options = tools.ellipse_options();
+                if options.fill_enabled {
+                    set_source_rgb_u8(
+                        context,
+                        options.color.r,
+                        options.color.g,
+                        options.color.b,
+                        20,
+                    );
+                    append_ellipse_path(context, x, y, width, height);
+                    let _ = context.fill();
+                }
+                set_source_rgb_u8(
+                    context,
+                    options.color.r,
+                    options.color.g,
+                    options.color.b,
+                    95,
+                );
+                context.set_line_width(f64::from(options.thickness.max(1)));
+                append_ellipse_path(context, x, y, width, height);
+                let _ = context.stroke();
+            }
+        }
+        ToolKind::Badge => {
+            let options = tools.badge_options();
+            draw_badge(
+                context,
+                preview.start,
+                options.leader_for(preview.start, preview.current),
+                options,
+                &tools.next_badge_label(),
+                85,
+            );
+        }
+        ToolKind::Spotlight => {
+            if let Some((x, y, width, height)) = normalize_tool_box(preview.start, preview.current)
+            {
+                let hole = SpotlightHole::new(
+                    tools.spotlight_options().shape,
+                    ToolBounds::new(x, y, width, height),
+                );
+                context.save().ok();
+                context.set_source_rgba(0.0, 0.0, 0.0, 0.18);
+                append_spotlight_hole_path(context, &hole);
+                let _ = context.fill();
+                context.set_source_rgba(1.0, 1.0, 1.0, 0.95);
+                context.set_line_width(1.5);
+                context.set_dash(&[4.0, 3.0], 0.0);
+                append_spotlight_hole_path(context, &hole);
+                let _ = context.stroke();
+                context.restore().ok();
+            }
+        }
+        ToolKind::Magnifier => {
+            let options = tools.magnifier_options();
+            let end = options.shape.constrain_drag(preview.start, preview.current);
+            if let Some((x, y, width, height)) = normalize_tool_box(preview.start, end) {
+                let source = ToolBounds::new(x, y, width, height);
+                let placed = MagnifierElement::new(
+                    0,
+                    source,
+                    ImageBounds::new(image_width, image_height),
+                    options,
+                );
+                context.save().ok();
+                set_source_rgb_u8(
+                    context,
+                    options.color.r,
+                    options.color.g,
+                    options.color.b,
+                    95,
+                );
+                context.set_line_width(f64::from(options.thickness.max(1)));
+                append_magnifier_shape_path(context, options.shape, source);
+                let _ = context.stroke();
+                context.set_line_width(1.5);
+                context.set_dash(&[4.0, 3.0], 0.0);
+                append_magnifier_shape_path(context, options.shape, placed.callout_bounds());
+                let _ = context.stroke();
+                context.restore().ok();
             }
         }
         ToolKind::Crop => {
@@ -836,8 +1771,32 @@ pub(in crate::app) fn draw_drag_preview_overlay(
                 }
             }
         }
+        ToolKind::Callout => {
+            let placed = CalloutElement::new(
+                0,
+                preview.start,
+                preview.current,
+                tools.text_options(),
+                tools.callout_options(),
+            );
+            draw_callout_bubble(context, &placed, 85);
+        }
+        ToolKind::Image => {
+            let frame = editor::tools::stamp_frame(
+                preview.start,
+                preview.current,
+                ImageBounds::new(image_width, image_height),
+            );
+            let placed = ImageElement::new(
+                0,
+                ImageSource::Stamp(tools.image_stamp()),
+                frame,
+                tools.image_options(),
+            );
+            draw_image_element(context, &placed, None, 70);
+        }
         ToolKind::Text => {}
-        ToolKind::Ocr => {
+        ToolKind::Ocr | ToolKind::Decode => {
             if let Some((x, y, width, height)) = normalize_tool_box(preview.start, preview.current)
             {
                 context.set_source_rgba(0.12, 0.28, 0.70, 0.18);
@@ -989,4 +1948,61 @@ mod tests {
         let merged = text_content_with_preedit(&text, &preedit);
         assert_eq!(merged, "hel가lo");
     }
+
+    #[test]
+    fn blurred_spotlight_keeps_solid_fill_redactions_filled() {
+        let Some(source) =
+            gtk4::gdk_pixbuf::Pixbuf::new(gtk4::gdk_pixbuf::Colorspace::Rgb, false, 8, 48, 48)
+        else {
+            panic!("pixbuf");
+        };
+        source.fill(0xff00_00ff);
+        let mut tools = editor::EditorTools::new();
+        tools.set_blur_mode(BlurMode::SolidFill);
+        tools.set_blur_fill_color(editor::tools::Color::new(0, 0, 255));
+        tools
+            .add_blur(editor::tools::BlurRegion::new(0, 0, 24, 24))
+            .expect("redaction should be added");
+        tools.set_spotlight_blur_outside(true);
+        tools
+            .add_spotlight_hole(ToolPoint::new(32, 32), ToolPoint::new(44, 44))
+            .expect("spotlight should be added");
+
+        let mut surface =
+            match gtk4::cairo::ImageSurface::create(gtk4::cairo::Format::ARgb32, 48, 48) {
+                Ok(surface) => surface,
+                Err(_) => panic!("surface"),
+            };
+        {
+            let context = match gtk4::cairo::Context::new(&surface) {
+                Ok(context) => context,
+                Err(_) => panic!("context"),
+            };
+            context.set_source_pixbuf(&source, 0.0, 0.0);
+            let _ = context.paint();
+            draw_editor_tool_objects(
+                &context,
+                &tools,
+                ToolRenderContext {
+                    image_bounds: ImageBounds::new(48, 48),
+                    show_crop_mask: false,
+                    selected_object_ids: &[],
+                    selection_palette: EditorSelectionPalette::default(),
+                    text_input_palette: super::super::EditorTextInputPalette::default(),
+                    source_pixbuf: Some(&source),
+                    active_text_id: None,
+                    active_text_preedit: None,
+                    blur_cache: None,
+                    image_cache: None,
+                },
+            );
+        }
+        surface.flush();
+        let stride = usize::try_from(surface.stride()).expect("stride");
+        let data = surface.data().expect("surface data");
+        // ARGB32 is stored as B, G, R, A on little-endian hosts.
+        let pixel = &data[12 * stride + 12 * 4..12 * stride + 12 * 4 + 4];
+        assert_eq!(pixel[2], 0, "no red from the unredacted source");
+        assert!(pixel[0] > 0, "the fill stays visible under the dimming");
+    }
 }
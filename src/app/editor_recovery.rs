@@ -0,0 +1,239 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::capture::CaptureArtifact;
+use crate::editor::{self, ToolObject};
+use crate::recovery::{RecoverableSession, RecoveryResult, RecoverySnapshot, RecoveryStore};
+use gtk4::glib;
+
+const EDITOR_AUTOSAVE_INTERVAL_SECS: u32 = 5;
+
+struct RecoverySessionState {
+    capture_id: String,
+    last_written: Option<Vec<ToolObject>>,
+}
+
+/// Autosave bookkeeping for the open editor plus objects restored from a
+/// previous run that are waiting for their capture to be opened in the editor.
+#[derive(Clone)]
+pub(super) struct EditorRecoveryRuntime {
+    store: RecoveryStore,
+    session: Rc<RefCell<Option<RecoverySessionState>>>,
+    generation: Rc<Cell<u64>>,
+    recovered_objects: Rc<RefCell<HashMap<String, Vec<ToolObject>>>>,
+}
+
+impl EditorRecoveryRuntime {
+    pub(super) fn new(store: RecoveryStore) -> Self {
+        Self {
+            store,
+            session: Rc::new(RefCell::new(None)),
+            generation: Rc::new(Cell::new(0)),
+            recovered_objects: Rc::new(RefCell::new(HashMap::new())),
+        }
+    }
+
+    pub(super) fn store(&self) -> &RecoveryStore {
+        &self.store
+    }
+
+    /// Starts tracking `capture_id` and returns the generation an autosave
+    /// timer must match to keep running.
+    pub(super) fn begin_session(&self, capture_id: &str) -> u64 {
+        let previous = self.session.borrow_mut().replace(RecoverySessionState {
+            capture_id: capture_id.to_string(),
+            last_written: None,
+        });
+        if let Some(previous) = previous {
+            if previous.capture_id != capture_id {
+                self.discard_capture(&previous.capture_id);
+            }
+        }
+        self.next_generation()
+    }
+
+    /// Stops autosave and removes the files of the current session.
+    pub(super) fn end_session(&self) {
+        self.next_generation();
+        if let Some(previous) = self.session.borrow_mut().take() {
+            self.discard_capture(&previous.capture_id);
+        }
+    }
+
+    /// Removes the files of the current session after its changes were saved.
+    pub(super) fn mark_saved(&self) {
+        let mut session = self.session.borrow_mut();
+        let Some(session) = session.as_mut() else {
+            return;
+        };
+        if session.last_written.take().is_some() {
+            self.discard_capture(&session.capture_id);
+        }
+    }
+
+    pub(super) fn discard_capture(&self, capture_id: &str) {
+        if let Err(err) = self.store.discard(capture_id) {
+            tracing::warn!(capture_id, ?err, "failed to discard editor recovery files");
+        }
+    }
+
+    pub(super) fn recoverable_sessions(&self) -> Vec<RecoverableSession> {
+        self.store.list().unwrap_or_else(|err| {
+            tracing::warn!(?err, "failed to scan editor recovery directory");
+            Vec::new()
+        })
+    }
+
+    /// Holds restored objects until their capture opens in the editor. The
+    /// recovery files stay on disk meanwhile, so a session that is never
+    /// opened is offered again on the next launch.
+    pub(super) fn stash_recovered_objects(&self, capture_id: &str, objects: Vec<ToolObject>) {
+        self.recovered_objects
+            .borrow_mut()
+            .insert(capture_id.to_string(), objects);
+    }
+
+    pub(super) fn take_recovered_objects(&self, capture_id: &str) -> Option<Vec<ToolObject>> {
+        self.recovered_objects.borrow_mut().remove(capture_id)
+    }
+
+    fn next_generation(&self) -> u64 {
+        let generation = self.generation.get().wrapping_add(1);
+        self.generation.set(generation);
+        generation
+    }
+
+    fn autosave(
+        &self,
+        capture: &CaptureArtifact,
+        objects: &[ToolObject],
+        source_pixbuf: &gtk4::gdk_pixbuf::Pixbuf,
+    ) -> RecoveryResult<()> {
+        if self.store.prepare_session(&capture.capture_id)? {
+            let source_path = self.store.source_image_path(&capture.capture_id)?;
+            source_pixbuf
+                .savev(&source_path, "png", &[])
+                .map_err(|err| std::io::Error::other(err.to_string()))?;
+        }
+        self.store
+            .write_snapshot(&RecoverySnapshot::new(capture.clone(), objects.to_vec()))
+    }
+
+    fn autosave_tick(
+        &self,
+        generation: u64,
+        capture: &CaptureArtifact,
+        editor_tools: &RefCell<editor::EditorTools>,
+        has_unsaved_changes: bool,
+        source_pixbuf: &gtk4::gdk_pixbuf::Pixbuf,
+    ) -> glib::ControlFlow {
+        if self.generation.get() != generation {
+            return glib::ControlFlow::Break;
+        }
+        if !has_unsaved_changes {
+            self.mark_saved();
+            return glib::ControlFlow::Continue;
+        }
+
+        let objects = editor_tools.borrow().objects().to_vec();
+        let unchanged = self
+            .session
+            .borrow()
+            .as_ref()
+            .and_then(|session| session.last_written.as_ref())
+            .is_some_and(|last_written| *last_written == objects);
+        if unchanged {
+            return glib::ControlFlow::Continue;
+        }
+
+        match self.autosave(capture, &objects, source_pixbuf) {
+            Ok(()) => {
+                if let Some(session) = self.session.borrow_mut().as_mut() {
+                    session.last_written = Some(objects);
+                }
+            }
+            Err(err) => {
+                tracing::warn!(
+                    capture_id = %capture.capture_id,
+                    ?err,
+                    "failed to autosave editor session"
+                );
+            }
+        }
+        glib::ControlFlow::Continue
+    }
+}
+
+impl Default for EditorRecoveryRuntime {
+    fn default() -> Self {
+        Self::new(RecoveryStore::with_default_path())
+    }
+}
+
+/// Periodically persists the editor objects while the session has unsaved
+/// changes. The timer stops once another session begins or the editor closes.
+pub(super) fn start_editor_autosave(
+    recovery: &EditorRecoveryRuntime,
+    generation: u64,
+    capture: CaptureArtifact,
+    editor_tools: Rc<RefCell<editor::EditorTools>>,
+    editor_has_unsaved_changes: Rc<RefCell<bool>>,
+    source_pixbuf: gtk4::gdk_pixbuf::Pixbuf,
+) {
+    let recovery = recovery.clone();
+    glib::timeout_add_seconds_local(EDITOR_AUTOSAVE_INTERVAL_SECS, move || {
+        let has_unsaved_changes = *editor_has_unsaved_changes.borrow();
+        recovery.autosave_tick(
+            generation,
+            &capture,
+            &editor_tools,
+            has_unsaved_changes,
+            &source_pixbuf,
+        )
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_runtime(name: &str) -> EditorRecoveryRuntime {
+        EditorRecoveryRuntime::new(RecoveryStore::new(std::env::temp_dir().join(format!(
+            "chalkak-editor-recovery-{name}-{}",
+            std::process::id()
+        ))))
+    }
+
+    #[test]
+    fn begin_session_invalidates_previous_generation() {
+        let recovery = scratch_runtime("generation");
+        let first = recovery.begin_session("a");
+        let second = recovery.begin_session("b");
+
+        assert_ne!(first, second);
+        recovery.end_session();
+        assert_ne!(recovery.generation.get(), second);
+    }
+
+    #[test]
+    fn begin_session_discards_files_of_previous_capture() {
+        let recovery = scratch_runtime("switch");
+        recovery.begin_session("a");
+        recovery.store().prepare_session("a").unwrap();
+
+        recovery.begin_session("b");
+
+        assert!(!recovery.store().root().join("a").exists());
+        let _ = std::fs::remove_dir_all(recovery.store().root());
+    }
+
+    #[test]
+    fn recovered_objects_are_taken_once() {
+        let recovery = scratch_runtime("stash");
+        recovery.stash_recovered_objects("a", Vec::new());
+
+        assert_eq!(recovery.take_recovered_objects("a"), Some(Vec::new()));
+        assert_eq!(recovery.take_recovered_objects("a"), None);
+    }
+}
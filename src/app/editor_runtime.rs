@@ -4,7 +4,8 @@ use std::rc::Rc;
 use std::time::Duration;
 
 use crate::capture;
-use crate::editor::tools::CropElement;
+use crate::clipboard::CopyProfile;
+use crate::editor::tools::{CropElement, ImageBounds};
 use crate::editor::{self, EditorAction, ToolKind, ToolObject};
 use crate::state::StateMachine;
 use crate::storage::StorageService;
@@ -21,6 +22,7 @@ use super::editor_popup::{
     EditorSelectionPalette, EditorTextInputPalette, ObjectDragState, TextPreeditState,
     ToolDragPreview,
 };
+use super::editor_recovery::start_editor_autosave;
 use super::editor_viewport::{
     apply_editor_viewport_and_refresh, apply_fit_zoom_once, scroller_center_anchor,
     sync_editor_zoom_slider, zoom_editor_viewport_and_refresh, ZOOM_SLIDER_STEPS,
@@ -31,6 +33,7 @@ use super::layout::{
     centered_window_geometry_for_capture, centered_window_geometry_for_point,
     clamp_window_geometry_to_current_monitors,
 };
+use super::ocr_overlay::{OcrOverlayState, SharedOcrOverlay};
 use super::runtime_support::{
     close_all_preview_windows, close_editor_window_if_open, PreviewWindowRuntime, RuntimeSession,
     ToastRuntime,
@@ -41,7 +44,7 @@ use super::{
     reset_editor_session_state, set_editor_pan_cursor, EditorOutputActionRuntime,
     EditorRuntimeState, EditorToolSwitchContext, SharedToolOptionsRefresh,
 };
-use crate::ui::{icon_button, StyleTokens};
+use crate::ui::{icon_button, icon_menu_button, StyleTokens};
 
 #[derive(Clone)]
 pub(super) struct EditorRenderContext {
@@ -62,6 +65,7 @@ pub(super) struct EditorRenderContext {
     pub(super) default_tool_color_override: Option<editor::tools::Color>,
     pub(super) default_text_size_override: Option<u8>,
     pub(super) default_stroke_width_override: Option<u8>,
+    pub(super) default_blur_mode: editor::tools::BlurMode,
     pub(super) editor_tool_option_presets: EditorToolOptionPresets,
     pub(super) editor_navigation_bindings: Rc<crate::input::EditorNavigationBindings>,
     pub(super) status_log_for_render: Rc<RefCell<String>>,
@@ -72,10 +76,12 @@ pub(super) struct EditorRenderContext {
     pub(super) close_editor_button: Button,
     pub(super) storage_service: Rc<Option<StorageService>>,
     pub(super) shared_machine: Rc<RefCell<StateMachine>>,
-    pub(super) ocr_engine: Rc<RefCell<Option<crate::ocr::OcrEngine>>>,
-    pub(super) ocr_language: crate::ocr::OcrLanguage,
-    pub(super) ocr_in_progress: Rc<Cell<bool>>,
+    pub(super) ocr_jobs: super::jobs::JobQueue,
+    pub(super) ocr_languages: super::ocr_support::SharedOcrLanguages,
+    pub(super) ocr_installed_languages: Rc<Vec<crate::ocr::OcrLanguage>>,
     pub(super) ocr_available: bool,
+    pub(super) copy_profile: crate::clipboard::CopyProfile,
+    pub(super) redaction_rules: Rc<crate::ocr::RedactionRules>,
 }
 
 mod canvas;
@@ -99,6 +105,7 @@ struct InitializedEditorRuntime {
     editor_redo_stack: Rc<RefCell<Vec<Vec<ToolObject>>>>,
     active_editor_tool: Rc<Cell<ToolKind>>,
     tool_drag_preview: Rc<RefCell<Option<ToolDragPreview>>>,
+    ocr_overlay: SharedOcrOverlay,
     tool_drag_start_canvas: Rc<Cell<(f64, f64)>>,
     active_pen_stroke_id: Rc<Cell<Option<u64>>>,
     selected_object_ids: Rc<RefCell<Vec<u64>>>,
@@ -126,6 +133,7 @@ fn initialize_editor_runtime_state(
     let editor_redo_stack = Rc::new(RefCell::new(Vec::<Vec<ToolObject>>::new()));
     let active_editor_tool = Rc::new(Cell::new(ToolKind::Select));
     let tool_drag_preview = Rc::new(RefCell::new(None::<ToolDragPreview>));
+    let ocr_overlay = Rc::new(RefCell::new(OcrOverlayState::default()));
     let tool_drag_start_canvas = Rc::new(Cell::new((0.0_f64, 0.0_f64)));
     let active_pen_stroke_id = Rc::new(Cell::new(None::<u64>));
     let selected_object_ids = Rc::new(RefCell::new(Vec::<u64>::new()));
@@ -167,6 +175,7 @@ fn initialize_editor_runtime_state(
         editor_redo_stack,
         active_editor_tool,
         tool_drag_preview,
+        ocr_overlay,
         tool_drag_start_canvas,
         active_pen_stroke_id,
         selected_object_ids,
@@ -203,6 +212,7 @@ pub(super) fn render_editor_state(
     let default_tool_color_override = context.default_tool_color_override;
     let default_text_size_override = context.default_text_size_override;
     let default_stroke_width_override = context.default_stroke_width_override;
+    let default_blur_mode = context.default_blur_mode;
     let editor_tool_option_presets = context.editor_tool_option_presets.clone();
     let editor_navigation_bindings = &context.editor_navigation_bindings;
     let status_log_for_render = &context.status_log_for_render;
@@ -213,7 +223,7 @@ pub(super) fn render_editor_state(
     let close_editor_button = &context.close_editor_button;
     let storage_service = &context.storage_service;
     let shared_machine = &context.shared_machine;
-    let ocr_engine = &context.ocr_engine;
+    let ocr_jobs = &context.ocr_jobs;
     let preview_anchor = active_capture.as_ref().and_then(|artifact| {
         let preview_title = format!("Preview - {}", artifact.capture_id);
         current_window_center(&preview_title)
@@ -252,6 +262,7 @@ pub(super) fn render_editor_state(
                 editor_redo_stack,
                 active_editor_tool,
                 tool_drag_preview,
+                ocr_overlay,
                 tool_drag_start_canvas,
                 active_pen_stroke_id,
                 selected_object_ids,
@@ -312,6 +323,7 @@ pub(super) fn render_editor_state(
                 default_text_size_override,
                 default_stroke_width_override,
                 rectangle_border_radius_override,
+                default_blur_mode,
                 editor_image_base_width,
                 editor_image_base_height,
             );
@@ -328,6 +340,8 @@ pub(super) fn render_editor_state(
                 EditorCanvasDrawDeps {
                     editor_tools: editor_tools.clone(),
                     tool_drag_preview: tool_drag_preview.clone(),
+                    active_editor_tool: active_editor_tool.clone(),
+                    ocr_overlay: ocr_overlay.clone(),
                     selected_object_ids: selected_object_ids.clone(),
                     pending_crop: pending_crop.clone(),
                     editor_selection_palette,
@@ -435,6 +449,7 @@ pub(super) fn render_editor_state(
                     selected_object_ids: selected_object_ids.clone(),
                     text_preedit_state: text_preedit_state.clone(),
                     editor_tool_switch_context: editor_tool_switch_context.clone(),
+                    ocr_overlay: ocr_overlay.clone(),
                     editor_image_base_width,
                     editor_image_base_height,
                 });
@@ -469,12 +484,17 @@ pub(super) fn render_editor_state(
                     selected_object_ids: selected_object_ids.clone(),
                     object_drag_state: object_drag_state.clone(),
                     pending_crop: pending_crop.clone(),
+                    text_preedit_state: text_preedit_state.clone(),
+                    editor_tool_switch_context: editor_tool_switch_context.clone(),
                     editor_image_base_width,
                     editor_image_base_height,
                     editor_source_pixbuf: editor_source_pixbuf.clone(),
-                    ocr_engine: ocr_engine.clone(),
-                    ocr_language: context.ocr_language,
-                    ocr_in_progress: context.ocr_in_progress.clone(),
+                    capture_id: artifact.capture_id.clone(),
+                    editor_toast: editor_toast.clone(),
+                    toast_duration_ms: style_tokens.toast_duration_ms,
+                    ocr_jobs: ocr_jobs.clone(),
+                    ocr_languages: context.ocr_languages.clone(),
+                    ocr_overlay: ocr_overlay.clone(),
                 });
             }
             {
@@ -723,6 +743,56 @@ pub(super) fn render_editor_state(
                 style_tokens.control_size as i32,
                 &["editor-action-button"],
             );
+            let ocr_language_labels =
+                crate::ocr::OcrLanguage::ALL.map(|language| language.display_name());
+            let (editor_ocr_language_button, editor_ocr_language_items) = icon_menu_button(
+                "languages-symbolic",
+                "OCR languages",
+                style_tokens.control_size as i32,
+                &["editor-action-button"],
+                &ocr_language_labels,
+            );
+            let editor_auto_redact_button = icon_button(
+                "shield-check-symbolic",
+                "Auto-redact sensitive text",
+                style_tokens.control_size as i32,
+                &["editor-action-button"],
+            );
+            if !context.ocr_available {
+                editor_auto_redact_button.set_sensitive(false);
+                editor_auto_redact_button.set_tooltip_text(Some("OCR models not installed"));
+                editor_ocr_language_button.set_sensitive(false);
+            }
+            let table_format_labels =
+                crate::ocr::TableFormat::ALL.map(crate::ocr::TableFormat::label);
+            let (editor_ocr_table_button, editor_ocr_table_items) = icon_menu_button(
+                "table-symbolic",
+                "Copy OCR region as table",
+                style_tokens.control_size as i32,
+                &["editor-action-button"],
+                &table_format_labels,
+            );
+            let export_format_labels =
+                crate::ocr::OcrExportFormat::ALL.map(crate::ocr::OcrExportFormat::label);
+            let (editor_ocr_export_button, editor_ocr_export_items) = icon_menu_button(
+                "file-search-symbolic",
+                "Save and export OCR text",
+                style_tokens.control_size as i32,
+                &["editor-action-button"],
+                &export_format_labels,
+            );
+            if !context.ocr_available {
+                editor_ocr_table_button.set_sensitive(false);
+                editor_ocr_export_button.set_sensitive(false);
+            }
+            let copy_as_labels = CopyProfile::ALL.map(CopyProfile::label);
+            let (editor_copy_as_button, editor_copy_as_items) = icon_menu_button(
+                "chevron-down-symbolic",
+                "Copy as…",
+                style_tokens.control_size as i32,
+                &["editor-action-button"],
+                &copy_as_labels,
+            );
             let editor_close_button = icon_button(
                 "x-symbolic",
                 "Close editor",
@@ -758,11 +828,16 @@ pub(super) fn render_editor_state(
             // Tool selector group
             top_controls_left.append(&top_toolbar_row);
 
-            // File actions group (save/copy)
+            // File actions group (auto-redact/OCR languages/OCR table/OCR export/save/copy)
             let file_actions_group = GtkBox::new(Orientation::Horizontal, style_tokens.spacing_4);
             file_actions_group.add_css_class("editor-action-group");
+            file_actions_group.append(&editor_auto_redact_button);
+            file_actions_group.append(&editor_ocr_language_button);
+            file_actions_group.append(&editor_ocr_table_button);
+            file_actions_group.append(&editor_ocr_export_button);
             file_actions_group.append(&editor_save_button);
             file_actions_group.append(&editor_copy_button);
+            file_actions_group.append(&editor_copy_as_button);
             top_controls_left.append(&file_actions_group);
             let top_controls_left_revealer = Revealer::new();
             top_controls_left_revealer.set_transition_duration(motion_hover_ms);
@@ -795,14 +870,18 @@ pub(super) fn render_editor_state(
             top_controls_right_revealer.set_can_target(false);
             top_controls_right_revealer.set_child(Some(&top_controls_right));
             editor_overlay.add_overlay(&top_controls_right_revealer);
+            let find_entry_focused = Rc::new(Cell::new(false));
             connect_editor_tool_shortcut_fallback(
                 &editor_window_instance,
                 &tool_buttons,
                 editor_input_mode,
+                &find_entry_focused,
             );
             let ToolOptionsRuntime {
                 tool_options_bar,
                 tool_options_toggle,
+                image_paste_button,
+                image_open_button,
             } = build_tool_options_runtime(ToolOptionsBuildContext {
                 style_tokens,
                 theme_mode,
@@ -813,6 +892,10 @@ pub(super) fn render_editor_state(
                 tool_option_presets: tool_option_presets.clone(),
                 refresh_tool_options: refresh_tool_options.clone(),
                 status_log_for_render: status_log_for_render.clone(),
+                editor_undo_stack: editor_undo_stack.clone(),
+                editor_redo_stack: editor_redo_stack.clone(),
+                editor_has_unsaved_changes: editor_has_unsaved_changes.clone(),
+                selected_object_ids: selected_object_ids.clone(),
             });
 
             let bottom_left_controls = GtkBox::new(Orientation::Horizontal, style_tokens.spacing_8);
@@ -865,6 +948,36 @@ pub(super) fn render_editor_state(
             bottom_right_controls_revealer.set_can_target(false);
             bottom_right_controls_revealer.set_child(Some(&bottom_right_controls));
             editor_overlay.add_overlay(&bottom_right_controls_revealer);
+            let editor_find_bar = build_editor_find_bar(
+                style_tokens,
+                motion_hover_ms,
+                EditorFindContext {
+                    editor_canvas: editor_canvas.clone(),
+                    editor_scroller: editor_scroller.clone(),
+                    editor_viewport: editor_viewport.clone(),
+                    editor_viewport_status: editor_viewport_status.clone(),
+                    zoom_slider: zoom_slider.clone(),
+                    zoom_slider_syncing: zoom_slider_syncing.clone(),
+                    editor_tools: editor_tools.clone(),
+                    editor_undo_stack: editor_undo_stack.clone(),
+                    editor_redo_stack: editor_redo_stack.clone(),
+                    selected_object_ids: selected_object_ids.clone(),
+                    editor_has_unsaved_changes: editor_has_unsaved_changes.clone(),
+                    status_log_for_render: status_log_for_render.clone(),
+                    editor_source_pixbuf: editor_source_pixbuf.clone(),
+                    capture_id: artifact.capture_id.clone(),
+                    editor_toast: editor_toast.clone(),
+                    toast_duration_ms: style_tokens.toast_duration_ms,
+                    ocr_jobs: ocr_jobs.clone(),
+                    ocr_languages: context.ocr_languages.clone(),
+                    ocr_available: context.ocr_available,
+                    ocr_overlay: ocr_overlay.clone(),
+                    find_entry_focused: find_entry_focused.clone(),
+                    editor_image_base_width,
+                    editor_image_base_height,
+                },
+            );
+            editor_overlay.add_overlay(editor_find_bar.widget());
 
             connect_editor_overlay_hover_controls(
                 &editor_overlay,
@@ -880,16 +993,46 @@ pub(super) fn render_editor_state(
             editor_toast_anchor.set_margin_bottom(style_tokens.spacing_12);
             editor_toast_anchor.set_margin_start(style_tokens.spacing_12);
             editor_toast_anchor.set_margin_end(style_tokens.spacing_12);
+            let editor_toast_row = GtkBox::new(Orientation::Horizontal, style_tokens.spacing_8);
             let editor_toast_label = Label::new(Some(""));
             editor_toast_label.add_css_class("toast-badge");
             editor_toast_label.set_visible(false);
-            editor_toast_anchor.append(&editor_toast_label);
+            let editor_toast_action_button = Button::with_label("");
+            editor_toast_action_button.add_css_class("toast-action-button");
+            editor_toast_action_button.set_valign(Align::Center);
+            editor_toast_action_button.set_visible(false);
+            editor_toast_row.append(&editor_toast_label);
+            editor_toast_row.append(&editor_toast_action_button);
+            editor_toast_anchor.append(&editor_toast_row);
             editor_overlay.add_overlay(&editor_toast_anchor);
             editor_window_instance.set_child(Some(&editor_overlay));
-            let editor_toast_runtime = ToastRuntime::new(&editor_toast_label);
+            let editor_toast_runtime =
+                ToastRuntime::with_action_button(&editor_toast_label, &editor_toast_action_button);
             *editor_toast.borrow_mut() = Some(editor_toast_runtime.clone());
             // Opening editor with a fresh capture should start as clean.
             reset_editor_session_state(editor_runtime);
+            let recovery_generation = editor_runtime.recovery.begin_session(&artifact.capture_id);
+            if let Some(objects) = editor_runtime
+                .recovery
+                .take_recovered_objects(&artifact.capture_id)
+            {
+                editor_tools.borrow_mut().replace_objects(objects);
+                *editor_has_unsaved_changes.borrow_mut() = true;
+                editor_toast_runtime.show(
+                    "Recovered unsaved annotations",
+                    style_tokens.toast_duration_ms,
+                );
+            }
+            if let Some(source_pixbuf) = editor_source_pixbuf.clone() {
+                start_editor_autosave(
+                    &editor_runtime.recovery,
+                    recovery_generation,
+                    artifact.clone(),
+                    editor_tools.clone(),
+                    editor_has_unsaved_changes.clone(),
+                    source_pixbuf,
+                );
+            }
 
             {
                 connect_editor_zoom_slider(
@@ -964,6 +1107,8 @@ pub(super) fn render_editor_state(
                     pending_crop: pending_crop.clone(),
                     editor_source_pixbuf: editor_source_pixbuf.clone(),
                     editor_has_unsaved_changes: editor_has_unsaved_changes.clone(),
+                    editor_recovery: editor_runtime.recovery.clone(),
+                    copy_profile: context.copy_profile,
                     toast_duration_ms: style_tokens.toast_duration_ms,
                 };
                 connect_editor_output_button(
@@ -978,6 +1123,86 @@ pub(super) fn render_editor_state(
                     EditorAction::Copy,
                     "copy",
                 );
+                for (profile, item) in CopyProfile::ALL.into_iter().zip(&editor_copy_as_items) {
+                    connect_editor_output_button(
+                        item,
+                        &output_action_runtime,
+                        EditorAction::CopyAs(profile),
+                        "copy",
+                    );
+                }
+                connect_editor_auto_redact_button(
+                    &editor_auto_redact_button,
+                    EditorAutoRedactContext {
+                        editor_canvas: editor_canvas.clone(),
+                        editor_tools: editor_tools.clone(),
+                        editor_undo_stack: editor_undo_stack.clone(),
+                        editor_redo_stack: editor_redo_stack.clone(),
+                        selected_object_ids: selected_object_ids.clone(),
+                        editor_has_unsaved_changes: editor_has_unsaved_changes.clone(),
+                        status_log_for_render: status_log_for_render.clone(),
+                        editor_toast: editor_toast_runtime.clone(),
+                        toast_duration_ms: style_tokens.toast_duration_ms,
+                        editor_source_pixbuf: editor_source_pixbuf.clone(),
+                        capture_id: artifact.capture_id.clone(),
+                        ocr_jobs: ocr_jobs.clone(),
+                        ocr_languages: context.ocr_languages.clone(),
+                        ocr_overlay: ocr_overlay.clone(),
+                        redaction_rules: context.redaction_rules.clone(),
+                    },
+                );
+                connect_editor_image_insert_buttons(
+                    &image_paste_button,
+                    &image_open_button,
+                    EditorImageInsertContext {
+                        editor_window: editor_window_instance.clone(),
+                        editor_canvas: editor_canvas.clone(),
+                        editor_tools: editor_tools.clone(),
+                        editor_undo_stack: editor_undo_stack.clone(),
+                        editor_redo_stack: editor_redo_stack.clone(),
+                        selected_object_ids: selected_object_ids.clone(),
+                        editor_has_unsaved_changes: editor_has_unsaved_changes.clone(),
+                        status_log_for_render: status_log_for_render.clone(),
+                        editor_toast: editor_toast_runtime.clone(),
+                        toast_duration_ms: style_tokens.toast_duration_ms,
+                        image_bounds: ImageBounds::new(
+                            editor_image_base_width,
+                            editor_image_base_height,
+                        ),
+                    },
+                );
+                connect_editor_ocr_language_menu(
+                    &editor_ocr_language_button,
+                    &editor_ocr_language_items,
+                    EditorOcrLanguageMenuContext {
+                        editor_canvas: editor_canvas.clone(),
+                        status_log_for_render: status_log_for_render.clone(),
+                        ocr_languages: context.ocr_languages.clone(),
+                        ocr_installed_languages: context.ocr_installed_languages.clone(),
+                        ocr_overlay: ocr_overlay.clone(),
+                    },
+                );
+                connect_editor_ocr_table_items(
+                    &editor_ocr_table_items,
+                    EditorOcrTableContext {
+                        status_log_for_render: status_log_for_render.clone(),
+                        editor_toast: editor_toast_runtime.clone(),
+                        toast_duration_ms: style_tokens.toast_duration_ms,
+                        ocr_overlay: ocr_overlay.clone(),
+                    },
+                );
+                connect_editor_ocr_export_items(
+                    &editor_ocr_export_items,
+                    EditorOcrExportContext {
+                        editor_canvas: editor_canvas.clone(),
+                        status_log_for_render: status_log_for_render.clone(),
+                        editor_toast: editor_toast_runtime.clone(),
+                        toast_duration_ms: style_tokens.toast_duration_ms,
+                        output_action_runtime: output_action_runtime.clone(),
+                        ocr_jobs: ocr_jobs.clone(),
+                        ocr_languages: context.ocr_languages.clone(),
+                    },
+                );
             }
             {
                 connect_editor_close_dialog(EditorCloseDialogContext {
@@ -1031,6 +1256,9 @@ pub(super) fn render_editor_state(
                     drag_pan_active: drag_pan_active.clone(),
                     drag_pan_pointer_origin: drag_pan_pointer_origin.clone(),
                     refresh_editor_cursor: refresh_editor_cursor.clone(),
+                    ocr_overlay: ocr_overlay.clone(),
+                    find_bar: editor_find_bar.clone(),
+                    find_entry_focused: find_entry_focused.clone(),
                     editor_image_base_width,
                     editor_image_base_height,
                 });
@@ -1,8 +1,8 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 
 use crate::editor::tools::{CropElement, ImageBounds};
-use crate::editor::{self};
+use crate::editor::{self, ToolKind};
 
 use gtk4::gdk::prelude::GdkCairoContextExt;
 use gtk4::prelude::*;
@@ -15,12 +15,15 @@ use crate::app::adaptive::{
 use crate::app::editor_popup::{
     caret_layout_to_canvas_cursor_rect, draw_crop_mask, draw_drag_preview_overlay,
     draw_editor_tool_objects, text_caret_layout, BlurRenderCache, EditorSelectionPalette,
-    EditorTextInputPalette, TextPreeditState, ToolDragPreview, ToolRenderContext,
+    EditorTextInputPalette, ImageRenderCache, TextPreeditState, ToolDragPreview, ToolRenderContext,
 };
+use crate::app::ocr_overlay::{draw_ocr_find_matches, draw_ocr_overlay, SharedOcrOverlay};
 
 pub(super) struct EditorCanvasDrawDeps {
     pub(super) editor_tools: Rc<RefCell<editor::EditorTools>>,
     pub(super) tool_drag_preview: Rc<RefCell<Option<ToolDragPreview>>>,
+    pub(super) active_editor_tool: Rc<Cell<ToolKind>>,
+    pub(super) ocr_overlay: SharedOcrOverlay,
     pub(super) selected_object_ids: Rc<RefCell<Vec<u64>>>,
     pub(super) pending_crop: Rc<RefCell<Option<CropElement>>>,
     pub(super) editor_selection_palette: EditorSelectionPalette,
@@ -38,6 +41,7 @@ pub(super) fn apply_editor_default_tool_settings(
     default_text_size_override: Option<u8>,
     default_stroke_width_override: Option<u8>,
     rectangle_border_radius_override: Option<u16>,
+    default_blur_mode: editor::tools::BlurMode,
     editor_image_base_width: i32,
     editor_image_base_height: i32,
 ) {
@@ -67,6 +71,7 @@ pub(super) fn apply_editor_default_tool_settings(
         tools.set_rectangle_border_radius(radius);
     }
     tools.set_arrow_head_size(default_stroke_size.saturating_mul(3).max(8));
+    tools.set_blur_mode(default_blur_mode);
 }
 
 pub(super) fn configure_editor_canvas_draw(
@@ -80,6 +85,8 @@ pub(super) fn configure_editor_canvas_draw(
     let EditorCanvasDrawDeps {
         editor_tools,
         tool_drag_preview,
+        active_editor_tool,
+        ocr_overlay,
         selected_object_ids,
         pending_crop,
         editor_selection_palette,
@@ -90,6 +97,7 @@ pub(super) fn configure_editor_canvas_draw(
     } = deps;
     let blur_render_cache = Rc::new(RefCell::new(BlurRenderCache::default()));
     let blur_render_cache_for_draw = blur_render_cache.clone();
+    let image_render_cache = Rc::new(RefCell::new(ImageRenderCache::default()));
     editor_canvas.set_draw_func(move |_, context, width, height| {
         if width <= 0 || height <= 0 {
             return;
@@ -117,6 +125,7 @@ pub(super) fn configure_editor_canvas_draw(
                 active_text_id: tools.active_text_id(),
                 active_text_preedit: Some(&preedit_state),
                 blur_cache: Some(&blur_render_cache_for_draw),
+                image_cache: Some(&image_render_cache),
             },
         );
         if editor_input_mode.borrow().text_input_active() {
@@ -169,6 +178,11 @@ pub(super) fn configure_editor_canvas_draw(
                 editor_selection_palette,
             );
         }
+        let ocr_overlay = ocr_overlay.borrow();
+        if active_editor_tool.get() == ToolKind::Ocr {
+            draw_ocr_overlay(context, &ocr_overlay);
+        }
+        draw_ocr_find_matches(context, &ocr_overlay);
         context.restore().ok();
     });
 }
@@ -1,8 +1,11 @@
 use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 
-use crate::editor::tools::CropPreset;
-use crate::editor::{self, ToolKind};
+use crate::editor::tools::{
+    BadgeOptions, BadgeStyle, BlurMode, Color, CropPreset, LineCap, LineDash, MagnifierShape,
+    PenSmoothing, PressureCurve, SpotlightOptions, SpotlightShape, Stamp,
+};
+use crate::editor::{self, ToolKind, ToolObject};
 
 use gtk4::prelude::*;
 use gtk4::{
@@ -10,6 +13,7 @@ use gtk4::{
 };
 
 use crate::app::adaptive::{nearest_preset_u8, EditorToolOptionPresets};
+use crate::app::editor_history::{record_undo_snapshot, snapshot_editor_objects};
 use crate::app::{EditorToolSwitchContext, SharedToolOptionsRefresh};
 use crate::ui::{icon_button, StyleTokens};
 
@@ -17,6 +21,22 @@ use super::wiring::{connect_tool_button_selection, EDITOR_TOOLBAR_ENTRIES};
 
 const STROKE_PREVIEW_ENDPOINT_MARGIN: f64 = 4.5;
 const STROKE_PREVIEW_STROKE_PADDING: i32 = 2;
+const BADGE_SIZE_PRESETS: [u8; 4] = [20, 28, 36, 48];
+const REDACTION_BLOCK_SIZE_PRESETS: [u8; 4] = [6, 12, 20, 32];
+const REDACTION_FILL_PRESETS: [(&str, Color); 3] = [
+    ("Black", Color::new(0, 0, 0)),
+    ("White", Color::new(255, 255, 255)),
+    ("Gray", Color::new(128, 128, 128)),
+];
+const SPOTLIGHT_OPACITY_PRESETS: [u8; 4] = [40, 60, 75, 90];
+const MAGNIFIER_ZOOM_PRESETS: [u16; 4] = [150, 200, 300, 400];
+const CALLOUT_FILL_PRESETS: [(&str, Color); 3] = [
+    ("White", Color::new(255, 255, 255)),
+    ("Yellow", Color::new(255, 243, 176)),
+    ("Sky", Color::new(219, 234, 254)),
+];
+const IMAGE_OPACITY_PRESETS: [u8; 4] = [25, 50, 75, 100];
+const IMAGE_ROTATION_PRESETS: [i16; 8] = [-90, -45, -15, 0, 15, 45, 90, 180];
 
 pub(super) struct ToolOptionsBuildContext {
     pub(super) style_tokens: StyleTokens,
@@ -28,11 +48,17 @@ pub(super) struct ToolOptionsBuildContext {
     pub(super) tool_option_presets: EditorToolOptionPresets,
     pub(super) refresh_tool_options: SharedToolOptionsRefresh,
     pub(super) status_log_for_render: Rc<RefCell<String>>,
+    pub(super) editor_undo_stack: Rc<RefCell<Vec<Vec<ToolObject>>>>,
+    pub(super) editor_redo_stack: Rc<RefCell<Vec<Vec<ToolObject>>>>,
+    pub(super) editor_has_unsaved_changes: Rc<RefCell<bool>>,
+    pub(super) selected_object_ids: Rc<RefCell<Vec<u64>>>,
 }
 
 pub(super) struct ToolOptionsRuntime {
     pub(super) tool_options_bar: GtkBox,
     pub(super) tool_options_toggle: Button,
+    pub(super) image_paste_button: Button,
+    pub(super) image_open_button: Button,
 }
 
 pub(super) fn build_top_toolbar_row(
@@ -81,6 +107,10 @@ pub(super) fn build_tool_options_runtime(context: ToolOptionsBuildContext) -> To
         tool_option_presets,
         refresh_tool_options,
         status_log_for_render,
+        editor_undo_stack,
+        editor_redo_stack,
+        editor_has_unsaved_changes,
+        selected_object_ids,
     } = context;
 
     let tool_options_bar = GtkBox::new(Orientation::Vertical, style_tokens.spacing_8);
@@ -214,6 +244,49 @@ pub(super) fn build_tool_options_runtime(context: ToolOptionsBuildContext) -> To
     collapsed_text_size_chip.set_size_request(34, 30);
     tool_options_collapsed_row.append(&collapsed_text_size_chip);
 
+    let initial_fill_enabled = editor_tools.borrow().ellipse_options().fill_enabled;
+    let collapsed_fill_chip = build_collapsed_label_chip(fill_mode_label(initial_fill_enabled));
+    tool_options_collapsed_row.append(&collapsed_fill_chip);
+
+    let initial_line_options = editor_tools.borrow().line_options();
+    let collapsed_dash_chip = build_collapsed_label_chip(initial_line_options.dash.label());
+    tool_options_collapsed_row.append(&collapsed_dash_chip);
+    let collapsed_cap_chip = build_collapsed_label_chip(initial_line_options.cap.label());
+    tool_options_collapsed_row.append(&collapsed_cap_chip);
+
+    let initial_pen_options = editor_tools.borrow().pen_options();
+    let collapsed_pen_chip = build_collapsed_label_chip(initial_pen_options.smoothing.label());
+    tool_options_collapsed_row.append(&collapsed_pen_chip);
+
+    let initial_blur_options = editor_tools.borrow().blur_options();
+    let collapsed_redaction_chip = build_collapsed_label_chip(initial_blur_options.mode.label());
+    tool_options_collapsed_row.append(&collapsed_redaction_chip);
+
+    let initial_badge_options = editor_tools.borrow().badge_options();
+    let collapsed_badge_chip =
+        build_collapsed_label_chip(&badge_sequence_label(initial_badge_options));
+    tool_options_collapsed_row.append(&collapsed_badge_chip);
+
+    let initial_spotlight_options = editor_tools.borrow().spotlight_options();
+    let collapsed_spotlight_chip =
+        build_collapsed_label_chip(&spotlight_dim_label(initial_spotlight_options));
+    tool_options_collapsed_row.append(&collapsed_spotlight_chip);
+
+    let initial_magnifier_options = editor_tools.borrow().magnifier_options();
+    let collapsed_magnifier_chip = build_collapsed_label_chip(&magnifier_zoom_label(
+        initial_magnifier_options.zoom_percent,
+    ));
+    tool_options_collapsed_row.append(&collapsed_magnifier_chip);
+
+    let initial_callout_options = editor_tools.borrow().callout_options();
+    let collapsed_callout_chip =
+        build_collapsed_label_chip(callout_fill_label(initial_callout_options.fill));
+    tool_options_collapsed_row.append(&collapsed_callout_chip);
+
+    let initial_image_stamp = editor_tools.borrow().image_stamp();
+    let collapsed_image_chip = build_collapsed_label_chip(initial_image_stamp.label());
+    tool_options_collapsed_row.append(&collapsed_image_chip);
+
     let initial_crop_preset = editor_tools.borrow().crop_options().preset;
     let collapsed_crop_preset_chip = Button::with_label(initial_crop_preset.label());
     collapsed_crop_preset_chip.set_focus_on_click(false);
@@ -256,6 +329,16 @@ pub(super) fn build_tool_options_runtime(context: ToolOptionsBuildContext) -> To
         let collapsed_thickness = collapsed_thickness.clone();
         let collapsed_thickness_preview = collapsed_thickness_preview.clone();
         let collapsed_text_size_chip = collapsed_text_size_chip.clone();
+        let collapsed_fill_chip = collapsed_fill_chip.clone();
+        let collapsed_dash_chip = collapsed_dash_chip.clone();
+        let collapsed_cap_chip = collapsed_cap_chip.clone();
+        let collapsed_pen_chip = collapsed_pen_chip.clone();
+        let collapsed_redaction_chip = collapsed_redaction_chip.clone();
+        let collapsed_badge_chip = collapsed_badge_chip.clone();
+        let collapsed_spotlight_chip = collapsed_spotlight_chip.clone();
+        let collapsed_magnifier_chip = collapsed_magnifier_chip.clone();
+        let collapsed_callout_chip = collapsed_callout_chip.clone();
+        let collapsed_image_chip = collapsed_image_chip.clone();
         let collapsed_crop_preset_chip = collapsed_crop_preset_chip.clone();
         let stroke_width_presets = stroke_width_presets.clone();
         let text_size_presets = text_size_presets.clone();
@@ -274,6 +357,19 @@ pub(super) fn build_tool_options_runtime(context: ToolOptionsBuildContext) -> To
             let text_size =
                 nearest_preset_u8(f64::from(tools.text_options().size), &text_size_presets);
             collapsed_text_size_chip.set_label(text_size.to_string().as_str());
+            collapsed_fill_chip.set_label(fill_mode_label(tools.ellipse_options().fill_enabled));
+            let line_options = tools.line_options();
+            collapsed_dash_chip.set_label(line_options.dash.label());
+            collapsed_cap_chip.set_label(line_options.cap.label());
+            collapsed_pen_chip.set_label(tools.pen_options().smoothing.label());
+            collapsed_redaction_chip.set_label(tools.blur_options().mode.label());
+            collapsed_badge_chip.set_label(&badge_sequence_label(tools.badge_options()));
+            collapsed_spotlight_chip.set_label(&spotlight_dim_label(tools.spotlight_options()));
+            collapsed_magnifier_chip.set_label(&magnifier_zoom_label(
+                tools.magnifier_options().zoom_percent,
+            ));
+            collapsed_callout_chip.set_label(callout_fill_label(tools.callout_options().fill));
+            collapsed_image_chip.set_label(tools.image_stamp().label());
             let crop_label = tools.crop_options().preset.label();
             collapsed_crop_preset_chip.set_label(crop_label);
         }
@@ -443,6 +539,604 @@ pub(super) fn build_tool_options_runtime(context: ToolOptionsBuildContext) -> To
     thickness_group.append(&thickness_row);
     tool_options_content.append(&thickness_group);
 
+    let fill_group = build_label_chip_group(
+        style_tokens,
+        "Fill",
+        &[false, true],
+        initial_fill_enabled,
+        -1,
+        4,
+        |fill_enabled: bool| fill_mode_label(fill_enabled).to_string(),
+        {
+            let editor_tools = editor_tools.clone();
+            let editor_canvas = editor_canvas.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            let refresh_collapsed_option_chips = refresh_collapsed_option_chips.clone();
+            Rc::new(move |fill_enabled: bool| {
+                editor_tools.borrow_mut().set_ellipse_fill(fill_enabled);
+                *status_log_for_render.borrow_mut() =
+                    format!("fill mode: {}", fill_mode_label(fill_enabled));
+                editor_canvas.queue_draw();
+                (refresh_collapsed_option_chips.as_ref())();
+            })
+        },
+    );
+    tool_options_content.append(&fill_group);
+
+    let dash_group = build_label_chip_group(
+        style_tokens,
+        "Line Style",
+        &LineDash::ALL,
+        initial_line_options.dash,
+        -1,
+        4,
+        |dash: LineDash| dash.label().to_string(),
+        {
+            let editor_tools = editor_tools.clone();
+            let editor_canvas = editor_canvas.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            let refresh_collapsed_option_chips = refresh_collapsed_option_chips.clone();
+            Rc::new(move |dash: LineDash| {
+                editor_tools.borrow_mut().set_shared_dash(dash);
+                *status_log_for_render.borrow_mut() = format!("line style: {}", dash.label());
+                editor_canvas.queue_draw();
+                (refresh_collapsed_option_chips.as_ref())();
+            })
+        },
+    );
+    tool_options_content.append(&dash_group);
+
+    let cap_group = build_label_chip_group(
+        style_tokens,
+        "Line Cap",
+        &LineCap::ALL,
+        initial_line_options.cap,
+        -1,
+        4,
+        |cap: LineCap| cap.label().to_string(),
+        {
+            let editor_tools = editor_tools.clone();
+            let editor_canvas = editor_canvas.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            let refresh_collapsed_option_chips = refresh_collapsed_option_chips.clone();
+            Rc::new(move |cap: LineCap| {
+                editor_tools.borrow_mut().set_line_cap(cap);
+                *status_log_for_render.borrow_mut() = format!("line cap: {}", cap.label());
+                editor_canvas.queue_draw();
+                (refresh_collapsed_option_chips.as_ref())();
+            })
+        },
+    );
+    tool_options_content.append(&cap_group);
+
+    let redaction_group = GtkBox::new(Orientation::Vertical, 0);
+    let redaction_block_size_group = build_label_chip_group(
+        style_tokens,
+        "Block Size",
+        &REDACTION_BLOCK_SIZE_PRESETS,
+        nearest_preset_u8(
+            f64::from(initial_blur_options.block_size),
+            &REDACTION_BLOCK_SIZE_PRESETS,
+        ),
+        34,
+        0,
+        |block_size: u8| block_size.to_string(),
+        {
+            let editor_tools = editor_tools.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            Rc::new(move |block_size: u8| {
+                editor_tools.borrow_mut().set_blur_block_size(block_size);
+                *status_log_for_render.borrow_mut() =
+                    format!("redaction block size preset: {block_size}");
+            })
+        },
+    );
+    let redaction_fill_group = build_label_chip_group(
+        style_tokens,
+        "Fill Color",
+        &REDACTION_FILL_PRESETS,
+        REDACTION_FILL_PRESETS
+            .iter()
+            .copied()
+            .find(|(_, color)| *color == initial_blur_options.fill_color)
+            .unwrap_or(REDACTION_FILL_PRESETS[0]),
+        -1,
+        4,
+        |(label, _): (&'static str, Color)| label.to_string(),
+        {
+            let editor_tools = editor_tools.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            Rc::new(move |(label, color): (&'static str, Color)| {
+                editor_tools.borrow_mut().set_blur_fill_color(color);
+                *status_log_for_render.borrow_mut() = format!("redaction fill color: {label}");
+            })
+        },
+    );
+    // Block size only matters for mosaic modes and the fill color only for
+    // solid fill, so the other row is greyed out rather than hidden.
+    let sync_redaction_groups = {
+        let redaction_block_size_group = redaction_block_size_group.clone();
+        let redaction_fill_group = redaction_fill_group.clone();
+        move |mode: BlurMode| {
+            redaction_block_size_group
+                .set_sensitive(matches!(mode, BlurMode::Pixelate | BlurMode::Secure));
+            redaction_fill_group.set_sensitive(mode == BlurMode::SolidFill);
+        }
+    };
+    sync_redaction_groups(initial_blur_options.mode);
+    let redaction_mode_group = build_label_chip_group(
+        style_tokens,
+        "Redaction",
+        &BlurMode::ALL,
+        initial_blur_options.mode,
+        -1,
+        4,
+        |mode: BlurMode| mode.label().to_string(),
+        {
+            let editor_tools = editor_tools.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            let refresh_collapsed_option_chips = refresh_collapsed_option_chips.clone();
+            Rc::new(move |mode: BlurMode| {
+                editor_tools.borrow_mut().set_blur_mode(mode);
+                sync_redaction_groups(mode);
+                *status_log_for_render.borrow_mut() = format!("redaction mode: {}", mode.label());
+                (refresh_collapsed_option_chips.as_ref())();
+            })
+        },
+    );
+    redaction_group.append(&redaction_mode_group);
+    redaction_group.append(&redaction_block_size_group);
+    redaction_group.append(&redaction_fill_group);
+    tool_options_content.append(&redaction_group);
+
+    let badge_group = GtkBox::new(Orientation::Vertical, 0);
+    let placed_object_history = PlacedObjectHistory {
+        editor_undo_stack,
+        editor_redo_stack,
+        editor_has_unsaved_changes,
+    };
+    let badge_style_group = build_label_chip_group(
+        style_tokens,
+        "Numbering",
+        &BadgeStyle::ALL,
+        initial_badge_options.style,
+        -1,
+        4,
+        |style: BadgeStyle| style.label().to_string(),
+        {
+            let editor_tools = editor_tools.clone();
+            let editor_canvas = editor_canvas.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            let refresh_collapsed_option_chips = refresh_collapsed_option_chips.clone();
+            let history = placed_object_history.clone();
+            Rc::new(move |style: BadgeStyle| {
+                history.apply(&editor_tools, |tools| tools.set_badge_style(style));
+                *status_log_for_render.borrow_mut() = format!("badge numbering: {}", style.label());
+                editor_canvas.queue_draw();
+                (refresh_collapsed_option_chips.as_ref())();
+            })
+        },
+    );
+    badge_group.append(&badge_style_group);
+    let badge_start_group = build_stepper_group(
+        style_tokens,
+        "Start At",
+        initial_badge_options.start,
+        (
+            editor::tools::BADGE_MIN_START,
+            editor::tools::BADGE_MAX_START,
+        ),
+        {
+            let editor_tools = editor_tools.clone();
+            let editor_canvas = editor_canvas.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            let refresh_collapsed_option_chips = refresh_collapsed_option_chips.clone();
+            let history = placed_object_history.clone();
+            Rc::new(move |start: u32| {
+                history.apply(&editor_tools, |tools| tools.set_badge_start(start));
+                *status_log_for_render.borrow_mut() = format!("badge numbering starts at {start}");
+                editor_canvas.queue_draw();
+                (refresh_collapsed_option_chips.as_ref())();
+            })
+        },
+    );
+    badge_group.append(&badge_start_group);
+    let badge_size_group = build_label_chip_group(
+        style_tokens,
+        "Badge Size",
+        &BADGE_SIZE_PRESETS,
+        nearest_preset_u8(f64::from(initial_badge_options.size), &BADGE_SIZE_PRESETS),
+        34,
+        0,
+        |size: u8| size.to_string(),
+        {
+            let editor_tools = editor_tools.clone();
+            let editor_canvas = editor_canvas.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            Rc::new(move |size: u8| {
+                editor_tools.borrow_mut().set_badge_size(size);
+                *status_log_for_render.borrow_mut() = format!("badge size preset: {size}");
+                editor_canvas.queue_draw();
+            })
+        },
+    );
+    badge_group.append(&badge_size_group);
+    tool_options_content.append(&badge_group);
+
+    let spotlight_group = GtkBox::new(Orientation::Vertical, 0);
+    let spotlight_shape_group = build_label_chip_group(
+        style_tokens,
+        "Hole Shape",
+        &SpotlightShape::ALL,
+        initial_spotlight_options.shape,
+        -1,
+        4,
+        |shape: SpotlightShape| shape.label().to_string(),
+        {
+            let editor_tools = editor_tools.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            Rc::new(move |shape: SpotlightShape| {
+                editor_tools.borrow_mut().set_spotlight_shape(shape);
+                *status_log_for_render.borrow_mut() =
+                    format!("spotlight hole shape: {}", shape.label());
+            })
+        },
+    );
+    spotlight_group.append(&spotlight_shape_group);
+    let spotlight_opacity_group = build_label_chip_group(
+        style_tokens,
+        "Dim",
+        &SPOTLIGHT_OPACITY_PRESETS,
+        nearest_preset_u8(
+            f64::from(initial_spotlight_options.opacity),
+            &SPOTLIGHT_OPACITY_PRESETS,
+        ),
+        -1,
+        4,
+        |opacity: u8| format!("{opacity}%"),
+        {
+            let editor_tools = editor_tools.clone();
+            let editor_canvas = editor_canvas.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            let refresh_collapsed_option_chips = refresh_collapsed_option_chips.clone();
+            let history = placed_object_history.clone();
+            Rc::new(move |opacity: u8| {
+                history.apply(&editor_tools, |tools| tools.set_spotlight_opacity(opacity));
+                *status_log_for_render.borrow_mut() = format!("spotlight dim: {opacity}%");
+                editor_canvas.queue_draw();
+                (refresh_collapsed_option_chips.as_ref())();
+            })
+        },
+    );
+    spotlight_group.append(&spotlight_opacity_group);
+    let spotlight_blur_group = build_label_chip_group(
+        style_tokens,
+        "Blur Outside",
+        &[false, true],
+        initial_spotlight_options.blur_outside,
+        -1,
+        4,
+        |blur_outside: bool| toggle_label(blur_outside).to_string(),
+        {
+            let editor_tools = editor_tools.clone();
+            let editor_canvas = editor_canvas.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            let history = placed_object_history.clone();
+            Rc::new(move |blur_outside: bool| {
+                history.apply(&editor_tools, |tools| {
+                    tools.set_spotlight_blur_outside(blur_outside)
+                });
+                *status_log_for_render.borrow_mut() =
+                    format!("spotlight blur outside: {}", toggle_label(blur_outside));
+                editor_canvas.queue_draw();
+            })
+        },
+    );
+    spotlight_group.append(&spotlight_blur_group);
+    tool_options_content.append(&spotlight_group);
+
+    let pen_group = GtkBox::new(Orientation::Vertical, 0);
+    let pen_smoothing_group = build_label_chip_group(
+        style_tokens,
+        "Smoothing",
+        &PenSmoothing::ALL,
+        initial_pen_options.smoothing,
+        -1,
+        4,
+        |smoothing: PenSmoothing| smoothing.label().to_string(),
+        {
+            let editor_tools = editor_tools.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            let refresh_collapsed_option_chips = refresh_collapsed_option_chips.clone();
+            Rc::new(move |smoothing: PenSmoothing| {
+                editor_tools.borrow_mut().set_pen_smoothing(smoothing);
+                *status_log_for_render.borrow_mut() =
+                    format!("pen smoothing: {}", smoothing.label());
+                (refresh_collapsed_option_chips.as_ref())();
+            })
+        },
+    );
+    pen_group.append(&pen_smoothing_group);
+    let pen_shape_snap_group = build_label_chip_group(
+        style_tokens,
+        "Shape Snap",
+        &[false, true],
+        initial_pen_options.shape_snap,
+        -1,
+        4,
+        |shape_snap: bool| toggle_label(shape_snap).to_string(),
+        {
+            let editor_tools = editor_tools.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            Rc::new(move |shape_snap: bool| {
+                editor_tools.borrow_mut().set_pen_shape_snap(shape_snap);
+                *status_log_for_render.borrow_mut() =
+                    format!("pen shape snap: {}", toggle_label(shape_snap));
+            })
+        },
+    );
+    pen_group.append(&pen_shape_snap_group);
+    let pen_pressure_group = build_label_chip_group(
+        style_tokens,
+        "Pressure",
+        &[false, true],
+        initial_pen_options.pressure_enabled,
+        -1,
+        4,
+        |pressure_enabled: bool| toggle_label(pressure_enabled).to_string(),
+        {
+            let editor_tools = editor_tools.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            Rc::new(move |pressure_enabled: bool| {
+                editor_tools
+                    .borrow_mut()
+                    .set_pen_pressure_enabled(pressure_enabled);
+                *status_log_for_render.borrow_mut() =
+                    format!("pen pressure: {}", toggle_label(pressure_enabled));
+            })
+        },
+    );
+    pen_group.append(&pen_pressure_group);
+    let pen_pressure_curve_group = build_label_chip_group(
+        style_tokens,
+        "Pressure Curve",
+        &PressureCurve::ALL,
+        initial_pen_options.pressure_curve,
+        -1,
+        4,
+        |curve: PressureCurve| curve.label().to_string(),
+        {
+            let editor_tools = editor_tools.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            Rc::new(move |curve: PressureCurve| {
+                editor_tools.borrow_mut().set_pen_pressure_curve(curve);
+                *status_log_for_render.borrow_mut() =
+                    format!("pen pressure curve: {}", curve.label());
+            })
+        },
+    );
+    pen_group.append(&pen_pressure_curve_group);
+    tool_options_content.append(&pen_group);
+
+    let magnifier_group = GtkBox::new(Orientation::Vertical, 0);
+    let magnifier_shape_group = build_label_chip_group(
+        style_tokens,
+        "Lens Shape",
+        &MagnifierShape::ALL,
+        initial_magnifier_options.shape,
+        -1,
+        4,
+        |shape: MagnifierShape| shape.label().to_string(),
+        {
+            let editor_tools = editor_tools.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            Rc::new(move |shape: MagnifierShape| {
+                editor_tools.borrow_mut().set_magnifier_shape(shape);
+                *status_log_for_render.borrow_mut() =
+                    format!("magnifier lens shape: {}", shape.label());
+            })
+        },
+    );
+    magnifier_group.append(&magnifier_shape_group);
+    let magnifier_zoom_group = build_label_chip_group(
+        style_tokens,
+        "Zoom",
+        &MAGNIFIER_ZOOM_PRESETS,
+        initial_magnifier_options.zoom_percent,
+        -1,
+        4,
+        magnifier_zoom_label,
+        {
+            let editor_tools = editor_tools.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            let refresh_collapsed_option_chips = refresh_collapsed_option_chips.clone();
+            Rc::new(move |zoom_percent: u16| {
+                editor_tools.borrow_mut().set_magnifier_zoom(zoom_percent);
+                *status_log_for_render.borrow_mut() =
+                    format!("magnifier zoom: {}", magnifier_zoom_label(zoom_percent));
+                (refresh_collapsed_option_chips.as_ref())();
+            })
+        },
+    );
+    magnifier_group.append(&magnifier_zoom_group);
+    let magnifier_connector_group = build_label_chip_group(
+        style_tokens,
+        "Connector",
+        &[false, true],
+        initial_magnifier_options.connector,
+        -1,
+        4,
+        |connector: bool| toggle_label(connector).to_string(),
+        {
+            let editor_tools = editor_tools.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            Rc::new(move |connector: bool| {
+                editor_tools.borrow_mut().set_magnifier_connector(connector);
+                *status_log_for_render.borrow_mut() =
+                    format!("magnifier connector: {}", toggle_label(connector));
+            })
+        },
+    );
+    magnifier_group.append(&magnifier_connector_group);
+    tool_options_content.append(&magnifier_group);
+
+    let callout_group = build_label_chip_group(
+        style_tokens,
+        "Bubble Fill",
+        &CALLOUT_FILL_PRESETS,
+        CALLOUT_FILL_PRESETS
+            .iter()
+            .copied()
+            .find(|(_, color)| *color == initial_callout_options.fill)
+            .unwrap_or(CALLOUT_FILL_PRESETS[0]),
+        -1,
+        4,
+        |(label, _): (&'static str, Color)| label.to_string(),
+        {
+            let editor_tools = editor_tools.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            let refresh_collapsed_option_chips = refresh_collapsed_option_chips.clone();
+            Rc::new(move |(label, color): (&'static str, Color)| {
+                editor_tools.borrow_mut().set_callout_fill(color);
+                *status_log_for_render.borrow_mut() = format!("callout bubble fill: {label}");
+                (refresh_collapsed_option_chips.as_ref())();
+            })
+        },
+    );
+    tool_options_content.append(&callout_group);
+
+    let initial_image_options = editor_tools.borrow().image_options();
+    let image_group = GtkBox::new(Orientation::Vertical, 0);
+    let image_stamp_group = build_label_chip_group(
+        style_tokens,
+        "Stamp",
+        &Stamp::ALL,
+        initial_image_stamp,
+        -1,
+        4,
+        |stamp: Stamp| stamp.label().to_string(),
+        {
+            let editor_tools = editor_tools.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            let refresh_collapsed_option_chips = refresh_collapsed_option_chips.clone();
+            Rc::new(move |stamp: Stamp| {
+                editor_tools.borrow_mut().set_image_stamp(stamp);
+                *status_log_for_render.borrow_mut() = format!("image stamp: {}", stamp.label());
+                (refresh_collapsed_option_chips.as_ref())();
+            })
+        },
+    );
+    image_group.append(&image_stamp_group);
+    // Opacity, rotation and aspect lock also apply to the selected images.
+    let image_opacity_group = build_label_chip_group(
+        style_tokens,
+        "Opacity",
+        &IMAGE_OPACITY_PRESETS,
+        nearest_preset_u8(
+            f64::from(initial_image_options.opacity),
+            &IMAGE_OPACITY_PRESETS,
+        ),
+        -1,
+        4,
+        |opacity: u8| format!("{opacity}%"),
+        {
+            let editor_tools = editor_tools.clone();
+            let editor_canvas = editor_canvas.clone();
+            let selected_object_ids = selected_object_ids.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            let history = placed_object_history.clone();
+            Rc::new(move |opacity: u8| {
+                let selected = selected_object_ids.borrow().clone();
+                history.apply(&editor_tools, |tools| {
+                    tools.set_image_opacity(opacity);
+                    tools.restyle_images(&selected, |options| options.set_opacity(opacity))
+                });
+                *status_log_for_render.borrow_mut() = format!("image opacity: {opacity}%");
+                editor_canvas.queue_draw();
+            })
+        },
+    );
+    image_group.append(&image_opacity_group);
+    let image_rotation_group = build_label_chip_group(
+        style_tokens,
+        "Rotation",
+        &IMAGE_ROTATION_PRESETS,
+        initial_image_options.rotation,
+        -1,
+        4,
+        |rotation: i16| format!("{rotation}°"),
+        {
+            let editor_tools = editor_tools.clone();
+            let editor_canvas = editor_canvas.clone();
+            let selected_object_ids = selected_object_ids.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            let history = placed_object_history.clone();
+            Rc::new(move |rotation: i16| {
+                let selected = selected_object_ids.borrow().clone();
+                history.apply(&editor_tools, |tools| {
+                    tools.set_image_rotation(rotation);
+                    tools.restyle_images(&selected, |options| options.set_rotation(rotation))
+                });
+                *status_log_for_render.borrow_mut() = format!("image rotation: {rotation}°");
+                editor_canvas.queue_draw();
+            })
+        },
+    );
+    image_group.append(&image_rotation_group);
+    let image_aspect_group = build_label_chip_group(
+        style_tokens,
+        "Aspect Lock",
+        &[false, true],
+        initial_image_options.lock_aspect,
+        -1,
+        4,
+        |lock_aspect: bool| toggle_label(lock_aspect).to_string(),
+        {
+            let editor_tools = editor_tools.clone();
+            let selected_object_ids = selected_object_ids.clone();
+            let status_log_for_render = status_log_for_render.clone();
+            let history = placed_object_history.clone();
+            Rc::new(move |lock_aspect: bool| {
+                let selected = selected_object_ids.borrow().clone();
+                history.apply(&editor_tools, |tools| {
+                    tools.set_image_lock_aspect(lock_aspect);
+                    tools.restyle_images(&selected, |options| options.set_lock_aspect(lock_aspect))
+                });
+                *status_log_for_render.borrow_mut() =
+                    format!("image aspect lock: {}", toggle_label(lock_aspect));
+            })
+        },
+    );
+    image_group.append(&image_aspect_group);
+    let image_insert_group = GtkBox::new(Orientation::Vertical, 2);
+    image_insert_group.add_css_class("stroke-options-section");
+    image_insert_group.set_margin_top(style_tokens.spacing_8);
+    let image_insert_title = Label::new(Some("Insert Picture"));
+    image_insert_title.add_css_class("stroke-options-title");
+    image_insert_title.set_xalign(0.0);
+    let image_insert_row = GtkBox::new(Orientation::Horizontal, style_tokens.spacing_4);
+    image_insert_row.add_css_class("stroke-chip-row");
+    let image_insert_button = |label: &str, tooltip: &str| {
+        let button = Button::with_label(label);
+        button.set_focus_on_click(false);
+        button.set_tooltip_text(Some(tooltip));
+        button.add_css_class("flat");
+        button.add_css_class("stroke-chip-button");
+        button.set_size_request(-1, 30);
+        if let Some(child) = button.child() {
+            child.set_margin_start(4);
+            child.set_margin_end(4);
+        }
+        image_insert_row.append(&button);
+        button
+    };
+    let image_paste_button = image_insert_button("Paste", "Insert the image on the clipboard");
+    let image_open_button = image_insert_button("Open…", "Insert an image file");
+    image_insert_group.append(&image_insert_title);
+    image_insert_group.append(&image_insert_row);
+    image_group.append(&image_insert_group);
+    tool_options_content.append(&image_group);
+
     let initial_text_size = {
         let options = editor_tools.borrow().text_options();
         nearest_preset_u8(f64::from(options.size), &text_size_presets)
@@ -502,10 +1196,30 @@ pub(super) fn build_tool_options_runtime(context: ToolOptionsBuildContext) -> To
         let tool_options_toggle = tool_options_toggle.clone();
         let color_group = color_group.clone();
         let thickness_group = thickness_group.clone();
+        let fill_group = fill_group.clone();
+        let dash_group = dash_group.clone();
+        let cap_group = cap_group.clone();
+        let pen_group = pen_group.clone();
+        let redaction_group = redaction_group.clone();
+        let badge_group = badge_group.clone();
+        let spotlight_group = spotlight_group.clone();
+        let magnifier_group = magnifier_group.clone();
+        let callout_group = callout_group.clone();
+        let image_group = image_group.clone();
         let text_size_group = text_size_group.clone();
         let crop_preset_group = crop_preset_group.clone();
         let collapsed_color_chip = collapsed_color_chip.clone();
         let collapsed_thickness_chip = collapsed_thickness_chip.clone();
+        let collapsed_fill_chip = collapsed_fill_chip.clone();
+        let collapsed_dash_chip = collapsed_dash_chip.clone();
+        let collapsed_cap_chip = collapsed_cap_chip.clone();
+        let collapsed_pen_chip = collapsed_pen_chip.clone();
+        let collapsed_redaction_chip = collapsed_redaction_chip.clone();
+        let collapsed_badge_chip = collapsed_badge_chip.clone();
+        let collapsed_spotlight_chip = collapsed_spotlight_chip.clone();
+        let collapsed_magnifier_chip = collapsed_magnifier_chip.clone();
+        let collapsed_callout_chip = collapsed_callout_chip.clone();
+        let collapsed_image_chip = collapsed_image_chip.clone();
         let collapsed_text_size_chip = collapsed_text_size_chip.clone();
         let collapsed_crop_preset_chip = collapsed_crop_preset_chip.clone();
         let refresh_collapsed_option_chips = refresh_collapsed_option_chips.clone();
@@ -520,12 +1234,32 @@ pub(super) fn build_tool_options_runtime(context: ToolOptionsBuildContext) -> To
             // Expanded section group visibility
             color_group.set_visible(vis.has_color);
             thickness_group.set_visible(vis.has_stroke_width);
+            fill_group.set_visible(vis.has_fill);
+            dash_group.set_visible(vis.has_dash);
+            cap_group.set_visible(vis.has_line_cap);
+            pen_group.set_visible(vis.has_pen);
+            redaction_group.set_visible(vis.has_redaction);
+            badge_group.set_visible(vis.has_badge);
+            spotlight_group.set_visible(vis.has_spotlight);
+            magnifier_group.set_visible(vis.has_magnifier);
+            callout_group.set_visible(vis.has_callout);
+            image_group.set_visible(vis.has_image);
             text_size_group.set_visible(vis.has_text_size);
             crop_preset_group.set_visible(vis.has_crop_preset);
 
             // Collapsed chip visibility
             collapsed_color_chip.set_visible(vis.has_color);
             collapsed_thickness_chip.set_visible(vis.has_stroke_width);
+            collapsed_fill_chip.set_visible(vis.has_fill);
+            collapsed_dash_chip.set_visible(vis.has_dash);
+            collapsed_cap_chip.set_visible(vis.has_line_cap);
+            collapsed_pen_chip.set_visible(vis.has_pen);
+            collapsed_redaction_chip.set_visible(vis.has_redaction);
+            collapsed_badge_chip.set_visible(vis.has_badge);
+            collapsed_spotlight_chip.set_visible(vis.has_spotlight);
+            collapsed_magnifier_chip.set_visible(vis.has_magnifier);
+            collapsed_callout_chip.set_visible(vis.has_callout);
+            collapsed_image_chip.set_visible(vis.has_image);
             collapsed_text_size_chip.set_visible(vis.has_text_size);
             collapsed_crop_preset_chip.set_visible(vis.has_crop_preset);
 
@@ -571,6 +1305,8 @@ pub(super) fn build_tool_options_runtime(context: ToolOptionsBuildContext) -> To
     ToolOptionsRuntime {
         tool_options_bar,
         tool_options_toggle,
+        image_paste_button,
+        image_open_button,
     }
 }
 
@@ -633,6 +1369,129 @@ fn build_label_chip_group<K: Copy + PartialEq + 'static>(
     group
 }
 
+/// A `- value +` row for numeric options with too many values for chips.
+fn build_stepper_group(
+    style_tokens: StyleTokens,
+    title: &str,
+    initial: u32,
+    (min, max): (u32, u32),
+    on_change: Rc<dyn Fn(u32)>,
+) -> GtkBox {
+    let group = GtkBox::new(Orientation::Vertical, 2);
+    group.add_css_class("stroke-options-section");
+    group.set_margin_top(style_tokens.spacing_8);
+    let title_label = Label::new(Some(title));
+    title_label.add_css_class("stroke-options-title");
+    title_label.set_xalign(0.0);
+    let row = GtkBox::new(Orientation::Horizontal, style_tokens.spacing_4);
+    row.add_css_class("stroke-chip-row");
+    let value = Rc::new(Cell::new(initial.clamp(min, max)));
+    let value_label = Label::new(Some(value.get().to_string().as_str()));
+    value_label.set_width_chars(4);
+    let step_button = |text: &str, tooltip: &str, step: i64| {
+        let button = Button::with_label(text);
+        button.set_focus_on_click(false);
+        button.set_tooltip_text(Some(tooltip));
+        button.add_css_class("flat");
+        button.add_css_class("stroke-chip-button");
+        button.set_size_request(30, 30);
+        let value = value.clone();
+        let value_label = value_label.clone();
+        let on_change = on_change.clone();
+        button.connect_clicked(move |_| {
+            let next = (i64::from(value.get()) + step).clamp(i64::from(min), i64::from(max));
+            let next = u32::try_from(next).unwrap_or(min);
+            if next != value.get() {
+                value.set(next);
+                value_label.set_text(next.to_string().as_str());
+                on_change(next);
+            }
+        });
+        button
+    };
+    row.append(&step_button("-", "Decrease", -1));
+    row.append(&value_label);
+    row.append(&step_button("+", "Increase", 1));
+    group.append(&title_label);
+    group.append(&row);
+    group
+}
+
+/// Undo bookkeeping for options that also restyle objects already placed,
+/// such as badge numbering or spotlight dimming.
+#[derive(Clone)]
+struct PlacedObjectHistory {
+    editor_undo_stack: Rc<RefCell<Vec<Vec<ToolObject>>>>,
+    editor_redo_stack: Rc<RefCell<Vec<Vec<ToolObject>>>>,
+    editor_has_unsaved_changes: Rc<RefCell<bool>>,
+}
+
+impl PlacedObjectHistory {
+    fn apply(
+        &self,
+        editor_tools: &RefCell<editor::EditorTools>,
+        change: impl FnOnce(&mut editor::EditorTools) -> bool,
+    ) {
+        let snapshot = snapshot_editor_objects(editor_tools);
+        if change(&mut editor_tools.borrow_mut()) {
+            record_undo_snapshot(
+                self.editor_undo_stack.as_ref(),
+                self.editor_redo_stack.as_ref(),
+                snapshot,
+            );
+            *self.editor_has_unsaved_changes.borrow_mut() = true;
+        }
+    }
+}
+
+fn badge_sequence_label(options: BadgeOptions) -> String {
+    format!("From {}", options.style.format(options.start))
+}
+
+fn spotlight_dim_label(options: SpotlightOptions) -> String {
+    format!("Dim {}%", options.opacity)
+}
+
+fn callout_fill_label(fill: Color) -> &'static str {
+    CALLOUT_FILL_PRESETS
+        .iter()
+        .find(|(_, color)| *color == fill)
+        .map_or("Custom", |(label, _)| label)
+}
+
+fn magnifier_zoom_label(zoom_percent: u16) -> String {
+    format!("{}x", f64::from(zoom_percent) / 100.0)
+}
+
+fn build_collapsed_label_chip(label: &str) -> Button {
+    let chip = Button::with_label(label);
+    chip.set_focus_on_click(false);
+    chip.set_can_target(false);
+    chip.set_valign(Align::Center);
+    chip.add_css_class("flat");
+    chip.add_css_class("stroke-chip-button");
+    chip.add_css_class("stroke-chip-active");
+    chip.set_size_request(-1, 30);
+    chip.set_visible(false);
+    chip
+}
+
+const fn fill_mode_label(fill_enabled: bool) -> &'static str {
+    if fill_enabled {
+        "Filled"
+    } else {
+        "Outline"
+    }
+}
+
+const fn toggle_label(enabled: bool) -> &'static str {
+    if enabled {
+        "On"
+    } else {
+        "Off"
+    }
+}
+
 fn stroke_preview_line_width(thickness: u8, preview_width: i32, preview_height: i32) -> f64 {
     let requested = f64::from(thickness.max(1));
     let vertical_limit = f64::from(
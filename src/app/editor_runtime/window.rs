@@ -1,4 +1,4 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 
 use crate::editor::{self, ToolKind};
@@ -62,13 +62,16 @@ pub(super) fn connect_editor_tool_shortcut_fallback(
     editor_window_instance: &ApplicationWindow,
     tool_buttons: &Rc<RefCell<Vec<(ToolKind, Button)>>>,
     editor_input_mode: &Rc<RefCell<editor::EditorInputMode>>,
+    find_entry_focused: &Rc<Cell<bool>>,
 ) {
     let tool_buttons = tool_buttons.clone();
     let editor_input_mode = editor_input_mode.clone();
+    let find_entry_focused = find_entry_focused.clone();
     let tool_shortcut_controller = gtk4::EventControllerKey::new();
     tool_shortcut_controller.set_propagation_phase(gtk4::PropagationPhase::Capture);
     tool_shortcut_controller.connect_key_pressed(move |_, key, keycode, modifier| {
         if editor_input_mode.borrow().text_input_active()
+            || find_entry_focused.get()
             || modifier.intersects(
                 gtk4::gdk::ModifierType::CONTROL_MASK
                     | gtk4::gdk::ModifierType::ALT_MASK
@@ -149,6 +152,10 @@ mod tests {
             resolve_editor_tool_fallback_shortcut(ShortcutKey::Character('p')),
             Some(ToolKind::Pen)
         );
+        assert_eq!(
+            resolve_editor_tool_fallback_shortcut(ShortcutKey::Character('m')),
+            Some(ToolKind::Highlighter)
+        );
         assert_eq!(
             resolve_editor_tool_fallback_shortcut(ShortcutKey::Character('a')),
             Some(ToolKind::Arrow)
@@ -157,6 +164,34 @@ mod tests {
             resolve_editor_tool_fallback_shortcut(ShortcutKey::Character('r')),
             Some(ToolKind::Rectangle)
         );
+        assert_eq!(
+            resolve_editor_tool_fallback_shortcut(ShortcutKey::Character('l')),
+            Some(ToolKind::Line)
+        );
+        assert_eq!(
+            resolve_editor_tool_fallback_shortcut(ShortcutKey::Character('e')),
+            Some(ToolKind::Ellipse)
+        );
+        assert_eq!(
+            resolve_editor_tool_fallback_shortcut(ShortcutKey::Character('n')),
+            Some(ToolKind::Badge)
+        );
+        assert_eq!(
+            resolve_editor_tool_fallback_shortcut(ShortcutKey::Character('d')),
+            Some(ToolKind::Spotlight)
+        );
+        assert_eq!(
+            resolve_editor_tool_fallback_shortcut(ShortcutKey::Character('z')),
+            Some(ToolKind::Magnifier)
+        );
+        assert_eq!(
+            resolve_editor_tool_fallback_shortcut(ShortcutKey::Character('k')),
+            Some(ToolKind::Callout)
+        );
+        assert_eq!(
+            resolve_editor_tool_fallback_shortcut(ShortcutKey::Character('i')),
+            Some(ToolKind::Image)
+        );
         assert_eq!(
             resolve_editor_tool_fallback_shortcut(ShortcutKey::Character('c')),
             Some(ToolKind::Crop)
@@ -169,6 +204,10 @@ mod tests {
             resolve_editor_tool_fallback_shortcut(ShortcutKey::Character('o')),
             Some(ToolKind::Ocr)
         );
+        assert_eq!(
+            resolve_editor_tool_fallback_shortcut(ShortcutKey::Character('q')),
+            Some(ToolKind::Decode)
+        );
     }
 
     #[test]
@@ -1,5 +1,11 @@
 mod dialog;
+mod find;
 mod gestures;
+mod image_insert;
+mod ocr_export;
+mod ocr_languages;
+mod ocr_table;
+mod redact;
 mod shortcuts;
 mod tools;
 mod viewport;
@@ -8,12 +14,18 @@ pub(super) use dialog::{
     connect_editor_close_dialog, connect_editor_window_close_request, EditorCloseDialogContext,
     EditorWindowCloseRequestContext,
 };
+pub(super) use find::{build_editor_find_bar, EditorFindContext};
 pub(super) use gestures::{
     connect_editor_draw_gesture, connect_editor_pan_drag_gesture,
     connect_editor_selection_click_gesture, connect_editor_text_click_gesture,
     EditorDrawGestureContext, EditorPanGestureContext, EditorSelectionClickContext,
     EditorTextClickContext,
 };
+pub(super) use image_insert::{connect_editor_image_insert_buttons, EditorImageInsertContext};
+pub(super) use ocr_export::{connect_editor_ocr_export_items, EditorOcrExportContext};
+pub(super) use ocr_languages::{connect_editor_ocr_language_menu, EditorOcrLanguageMenuContext};
+pub(super) use ocr_table::{connect_editor_ocr_table_items, EditorOcrTableContext};
+pub(super) use redact::{connect_editor_auto_redact_button, EditorAutoRedactContext};
 pub(super) use shortcuts::{connect_editor_key_handling, EditorKeyHandlingContext};
 pub(super) use tools::{
     connect_editor_output_button, connect_tool_button_selection, EDITOR_TOOLBAR_ENTRIES,
@@ -143,6 +143,7 @@ fn open_editor_unsaved_close_dialog(
                     pending_crop: pending_crop.borrow().as_ref().copied(),
                     source_pixbuf,
                     storage_service: &service,
+                    copy_profile: crate::clipboard::CopyProfile::default(),
                     status_log: &status_log_for_render,
                     editor_toast: &editor_toast_runtime,
                     toast_duration_ms: style_tokens.toast_duration_ms,
@@ -0,0 +1,428 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use crate::editor::tools::ToolPoint;
+use crate::editor::{self, ToolObject};
+use crate::ocr::OcrRect;
+
+use gtk4::prelude::*;
+use gtk4::{
+    Align, Box as GtkBox, Button, DrawingArea, Label, Orientation, Revealer,
+    RevealerTransitionType, Scale, ScrolledWindow, SearchEntry,
+};
+
+use crate::app::editor_history::{record_undo_snapshot, snapshot_editor_objects};
+use crate::app::editor_popup::set_single_selection;
+use crate::app::editor_viewport::center_editor_viewport_on_image_point;
+use crate::app::jobs::JobQueue;
+use crate::app::ocr_overlay::SharedOcrOverlay;
+use crate::app::ocr_support::{
+    blur_region_within_image, queue_full_image_ocr, OcrJobFeedback, SharedOcrLanguages,
+};
+use crate::app::runtime_support::ToastRuntime;
+use crate::ui::{icon_button, StyleTokens};
+
+use super::viewport::editor_viewport_runtime;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FindMatchObject {
+    Rectangle,
+    Highlight,
+    Blur,
+}
+
+impl FindMatchObject {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Rectangle => "rectangle",
+            Self::Highlight => "highlight",
+            Self::Blur => "blur",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(in crate::app::editor_runtime) struct EditorFindContext {
+    pub(in crate::app::editor_runtime) editor_canvas: DrawingArea,
+    pub(in crate::app::editor_runtime) editor_scroller: ScrolledWindow,
+    pub(in crate::app::editor_runtime) editor_viewport: Rc<RefCell<editor::EditorViewport>>,
+    pub(in crate::app::editor_runtime) editor_viewport_status: Label,
+    pub(in crate::app::editor_runtime) zoom_slider: Scale,
+    pub(in crate::app::editor_runtime) zoom_slider_syncing: Rc<Cell<bool>>,
+    pub(in crate::app::editor_runtime) editor_tools: Rc<RefCell<editor::EditorTools>>,
+    pub(in crate::app::editor_runtime) editor_undo_stack: Rc<RefCell<Vec<Vec<ToolObject>>>>,
+    pub(in crate::app::editor_runtime) editor_redo_stack: Rc<RefCell<Vec<Vec<ToolObject>>>>,
+    pub(in crate::app::editor_runtime) selected_object_ids: Rc<RefCell<Vec<u64>>>,
+    pub(in crate::app::editor_runtime) editor_has_unsaved_changes: Rc<RefCell<bool>>,
+    pub(in crate::app::editor_runtime) status_log_for_render: Rc<RefCell<String>>,
+    pub(in crate::app::editor_runtime) editor_source_pixbuf: Option<gtk4::gdk_pixbuf::Pixbuf>,
+    pub(in crate::app::editor_runtime) capture_id: String,
+    pub(in crate::app::editor_runtime) editor_toast: Rc<RefCell<Option<ToastRuntime>>>,
+    pub(in crate::app::editor_runtime) toast_duration_ms: u32,
+    pub(in crate::app::editor_runtime) ocr_jobs: JobQueue,
+    pub(in crate::app::editor_runtime) ocr_languages: SharedOcrLanguages,
+    pub(in crate::app::editor_runtime) ocr_available: bool,
+    pub(in crate::app::editor_runtime) ocr_overlay: SharedOcrOverlay,
+    /// Set while the search entry has keyboard focus so editor shortcuts
+    /// let typed keys through.
+    pub(in crate::app::editor_runtime) find_entry_focused: Rc<Cell<bool>>,
+    pub(in crate::app::editor_runtime) editor_image_base_width: i32,
+    pub(in crate::app::editor_runtime) editor_image_base_height: i32,
+}
+
+/// Ctrl+F bar that searches recognized text. Matches are highlighted on the
+/// canvas and can be turned into rectangle, highlight or blur objects.
+#[derive(Clone)]
+pub(in crate::app::editor_runtime) struct EditorFindBar {
+    revealer: Revealer,
+    entry: SearchEntry,
+    count_label: Label,
+    context: EditorFindContext,
+}
+
+pub(in crate::app::editor_runtime) fn build_editor_find_bar(
+    style_tokens: StyleTokens,
+    motion_hover_ms: u32,
+    context: EditorFindContext,
+) -> EditorFindBar {
+    let control_size = style_tokens.control_size as i32;
+    let entry = SearchEntry::new();
+    entry.set_placeholder_text(Some("Find text"));
+    entry.add_css_class("editor-find-entry");
+    let count_label = Label::new(None);
+    count_label.add_css_class("editor-find-count");
+    let previous_button = icon_button(
+        "chevron-up-symbolic",
+        "Previous match (Shift+Enter)",
+        control_size,
+        &["editor-action-button"],
+    );
+    let next_button = icon_button(
+        "chevron-down-symbolic",
+        "Next match (Enter)",
+        control_size,
+        &["editor-action-button"],
+    );
+    let rectangle_button = icon_button(
+        "rectangle-horizontal-symbolic",
+        "Outline match with a rectangle",
+        control_size,
+        &["editor-action-button"],
+    );
+    let highlight_button = icon_button(
+        "highlighter-symbolic",
+        "Highlight match",
+        control_size,
+        &["editor-action-button"],
+    );
+    let blur_button = icon_button(
+        "eye-off-symbolic",
+        "Blur match",
+        control_size,
+        &["editor-action-button"],
+    );
+    let close_button = icon_button(
+        "x-symbolic",
+        "Close find (Esc)",
+        control_size,
+        &["editor-action-button"],
+    );
+
+    let find_group = GtkBox::new(Orientation::Horizontal, style_tokens.spacing_4);
+    find_group.add_css_class("editor-action-group");
+    find_group.append(&entry);
+    find_group.append(&count_label);
+    find_group.append(&previous_button);
+    find_group.append(&next_button);
+    find_group.append(&rectangle_button);
+    find_group.append(&highlight_button);
+    find_group.append(&blur_button);
+    find_group.append(&close_button);
+
+    let revealer = Revealer::new();
+    revealer.set_transition_duration(motion_hover_ms);
+    revealer.set_transition_type(RevealerTransitionType::Crossfade);
+    revealer.set_halign(Align::Center);
+    revealer.set_valign(Align::End);
+    revealer.set_margin_bottom(style_tokens.spacing_16);
+    revealer.set_reveal_child(false);
+    revealer.set_can_target(false);
+    revealer.set_child(Some(&find_group));
+
+    let find_bar = EditorFindBar {
+        revealer,
+        entry,
+        count_label,
+        context,
+    };
+
+    let focus = gtk4::EventControllerFocus::new();
+    {
+        let focused = find_bar.context.find_entry_focused.clone();
+        focus.connect_enter(move |_| focused.set(true));
+    }
+    {
+        let focused = find_bar.context.find_entry_focused.clone();
+        focus.connect_leave(move |_| focused.set(false));
+    }
+    find_bar.entry.add_controller(focus);
+
+    {
+        let find_bar_for_change = find_bar.clone();
+        find_bar
+            .entry
+            .connect_changed(move |_| find_bar_for_change.apply_query());
+    }
+    {
+        let find_bar_for_activate = find_bar.clone();
+        find_bar
+            .entry
+            .connect_activate(move |_| find_bar_for_activate.step(true));
+    }
+    {
+        let find_bar_for_next = find_bar.clone();
+        find_bar
+            .entry
+            .connect_next_match(move |_| find_bar_for_next.step(true));
+    }
+    {
+        let find_bar_for_previous = find_bar.clone();
+        find_bar
+            .entry
+            .connect_previous_match(move |_| find_bar_for_previous.step(false));
+    }
+    {
+        let find_bar_for_stop = find_bar.clone();
+        find_bar
+            .entry
+            .connect_stop_search(move |_| find_bar_for_stop.close());
+    }
+    {
+        // Shift+Enter steps backwards; plain Enter reaches `activate`.
+        let find_bar_for_keys = find_bar.clone();
+        let keys = gtk4::EventControllerKey::new();
+        keys.set_propagation_phase(gtk4::PropagationPhase::Capture);
+        keys.connect_key_pressed(move |_, key, _, modifier| {
+            let is_enter = matches!(key, gtk4::gdk::Key::Return | gtk4::gdk::Key::KP_Enter);
+            if is_enter && modifier.contains(gtk4::gdk::ModifierType::SHIFT_MASK) {
+                find_bar_for_keys.step(false);
+                return gtk4::glib::Propagation::Stop;
+            }
+            gtk4::glib::Propagation::Proceed
+        });
+        find_bar.entry.add_controller(keys);
+    }
+    connect_find_button(&previous_button, &find_bar, |bar| bar.step(false));
+    connect_find_button(&next_button, &find_bar, |bar| bar.step(true));
+    connect_find_button(&rectangle_button, &find_bar, |bar| {
+        bar.convert_current_match(FindMatchObject::Rectangle)
+    });
+    connect_find_button(&highlight_button, &find_bar, |bar| {
+        bar.convert_current_match(FindMatchObject::Highlight)
+    });
+    connect_find_button(&blur_button, &find_bar, |bar| {
+        bar.convert_current_match(FindMatchObject::Blur)
+    });
+    connect_find_button(&close_button, &find_bar, EditorFindBar::close);
+
+    find_bar
+}
+
+fn connect_find_button(
+    button: &Button,
+    find_bar: &EditorFindBar,
+    action: impl Fn(&EditorFindBar) + 'static,
+) {
+    let find_bar = find_bar.clone();
+    button.connect_clicked(move |_| action(&find_bar));
+}
+
+impl EditorFindBar {
+    pub(in crate::app::editor_runtime) fn widget(&self) -> &Revealer {
+        &self.revealer
+    }
+
+    /// Shows the bar with the query selected, recognizing the whole image
+    /// first when the current text layer does not cover it.
+    pub(in crate::app::editor_runtime) fn open(&self) {
+        self.revealer.set_can_target(true);
+        self.revealer.set_reveal_child(true);
+        self.entry.grab_focus();
+        self.entry.select_region(0, -1);
+        if self.context.ocr_overlay.borrow().covers_full_image() {
+            self.apply_query();
+        } else {
+            self.recognize_full_image();
+        }
+    }
+
+    fn close(&self) {
+        self.revealer.set_reveal_child(false);
+        self.revealer.set_can_target(false);
+        self.context.find_entry_focused.set(false);
+        self.context.ocr_overlay.borrow_mut().clear_find();
+        self.count_label.set_text("");
+        self.context.editor_canvas.queue_draw();
+        self.context.editor_canvas.grab_focus();
+    }
+
+    fn recognize_full_image(&self) {
+        let context = &self.context;
+        if !context.ocr_available {
+            self.count_label.set_text("OCR unavailable");
+            *context.status_log_for_render.borrow_mut() =
+                "find: OCR models not installed".to_string();
+            return;
+        }
+        let Some(ref pixbuf) = context.editor_source_pixbuf else {
+            *context.status_log_for_render.borrow_mut() =
+                "find: no source image available".to_string();
+            return;
+        };
+
+        let find_bar = self.clone();
+        let on_done = move |result: crate::ocr::OcrResult<crate::ocr::OcrDocument>| {
+            let context = &find_bar.context;
+            context.editor_canvas.set_cursor_from_name(None::<&str>);
+            match result {
+                Ok(document) => {
+                    context.ocr_overlay.borrow_mut().set_full_image_layer(
+                        crate::ocr::OcrTextLayer::from_document(&document, 0, 0),
+                    );
+                    find_bar.apply_query();
+                }
+                Err(crate::ocr::OcrError::Cancelled) => {
+                    find_bar.count_label.set_text("OCR cancelled");
+                }
+                Err(err) => {
+                    find_bar.count_label.set_text("OCR failed");
+                    *context.status_log_for_render.borrow_mut() =
+                        format!("find: OCR failed: {err}");
+                    crate::notification::send(format!("OCR failed: {err}"));
+                }
+            }
+        };
+        let feedback = OcrJobFeedback {
+            status_log: context.status_log_for_render.clone(),
+            toast: context.editor_toast.borrow().clone(),
+            toast_duration_ms: context.toast_duration_ms,
+        };
+        if let Err(err) = queue_full_image_ocr(
+            &context.ocr_jobs,
+            &context.capture_id,
+            pixbuf,
+            &context.ocr_languages,
+            feedback,
+            on_done,
+        ) {
+            self.count_label.set_text("OCR failed");
+            *context.status_log_for_render.borrow_mut() =
+                format!("find: image conversion failed: {err}");
+            return;
+        }
+        self.count_label.set_text("Reading text…");
+        context.editor_canvas.set_cursor_from_name(Some("progress"));
+    }
+
+    fn apply_query(&self) {
+        if !self.revealer.reveals_child() {
+            return;
+        }
+        let query = self.entry.text();
+        self.context
+            .ocr_overlay
+            .borrow_mut()
+            .set_find_query(query.as_str());
+        self.show_current_match();
+    }
+
+    fn step(&self, forward: bool) {
+        if self
+            .context
+            .ocr_overlay
+            .borrow_mut()
+            .step_find(forward)
+            .is_none()
+        {
+            return;
+        }
+        self.show_current_match();
+    }
+
+    fn show_current_match(&self) {
+        let (summary, bounds) = {
+            let overlay = self.context.ocr_overlay.borrow();
+            (overlay.find_summary(), overlay.current_find_bounds())
+        };
+        self.count_label.set_text(&summary);
+        if let Some(bounds) = bounds {
+            self.center_on(bounds);
+        }
+        self.context.editor_canvas.queue_draw();
+    }
+
+    fn center_on(&self, bounds: OcrRect) {
+        let context = &self.context;
+        let mut viewport = context.editor_viewport.borrow_mut();
+        center_editor_viewport_on_image_point(
+            &mut viewport,
+            &editor_viewport_runtime(
+                &context.editor_canvas,
+                &context.editor_scroller,
+                &context.editor_viewport_status,
+                &context.zoom_slider,
+                context.zoom_slider_syncing.as_ref(),
+                context.editor_image_base_width,
+                context.editor_image_base_height,
+            ),
+            f64::from(bounds.x) + f64::from(bounds.width) / 2.0,
+            f64::from(bounds.y) + f64::from(bounds.height) / 2.0,
+        );
+    }
+
+    fn convert_current_match(&self, kind: FindMatchObject) {
+        let context = &self.context;
+        let Some(bounds) = context.ocr_overlay.borrow().current_find_bounds() else {
+            *context.status_log_for_render.borrow_mut() = "find: no match selected".to_string();
+            return;
+        };
+
+        let snapshot = snapshot_editor_objects(context.editor_tools.as_ref());
+        let added = {
+            let mut tools = context.editor_tools.borrow_mut();
+            match kind {
+                FindMatchObject::Rectangle => tools
+                    .add_rectangle(
+                        ToolPoint::new(bounds.x, bounds.y),
+                        ToolPoint::new(bounds.right(), bounds.bottom()),
+                    )
+                    .ok(),
+                FindMatchObject::Highlight => tools
+                    .add_highlight(bounds.x, bounds.y, bounds.width, bounds.height)
+                    .ok(),
+                FindMatchObject::Blur => blur_region_within_image(
+                    bounds,
+                    context.editor_image_base_width,
+                    context.editor_image_base_height,
+                )
+                .and_then(|region| tools.add_blur(region).ok()),
+            }
+        };
+        let Some(object_id) = added else {
+            *context.status_log_for_render.borrow_mut() =
+                format!("find: could not add {} for match", kind.label());
+            return;
+        };
+
+        record_undo_snapshot(
+            context.editor_undo_stack.as_ref(),
+            context.editor_redo_stack.as_ref(),
+            snapshot,
+        );
+        set_single_selection(&context.selected_object_ids, object_id);
+        *context.editor_has_unsaved_changes.borrow_mut() = true;
+        *context.status_log_for_render.borrow_mut() =
+            format!("find: added {} #{object_id}", kind.label());
+        context.editor_canvas.queue_draw();
+    }
+}
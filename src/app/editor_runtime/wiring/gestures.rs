@@ -1,7 +1,9 @@
 use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 
-use crate::editor::tools::{CropElement, RectangleElement};
+use crate::editor::tools::{
+    CropElement, ImageBounds, MagnifierPart, RectangleElement, StylusSample,
+};
 use crate::editor::{self, ToolKind, ToolObject};
 
 use gtk4::prelude::*;
@@ -9,18 +11,24 @@ use gtk4::{DrawingArea, Label, ScrolledWindow};
 
 use crate::app::editor_history::{record_undo_snapshot, snapshot_editor_objects};
 use crate::app::editor_popup::{
-    canvas_point_to_image_point, clear_selection, normalize_tool_box, point_in_bounds,
-    rectangle_handle_at_point, resizable_object_handle_at_point, resize_object_from_handle,
-    resize_status_label, resized_crop_from_handle, set_optional_single_selection,
-    set_single_selection, tool_kind_label, top_object_id_at_point, top_object_id_in_drag_box,
-    top_text_id_at_point, ObjectDragState, TextPreeditState, ToolDragPreview,
+    callout_tail_at_point, canvas_point_to_image_point, clear_selection, normalize_tool_box,
+    point_in_bounds, rectangle_handle_at_point, resizable_object_handle_at_point,
+    resize_object_from_handle, resize_status_label, resized_crop_from_handle,
+    set_optional_single_selection, set_single_selection, tool_kind_label, top_object_id_at_point,
+    top_object_id_in_drag_box, top_text_id_at_point, ObjectDragState, TextPreeditState,
+    ToolDragPreview,
 };
 use crate::app::editor_viewport::{apply_editor_viewport_to_canvas, set_editor_viewport_status};
-use crate::app::EditorToolSwitchContext;
+use crate::app::jobs::JobQueue;
+use crate::app::ocr_overlay::SharedOcrOverlay;
+use crate::app::ocr_support::SharedOcrLanguages;
+use crate::app::runtime_support::ToastRuntime;
+use crate::app::{EditorToolSwitchContext, TextInputActivation};
 
 use super::tools::{
-    add_text_box_and_enter_editing, arm_text_tool_for_selection, enter_text_box_editing,
-    finish_text_editing_and_arm_text_tool,
+    add_text_box_and_enter_editing, arm_text_tool_for_selection, enter_callout_editing,
+    enter_text_box_editing, finish_text_editing_and_arm_text_tool,
+    switch_editor_tool_with_text_policy,
 };
 
 #[derive(Clone)]
@@ -36,6 +44,7 @@ pub(in crate::app::editor_runtime) struct EditorTextClickContext {
     pub(in crate::app::editor_runtime) selected_object_ids: Rc<RefCell<Vec<u64>>>,
     pub(in crate::app::editor_runtime) text_preedit_state: Rc<RefCell<TextPreeditState>>,
     pub(in crate::app::editor_runtime) editor_tool_switch_context: EditorToolSwitchContext,
+    pub(in crate::app::editor_runtime) ocr_overlay: SharedOcrOverlay,
     pub(in crate::app::editor_runtime) editor_image_base_width: i32,
     pub(in crate::app::editor_runtime) editor_image_base_height: i32,
 }
@@ -59,6 +68,27 @@ pub(in crate::app::editor_runtime) fn connect_editor_text_click_gesture(
             press_context.editor_image_base_width,
             press_context.editor_image_base_height,
         );
+        if active_tool == ToolKind::Ocr {
+            let hit = press_context.ocr_overlay.borrow_mut().click(
+                f64::from(anchor.x),
+                f64::from(anchor.y),
+                n_press,
+            );
+            if let Some(text) = press_context.ocr_overlay.borrow().selected_text() {
+                *press_context.status_log_for_render.borrow_mut() =
+                    format!("OCR text selected: {} chars", text.chars().count());
+            } else if hit {
+                *press_context.status_log_for_render.borrow_mut() =
+                    "OCR text selection cleared".to_string();
+            }
+            editor_canvas_for_text_click.queue_draw();
+            return;
+        }
+        if active_tool == ToolKind::Line {
+            handle_line_click(&press_context, anchor, n_press);
+            gesture.set_state(gtk4::EventSequenceState::Claimed);
+            return;
+        }
         if active_tool == ToolKind::Text {
             let hit_text_id = {
                 let tools = press_context.editor_tools.borrow();
@@ -134,6 +164,43 @@ pub(in crate::app::editor_runtime) fn connect_editor_text_click_gesture(
     context.editor_canvas.add_controller(text_click);
 }
 
+/// Each click adds a polyline vertex; a double-click finishes the line.
+fn handle_line_click(
+    context: &EditorTextClickContext,
+    point: editor::tools::ToolPoint,
+    n_press: i32,
+) {
+    if n_press >= 2 {
+        let finished = context.editor_tools.borrow_mut().finish_active_line();
+        *context.status_log_for_render.borrow_mut() = match finished {
+            Ok(line_id) => {
+                set_single_selection(&context.selected_object_ids, line_id);
+                format!("Line object #{line_id} created")
+            }
+            Err(_) => "line discarded: it needs at least two vertices".to_string(),
+        };
+        context.editor_canvas.queue_draw();
+        return;
+    }
+
+    let starts_new_line = context.editor_tools.borrow().active_line_id().is_none();
+    if starts_new_line {
+        let snapshot = snapshot_editor_objects(context.editor_tools.as_ref());
+        record_undo_snapshot(
+            context.editor_undo_stack.as_ref(),
+            context.editor_redo_stack.as_ref(),
+            snapshot,
+        );
+    }
+    let line_id = context.editor_tools.borrow_mut().add_line_vertex(point);
+    *context.editor_has_unsaved_changes.borrow_mut() = true;
+    *context.status_log_for_render.borrow_mut() = format!(
+        "line #{line_id} vertex at ({}, {}); double-click to finish",
+        point.x, point.y
+    );
+    context.editor_canvas.queue_draw();
+}
+
 #[derive(Clone)]
 pub(in crate::app::editor_runtime) struct EditorSelectionClickContext {
     pub(in crate::app::editor_runtime) editor_canvas: DrawingArea,
@@ -166,9 +233,18 @@ pub(in crate::app::editor_runtime) fn connect_editor_selection_click_gesture(
                 | ToolKind::Pan
                 | ToolKind::Blur
                 | ToolKind::Pen
+                | ToolKind::Highlighter
                 | ToolKind::Arrow
+                | ToolKind::Line
                 | ToolKind::Rectangle
+                | ToolKind::Ellipse
+                | ToolKind::Badge
+                | ToolKind::Spotlight
+                | ToolKind::Magnifier
+                | ToolKind::Callout
+                | ToolKind::Image
                 | ToolKind::Ocr
+                | ToolKind::Decode
         ) {
             return;
         }
@@ -187,12 +263,30 @@ pub(in crate::app::editor_runtime) fn connect_editor_selection_click_gesture(
             top_object_id_at_point(&tools, point)
         };
         if let Some(id) = selected {
-            if n_press >= 2
-                && matches!(
-                    press_context.editor_tools.borrow().object(id),
-                    Some(ToolObject::Text(_))
-                )
-            {
+            let double_clicked_kind = if n_press >= 2 {
+                press_context
+                    .editor_tools
+                    .borrow()
+                    .object(id)
+                    .and_then(|object| match object {
+                        ToolObject::Text(_) => Some(ToolKind::Text),
+                        ToolObject::Callout(_) => Some(ToolKind::Callout),
+                        _ => None,
+                    })
+            } else {
+                None
+            };
+            if double_clicked_kind == Some(ToolKind::Callout) {
+                enter_callout_editing(
+                    &press_context.editor_tool_switch_context,
+                    press_context.editor_tools.as_ref(),
+                    &press_context.selected_object_ids,
+                    press_context.text_preedit_state.as_ref(),
+                    id,
+                );
+                *press_context.status_log_for_render.borrow_mut() =
+                    format!("callout #{id} editing");
+            } else if double_clicked_kind == Some(ToolKind::Text) {
                 enter_text_box_editing(
                     &press_context.editor_tool_switch_context,
                     press_context.editor_tools.as_ref(),
@@ -233,12 +327,17 @@ pub(in crate::app::editor_runtime) struct EditorDrawGestureContext {
     pub(in crate::app::editor_runtime) selected_object_ids: Rc<RefCell<Vec<u64>>>,
     pub(in crate::app::editor_runtime) object_drag_state: Rc<RefCell<Option<ObjectDragState>>>,
     pub(in crate::app::editor_runtime) pending_crop: Rc<RefCell<Option<CropElement>>>,
+    pub(in crate::app::editor_runtime) text_preedit_state: Rc<RefCell<TextPreeditState>>,
+    pub(in crate::app::editor_runtime) editor_tool_switch_context: EditorToolSwitchContext,
     pub(in crate::app::editor_runtime) editor_image_base_width: i32,
     pub(in crate::app::editor_runtime) editor_image_base_height: i32,
     pub(in crate::app::editor_runtime) editor_source_pixbuf: Option<gtk4::gdk_pixbuf::Pixbuf>,
-    pub(in crate::app::editor_runtime) ocr_engine: Rc<RefCell<Option<crate::ocr::OcrEngine>>>,
-    pub(in crate::app::editor_runtime) ocr_language: crate::ocr::OcrLanguage,
-    pub(in crate::app::editor_runtime) ocr_in_progress: Rc<Cell<bool>>,
+    pub(in crate::app::editor_runtime) capture_id: String,
+    pub(in crate::app::editor_runtime) editor_toast: Rc<RefCell<Option<ToastRuntime>>>,
+    pub(in crate::app::editor_runtime) toast_duration_ms: u32,
+    pub(in crate::app::editor_runtime) ocr_jobs: JobQueue,
+    pub(in crate::app::editor_runtime) ocr_languages: SharedOcrLanguages,
+    pub(in crate::app::editor_runtime) ocr_overlay: SharedOcrOverlay,
 }
 
 fn handle_draw_gesture_begin(
@@ -252,7 +351,7 @@ fn handle_draw_gesture_begin(
         gesture.set_state(gtk4::EventSequenceState::Denied);
         return;
     }
-    if tool == ToolKind::Text {
+    if matches!(tool, ToolKind::Text | ToolKind::Line) {
         gesture.set_state(gtk4::EventSequenceState::Denied);
         return;
     }
@@ -265,6 +364,18 @@ fn handle_draw_gesture_begin(
         context.editor_image_base_height,
     );
 
+    if tool == ToolKind::Ocr
+        && context
+            .ocr_overlay
+            .borrow_mut()
+            .begin_drag(f64::from(start.x), f64::from(start.y))
+    {
+        context.tool_drag_preview.borrow_mut().take();
+        *context.status_log_for_render.borrow_mut() = "OCR text selection started".to_string();
+        gesture.set_state(gtk4::EventSequenceState::Claimed);
+        return;
+    }
+
     if context.active_editor_tool.get() == ToolKind::Crop {
         if let Some(crop) = context.pending_crop.borrow().as_ref().copied() {
             let crop_rect = RectangleElement::new(
@@ -298,6 +409,11 @@ fn handle_draw_gesture_begin(
         }
     }
 
+    if tool == ToolKind::Callout && handle_callout_tool_press(context, start) {
+        gesture.set_state(gtk4::EventSequenceState::Claimed);
+        return;
+    }
+
     if tool == ToolKind::Select {
         let hit_id = {
             let tools = context.editor_tools.borrow();
@@ -318,6 +434,29 @@ fn handle_draw_gesture_begin(
                             kind,
                             handle,
                         })
+                    } else if let Some(hole_index) = match object {
+                        ToolObject::Spotlight(spotlight) => spotlight.hole_index_at(start),
+                        _ => None,
+                    } {
+                        Some(ObjectDragState::MoveSpotlightHole {
+                            object_id: hit_id,
+                            hole_index,
+                            last: start,
+                        })
+                    } else if matches!(
+                        object,
+                        ToolObject::Callout(callout) if callout_tail_at_point(callout, start)
+                    ) {
+                        Some(ObjectDragState::MoveCalloutTail { object_id: hit_id })
+                    } else if let Some(part) = match object {
+                        ToolObject::Magnifier(magnifier) => magnifier.part_at(start),
+                        _ => None,
+                    } {
+                        Some(ObjectDragState::MoveMagnifierPart {
+                            object_id: hit_id,
+                            part,
+                            last: start,
+                        })
                     } else {
                         Some(ObjectDragState::Move {
                             object_ids: vec![hit_id],
@@ -349,7 +488,17 @@ fn handle_draw_gesture_begin(
 
     if matches!(
         tool,
-        ToolKind::Pen | ToolKind::Blur | ToolKind::Arrow | ToolKind::Rectangle
+        ToolKind::Pen
+            | ToolKind::Highlighter
+            | ToolKind::Blur
+            | ToolKind::Arrow
+            | ToolKind::Rectangle
+            | ToolKind::Ellipse
+            | ToolKind::Badge
+            | ToolKind::Spotlight
+            | ToolKind::Magnifier
+            | ToolKind::Callout
+            | ToolKind::Image
     ) {
         let snapshot = snapshot_editor_objects(context.editor_tools.as_ref());
         record_undo_snapshot(
@@ -375,11 +524,26 @@ fn handle_draw_gesture_begin(
             context.tool_drag_preview.borrow_mut().take();
         }
         ToolKind::Pen => {
-            let stroke_id = tools.begin_pen_stroke(start);
+            let stroke_id = tools.begin_pen_stroke(start, gesture_stylus_sample(gesture));
             context.active_pen_stroke_id.set(Some(stroke_id));
             context.tool_drag_preview.borrow_mut().take();
         }
-        ToolKind::Blur | ToolKind::Arrow | ToolKind::Rectangle | ToolKind::Ocr => {
+        ToolKind::Highlighter => {
+            let stroke_id = tools.begin_highlighter_stroke(start);
+            context.active_pen_stroke_id.set(Some(stroke_id));
+            context.tool_drag_preview.borrow_mut().take();
+        }
+        ToolKind::Blur
+        | ToolKind::Arrow
+        | ToolKind::Rectangle
+        | ToolKind::Ellipse
+        | ToolKind::Badge
+        | ToolKind::Spotlight
+        | ToolKind::Magnifier
+        | ToolKind::Callout
+        | ToolKind::Image
+        | ToolKind::Ocr
+        | ToolKind::Decode => {
             context.active_pen_stroke_id.set(None);
             *context.tool_drag_preview.borrow_mut() = Some(ToolDragPreview {
                 tool,
@@ -396,7 +560,7 @@ fn handle_draw_gesture_begin(
                 current: start,
             });
         }
-        ToolKind::Text => {}
+        ToolKind::Text | ToolKind::Line => {}
     }
 
     *context.status_log_for_render.borrow_mut() = format!("{} drag started", tool_kind_label(tool));
@@ -404,7 +568,69 @@ fn handle_draw_gesture_begin(
     gesture.set_state(gtk4::EventSequenceState::Claimed);
 }
 
-fn handle_draw_gesture_update(context: &EditorDrawGestureContext, offset_x: f64, offset_y: f64) {
+/// With the callout tool armed, a press on a bubble edits its text and a
+/// press elsewhere while a bubble is being edited only finishes that edit.
+/// Returns whether the press was consumed.
+fn handle_callout_tool_press(
+    context: &EditorDrawGestureContext,
+    start: editor::tools::ToolPoint,
+) -> bool {
+    let (hit_id, editing) = {
+        let tools = context.editor_tools.borrow();
+        (
+            top_text_id_at_point(&tools, start)
+                .filter(|id| matches!(tools.object(*id), Some(ToolObject::Callout(_)))),
+            tools.active_text_id().is_some(),
+        )
+    };
+    if let Some(id) = hit_id {
+        enter_callout_editing(
+            &context.editor_tool_switch_context,
+            context.editor_tools.as_ref(),
+            &context.selected_object_ids,
+            context.text_preedit_state.as_ref(),
+            id,
+        );
+        *context.status_log_for_render.borrow_mut() = format!("callout #{id} editing");
+    } else if editing {
+        context.editor_tools.borrow_mut().finish_text_box();
+        switch_editor_tool_with_text_policy(
+            &context.editor_tool_switch_context,
+            ToolKind::Callout,
+            true,
+            TextInputActivation::ForceOff,
+        );
+        *context.status_log_for_render.borrow_mut() =
+            "callout editing completed; callout tool armed".to_string();
+    } else {
+        return false;
+    }
+    context.editor_canvas.queue_draw();
+    true
+}
+
+/// Holding Shift while dragging an ellipse draws a circle. Highlighter
+/// strokes handle Shift themselves by snapping to a horizontal segment.
+fn constrain_drag_point(
+    tool: ToolKind,
+    start: editor::tools::ToolPoint,
+    point: editor::tools::ToolPoint,
+    shift_held: bool,
+) -> editor::tools::ToolPoint {
+    if shift_held && tool == ToolKind::Ellipse {
+        editor::tools::constrain_to_circle(start, point)
+    } else {
+        point
+    }
+}
+
+fn handle_draw_gesture_update(
+    context: &EditorDrawGestureContext,
+    offset_x: f64,
+    offset_y: f64,
+    shift_held: bool,
+    stylus: Option<StylusSample>,
+) {
     if context.space_pan_pressed.get() {
         return;
     }
@@ -419,6 +645,15 @@ fn handle_draw_gesture_update(context: &EditorDrawGestureContext, offset_x: f64,
         context.editor_image_base_height,
     );
 
+    if context
+        .ocr_overlay
+        .borrow_mut()
+        .update_drag(f64::from(current.x), f64::from(current.y))
+    {
+        context.editor_canvas.queue_draw();
+        return;
+    }
+
     if let Some(state) = context.object_drag_state.borrow_mut().as_mut() {
         match state {
             ObjectDragState::Move {
@@ -445,6 +680,62 @@ fn handle_draw_gesture_update(context: &EditorDrawGestureContext, offset_x: f64,
                     *last = current;
                 }
             }
+            ObjectDragState::MoveSpotlightHole {
+                object_id,
+                hole_index,
+                last,
+            } => {
+                let moved = context
+                    .editor_tools
+                    .borrow_mut()
+                    .move_spotlight_hole(
+                        *object_id,
+                        *hole_index,
+                        current.x - last.x,
+                        current.y - last.y,
+                        ImageBounds::new(
+                            context.editor_image_base_width,
+                            context.editor_image_base_height,
+                        ),
+                    )
+                    .is_ok();
+                if moved {
+                    *last = current;
+                }
+            }
+            ObjectDragState::MoveMagnifierPart {
+                object_id,
+                part,
+                last,
+            } => {
+                let moved = context
+                    .editor_tools
+                    .borrow_mut()
+                    .move_magnifier_part(
+                        *object_id,
+                        *part,
+                        current.x - last.x,
+                        current.y - last.y,
+                        ImageBounds::new(
+                            context.editor_image_base_width,
+                            context.editor_image_base_height,
+                        ),
+                    )
+                    .is_ok();
+                if moved {
+                    *last = current;
+                }
+            }
+            ObjectDragState::MoveCalloutTail { object_id } => {
+                let _ = context.editor_tools.borrow_mut().retarget_callout_tail(
+                    *object_id,
+                    current,
+                    ImageBounds::new(
+                        context.editor_image_base_width,
+                        context.editor_image_base_height,
+                    ),
+                );
+            }
             ObjectDragState::ResizeObject {
                 object_id,
                 kind,
@@ -501,22 +792,33 @@ fn handle_draw_gesture_update(context: &EditorDrawGestureContext, offset_x: f64,
     }
 
     if let Some(stroke_id) = context.active_pen_stroke_id.get() {
-        let appended = context
-            .editor_tools
-            .borrow_mut()
-            .append_pen_point(stroke_id, current)
-            .is_ok();
+        let appended = {
+            let mut tools = context.editor_tools.borrow_mut();
+            if tools.active_highlighter_stroke_id() == Some(stroke_id) {
+                tools
+                    .append_highlighter_point(stroke_id, current, shift_held)
+                    .is_ok()
+            } else {
+                tools.append_pen_point(stroke_id, current, stylus).is_ok()
+            }
+        };
         if appended {
             *context.status_log_for_render.borrow_mut() =
                 format!("pen stroke point ({}, {})", current.x, current.y);
         }
     } else if let Some(preview) = context.tool_drag_preview.borrow_mut().as_mut() {
-        preview.current = current;
+        preview.current = constrain_drag_point(preview.tool, preview.start, current, shift_held);
     }
     context.editor_canvas.queue_draw();
 }
 
-fn handle_draw_gesture_end(context: &EditorDrawGestureContext, offset_x: f64, offset_y: f64) {
+fn handle_draw_gesture_end(
+    context: &EditorDrawGestureContext,
+    offset_x: f64,
+    offset_y: f64,
+    shift_held: bool,
+    stylus: Option<StylusSample>,
+) {
     let (start_x, start_y) = context.tool_drag_start_canvas.get();
     let end = canvas_point_to_image_point(
         &context.editor_canvas,
@@ -525,6 +827,14 @@ fn handle_draw_gesture_end(context: &EditorDrawGestureContext, offset_x: f64, of
         context.editor_image_base_width,
         context.editor_image_base_height,
     );
+    if context.ocr_overlay.borrow_mut().end_drag() {
+        let selected = context.ocr_overlay.borrow().selected_text();
+        *context.status_log_for_render.borrow_mut() = selected
+            .map(|text| format!("OCR text selected: {} chars", text.chars().count()))
+            .unwrap_or_else(|| "OCR text selection cleared".to_string());
+        context.editor_canvas.queue_draw();
+        return;
+    }
     if let Some(state) = context.object_drag_state.borrow_mut().take() {
         match state {
             ObjectDragState::Move { object_ids, .. } => {
@@ -532,6 +842,27 @@ fn handle_draw_gesture_end(context: &EditorDrawGestureContext, offset_x: f64, of
                 *context.status_log_for_render.borrow_mut() =
                     format!("moved {} object(s)", object_ids.len());
             }
+            ObjectDragState::MoveSpotlightHole { object_id, .. } => {
+                *context.editor_has_unsaved_changes.borrow_mut() = true;
+                set_single_selection(&context.selected_object_ids, object_id);
+                *context.status_log_for_render.borrow_mut() = "spotlight hole moved".to_string();
+            }
+            ObjectDragState::MoveMagnifierPart {
+                object_id, part, ..
+            } => {
+                *context.editor_has_unsaved_changes.borrow_mut() = true;
+                set_single_selection(&context.selected_object_ids, object_id);
+                *context.status_log_for_render.borrow_mut() = match part {
+                    MagnifierPart::Source => "magnifier source moved",
+                    MagnifierPart::Callout => "magnifier callout moved",
+                }
+                .to_string();
+            }
+            ObjectDragState::MoveCalloutTail { object_id } => {
+                *context.editor_has_unsaved_changes.borrow_mut() = true;
+                set_single_selection(&context.selected_object_ids, object_id);
+                *context.status_log_for_render.borrow_mut() = "callout tail moved".to_string();
+            }
             ObjectDragState::ResizeObject {
                 object_id, kind, ..
             } => {
@@ -549,13 +880,35 @@ fn handle_draw_gesture_end(context: &EditorDrawGestureContext, offset_x: f64, of
 
     if let Some(stroke_id) = context.active_pen_stroke_id.get() {
         let mut tools = context.editor_tools.borrow_mut();
-        let _ = tools.append_pen_point(stroke_id, end);
+        if tools.active_highlighter_stroke_id() == Some(stroke_id) {
+            let _ = tools.append_highlighter_point(stroke_id, end, shift_held);
+            if tools.finish_highlighter_stroke(stroke_id).is_ok() {
+                context.editor_redo_stack.borrow_mut().clear();
+                *context.editor_has_unsaved_changes.borrow_mut() = true;
+                set_single_selection(&context.selected_object_ids, stroke_id);
+                *context.status_log_for_render.borrow_mut() =
+                    format!("highlighter stroke finalized at ({}, {})", end.x, end.y);
+            }
+            context.active_pen_stroke_id.set(None);
+            context.editor_canvas.queue_draw();
+            return;
+        }
+        let _ = tools.append_pen_point(stroke_id, end, stylus);
         if tools.finish_pen_stroke(stroke_id).is_ok() {
             context.editor_redo_stack.borrow_mut().clear();
             *context.editor_has_unsaved_changes.borrow_mut() = true;
             set_single_selection(&context.selected_object_ids, stroke_id);
-            *context.status_log_for_render.borrow_mut() =
-                format!("pen stroke finalized at ({}, {})", end.x, end.y);
+            let snapped_to = match tools.object(stroke_id) {
+                Some(ToolObject::Line(_)) => Some("line"),
+                Some(ToolObject::Arrow(_)) => Some("arrow"),
+                Some(ToolObject::Rectangle(_)) => Some("rectangle"),
+                Some(ToolObject::Ellipse(_)) => Some("ellipse"),
+                _ => None,
+            };
+            *context.status_log_for_render.borrow_mut() = match snapped_to {
+                Some(shape) => format!("pen stroke snapped to {shape}"),
+                None => format!("pen stroke finalized at ({}, {})", end.x, end.y),
+            };
         }
         context.active_pen_stroke_id.set(None);
         context.editor_canvas.queue_draw();
@@ -566,6 +919,7 @@ fn handle_draw_gesture_end(context: &EditorDrawGestureContext, offset_x: f64, of
     let Some(preview) = preview else {
         return;
     };
+    let end = constrain_drag_point(preview.tool, preview.start, end, shift_held);
     let mut tools = context.editor_tools.borrow_mut();
     if preview.tool == ToolKind::Select {
         let selected = top_object_id_in_drag_box(&tools, preview.start, end);
@@ -612,6 +966,13 @@ fn handle_draw_gesture_end(context: &EditorDrawGestureContext, offset_x: f64, of
         return;
     }
 
+    if preview.tool == ToolKind::Decode {
+        drop(tools);
+        perform_barcode_decode(context, preview.start, end);
+        context.editor_canvas.queue_draw();
+        return;
+    }
+
     let outcome = match preview.tool {
         ToolKind::Select | ToolKind::Pan => Err(editor::ToolError::ToolNotSelected),
         ToolKind::Blur => normalize_tool_box(preview.start, end)
@@ -621,16 +982,53 @@ fn handle_draw_gesture_end(context: &EditorDrawGestureContext, offset_x: f64, of
             }),
         ToolKind::Arrow => tools.add_arrow(preview.start, end),
         ToolKind::Rectangle => tools.add_rectangle(preview.start, end),
+        ToolKind::Ellipse => tools.add_ellipse(preview.start, end),
+        ToolKind::Badge => Ok(tools.add_badge(preview.start, end)),
+        ToolKind::Spotlight => tools.add_spotlight_hole(preview.start, end),
+        ToolKind::Magnifier => tools.add_magnifier(
+            preview.start,
+            end,
+            ImageBounds::new(
+                context.editor_image_base_width,
+                context.editor_image_base_height,
+            ),
+        ),
+        ToolKind::Callout => Ok(tools.add_callout(preview.start, end)),
+        ToolKind::Image => tools.add_stamp(
+            preview.start,
+            end,
+            ImageBounds::new(
+                context.editor_image_base_width,
+                context.editor_image_base_height,
+            ),
+        ),
         ToolKind::Crop => Err(editor::ToolError::ToolNotSelected),
-        ToolKind::Pen | ToolKind::Text | ToolKind::Ocr => Err(editor::ToolError::ToolNotSelected),
+        ToolKind::Pen
+        | ToolKind::Highlighter
+        | ToolKind::Line
+        | ToolKind::Text
+        | ToolKind::Ocr
+        | ToolKind::Decode => Err(editor::ToolError::ToolNotSelected),
     };
 
+    drop(tools);
+
     match outcome {
         Ok(object_id) => {
             let created_tool = preview.tool;
             context.editor_redo_stack.borrow_mut().clear();
             *context.editor_has_unsaved_changes.borrow_mut() = true;
             set_single_selection(&context.selected_object_ids, object_id);
+            if created_tool == ToolKind::Callout {
+                // The new bubble is already the active text target.
+                switch_editor_tool_with_text_policy(
+                    &context.editor_tool_switch_context,
+                    ToolKind::Callout,
+                    true,
+                    TextInputActivation::ForceOn,
+                );
+                *context.text_preedit_state.borrow_mut() = TextPreeditState::default();
+            }
             *context.status_log_for_render.borrow_mut() = format!(
                 "{} object #{object_id} created",
                 tool_kind_label(created_tool)
@@ -649,13 +1047,8 @@ fn perform_ocr_recognition(
     start: editor::tools::ToolPoint,
     end: editor::tools::ToolPoint,
 ) {
-    use crate::app::ocr_support::{handle_ocr_text_result, ocr_processing_status};
-    use crate::app::worker::spawn_worker_action;
-
-    if context.ocr_in_progress.get() {
-        *context.status_log_for_render.borrow_mut() = "OCR already in progress".to_string();
-        return;
-    }
+    use crate::app::jobs::JobKey;
+    use crate::app::ocr_support::{handle_ocr_text_result, queue_ocr, OcrInput, OcrJobFeedback};
 
     let Some((x, y, width, height)) = normalize_tool_box(start, end) else {
         *context.status_log_for_render.borrow_mut() = "OCR drag too small".to_string();
@@ -680,46 +1073,43 @@ fn perform_ocr_recognition(
         }
     };
 
-    // Take the engine for the worker thread.
-    let engine = context.ocr_engine.borrow_mut().take();
-    let ocr_language = context.ocr_language;
-
-    // Set progress state and feedback.
-    context.ocr_in_progress.set(true);
-    *context.status_log_for_render.borrow_mut() =
-        ocr_processing_status(engine.is_some()).to_string();
+    let key = JobKey::for_capture(
+        &context.capture_id,
+        format!(
+            "ocr-region:{x},{y},{width},{height}:{}",
+            crate::ocr::format_ocr_languages(&context.ocr_languages.borrow())
+        ),
+    );
+    let feedback = OcrJobFeedback {
+        status_log: context.status_log_for_render.clone(),
+        toast: context.editor_toast.borrow().clone(),
+        toast_duration_ms: context.toast_duration_ms,
+    };
     context.editor_canvas.set_cursor_from_name(Some("progress"));
 
-    let ocr_engine = context.ocr_engine.clone();
-    let ocr_in_progress = context.ocr_in_progress.clone();
     let status_log = context.status_log_for_render.clone();
     let editor_canvas = context.editor_canvas.clone();
-
-    spawn_worker_action(
-        move || {
-            let engine = match crate::app::ocr_support::resolve_or_init_engine(engine, ocr_language)
-            {
-                Ok(e) => e,
-                Err(err) => return (None, Err(err)),
-            };
-            let result = crate::ocr::recognize_text(&engine, &image);
-            (Some(engine), result)
-        },
-        move |(engine, result): (
-            Option<crate::ocr::OcrEngine>,
-            Result<String, crate::ocr::OcrError>,
-        )| {
-            // Restore engine.
-            if let Some(engine) = engine {
-                *ocr_engine.borrow_mut() = Some(engine);
-            }
-            ocr_in_progress.set(false);
-
+    let ocr_overlay = context.ocr_overlay.clone();
+    queue_ocr(
+        &context.ocr_jobs,
+        key,
+        OcrInput::Image(image),
+        &context.ocr_languages,
+        feedback,
+        move |result| {
             // Restore cursor.
             editor_canvas.set_cursor_from_name(None::<&str>);
 
             match result {
-                Ok(text) => handle_ocr_text_result(&status_log, text),
+                Ok(document) => {
+                    // Region results are relative to the crop; shift them
+                    // back so the overlay lines up with the canvas.
+                    ocr_overlay
+                        .borrow_mut()
+                        .set_region_document(&document, x, y);
+                    handle_ocr_text_result(&status_log, document.to_plain_text());
+                }
+                Err(crate::ocr::OcrError::Cancelled) => {}
                 Err(err) => {
                     *status_log.borrow_mut() = format!("OCR failed: {err}");
                     crate::notification::send(format!("OCR failed: {err}"));
@@ -730,6 +1120,103 @@ fn perform_ocr_recognition(
     );
 }
 
+/// Decodes codes inside the dragged box, or across the whole capture when
+/// the tool is clicked without dragging.
+fn perform_barcode_decode(
+    context: &EditorDrawGestureContext,
+    start: editor::tools::ToolPoint,
+    end: editor::tools::ToolPoint,
+) {
+    use crate::app::barcode_results::{barcode_decode_key, queue_barcode_decode, BarcodeInput};
+    use crate::app::jobs::JobOutcome;
+
+    let Some(ref pixbuf) = context.editor_source_pixbuf else {
+        *context.status_log_for_render.borrow_mut() =
+            "decode: no source image available".to_string();
+        return;
+    };
+    let (x, y, width, height) = normalize_tool_box(start, end).unwrap_or((
+        0,
+        0,
+        pixbuf.width().max(0) as u32,
+        pixbuf.height().max(0) as u32,
+    ));
+
+    // Pixbuf is not Send; convert on the main thread like the OCR tool does.
+    let image = match crate::app::ocr_support::pixbuf_region_to_dynamic_image(
+        pixbuf, x, y, width, height,
+    ) {
+        Ok(image) => image,
+        Err(err) => {
+            *context.status_log_for_render.borrow_mut() =
+                format!("decode image conversion failed: {err}");
+            crate::notification::send(format!("Decode failed: {err}"));
+            return;
+        }
+    };
+
+    *context.status_log_for_render.borrow_mut() = "Decoding codes...".to_string();
+    context.editor_canvas.set_cursor_from_name(Some("progress"));
+
+    // Closing the editor cancels the decode; the hook goes away once the
+    // decode is done.
+    let destroy_hook: Rc<RefCell<Option<(gtk4::Window, gtk4::glib::SignalHandlerId)>>> =
+        Rc::new(RefCell::new(None));
+    let status_log = context.status_log_for_render.clone();
+    let editor_canvas = context.editor_canvas.clone();
+    let handle = {
+        let destroy_hook = destroy_hook.clone();
+        queue_barcode_decode(
+            &context.ocr_jobs,
+            barcode_decode_key(&context.capture_id, Some((x, y, width, height))),
+            BarcodeInput::Image(image),
+            move |outcome| {
+                if let Some((window, handler)) = destroy_hook.borrow_mut().take() {
+                    window.disconnect(handler);
+                }
+                editor_canvas.set_cursor_from_name(None::<&str>);
+                let JobOutcome::Finished(result) = outcome else {
+                    *status_log.borrow_mut() = "decode cancelled".to_string();
+                    return;
+                };
+                let parent = editor_canvas.root().and_downcast::<gtk4::Window>();
+                crate::app::barcode_results::handle_barcode_result(parent, &status_log, result);
+            },
+        )
+    };
+    // `None` means the same decode is still pending; it keeps the cursor and
+    // shows its own result.
+    let Some(handle) = handle else {
+        return;
+    };
+    if let Some(window) = context.editor_canvas.root().and_downcast::<gtk4::Window>() {
+        let hook = destroy_hook.clone();
+        let handler = window.connect_destroy(move |_| {
+            hook.borrow_mut().take();
+            handle.cancel();
+        });
+        *destroy_hook.borrow_mut() = Some((window, handler));
+    }
+}
+
+fn gesture_shift_held(gesture: &gtk4::GestureDrag) -> bool {
+    gesture
+        .current_event_state()
+        .contains(gtk4::gdk::ModifierType::SHIFT_MASK)
+}
+
+/// Reads pressure and tilt from the event driving the gesture. Mice and
+/// touchpads report no pressure axis and yield `None`.
+fn gesture_stylus_sample(gesture: &gtk4::GestureDrag) -> Option<StylusSample> {
+    let event = gesture.current_event()?;
+    let pressure = event.axis(gtk4::gdk::AxisUse::Pressure)?;
+    Some(StylusSample::from_axes(
+        pressure,
+        event.axis(gtk4::gdk::AxisUse::Xtilt),
+        event.axis(gtk4::gdk::AxisUse::Ytilt),
+    ))
+}
+
 pub(in crate::app::editor_runtime) fn connect_editor_draw_gesture(
     context: EditorDrawGestureContext,
 ) {
@@ -742,13 +1229,25 @@ pub(in crate::app::editor_runtime) fn connect_editor_draw_gesture(
     });
 
     let update_context = context.clone();
-    draw_gesture.connect_drag_update(move |_, offset_x, offset_y| {
-        handle_draw_gesture_update(&update_context, offset_x, offset_y);
+    draw_gesture.connect_drag_update(move |gesture, offset_x, offset_y| {
+        handle_draw_gesture_update(
+            &update_context,
+            offset_x,
+            offset_y,
+            gesture_shift_held(gesture),
+            gesture_stylus_sample(gesture),
+        );
     });
 
     let end_context = context.clone();
-    draw_gesture.connect_drag_end(move |_, offset_x, offset_y| {
-        handle_draw_gesture_end(&end_context, offset_x, offset_y);
+    draw_gesture.connect_drag_end(move |gesture, offset_x, offset_y| {
+        handle_draw_gesture_end(
+            &end_context,
+            offset_x,
+            offset_y,
+            gesture_shift_held(gesture),
+            gesture_stylus_sample(gesture),
+        );
     });
 
     context.editor_canvas.add_controller(draw_gesture);
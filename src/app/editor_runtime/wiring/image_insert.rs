@@ -0,0 +1,172 @@
+use std::cell::RefCell;
+use std::path::Path;
+use std::rc::Rc;
+
+use crate::editor::tools::{EmbeddedImage, ImageBounds};
+use crate::editor::{self, ToolObject};
+
+use gtk4::prelude::*;
+use gtk4::{
+    gio, ApplicationWindow, Button, DrawingArea, FileChooserAction, FileChooserNative, FileFilter,
+    ResponseType,
+};
+
+use crate::app::editor_history::{record_undo_snapshot, snapshot_editor_objects};
+use crate::app::editor_popup::set_single_selection;
+use crate::app::runtime_support::ToastRuntime;
+
+/// Larger files are refused before reading them into memory; the decoded
+/// picture is bounded separately by the image tool's side limit.
+const MAX_PICTURE_FILE_BYTES: u64 = 64 * 1024 * 1024;
+
+#[derive(Clone)]
+pub(in crate::app::editor_runtime) struct EditorImageInsertContext {
+    pub(in crate::app::editor_runtime) editor_window: ApplicationWindow,
+    pub(in crate::app::editor_runtime) editor_canvas: DrawingArea,
+    pub(in crate::app::editor_runtime) editor_tools: Rc<RefCell<editor::EditorTools>>,
+    pub(in crate::app::editor_runtime) editor_undo_stack: Rc<RefCell<Vec<Vec<ToolObject>>>>,
+    pub(in crate::app::editor_runtime) editor_redo_stack: Rc<RefCell<Vec<Vec<ToolObject>>>>,
+    pub(in crate::app::editor_runtime) selected_object_ids: Rc<RefCell<Vec<u64>>>,
+    pub(in crate::app::editor_runtime) editor_has_unsaved_changes: Rc<RefCell<bool>>,
+    pub(in crate::app::editor_runtime) status_log_for_render: Rc<RefCell<String>>,
+    pub(in crate::app::editor_runtime) editor_toast: ToastRuntime,
+    pub(in crate::app::editor_runtime) toast_duration_ms: u32,
+    pub(in crate::app::editor_runtime) image_bounds: ImageBounds,
+}
+
+/// Wires the image tool's "Paste" and "Open…" buttons. Both insert the
+/// picture as a new image object centered on the capture and select it.
+pub(in crate::app::editor_runtime) fn connect_editor_image_insert_buttons(
+    paste_button: &Button,
+    open_button: &Button,
+    context: EditorImageInsertContext,
+) {
+    {
+        let context = context.clone();
+        paste_button.connect_clicked(move |_| {
+            paste_picture(&context);
+        });
+    }
+    let active_chooser = Rc::new(RefCell::new(None::<FileChooserNative>));
+    open_button.connect_clicked(move |_| {
+        open_picture(&context, &active_chooser);
+    });
+}
+
+fn paste_picture(context: &EditorImageInsertContext) {
+    let clipboard = context.editor_canvas.clipboard();
+    let context = context.clone();
+    clipboard.read_texture_async(None::<&gio::Cancellable>, move |result| {
+        let texture = match result {
+            Ok(Some(texture)) => texture,
+            Ok(None) => {
+                report_failure(&context, "clipboard has no image");
+                return;
+            }
+            Err(err) => {
+                tracing::warn!(error = %err, "failed to read image from clipboard");
+                report_failure(&context, "clipboard has no image");
+                return;
+            }
+        };
+        let encoded = gtk4::gdk::pixbuf_get_from_texture(&texture)
+            .and_then(|pixbuf| pixbuf.save_to_bufferv("png", &[]).ok());
+        match encoded {
+            Some(data) => insert_picture(&context, data, "clipboard"),
+            None => report_failure(&context, "clipboard image could not be encoded"),
+        }
+    });
+}
+
+fn open_picture(
+    context: &EditorImageInsertContext,
+    active_chooser: &Rc<RefCell<Option<FileChooserNative>>>,
+) {
+    if active_chooser.borrow().is_some() {
+        return;
+    }
+    let chooser = FileChooserNative::new(
+        Some("Insert Image"),
+        Some(&context.editor_window),
+        FileChooserAction::Open,
+        Some("Insert"),
+        Some("Cancel"),
+    );
+    chooser.set_modal(true);
+    let filter = FileFilter::new();
+    filter.set_name(Some("Images"));
+    filter.add_pixbuf_formats();
+    chooser.add_filter(&filter);
+    {
+        let context = context.clone();
+        let active_chooser = active_chooser.clone();
+        chooser.connect_response(move |chooser, response| {
+            let path = (response == ResponseType::Accept)
+                .then(|| chooser.file().and_then(|file| file.path()))
+                .flatten();
+            active_chooser.borrow_mut().take();
+            if let Some(path) = path {
+                insert_picture_file(&context, &path);
+            }
+        });
+    }
+    chooser.show();
+    *active_chooser.borrow_mut() = Some(chooser);
+}
+
+fn insert_picture_file(context: &EditorImageInsertContext, path: &Path) {
+    let too_large = std::fs::metadata(path)
+        .map(|metadata| metadata.len() > MAX_PICTURE_FILE_BYTES)
+        .unwrap_or(false);
+    if too_large {
+        report_failure(context, "image file is too large");
+        return;
+    }
+    match std::fs::read(path) {
+        Ok(data) => {
+            let name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| path.display().to_string());
+            insert_picture(context, data, &name);
+        }
+        Err(err) => {
+            tracing::warn!(path = %path.display(), error = %err, "failed to read image file");
+            report_failure(context, "image file could not be read");
+        }
+    }
+}
+
+fn insert_picture(context: &EditorImageInsertContext, data: Vec<u8>, source: &str) {
+    let picture = match EmbeddedImage::from_encoded(data) {
+        Ok(picture) => picture,
+        Err(err) => {
+            tracing::warn!(source, ?err, "rejected inserted image");
+            report_failure(context, "unsupported or oversized image");
+            return;
+        }
+    };
+    let snapshot = snapshot_editor_objects(&context.editor_tools);
+    let id = context
+        .editor_tools
+        .borrow_mut()
+        .add_picture(picture, context.image_bounds);
+    record_undo_snapshot(
+        &context.editor_undo_stack,
+        &context.editor_redo_stack,
+        snapshot,
+    );
+    set_single_selection(&context.selected_object_ids, id);
+    *context.editor_has_unsaved_changes.borrow_mut() = true;
+    *context.status_log_for_render.borrow_mut() =
+        format!("image object #{id} inserted from {source}");
+    context.editor_canvas.queue_draw();
+}
+
+fn report_failure(context: &EditorImageInsertContext, reason: &str) {
+    *context.status_log_for_render.borrow_mut() = format!("image insert failed: {reason}");
+    context.editor_toast.show(
+        format!("Image insert failed: {reason}"),
+        context.toast_duration_ms,
+    );
+}
@@ -0,0 +1,93 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gtk4::prelude::*;
+use gtk4::{Button, DrawingArea};
+
+use crate::app::jobs::{JobOutcome, JobQueue};
+use crate::app::ocr_support::{queue_ocr_export, OcrJobFeedback, SharedOcrLanguages};
+use crate::app::runtime_support::ToastRuntime;
+use crate::app::EditorOutputActionRuntime;
+use crate::editor::EditorAction;
+use crate::ocr::OcrExportFormat;
+
+#[derive(Clone)]
+pub(in crate::app::editor_runtime) struct EditorOcrExportContext {
+    pub(in crate::app::editor_runtime) editor_canvas: DrawingArea,
+    pub(in crate::app::editor_runtime) status_log_for_render: Rc<RefCell<String>>,
+    pub(in crate::app::editor_runtime) editor_toast: ToastRuntime,
+    pub(in crate::app::editor_runtime) toast_duration_ms: u32,
+    pub(in crate::app::editor_runtime) output_action_runtime: EditorOutputActionRuntime,
+    pub(in crate::app::editor_runtime) ocr_jobs: JobQueue,
+    pub(in crate::app::editor_runtime) ocr_languages: SharedOcrLanguages,
+}
+
+/// Wires the "export OCR" items, which follow [`OcrExportFormat::ALL`].
+pub(in crate::app::editor_runtime) fn connect_editor_ocr_export_items(
+    items: &[Button],
+    context: EditorOcrExportContext,
+) {
+    for (format, item) in OcrExportFormat::ALL.into_iter().zip(items) {
+        let context = context.clone();
+        item.connect_clicked(move |_| {
+            run_ocr_export(&context, format);
+        });
+    }
+}
+
+/// Saves the capture, then recognizes the saved image and writes the export
+/// next to it, so the text layer matches exactly what was saved.
+fn run_ocr_export(context: &EditorOcrExportContext, format: OcrExportFormat) {
+    if !context
+        .output_action_runtime
+        .run(EditorAction::Save, "export")
+    {
+        return;
+    }
+    let Some(image_path) = context.output_action_runtime.saved_capture_path() else {
+        *context.status_log_for_render.borrow_mut() =
+            "OCR export: saved capture path unavailable".to_string();
+        return;
+    };
+
+    let on_done = {
+        let context = context.clone();
+        move |outcome: JobOutcome<Result<std::path::PathBuf, String>>| {
+            context.editor_canvas.set_cursor_from_name(None::<&str>);
+            match outcome {
+                JobOutcome::Finished(Ok(path)) => {
+                    let name = path
+                        .file_name()
+                        .map(|name| name.to_string_lossy().into_owned())
+                        .unwrap_or_default();
+                    *context.status_log_for_render.borrow_mut() =
+                        format!("OCR export wrote {}", path.display());
+                    context.editor_toast.show(
+                        format!("Exported {} as {name}", format.label()),
+                        context.toast_duration_ms,
+                    );
+                }
+                JobOutcome::Finished(Err(err)) => {
+                    *context.status_log_for_render.borrow_mut() =
+                        format!("OCR export failed: {err}");
+                    crate::notification::send(format!("OCR export failed: {err}"));
+                }
+                JobOutcome::Cancelled => {}
+            }
+        }
+    };
+    let feedback = OcrJobFeedback {
+        status_log: context.status_log_for_render.clone(),
+        toast: Some(context.editor_toast.clone()),
+        toast_duration_ms: context.toast_duration_ms,
+    };
+    queue_ocr_export(
+        &context.ocr_jobs,
+        image_path,
+        format,
+        &context.ocr_languages,
+        feedback,
+        on_done,
+    );
+    context.editor_canvas.set_cursor_from_name(Some("progress"));
+}
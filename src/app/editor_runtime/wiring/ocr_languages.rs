@@ -0,0 +1,81 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gtk4::prelude::*;
+use gtk4::{Button, DrawingArea, MenuButton};
+
+use crate::app::ocr_overlay::SharedOcrOverlay;
+use crate::app::ocr_support::SharedOcrLanguages;
+use crate::ocr::{format_ocr_languages, toggle_ocr_language, OcrLanguage};
+
+const CHECKED_ITEM_CLASS: &str = "menu-popover-item-checked";
+
+#[derive(Clone)]
+pub(in crate::app::editor_runtime) struct EditorOcrLanguageMenuContext {
+    pub(in crate::app::editor_runtime) editor_canvas: DrawingArea,
+    pub(in crate::app::editor_runtime) status_log_for_render: Rc<RefCell<String>>,
+    pub(in crate::app::editor_runtime) ocr_languages: SharedOcrLanguages,
+    pub(in crate::app::editor_runtime) ocr_installed_languages: Rc<Vec<OcrLanguage>>,
+    pub(in crate::app::editor_runtime) ocr_overlay: SharedOcrOverlay,
+}
+
+/// Wires the OCR language menu. `items` follow [`OcrLanguage::ALL`]; clicking
+/// one adds it to the end of the candidate list or removes it. Languages
+/// without installed models are greyed out unless already selected, so they
+/// can still be removed.
+pub(in crate::app::editor_runtime) fn connect_editor_ocr_language_menu(
+    menu_button: &MenuButton,
+    items: &[Button],
+    context: EditorOcrLanguageMenuContext,
+) {
+    refresh_ocr_language_menu(menu_button, items, &context);
+    for (language, item) in OcrLanguage::ALL.into_iter().zip(items) {
+        let menu_button = menu_button.clone();
+        let items = items.to_vec();
+        let context = context.clone();
+        item.connect_clicked(move |_| {
+            let toggled = toggle_ocr_language(&context.ocr_languages.borrow(), language);
+            if toggled == *context.ocr_languages.borrow() {
+                *context.status_log_for_render.borrow_mut() =
+                    "OCR needs at least one language".to_string();
+                return;
+            }
+            let summary = format_ocr_languages(&toggled);
+            *context.ocr_languages.borrow_mut() = toggled;
+            // Text read with the previous languages would keep find and
+            // auto-redact from recognizing the image again.
+            context
+                .ocr_overlay
+                .borrow_mut()
+                .set_layer(crate::ocr::OcrTextLayer::default());
+            refresh_ocr_language_menu(&menu_button, &items, &context);
+            *context.status_log_for_render.borrow_mut() = format!("OCR languages: {summary}");
+            context.editor_canvas.queue_draw();
+        });
+    }
+}
+
+fn refresh_ocr_language_menu(
+    menu_button: &MenuButton,
+    items: &[Button],
+    context: &EditorOcrLanguageMenuContext,
+) {
+    let selected = context.ocr_languages.borrow();
+    for (language, item) in OcrLanguage::ALL.into_iter().zip(items) {
+        let is_selected = selected.contains(&language);
+        let is_installed = context.ocr_installed_languages.contains(&language);
+        if is_selected {
+            item.add_css_class(CHECKED_ITEM_CLASS);
+        } else {
+            item.remove_css_class(CHECKED_ITEM_CLASS);
+        }
+        item.set_sensitive(is_installed || is_selected);
+        item.set_tooltip_text(
+            (!is_installed).then_some("Model not installed (see chalkak models)"),
+        );
+    }
+    menu_button.set_tooltip_text(Some(&format!(
+        "OCR languages: {}",
+        format_ocr_languages(&selected)
+    )));
+}
@@ -0,0 +1,54 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gtk4::prelude::*;
+use gtk4::Button;
+
+use crate::app::ocr_overlay::SharedOcrOverlay;
+use crate::app::runtime_support::ToastRuntime;
+use crate::ocr::TableFormat;
+
+#[derive(Clone)]
+pub(in crate::app::editor_runtime) struct EditorOcrTableContext {
+    pub(in crate::app::editor_runtime) status_log_for_render: Rc<RefCell<String>>,
+    pub(in crate::app::editor_runtime) editor_toast: ToastRuntime,
+    pub(in crate::app::editor_runtime) toast_duration_ms: u32,
+    pub(in crate::app::editor_runtime) ocr_overlay: SharedOcrOverlay,
+}
+
+/// Wires the "copy as table" items, which follow [`TableFormat::ALL`]. The
+/// table comes from the last region recognized with the OCR tool.
+pub(in crate::app::editor_runtime) fn connect_editor_ocr_table_items(
+    items: &[Button],
+    context: EditorOcrTableContext,
+) {
+    for (format, item) in TableFormat::ALL.into_iter().zip(items) {
+        let context = context.clone();
+        item.connect_clicked(move |button| {
+            copy_region_table(button, &context, format);
+        });
+    }
+}
+
+fn copy_region_table(button: &Button, context: &EditorOcrTableContext, format: TableFormat) {
+    let Some(table) = context.ocr_overlay.borrow().region_table() else {
+        *context.status_log_for_render.borrow_mut() =
+            "table: recognize a region with the OCR tool first".to_string();
+        context.editor_toast.show(
+            "Drag over a table with the OCR tool first",
+            context.toast_duration_ms,
+        );
+        return;
+    };
+    button.clipboard().set_text(&table.render(format));
+    let message = format!(
+        "Copied {}×{} table as {}",
+        table.row_count(),
+        table.column_count(),
+        format.label()
+    );
+    *context.status_log_for_render.borrow_mut() = format!("table: {message}");
+    context
+        .editor_toast
+        .show(message, context.toast_duration_ms);
+}
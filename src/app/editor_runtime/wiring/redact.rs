@@ -0,0 +1,157 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::editor::{self, ToolObject};
+
+use gtk4::prelude::*;
+use gtk4::{Button, DrawingArea};
+
+use crate::app::editor_history::{record_undo_snapshot, snapshot_editor_objects};
+use crate::app::editor_popup::clear_selection;
+use crate::app::jobs::JobQueue;
+use crate::app::ocr_overlay::SharedOcrOverlay;
+use crate::app::ocr_support::{
+    blur_region_within_image, queue_full_image_ocr, OcrJobFeedback, SharedOcrLanguages,
+};
+use crate::app::runtime_support::ToastRuntime;
+
+#[derive(Clone)]
+pub(in crate::app::editor_runtime) struct EditorAutoRedactContext {
+    pub(in crate::app::editor_runtime) editor_canvas: DrawingArea,
+    pub(in crate::app::editor_runtime) editor_tools: Rc<RefCell<editor::EditorTools>>,
+    pub(in crate::app::editor_runtime) editor_undo_stack: Rc<RefCell<Vec<Vec<ToolObject>>>>,
+    pub(in crate::app::editor_runtime) editor_redo_stack: Rc<RefCell<Vec<Vec<ToolObject>>>>,
+    pub(in crate::app::editor_runtime) selected_object_ids: Rc<RefCell<Vec<u64>>>,
+    pub(in crate::app::editor_runtime) editor_has_unsaved_changes: Rc<RefCell<bool>>,
+    pub(in crate::app::editor_runtime) status_log_for_render: Rc<RefCell<String>>,
+    pub(in crate::app::editor_runtime) editor_toast: ToastRuntime,
+    pub(in crate::app::editor_runtime) toast_duration_ms: u32,
+    pub(in crate::app::editor_runtime) editor_source_pixbuf: Option<gtk4::gdk_pixbuf::Pixbuf>,
+    pub(in crate::app::editor_runtime) capture_id: String,
+    pub(in crate::app::editor_runtime) ocr_jobs: JobQueue,
+    pub(in crate::app::editor_runtime) ocr_languages: SharedOcrLanguages,
+    pub(in crate::app::editor_runtime) ocr_overlay: SharedOcrOverlay,
+    pub(in crate::app::editor_runtime) redaction_rules: Rc<crate::ocr::RedactionRules>,
+}
+
+pub(in crate::app::editor_runtime) fn connect_editor_auto_redact_button(
+    button: &Button,
+    context: EditorAutoRedactContext,
+) {
+    button.connect_clicked(move |_| {
+        run_auto_redact(&context);
+    });
+}
+
+/// Recognizes the whole capture and adds a blur object over every line
+/// fragment matched by the configured redaction rules. The blurs are ordinary
+/// objects, so one undo step removes them all and each can be adjusted.
+fn run_auto_redact(context: &EditorAutoRedactContext) {
+    if context.redaction_rules.is_empty() {
+        *context.status_log_for_render.borrow_mut() =
+            "auto-redact: no patterns configured".to_string();
+        context.editor_toast.show(
+            "No auto-redact patterns configured",
+            context.toast_duration_ms,
+        );
+        return;
+    }
+    let Some(ref pixbuf) = context.editor_source_pixbuf else {
+        *context.status_log_for_render.borrow_mut() =
+            "auto-redact: no source image available".to_string();
+        return;
+    };
+
+    let image_width = pixbuf.width();
+    let image_height = pixbuf.height();
+    let on_done = {
+        let context = context.clone();
+        move |result: crate::ocr::OcrResult<crate::ocr::OcrDocument>| {
+            context.editor_canvas.set_cursor_from_name(None::<&str>);
+            match result {
+                Ok(document) => {
+                    let layer = crate::ocr::OcrTextLayer::from_document(&document, 0, 0);
+                    let matches = crate::ocr::find_redactions(&layer, &context.redaction_rules);
+                    context.ocr_overlay.borrow_mut().set_full_image_layer(layer);
+                    apply_redactions(&context, &matches, image_width, image_height);
+                }
+                Err(crate::ocr::OcrError::Cancelled) => {}
+                Err(err) => {
+                    *context.status_log_for_render.borrow_mut() =
+                        format!("auto-redact failed: {err}");
+                    crate::notification::send(format!("Auto-redact failed: {err}"));
+                }
+            }
+            context.editor_canvas.queue_draw();
+        }
+    };
+    let feedback = OcrJobFeedback {
+        status_log: context.status_log_for_render.clone(),
+        toast: Some(context.editor_toast.clone()),
+        toast_duration_ms: context.toast_duration_ms,
+    };
+    if let Err(err) = queue_full_image_ocr(
+        &context.ocr_jobs,
+        &context.capture_id,
+        pixbuf,
+        &context.ocr_languages,
+        feedback,
+        on_done,
+    ) {
+        *context.status_log_for_render.borrow_mut() =
+            format!("auto-redact image conversion failed: {err}");
+        crate::notification::send(format!("Auto-redact failed: {err}"));
+        return;
+    }
+    context.editor_canvas.set_cursor_from_name(Some("progress"));
+}
+
+fn apply_redactions(
+    context: &EditorAutoRedactContext,
+    matches: &[crate::ocr::RedactionMatch],
+    image_width: i32,
+    image_height: i32,
+) {
+    let regions: Vec<_> = matches
+        .iter()
+        .filter_map(|found| blur_region_within_image(found.bbox, image_width, image_height))
+        .collect();
+    if regions.is_empty() {
+        *context.status_log_for_render.borrow_mut() =
+            "auto-redact: no sensitive text found".to_string();
+        context
+            .editor_toast
+            .show("No sensitive text found", context.toast_duration_ms);
+        return;
+    }
+
+    let snapshot = snapshot_editor_objects(context.editor_tools.as_ref());
+    let mut added = 0_usize;
+    {
+        let mut tools = context.editor_tools.borrow_mut();
+        for region in regions {
+            if tools.add_blur(region).is_ok() {
+                added += 1;
+            }
+        }
+    }
+    if added == 0 {
+        return;
+    }
+    record_undo_snapshot(
+        context.editor_undo_stack.as_ref(),
+        context.editor_redo_stack.as_ref(),
+        snapshot,
+    );
+    clear_selection(&context.selected_object_ids);
+    *context.editor_has_unsaved_changes.borrow_mut() = true;
+
+    let message = format!(
+        "Blurred {added} sensitive region{}",
+        if added == 1 { "" } else { "s" }
+    );
+    *context.status_log_for_render.borrow_mut() = format!("auto-redact: {message}");
+    context
+        .editor_toast
+        .show(message, context.toast_duration_ms);
+}
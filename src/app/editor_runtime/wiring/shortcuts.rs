@@ -20,8 +20,10 @@ use crate::app::editor_text_runtime::{
 use crate::app::input_bridge::{
     key_name, modifier_state, normalize_shortcut_key, resolve_text_input_event, shortcut_modifiers,
 };
+use crate::app::ocr_overlay::{copy_ocr_selection, SharedOcrOverlay};
 use crate::app::{shortcut_editor_tool_switch, EditorToolSwitchContext, TextInputActivation};
 
+use super::find::EditorFindBar;
 use super::tools::switch_editor_tool_with_text_policy;
 use super::viewport::{
     editor_viewport_runtime, handle_editor_viewport_shortcuts, EditorViewportShortcutContext,
@@ -44,6 +46,8 @@ struct EditorShortcutActionContext {
     editor_has_unsaved_changes: Rc<RefCell<bool>>,
     status_log_for_render: Rc<RefCell<String>>,
     editor_canvas: DrawingArea,
+    ocr_overlay: SharedOcrOverlay,
+    find_bar: EditorFindBar,
 }
 
 fn handle_editor_shortcut_action(
@@ -56,7 +60,17 @@ fn handle_editor_shortcut_action(
             context.editor_save_button.emit_clicked();
         }
         ShortcutAction::EditorCopyImage => {
-            context.editor_copy_button.emit_clicked();
+            let copied_text = context.active_editor_tool.get() == ToolKind::Ocr
+                && copy_ocr_selection(
+                    &context.status_log_for_render,
+                    &context.ocr_overlay.borrow(),
+                );
+            if !copied_text {
+                context.editor_copy_button.emit_clicked();
+            }
+        }
+        ShortcutAction::EditorFind => {
+            context.find_bar.open();
         }
         ShortcutAction::EditorToggleToolOptions => {
             context.tool_options_toggle_button.emit_clicked();
@@ -95,7 +109,7 @@ fn handle_editor_shortcut_action(
                     snapshot,
                 );
                 clear_selection(&context.selected_object_ids);
-                if matches!(removed_object, ToolObject::Text(_)) {
+                if matches!(removed_object, ToolObject::Text(_) | ToolObject::Callout(_)) {
                     switch_editor_tool_with_text_policy(
                         &context.editor_tool_switch,
                         context.active_editor_tool.get(),
@@ -116,6 +130,41 @@ fn handle_editor_shortcut_action(
                     "selected object not found".to_string();
             }
         }
+        ShortcutAction::EditorBadgeEarlier | ShortcutAction::EditorBadgeLater => {
+            let selected_id = context.selected_object_ids.borrow().first().copied();
+            let Some(selected_id) = selected_id else {
+                return gtk4::glib::Propagation::Proceed;
+            };
+            if !matches!(
+                context.editor_tools.borrow().object(selected_id),
+                Some(ToolObject::Badge(_))
+            ) {
+                return gtk4::glib::Propagation::Proceed;
+            }
+            let later = matches!(action, ShortcutAction::EditorBadgeLater);
+            let snapshot = snapshot_editor_objects(context.editor_tools.as_ref());
+            let moved = context
+                .editor_tools
+                .borrow_mut()
+                .move_badge_in_sequence(selected_id, later);
+            if moved == Ok(true) {
+                record_undo_snapshot(
+                    context.editor_undo_stack.as_ref(),
+                    context.editor_redo_stack.as_ref(),
+                    snapshot,
+                );
+                *context.editor_has_unsaved_changes.borrow_mut() = true;
+                *context.status_log_for_render.borrow_mut() = if later {
+                    format!("badge #{selected_id} moved later in sequence")
+                } else {
+                    format!("badge #{selected_id} moved earlier in sequence")
+                };
+                context.editor_canvas.queue_draw();
+            } else {
+                *context.status_log_for_render.borrow_mut() =
+                    format!("badge #{selected_id} is already at the sequence edge");
+            }
+        }
         ShortcutAction::TextInsertLineBreak => {
             let snapshot = snapshot_editor_objects(context.editor_tools.as_ref());
             let input_action = context
@@ -199,6 +248,9 @@ pub(in crate::app::editor_runtime) struct EditorKeyHandlingContext {
     pub(in crate::app::editor_runtime) drag_pan_active: Rc<Cell<bool>>,
     pub(in crate::app::editor_runtime) drag_pan_pointer_origin: Rc<Cell<(f64, f64)>>,
     pub(in crate::app::editor_runtime) refresh_editor_cursor: Rc<dyn Fn()>,
+    pub(in crate::app::editor_runtime) ocr_overlay: SharedOcrOverlay,
+    pub(in crate::app::editor_runtime) find_bar: EditorFindBar,
+    pub(in crate::app::editor_runtime) find_entry_focused: Rc<Cell<bool>>,
     pub(in crate::app::editor_runtime) editor_image_base_width: i32,
     pub(in crate::app::editor_runtime) editor_image_base_height: i32,
 }
@@ -241,6 +293,7 @@ pub(in crate::app::editor_runtime) fn connect_editor_key_handling(
     let schedule_fit_settle_pass = context.schedule_fit_settle_pass.clone();
     let editor_image_base_width = context.editor_image_base_width;
     let editor_image_base_height = context.editor_image_base_height;
+    let find_entry_focused = context.find_entry_focused.clone();
 
     {
         let editor_tools = editor_tools.clone();
@@ -305,8 +358,14 @@ pub(in crate::app::editor_runtime) fn connect_editor_key_handling(
         editor_has_unsaved_changes: editor_has_unsaved_changes.clone(),
         status_log_for_render: status_log_for_render.clone(),
         editor_canvas: editor_canvas.clone(),
+        ocr_overlay: context.ocr_overlay.clone(),
+        find_bar: context.find_bar.clone(),
     };
     key_controller.connect_key_pressed(move |_, key, keycode, modifier| {
+        if find_entry_focused.get() {
+            // Keys belong to the find bar's search entry.
+            return gtk4::glib::Propagation::Proceed;
+        }
         let mode = *editor_input_mode.borrow();
         let key_name = key_name(key);
         if mode.text_input_active() {
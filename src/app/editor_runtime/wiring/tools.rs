@@ -24,20 +24,37 @@ pub(in crate::app::editor_runtime) fn connect_editor_output_button(
     });
 }
 
-pub(in crate::app::editor_runtime) const EDITOR_TOOLBAR_ENTRIES: [(ToolKind, &str, &str); 9] = [
+pub(in crate::app::editor_runtime) const EDITOR_TOOLBAR_ENTRIES: [(ToolKind, &str, &str); 18] = [
     (ToolKind::Select, "mouse-pointer-symbolic", "Select (V)"),
     (ToolKind::Pan, "hand-symbolic", "Pan (H)"),
     (ToolKind::Blur, "eye-off-symbolic", "Blur (B)"),
     (ToolKind::Pen, EDITOR_PEN_ICON_NAME, "Pen (P)"),
+    (
+        ToolKind::Highlighter,
+        "highlighter-symbolic",
+        "Highlighter (M)",
+    ),
     (ToolKind::Arrow, "move-up-right-symbolic", "Arrow (A)"),
+    (ToolKind::Line, "polyline-symbolic", "Line (L)"),
     (
         ToolKind::Rectangle,
         "rectangle-horizontal-symbolic",
         "Rectangle (R)",
     ),
+    (ToolKind::Ellipse, "circle-symbolic", "Ellipse (E)"),
+    (ToolKind::Badge, "list-ordered-symbolic", "Step Badge (N)"),
+    (ToolKind::Spotlight, "focus-symbolic", "Spotlight (D)"),
+    (ToolKind::Magnifier, "zoom-in-symbolic", "Magnifier (Z)"),
+    (ToolKind::Callout, "message-square-symbolic", "Callout (K)"),
+    (ToolKind::Image, "image-symbolic", "Image (I)"),
     (ToolKind::Crop, "crop-symbolic", "Crop (C)"),
     (ToolKind::Text, "text-cursor-input-symbolic", "Text (T)"),
     (ToolKind::Ocr, "scan-text-symbolic", "OCR (O)"),
+    (
+        ToolKind::Decode,
+        "qr-code-symbolic",
+        "Decode QR/Barcode (Q)",
+    ),
 ];
 
 pub(in crate::app::editor_runtime) fn connect_tool_button_selection(
@@ -88,6 +105,26 @@ pub(super) fn enter_text_box_editing(
     *text_preedit_state.borrow_mut() = TextPreeditState::default();
 }
 
+/// Like [`enter_text_box_editing`], but keeps the callout tool armed so the
+/// next click can place another bubble.
+pub(super) fn enter_callout_editing(
+    editor_tool_switch_context: &EditorToolSwitchContext,
+    editor_tools: &RefCell<editor::EditorTools>,
+    selected_object_ids: &Rc<RefCell<Vec<u64>>>,
+    text_preedit_state: &RefCell<TextPreeditState>,
+    callout_id: u64,
+) {
+    set_single_selection(selected_object_ids, callout_id);
+    switch_editor_tool_with_text_policy(
+        editor_tool_switch_context,
+        ToolKind::Callout,
+        true,
+        TextInputActivation::ForceOn,
+    );
+    let _ = editor_tools.borrow_mut().focus_text_box(callout_id);
+    *text_preedit_state.borrow_mut() = TextPreeditState::default();
+}
+
 pub(super) fn arm_text_tool_for_selection(
     editor_tool_switch_context: &EditorToolSwitchContext,
     editor_tools: &RefCell<editor::EditorTools>,
@@ -282,6 +282,23 @@ fn pan_for_anchor_axis(
     (clamped_scroll - track.centered_scroll).round() as i32
 }
 
+/// Pan that puts `image_coord` (in base image pixels) in the middle of the
+/// viewport, as far as the scroll track allows.
+fn pan_to_center_axis(
+    viewport_extent: i32,
+    content_extent: i32,
+    base_extent: i32,
+    image_coord: f64,
+) -> i32 {
+    let anchor_ratio = image_coord / f64::from(base_extent.max(1));
+    pan_for_anchor_axis(
+        viewport_extent,
+        content_extent,
+        f64::from(viewport_extent.max(1)) / 2.0,
+        anchor_ratio,
+    )
+}
+
 pub(super) fn apply_editor_viewport_to_canvas(
     canvas: &DrawingArea,
     scrolled: &ScrolledWindow,
@@ -466,6 +483,28 @@ pub(super) fn set_editor_zoom_percent_and_refresh(
     refresh_editor_viewport_ui(runtime, viewport);
 }
 
+/// Scrolls without zooming so the image point lands in the viewport center.
+pub(super) fn center_editor_viewport_on_image_point(
+    viewport: &mut editor::EditorViewport,
+    runtime: &EditorViewportRuntime<'_>,
+    image_x: f64,
+    image_y: f64,
+) {
+    let (viewport_width, viewport_height) = viewport_extent(runtime.scrolled);
+    let (content_width, content_height) =
+        content_dimensions_for_viewport(viewport, runtime.base_width, runtime.base_height);
+    viewport.set_pan(
+        pan_to_center_axis(viewport_width, content_width, runtime.base_width, image_x),
+        pan_to_center_axis(
+            viewport_height,
+            content_height,
+            runtime.base_height,
+            image_y,
+        ),
+    );
+    apply_editor_viewport_and_refresh(viewport, runtime);
+}
+
 pub(super) fn set_editor_actual_size_and_refresh(
     viewport: &mut editor::EditorViewport,
     runtime: &EditorViewportRuntime<'_>,
@@ -476,7 +515,9 @@ pub(super) fn set_editor_actual_size_and_refresh(
 
 #[cfg(test)]
 mod tests {
-    use super::{axis_layout, fit_zoom_percent_for_extent, pan_for_anchor_axis};
+    use super::{
+        axis_layout, fit_zoom_percent_for_extent, pan_for_anchor_axis, pan_to_center_axis,
+    };
 
     #[test]
     fn fit_zoom_percent_for_extent_uses_smallest_axis_ratio() {
@@ -521,4 +562,12 @@ mod tests {
         assert_eq!(pan_for_anchor_axis(200, 100, 100.0, 0.5), 0);
         assert_eq!(pan_for_anchor_axis(200, 100, 150.0, 0.5), -50);
     }
+
+    #[test]
+    fn pan_to_center_axis_centers_image_point_within_scroll_track() {
+        // 200px image shown at 2x in a 100px viewport.
+        assert_eq!(pan_to_center_axis(100, 400, 200, 100.0), 0);
+        assert_eq!(pan_to_center_axis(100, 400, 200, 150.0), 100);
+        assert_eq!(pan_to_center_axis(100, 400, 200, 0.0), -200);
+    }
 }
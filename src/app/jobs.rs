@@ -0,0 +1,589 @@
+//! Background queue for heavy work such as OCR and exports.
+//!
+//! Jobs run one at a time on a single worker thread. That thread also owns
+//! the OCR engine, so jobs never wait for it to be handed back. Every job
+//! has a [`CancelToken`]: cancelled jobs that are still queued are skipped,
+//! and a running job stops at its next progress report. A request whose
+//! [`JobKey`] matches a queued or running job joins that job instead of
+//! starting another run.
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+
+use super::worker::ACTION_RESULT_POLL_INTERVAL;
+
+type JobId = u64;
+
+/// Shared flag a job polls to find out that it should stop.
+#[derive(Debug, Clone, Default)]
+pub(super) struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub(super) fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub(super) fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Identifies equivalent requests. Jobs scoped to a capture can be cancelled
+/// together when it goes away.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct JobKey {
+    capture_id: Option<String>,
+    task: String,
+}
+
+impl JobKey {
+    pub(super) fn for_capture(capture_id: &str, task: impl Into<String>) -> Self {
+        Self {
+            capture_id: Some(capture_id.to_string()),
+            task: task.into(),
+        }
+    }
+
+    pub(super) fn global(task: impl Into<String>) -> Self {
+        Self {
+            capture_id: None,
+            task: task.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct JobProgress {
+    /// Completed share from 0.0 to 1.0; 0.0 shows no percentage.
+    pub(super) fraction: f64,
+    pub(super) message: String,
+}
+
+impl JobProgress {
+    /// `"Recognizing text... 45%"`, or just the message before any progress.
+    pub(super) fn describe(&self) -> String {
+        let percent = (self.fraction.clamp(0.0, 1.0) * 100.0).round() as u32;
+        if percent == 0 {
+            format!("{}...", self.message)
+        } else {
+            format!("{}... {percent}%", self.message)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum JobOutcome<T> {
+    Finished(T),
+    Cancelled,
+}
+
+/// Refers to a submitted job; cancelling it cancels the run for every
+/// request that joined it.
+#[derive(Debug, Clone)]
+pub(super) struct JobHandle {
+    id: JobId,
+    token: CancelToken,
+}
+
+impl JobHandle {
+    pub(super) fn cancel(&self) {
+        self.token.cancel();
+    }
+}
+
+type JobOutput = Box<dyn Any + Send>;
+type JobWork = Box<dyn FnOnce(&mut JobContext<'_>) -> JobOutput + Send>;
+type ProgressListener = Rc<dyn Fn(&JobHandle, &JobProgress)>;
+type DoneListener = Box<dyn FnOnce(Option<&JobOutput>)>;
+
+/// What a job can use on the worker thread.
+pub(super) struct JobContext<'a> {
+    ocr_engine: &'a mut Option<crate::ocr::OcrEngine>,
+    token: &'a CancelToken,
+    report: &'a dyn Fn(JobProgress),
+}
+
+impl JobContext<'_> {
+    pub(super) fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    pub(super) fn report(&self, fraction: f64, message: impl Into<String>) {
+        (self.report)(JobProgress {
+            fraction,
+            message: message.into(),
+        });
+    }
+
+    /// Points the shared engine at `languages`, loading it on first use.
+    pub(super) fn warm_up_ocr(
+        &mut self,
+        languages: &[crate::ocr::OcrLanguage],
+    ) -> crate::ocr::OcrResult<()> {
+        let engine = super::ocr_support::resolve_or_init_engine(self.ocr_engine.take(), languages)?;
+        *self.ocr_engine = Some(engine);
+        Ok(())
+    }
+
+    /// Recognizes `image` with the shared engine, reporting each stage and
+    /// stopping with [`crate::ocr::OcrError::Cancelled`] once cancelled.
+    pub(super) fn recognize(
+        &mut self,
+        languages: &[crate::ocr::OcrLanguage],
+        image: &image::DynamicImage,
+    ) -> crate::ocr::OcrResult<crate::ocr::OcrDocument> {
+        if self.is_cancelled() {
+            return Err(crate::ocr::OcrError::Cancelled);
+        }
+        if self.ocr_engine.is_none() {
+            self.report(0.0, "Initializing OCR engine");
+        }
+        self.warm_up_ocr(languages)?;
+        let (token, report) = (self.token, self.report);
+        let Some(engine) = self.ocr_engine.as_ref() else {
+            return Err(crate::ocr::OcrError::EngineInit {
+                message: "engine unavailable".to_string(),
+            });
+        };
+        crate::ocr::recognize_document_with(engine, image, &mut |stage| {
+            report(JobProgress {
+                fraction: stage.fraction(),
+                message: stage.label().to_string(),
+            });
+            !token.is_cancelled()
+        })
+    }
+}
+
+struct JobEntry {
+    handle: JobHandle,
+    key: JobKey,
+    progress: Vec<ProgressListener>,
+    done: Vec<DoneListener>,
+}
+
+/// Submitted jobs in worker order; the first one is running.
+#[derive(Default)]
+struct JobBook {
+    next_id: JobId,
+    entries: Vec<JobEntry>,
+}
+
+impl JobBook {
+    /// The live job for `key`, if any.
+    fn find(&mut self, key: &JobKey) -> Option<&mut JobEntry> {
+        self.entries
+            .iter_mut()
+            .find(|entry| entry.key == *key && !entry.handle.token.is_cancelled())
+    }
+
+    fn insert(&mut self, key: JobKey) -> JobHandle {
+        self.next_id += 1;
+        let handle = JobHandle {
+            id: self.next_id,
+            token: CancelToken::default(),
+        };
+        self.entries.push(JobEntry {
+            handle: handle.clone(),
+            key,
+            progress: Vec::new(),
+            done: Vec::new(),
+        });
+        handle
+    }
+
+    fn get(&self, id: JobId) -> Option<&JobEntry> {
+        self.entries.iter().find(|entry| entry.handle.id == id)
+    }
+
+    fn take(&mut self, id: JobId) -> Option<JobEntry> {
+        let index = self
+            .entries
+            .iter()
+            .position(|entry| entry.handle.id == id)?;
+        Some(self.entries.remove(index))
+    }
+
+    fn jobs_ahead(&self, id: JobId) -> usize {
+        self.entries
+            .iter()
+            .take_while(|entry| entry.handle.id != id)
+            .count()
+    }
+
+    fn cancel_capture(&self, capture_id: &str) {
+        for entry in &self.entries {
+            if entry.key.capture_id.as_deref() == Some(capture_id) {
+                entry.handle.cancel();
+            }
+        }
+    }
+}
+
+struct WorkerCommand {
+    id: JobId,
+    token: CancelToken,
+    work: JobWork,
+}
+
+enum WorkerEvent {
+    Progress(JobId, JobProgress),
+    /// `None` when the job was cancelled or panicked.
+    Finished(JobId, Option<JobOutput>),
+}
+
+/// Main-thread handle to the queue; clones share it.
+#[derive(Clone)]
+pub(super) struct JobQueue {
+    inner: Rc<JobQueueInner>,
+}
+
+struct JobQueueInner {
+    commands: mpsc::Sender<WorkerCommand>,
+    events: mpsc::Receiver<WorkerEvent>,
+    book: RefCell<JobBook>,
+    polling: Cell<bool>,
+    ocr_engine_ready: Arc<AtomicBool>,
+}
+
+impl JobQueue {
+    pub(super) fn new() -> Self {
+        let (commands, command_receiver) = mpsc::channel();
+        let (event_sender, events) = mpsc::channel();
+        let ocr_engine_ready = Arc::new(AtomicBool::new(false));
+        {
+            let ocr_engine_ready = ocr_engine_ready.clone();
+            let spawned = std::thread::Builder::new()
+                .name("chalkak-jobs".to_string())
+                .spawn(move || run_worker(command_receiver, event_sender, &ocr_engine_ready));
+            if let Err(err) = spawned {
+                tracing::error!("failed to start background job thread: {err}");
+            }
+        }
+        Self {
+            inner: Rc::new(JobQueueInner {
+                commands,
+                events,
+                book: RefCell::new(JobBook::default()),
+                polling: Cell::new(false),
+                ocr_engine_ready,
+            }),
+        }
+    }
+
+    /// Queues `work`, or joins the live job with the same `key`. Callbacks
+    /// run on the main thread; `on_done` gets [`JobOutcome::Cancelled`] when
+    /// the job was cancelled or failed to run.
+    pub(super) fn submit<T, W>(
+        &self,
+        key: JobKey,
+        work: W,
+        on_progress: impl Fn(&JobHandle, &JobProgress) + 'static,
+        on_done: impl FnOnce(JobOutcome<T>) + 'static,
+    ) -> JobHandle
+    where
+        T: Clone + Send + 'static,
+        W: FnOnce(&mut JobContext<'_>) -> T + Send + 'static,
+    {
+        let done: DoneListener =
+            Box::new(
+                move |output| match output.and_then(|output| output.downcast_ref::<T>()) {
+                    Some(value) => on_done(JobOutcome::Finished(value.clone())),
+                    None => on_done(JobOutcome::Cancelled),
+                },
+            );
+        let progress: ProgressListener = Rc::new(on_progress);
+
+        let mut book = self.inner.book.borrow_mut();
+        if let Some(entry) = book.find(&key) {
+            entry.progress.push(progress);
+            entry.done.push(done);
+            return entry.handle.clone();
+        }
+        let handle = book.insert(key);
+        if let Some(entry) = book.entries.last_mut() {
+            entry.progress.push(progress);
+            entry.done.push(done);
+        }
+        drop(book);
+
+        let command = WorkerCommand {
+            id: handle.id,
+            token: handle.token.clone(),
+            work: Box::new(move |context| Box::new(work(context)) as JobOutput),
+        };
+        if self.inner.commands.send(command).is_err() {
+            tracing::error!("background job thread is gone; dropping job {}", handle.id);
+            if let Some(entry) = self.inner.book.borrow_mut().take(handle.id) {
+                entry.done.into_iter().for_each(|done| done(None));
+            }
+            return handle;
+        }
+        self.ensure_polling();
+        handle
+    }
+
+    /// Number of jobs that run before `handle`, including a running one.
+    pub(super) fn jobs_ahead(&self, handle: &JobHandle) -> usize {
+        self.inner.book.borrow().jobs_ahead(handle.id)
+    }
+
+    /// Whether the worker holds a loaded OCR engine.
+    pub(super) fn ocr_engine_ready(&self) -> bool {
+        self.inner.ocr_engine_ready.load(Ordering::Relaxed)
+    }
+
+    /// Whether a queued or running job that is not cancelled uses `key`.
+    pub(super) fn is_pending(&self, key: &JobKey) -> bool {
+        self.inner.book.borrow_mut().find(key).is_some()
+    }
+
+    /// Cancels every queued or running job scoped to `capture_id`.
+    pub(super) fn cancel_capture(&self, capture_id: &str) {
+        self.inner.book.borrow().cancel_capture(capture_id);
+    }
+
+    fn ensure_polling(&self) {
+        if self.inner.polling.replace(true) {
+            return;
+        }
+        let inner = Rc::downgrade(&self.inner);
+        gtk4::glib::timeout_add_local(ACTION_RESULT_POLL_INTERVAL, move || {
+            let Some(inner) = inner.upgrade() else {
+                return gtk4::glib::ControlFlow::Break;
+            };
+            if (JobQueue { inner }).dispatch_events() {
+                gtk4::glib::ControlFlow::Continue
+            } else {
+                gtk4::glib::ControlFlow::Break
+            }
+        });
+    }
+
+    /// Delivers pending worker events; returns whether jobs remain.
+    fn dispatch_events(&self) -> bool {
+        loop {
+            match self.inner.events.try_recv() {
+                Ok(WorkerEvent::Progress(id, progress)) => {
+                    let listeners = self.inner.book.borrow().get(id).and_then(|entry| {
+                        (!entry.handle.token.is_cancelled())
+                            .then(|| (entry.handle.clone(), entry.progress.clone()))
+                    });
+                    if let Some((handle, listeners)) = listeners {
+                        listeners
+                            .iter()
+                            .for_each(|listener| listener(&handle, &progress));
+                    }
+                }
+                Ok(WorkerEvent::Finished(id, output)) => {
+                    let entry = self.inner.book.borrow_mut().take(id);
+                    if let Some(entry) = entry {
+                        entry
+                            .done
+                            .into_iter()
+                            .for_each(|done| done(output.as_ref()));
+                    }
+                }
+                Err(mpsc::TryRecvError::Empty) => break,
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    let entries = std::mem::take(&mut self.inner.book.borrow_mut().entries);
+                    for entry in entries {
+                        entry.done.into_iter().for_each(|done| done(None));
+                    }
+                    break;
+                }
+            }
+        }
+        let busy = !self.inner.book.borrow().entries.is_empty();
+        self.inner.polling.set(busy);
+        busy
+    }
+}
+
+fn run_worker(
+    commands: mpsc::Receiver<WorkerCommand>,
+    events: mpsc::Sender<WorkerEvent>,
+    ocr_engine_ready: &AtomicBool,
+) {
+    let mut ocr_engine = None;
+    for WorkerCommand { id, token, work } in commands {
+        let output = if token.is_cancelled() {
+            None
+        } else {
+            let progress_events = events.clone();
+            let report = move |progress| {
+                let _ = progress_events.send(WorkerEvent::Progress(id, progress));
+            };
+            let mut context = JobContext {
+                ocr_engine: &mut ocr_engine,
+                token: &token,
+                report: &report,
+            };
+            match catch_unwind(AssertUnwindSafe(|| work(&mut context))) {
+                Ok(output) => (!token.is_cancelled()).then_some(output),
+                Err(_) => {
+                    tracing::error!("background job {id} panicked");
+                    ocr_engine = None;
+                    None
+                }
+            }
+        };
+        ocr_engine_ready.store(ocr_engine.is_some(), Ordering::Relaxed);
+        if events.send(WorkerEvent::Finished(id, output)).is_err() {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(commands: Vec<WorkerCommand>) -> Vec<WorkerEvent> {
+        let (command_sender, command_receiver) = mpsc::channel();
+        let (event_sender, event_receiver) = mpsc::channel();
+        for command in commands {
+            command_sender.send(command).unwrap();
+        }
+        drop(command_sender);
+        run_worker(command_receiver, event_sender, &AtomicBool::new(false));
+        event_receiver.try_iter().collect()
+    }
+
+    fn command(id: JobId, token: &CancelToken, work: JobWork) -> WorkerCommand {
+        WorkerCommand {
+            id,
+            token: token.clone(),
+            work,
+        }
+    }
+
+    fn finished_value(event: &WorkerEvent) -> Option<(JobId, Option<u32>)> {
+        match event {
+            WorkerEvent::Finished(id, output) => Some((
+                *id,
+                output
+                    .as_ref()
+                    .and_then(|output| output.downcast_ref::<u32>().copied()),
+            )),
+            WorkerEvent::Progress(..) => None,
+        }
+    }
+
+    #[test]
+    fn worker_runs_jobs_in_order_and_forwards_progress() {
+        let token = CancelToken::default();
+        let events = run(vec![
+            command(
+                1,
+                &token,
+                Box::new(|context| {
+                    context.report(0.5, "Halfway");
+                    Box::new(7_u32)
+                }),
+            ),
+            command(2, &token, Box::new(|_| Box::new(9_u32))),
+        ]);
+
+        assert_eq!(events.len(), 3);
+        match &events[0] {
+            WorkerEvent::Progress(1, progress) => assert_eq!(progress.describe(), "Halfway... 50%"),
+            _ => panic!("expected progress for job 1"),
+        }
+        assert_eq!(finished_value(&events[1]), Some((1, Some(7))));
+        assert_eq!(finished_value(&events[2]), Some((2, Some(9))));
+    }
+
+    #[test]
+    fn worker_skips_cancelled_jobs_and_drops_results_cancelled_mid_run() {
+        let skipped = CancelToken::default();
+        skipped.cancel();
+        let mid_run = CancelToken::default();
+        let events = run(vec![
+            command(
+                1,
+                &skipped,
+                Box::new(|_| panic!("cancelled job must not run")),
+            ),
+            command(
+                2,
+                &mid_run,
+                Box::new({
+                    let mid_run = mid_run.clone();
+                    move |context| {
+                        mid_run.cancel();
+                        assert!(context.is_cancelled());
+                        Box::new(1_u32)
+                    }
+                }),
+            ),
+        ]);
+
+        let finished = events.iter().filter_map(finished_value).collect::<Vec<_>>();
+        assert_eq!(finished, vec![(1, None), (2, None)]);
+    }
+
+    #[test]
+    fn worker_survives_a_panicking_job() {
+        let token = CancelToken::default();
+        let events = run(vec![
+            command(1, &token, Box::new(|_| panic!("boom"))),
+            command(2, &token, Box::new(|_| Box::new(3_u32))),
+        ]);
+
+        let finished = events.iter().filter_map(finished_value).collect::<Vec<_>>();
+        assert_eq!(finished, vec![(1, None), (2, Some(3))]);
+    }
+
+    #[test]
+    fn book_finds_live_jobs_by_key_and_counts_jobs_ahead() {
+        let mut book = JobBook::default();
+        let full = JobKey::for_capture("a", "ocr");
+        let first = book.insert(full.clone());
+        let second = book.insert(JobKey::for_capture("b", "ocr"));
+
+        assert_eq!(
+            book.find(&full).map(|entry| entry.handle.id),
+            Some(first.id)
+        );
+        assert_eq!(book.jobs_ahead(first.id), 0);
+        assert_eq!(book.jobs_ahead(second.id), 1);
+
+        first.cancel();
+        assert!(book.find(&full).is_none());
+        assert!(book.take(first.id).is_some());
+        assert_eq!(book.jobs_ahead(second.id), 0);
+    }
+
+    #[test]
+    fn book_cancels_only_jobs_of_the_capture() {
+        let mut book = JobBook::default();
+        let region = book.insert(JobKey::for_capture("a", "ocr-region"));
+        let full = book.insert(JobKey::for_capture("a", "ocr"));
+        let other = book.insert(JobKey::for_capture("b", "ocr"));
+        let warm_up = book.insert(JobKey::global("ocr-warm-up"));
+
+        book.cancel_capture("a");
+
+        assert!(region.token.is_cancelled());
+        assert!(full.token.is_cancelled());
+        assert!(!other.token.is_cancelled());
+        assert!(!warm_up.token.is_cancelled());
+    }
+
+    #[test]
+    fn job_progress_describe_hides_zero_percent() {
+        let progress = |fraction| JobProgress {
+            fraction,
+            message: "Recognizing text".to_string(),
+        };
+
+        assert_eq!(progress(0.0).describe(), "Recognizing text...");
+        assert_eq!(progress(0.456).describe(), "Recognizing text... 46%");
+        assert_eq!(progress(1.2).describe(), "Recognizing text... 100%");
+    }
+}
@@ -1,11 +1,12 @@
 use std::rc::Rc;
 
 use crate::capture;
+use crate::clipboard::CopyProfile;
 use crate::preview::PreviewAction;
 use crate::state::AppState;
-use crate::ui::StyleTokens;
+use crate::ui::{icon_menu_button, StyleTokens};
 use gtk4::prelude::*;
-use gtk4::{Align, Box as GtkBox, Button, Frame, Label, Orientation, ScrolledWindow};
+use gtk4::{Align, Box as GtkBox, Button, Frame, Label, MenuButton, Orientation, ScrolledWindow};
 
 use super::launchpad_actions::LaunchpadActionExecutor;
 
@@ -13,6 +14,7 @@ use super::launchpad_actions::LaunchpadActionExecutor;
 pub(super) struct LaunchpadUi {
     pub(super) root: GtkBox,
     pub(super) toast_label: Label,
+    pub(super) toast_action_button: Button,
     pub(super) state_label: Label,
     pub(super) status_label: Label,
     pub(super) active_capture_label: Label,
@@ -28,8 +30,15 @@ pub(super) struct LaunchpadUi {
     pub(super) close_editor_button: Button,
     pub(super) save_button: Button,
     pub(super) copy_button: Button,
+    pub(super) copy_as_button: MenuButton,
+    pub(super) copy_as_buttons: Vec<(CopyProfile, Button)>,
     pub(super) ocr_button: Button,
+    pub(super) decode_button: Button,
     pub(super) delete_button: Button,
+    pub(super) recovery_panel: Frame,
+    pub(super) recovery_label: Label,
+    pub(super) restore_recovery_button: Button,
+    pub(super) discard_recovery_button: Button,
 }
 
 impl LaunchpadUi {
@@ -66,16 +75,26 @@ impl LaunchpadUi {
             .set_sensitive(matches!(state, AppState::Preview) && has_capture);
         self.copy_button
             .set_sensitive(matches!(state, AppState::Preview) && has_capture);
+        self.copy_as_button
+            .set_sensitive(matches!(state, AppState::Preview) && has_capture);
         self.ocr_button
             .set_sensitive(ocr_available && matches!(state, AppState::Preview) && has_capture);
         if !ocr_available {
             self.ocr_button
                 .set_tooltip_text(Some("OCR models not installed"));
         }
+        self.decode_button
+            .set_sensitive(matches!(state, AppState::Preview) && has_capture);
         self.delete_button
             .set_sensitive(matches!(state, AppState::Preview) && has_capture);
     }
 
+    pub(super) fn set_recoverable_session_count(&self, count: usize) {
+        self.recovery_label
+            .set_text(&format_recoverable_sessions(count));
+        self.recovery_panel.set_visible(count > 0);
+    }
+
     pub(super) fn set_status_text(&self, message: &str) {
         self.status_label.set_text(message);
     }
@@ -136,6 +155,13 @@ pub(super) fn format_capture_ids_for_display(ids: &[String]) -> String {
     format!("IDs:\n{id_lines}")
 }
 
+pub(super) fn format_recoverable_sessions(count: usize) -> String {
+    match count {
+        1 => "1 unsaved editor session was left behind by a previous run.".to_string(),
+        _ => format!("{count} unsaved editor sessions were left behind by a previous run."),
+    }
+}
+
 pub(super) struct LaunchpadSettingsInfo {
     pub(super) theme_label: String,
     pub(super) ocr_language_label: String,
@@ -161,6 +187,13 @@ pub(super) fn build_launchpad_ui(
     toast_label.add_css_class("toast-badge");
     toast_label.set_halign(Align::Start);
     toast_label.set_visible(false);
+    let toast_action_button = Button::with_label("");
+    toast_action_button.add_css_class("toast-action-button");
+    toast_action_button.set_valign(Align::Center);
+    toast_action_button.set_visible(false);
+    let toast_row = GtkBox::new(Orientation::Horizontal, style_tokens.spacing_8);
+    toast_row.append(&toast_label);
+    toast_row.append(&toast_action_button);
 
     // ── Header row: title + version badge ──
     let title_label = Label::new(Some("ChalKak Launchpad"));
@@ -264,12 +297,25 @@ pub(super) fn build_launchpad_ui(
     save_button.set_hexpand(true);
     let copy_button = Button::with_label("Copy");
     copy_button.set_hexpand(true);
+    let copy_as_labels = CopyProfile::ALL.map(CopyProfile::label);
+    let (copy_as_button, copy_as_items) = icon_menu_button(
+        "chevron-down-symbolic",
+        "Copy as…",
+        style_tokens.control_size as i32,
+        &[],
+        &copy_as_labels,
+    );
+    let copy_as_buttons = CopyProfile::ALL
+        .into_iter()
+        .zip(copy_as_items)
+        .collect::<Vec<_>>();
 
     let actions_row1 = GtkBox::new(Orientation::Horizontal, style_tokens.spacing_8);
     actions_row1.append(&open_preview_button);
     actions_row1.append(&open_editor_button);
     actions_row1.append(&save_button);
     actions_row1.append(&copy_button);
+    actions_row1.append(&copy_as_button);
 
     let close_preview_button = Button::with_label("Close Preview");
     close_preview_button.set_hexpand(true);
@@ -277,6 +323,8 @@ pub(super) fn build_launchpad_ui(
     close_editor_button.set_hexpand(true);
     let ocr_button = Button::with_label("OCR");
     ocr_button.set_hexpand(true);
+    let decode_button = Button::with_label("Decode");
+    decode_button.set_hexpand(true);
     let delete_button = Button::with_label("Delete");
     delete_button.set_hexpand(true);
     delete_button.add_css_class("launchpad-danger-button");
@@ -285,6 +333,7 @@ pub(super) fn build_launchpad_ui(
     actions_row2.append(&close_preview_button);
     actions_row2.append(&close_editor_button);
     actions_row2.append(&ocr_button);
+    actions_row2.append(&decode_button);
     actions_row2.append(&delete_button);
 
     let actions_content = GtkBox::new(Orientation::Vertical, style_tokens.spacing_8);
@@ -292,8 +341,29 @@ pub(super) fn build_launchpad_ui(
     actions_content.append(&actions_row2);
     let actions_panel = launchpad_panel(style_tokens, "Actions", &actions_content);
 
+    // ── Recovery panel (hidden unless a previous run left unsaved edits) ──
+    let recovery_label = Label::new(None);
+    recovery_label.set_halign(Align::Start);
+    recovery_label.set_xalign(0.0);
+    recovery_label.set_wrap(true);
+    let restore_recovery_button = Button::with_label("Restore");
+    restore_recovery_button.add_css_class("launchpad-primary-button");
+    restore_recovery_button.set_hexpand(true);
+    let discard_recovery_button = Button::with_label("Discard");
+    discard_recovery_button.add_css_class("launchpad-danger-button");
+    discard_recovery_button.set_hexpand(true);
+    let recovery_row = GtkBox::new(Orientation::Horizontal, style_tokens.spacing_8);
+    recovery_row.append(&restore_recovery_button);
+    recovery_row.append(&discard_recovery_button);
+    let recovery_content = GtkBox::new(Orientation::Vertical, style_tokens.spacing_8);
+    recovery_content.append(&recovery_label);
+    recovery_content.append(&recovery_row);
+    let recovery_panel = launchpad_panel(style_tokens, "Recovery", &recovery_content);
+    recovery_panel.set_visible(false);
+
     // ── Scrollable content area ──
     let launchpad_content = GtkBox::new(Orientation::Vertical, style_tokens.spacing_12);
+    launchpad_content.append(&recovery_panel);
     launchpad_content.append(&capture_panel);
     launchpad_content.append(&info_row);
     launchpad_content.append(&actions_panel);
@@ -316,7 +386,7 @@ pub(super) fn build_launchpad_ui(
     root.append(&subtitle_label);
     root.append(&scrolled_window);
     root.append(&hint_label);
-    root.append(&toast_label);
+    root.append(&toast_row);
 
     if !show_launchpad {
         header_row.set_visible(false);
@@ -328,6 +398,7 @@ pub(super) fn build_launchpad_ui(
     LaunchpadUi {
         root,
         toast_label,
+        toast_action_button,
         state_label,
         status_label,
         active_capture_label,
@@ -343,8 +414,15 @@ pub(super) fn build_launchpad_ui(
         close_editor_button,
         save_button,
         copy_button,
+        copy_as_button,
+        copy_as_buttons,
         ocr_button,
+        decode_button,
         delete_button,
+        recovery_panel,
+        recovery_label,
+        restore_recovery_button,
+        discard_recovery_button,
     }
 }
 
@@ -470,6 +548,17 @@ pub(super) fn connect_launchpad_default_buttons<R: Fn() + 'static>(
             });
         });
     }
+    for (profile, button) in &launchpad.copy_as_buttons {
+        let launchpad_actions = launchpad_actions.clone();
+        let render = render.clone();
+        let action = PreviewAction::CopyAs(*profile);
+        button.connect_clicked(move |_| {
+            let render = render.clone();
+            launchpad_actions.run_preview_action_async(action, move || {
+                (render.as_ref())();
+            });
+        });
+    }
     connect_launchpad_button(
         &launchpad.ocr_button,
         launchpad_actions,
@@ -478,6 +567,14 @@ pub(super) fn connect_launchpad_default_buttons<R: Fn() + 'static>(
             actions.run_preview_ocr_action();
         },
     );
+    connect_launchpad_button(
+        &launchpad.decode_button,
+        launchpad_actions,
+        render,
+        |actions| {
+            actions.run_preview_decode_action();
+        },
+    );
     {
         let launchpad_actions = launchpad_actions.clone();
         let render = render.clone();
@@ -488,4 +585,28 @@ pub(super) fn connect_launchpad_default_buttons<R: Fn() + 'static>(
             });
         });
     }
+    {
+        let launchpad_ui = launchpad.clone();
+        connect_launchpad_button(
+            &launchpad.restore_recovery_button,
+            launchpad_actions,
+            render,
+            move |actions| {
+                actions.restore_recovered_sessions();
+                launchpad_ui.set_recoverable_session_count(0);
+            },
+        );
+    }
+    {
+        let launchpad_ui = launchpad.clone();
+        connect_launchpad_button(
+            &launchpad.discard_recovery_button,
+            launchpad_actions,
+            render,
+            move |actions| {
+                actions.discard_recovered_sessions();
+                launchpad_ui.set_recoverable_session_count(0);
+            },
+        );
+    }
 }
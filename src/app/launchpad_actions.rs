@@ -3,15 +3,19 @@ use std::collections::HashMap;
 use std::rc::Rc;
 
 use crate::capture;
-use crate::clipboard::WlCopyBackend;
+use crate::clipboard::{CopyProfile, WlCopyBackend};
 use crate::preview::{PreviewAction, PreviewActionError, PreviewEvent};
 use crate::state::{AppEvent, AppState, StateMachine};
-use crate::storage::StorageService;
+use crate::storage::{StorageService, TrashedCapture};
 use gtk4::prelude::*;
 
+use super::barcode_results::{barcode_decode_key, queue_barcode_decode, BarcodeInput};
+use super::editor_recovery::EditorRecoveryRuntime;
+use super::jobs::{JobOutcome, JobQueue};
+use super::ocr_support::SharedOcrLanguages;
 use super::runtime_support::{
-    close_preview_window_for_capture, show_toast_for_capture, PreviewWindowRuntime, RuntimeSession,
-    ToastRuntime,
+    close_preview_window_for_capture, show_action_toast_for_capture, show_toast_for_capture,
+    PreviewWindowRuntime, RuntimeSession, ToastRuntime,
 };
 use super::window_state::RuntimeWindowState;
 use super::worker::spawn_worker_action;
@@ -19,8 +23,13 @@ use super::worker::spawn_worker_action;
 pub(super) type SharedMachine = Rc<RefCell<StateMachine>>;
 pub(super) type SharedRuntimeSession = Rc<RefCell<RuntimeSession>>;
 pub(super) type SharedStatusLog = Rc<RefCell<String>>;
+/// Number of deleted captures whose Undo is still offered. The headless
+/// startup hold stays in place while it is non-zero.
+pub(super) type SharedPendingDeleteUndos = Rc<Cell<usize>>;
 pub(super) type SharedCaptureSelection = Rc<RefCell<Option<String>>>;
 
+const DELETE_UNDO_TOAST_MIN_MS: u32 = 6_000;
+
 #[derive(Clone)]
 pub(super) struct LaunchpadActionExecutor {
     runtime_session: SharedRuntimeSession,
@@ -32,9 +41,11 @@ pub(super) struct LaunchpadActionExecutor {
     runtime_window_state: Rc<RefCell<RuntimeWindowState>>,
     fallback_toast: ToastRuntime,
     toast_duration_ms: u32,
-    ocr_engine: Rc<RefCell<Option<crate::ocr::OcrEngine>>>,
-    ocr_language: crate::ocr::OcrLanguage,
-    ocr_in_progress: Rc<Cell<bool>>,
+    copy_profile: CopyProfile,
+    ocr_jobs: JobQueue,
+    ocr_languages: SharedOcrLanguages,
+    editor_recovery: EditorRecoveryRuntime,
+    pending_delete_undos: SharedPendingDeleteUndos,
 }
 
 #[derive(Debug, Clone)]
@@ -64,9 +75,11 @@ impl LaunchpadActionExecutor {
         runtime_window_state: Rc<RefCell<RuntimeWindowState>>,
         fallback_toast: ToastRuntime,
         toast_duration_ms: u32,
-        ocr_engine: Rc<RefCell<Option<crate::ocr::OcrEngine>>>,
-        ocr_language: crate::ocr::OcrLanguage,
-        ocr_in_progress: Rc<Cell<bool>>,
+        copy_profile: CopyProfile,
+        ocr_jobs: JobQueue,
+        ocr_languages: SharedOcrLanguages,
+        editor_recovery: EditorRecoveryRuntime,
+        pending_delete_undos: SharedPendingDeleteUndos,
     ) -> Self {
         Self {
             runtime_session,
@@ -78,9 +91,11 @@ impl LaunchpadActionExecutor {
             runtime_window_state,
             fallback_toast,
             toast_duration_ms,
-            ocr_engine,
-            ocr_language,
-            ocr_in_progress,
+            copy_profile,
+            ocr_jobs,
+            ocr_languages,
+            editor_recovery,
+            pending_delete_undos,
         }
     }
 
@@ -222,6 +237,7 @@ impl LaunchpadActionExecutor {
         self.runtime_session
             .borrow_mut()
             .remove_capture(&capture_id_to_close);
+        self.ocr_jobs.cancel_capture(&capture_id_to_close);
         if let Some(service) = self.storage_service.as_ref() {
             if let Err(err) = service.discard_session_artifacts(&capture_id_to_close) {
                 tracing::warn!(
@@ -275,6 +291,91 @@ impl LaunchpadActionExecutor {
         }
     }
 
+    pub(super) fn restore_recovered_sessions(&self) {
+        let state = self.machine.borrow().state();
+        if !matches!(state, AppState::Idle | AppState::Preview) {
+            set_status(
+                &self.status_log,
+                format!("cannot restore editor sessions from state {state:?}"),
+            );
+            return;
+        }
+        let Some(service) = self.storage_service.as_ref() else {
+            set_status(&self.status_log, "storage service unavailable");
+            return;
+        };
+
+        let mut restored_ids = Vec::new();
+        for session in self.editor_recovery.recoverable_sessions() {
+            let capture_id = session.capture_id().to_string();
+            let temp_path = match service.temp_path_for_capture(&capture_id) {
+                Ok(path) => path,
+                Err(err) => {
+                    tracing::warn!(capture_id, ?err, "failed to resolve recovery temp path");
+                    continue;
+                }
+            };
+            match self
+                .editor_recovery
+                .store()
+                .restore_source_image(&session, &temp_path)
+            {
+                Ok(artifact) => {
+                    self.editor_recovery
+                        .stash_recovered_objects(&capture_id, session.snapshot.objects);
+                    self.runtime_session.borrow_mut().push_capture(artifact);
+                    restored_ids.push(capture_id);
+                }
+                Err(err) => {
+                    tracing::warn!(capture_id, ?err, "failed to restore editor session");
+                }
+            }
+        }
+
+        let Some(latest_capture_id) = restored_ids.last().cloned() else {
+            set_status(&self.status_log, "no editor sessions could be restored");
+            crate::notification::send("No editor sessions could be restored");
+            return;
+        };
+        if matches!(state, AppState::Idle)
+            && !transition_with_status(
+                &self.machine,
+                &self.status_log,
+                AppEvent::OpenPreview,
+                "preview transition blocked for current state",
+                "cannot open restored capture",
+            )
+        {
+            return;
+        }
+        if transition_with_status(
+            &self.machine,
+            &self.status_log,
+            AppEvent::OpenEditor,
+            "editor transition blocked for current state",
+            "cannot open restored editor session",
+        ) {
+            set_status(
+                &self.status_log,
+                format!(
+                    "restored {} editor session(s); editor opened for {latest_capture_id}",
+                    restored_ids.len()
+                ),
+            );
+        }
+    }
+
+    pub(super) fn discard_recovered_sessions(&self) {
+        let sessions = self.editor_recovery.recoverable_sessions();
+        for session in &sessions {
+            self.editor_recovery.discard_capture(session.capture_id());
+        }
+        set_status(
+            &self.status_log,
+            format!("discarded {} recovered editor session(s)", sessions.len()),
+        );
+    }
+
     pub(super) fn run_preview_action_async<R>(&self, action: PreviewAction, on_complete: R)
     where
         R: Fn() + 'static,
@@ -296,7 +397,7 @@ impl LaunchpadActionExecutor {
                 &prepared.active_capture,
                 prepared.action,
                 &prepared.storage_service,
-                &WlCopyBackend,
+                &WlCopyBackend::with_profile(self.copy_profile),
             );
             let _ = apply_preview_action_result(
                 prepared.action,
@@ -314,6 +415,7 @@ impl LaunchpadActionExecutor {
         let worker_capture = prepared.active_capture.clone();
         let worker_action = prepared.action;
         let worker_storage = prepared.storage_service.clone();
+        let worker_copy_profile = self.copy_profile;
 
         let executor = self.clone();
         let mut on_complete = Some(on_complete);
@@ -323,7 +425,7 @@ impl LaunchpadActionExecutor {
                     &worker_capture,
                     worker_action,
                     &worker_storage,
-                    &WlCopyBackend,
+                    &WlCopyBackend::with_profile(worker_copy_profile),
                 )
             },
             move |result| {
@@ -363,14 +465,15 @@ impl LaunchpadActionExecutor {
         let worker_storage = prepared.storage_service.clone();
 
         let executor = self.clone();
-        let mut on_complete = Some(on_complete);
+        let on_complete: Rc<dyn Fn()> = Rc::new(on_complete);
+        let mut pending_on_complete = Some(on_complete.clone());
         spawn_worker_action(
             move || {
                 super::actions::execute_preview_action(
                     &worker_capture,
                     PreviewAction::Delete,
                     &worker_storage,
-                    &WlCopyBackend,
+                    &WlCopyBackend::default(),
                 )
             },
             move |result| {
@@ -383,21 +486,164 @@ impl LaunchpadActionExecutor {
                     &executor.fallback_toast,
                     executor.toast_duration_ms,
                 );
-                if let Some(PreviewEvent::Delete { capture_id }) = event {
+                if let Some(PreviewEvent::Delete {
+                    capture_id,
+                    trashed,
+                }) = event
+                {
                     executor.apply_deleted_capture(&capture_id);
+                    executor.offer_delete_undo(
+                        prepared.active_capture.clone(),
+                        trashed,
+                        on_complete.clone(),
+                    );
                 }
-                if let Some(on_complete) = on_complete.take() {
+                if let Some(on_complete) = pending_on_complete.take() {
                     on_complete();
                 }
             },
         );
     }
 
-    pub(super) fn run_preview_ocr_action(&self) {
-        if self.ocr_in_progress.get() {
-            set_status(&self.status_log, "OCR already in progress");
+    /// Offers Undo for a deleted capture. The toast goes to a visible preview
+    /// or the launchpad; in headless mode neither is on screen, so a desktop
+    /// notification carries the action instead. `render` runs once the offer
+    /// settles, which lets the headless hold release afterwards: when the
+    /// toast expires, or when the notification closes, since daemons may
+    /// keep it open past its timeout.
+    fn offer_delete_undo(
+        &self,
+        artifact: capture::CaptureArtifact,
+        trashed: TrashedCapture,
+        render: Rc<dyn Fn()>,
+    ) {
+        let capture_id = artifact.capture_id.clone();
+        let duration_ms = self.toast_duration_ms.max(DELETE_UNDO_TOAST_MIN_MS);
+        self.pending_delete_undos
+            .set(self.pending_delete_undos.get().saturating_add(1));
+        let settled = Rc::new(Cell::new(false));
+        let settle: Rc<dyn Fn()> = {
+            let pending_delete_undos = self.pending_delete_undos.clone();
+            Rc::new(move || {
+                if !settled.replace(true) {
+                    pending_delete_undos.set(pending_delete_undos.get().saturating_sub(1));
+                    render();
+                }
+            })
+        };
+        let undo_started = Rc::new(Cell::new(false));
+        let undo = {
+            let executor = self.clone();
+            let undo_started = undo_started.clone();
+            let settle = settle.clone();
+            move || {
+                if undo_started.replace(true) {
+                    return;
+                }
+                executor.restore_deleted_capture(artifact.clone(), trashed.clone(), settle.clone());
+            }
+        };
+        let toast_target = self
+            .runtime_session
+            .borrow()
+            .active_capture()
+            .map(|active| active.capture_id.clone());
+        let target_has_preview = toast_target
+            .as_deref()
+            .is_some_and(|id| self.preview_windows.borrow().contains_key(id));
+        let message = format!("Moved {capture_id} to Trash");
+        if target_has_preview || self.fallback_toast.is_on_screen() {
+            // Once Undo starts, the restore settles the offer when it finishes.
+            gtk4::glib::timeout_add_local_once(
+                std::time::Duration::from_millis(u64::from(duration_ms)),
+                move || {
+                    if !undo_started.get() {
+                        settle();
+                    }
+                },
+            );
+            show_action_toast_for_capture(
+                &self.preview_windows,
+                toast_target.as_deref(),
+                &self.fallback_toast,
+                message,
+                "Undo",
+                duration_ms,
+                undo,
+            );
+            return;
+        }
+        spawn_worker_action(
+            move || crate::notification::send_with_action_blocking(message, "Undo", duration_ms),
+            move |chosen| {
+                if chosen {
+                    undo();
+                } else {
+                    settle();
+                }
+            },
+        );
+    }
+
+    fn restore_deleted_capture(
+        &self,
+        artifact: capture::CaptureArtifact,
+        trashed: TrashedCapture,
+        on_restored: Rc<dyn Fn()>,
+    ) {
+        let Some(service) = self.storage_service.as_ref() else {
+            set_status(&self.status_log, "storage service unavailable");
+            on_restored();
+            return;
+        };
+        let service = service.clone();
+        let capture_id = artifact.capture_id.clone();
+        set_status(&self.status_log, format!("restoring capture {capture_id}"));
+
+        let executor = self.clone();
+        let mut artifact = Some(artifact);
+        spawn_worker_action(
+            move || service.restore_trashed_capture(&trashed),
+            move |result| {
+                match result {
+                    Ok(()) => {
+                        if let Some(artifact) = artifact.take() {
+                            executor.apply_restored_capture(artifact);
+                        }
+                    }
+                    Err(err) => {
+                        set_status(
+                            &executor.status_log,
+                            format!("undo delete failed for {capture_id}: {err}"),
+                        );
+                        crate::notification::send(format!("Undo delete failed: {err}"));
+                    }
+                }
+                on_restored();
+            },
+        );
+    }
+
+    fn apply_restored_capture(&self, artifact: capture::CaptureArtifact) {
+        let capture_id = artifact.capture_id.clone();
+        self.runtime_session.borrow_mut().push_capture(artifact);
+        let state = self.machine.borrow().state();
+        if matches!(state, AppState::Idle)
+            && !transition_with_status(
+                &self.machine,
+                &self.status_log,
+                AppEvent::OpenPreview,
+                "preview transition blocked for current state",
+                "cannot reopen preview",
+            )
+        {
             return;
         }
+        set_status(&self.status_log, format!("restored capture {capture_id}"));
+    }
+
+    pub(super) fn run_preview_ocr_action(&self) {
+        use super::ocr_support::{full_image_ocr_key, queue_ocr, OcrInput, OcrJobFeedback};
 
         let active_capture = match consume_and_resolve_active_capture(
             &self.runtime_session,
@@ -414,47 +660,44 @@ impl LaunchpadActionExecutor {
             return;
         }
 
-        let engine = self.ocr_engine.borrow_mut().take();
-        let ocr_language = self.ocr_language;
-        let temp_path = active_capture.temp_path.clone();
-
-        self.ocr_in_progress.set(true);
-        set_status(
-            &self.status_log,
-            super::ocr_support::ocr_processing_status(engine.is_some()),
-        );
-        set_preview_cursor(
-            &self.preview_windows,
-            &active_capture.capture_id,
-            Some("progress"),
+        let capture_id = active_capture.capture_id.clone();
+        let key = full_image_ocr_key(&capture_id, &self.ocr_languages.borrow());
+        let toast = self.preview_windows.borrow().get(&capture_id).map_or_else(
+            || self.fallback_toast.clone(),
+            |runtime| runtime.toast.clone(),
         );
+        let feedback = OcrJobFeedback {
+            status_log: self.status_log.clone(),
+            toast: Some(toast),
+            toast_duration_ms: self.toast_duration_ms,
+        };
+        set_preview_cursor(&self.preview_windows, &capture_id, Some("progress"));
 
         let executor = self.clone();
-        let capture_id = active_capture.capture_id.clone();
-        spawn_worker_action(
-            move || {
-                let engine = match super::ocr_support::resolve_or_init_engine(engine, ocr_language)
-                {
-                    Ok(e) => e,
-                    Err(err) => return (None, Err(err)),
-                };
-                let result = crate::ocr::recognize_text_from_file(&engine, &temp_path);
-                (Some(engine), result)
-            },
-            move |(engine, result): (
-                Option<crate::ocr::OcrEngine>,
-                Result<String, crate::ocr::OcrError>,
-            )| {
-                if let Some(engine) = engine {
-                    *executor.ocr_engine.borrow_mut() = Some(engine);
-                }
-                executor.ocr_in_progress.set(false);
+        queue_ocr(
+            &self.ocr_jobs,
+            key,
+            OcrInput::File(active_capture.temp_path.clone()),
+            &self.ocr_languages,
+            feedback,
+            move |result| {
                 set_preview_cursor(&executor.preview_windows, &capture_id, None);
 
                 match result {
-                    Ok(text) => {
-                        super::ocr_support::handle_ocr_text_result(&executor.status_log, text)
+                    Ok(document) => {
+                        if let Some(runtime) = executor.preview_windows.borrow().get(&capture_id) {
+                            runtime
+                                .ocr_overlay
+                                .set_layer(crate::ocr::OcrTextLayer::from_document(
+                                    &document, 0, 0,
+                                ));
+                        }
+                        super::ocr_support::handle_ocr_text_result(
+                            &executor.status_log,
+                            document.to_plain_text(),
+                        )
                     }
+                    Err(crate::ocr::OcrError::Cancelled) => {}
                     Err(err) => {
                         set_status(&executor.status_log, format!("OCR failed: {err}"));
                         crate::notification::send(format!("OCR failed: {err}"));
@@ -464,8 +707,56 @@ impl LaunchpadActionExecutor {
         );
     }
 
+    pub(super) fn run_preview_decode_action(&self) {
+        let active_capture = match consume_and_resolve_active_capture(
+            &self.runtime_session,
+            &self.capture_selection,
+        ) {
+            Some(artifact) => artifact,
+            None => {
+                set_status(&self.status_log, "decode requires an active capture");
+                return;
+            }
+        };
+        if !matches!(self.machine.borrow().state(), AppState::Preview) {
+            set_status(&self.status_log, "decode requires preview state");
+            return;
+        }
+
+        set_status(&self.status_log, "Decoding codes...");
+        set_preview_cursor(
+            &self.preview_windows,
+            &active_capture.capture_id,
+            Some("progress"),
+        );
+
+        let executor = self.clone();
+        let capture_id = active_capture.capture_id.clone();
+        // A decode already pending for this capture keeps the cursor and
+        // shows its own result.
+        let _ = queue_barcode_decode(
+            &self.ocr_jobs,
+            barcode_decode_key(&capture_id, None),
+            BarcodeInput::File(active_capture.temp_path.clone()),
+            move |outcome| {
+                set_preview_cursor(&executor.preview_windows, &capture_id, None);
+                let JobOutcome::Finished(result) = outcome else {
+                    set_status(&executor.status_log, "decode cancelled");
+                    return;
+                };
+                let parent = executor
+                    .preview_windows
+                    .borrow()
+                    .get(&capture_id)
+                    .map(|runtime| runtime.window.clone().upcast::<gtk4::Window>());
+                super::barcode_results::handle_barcode_result(parent, &executor.status_log, result);
+            },
+        );
+    }
+
     fn apply_deleted_capture(&self, capture_id: &str) {
         self.runtime_session.borrow_mut().remove_capture(capture_id);
+        self.ocr_jobs.cancel_capture(capture_id);
         close_preview_window_for_capture(
             &self.preview_windows,
             capture_id,
@@ -494,7 +785,7 @@ impl PreviewActionLabels {
                 past: "saved",
                 success_title: "Saved",
             },
-            PreviewAction::Copy => Self {
+            PreviewAction::Copy | PreviewAction::CopyAs(_) => Self {
                 operation: "copy",
                 title: "Copy",
                 past: "copied",
@@ -659,7 +950,10 @@ fn apply_preview_action_result(
 ) -> Option<PreviewEvent> {
     let outcome = preview_action_ui_outcome(action, &active_capture.capture_id, result);
     set_status(status_log, outcome.status_message);
-    if matches!(action, PreviewAction::Save | PreviewAction::Copy) {
+    if matches!(
+        action,
+        PreviewAction::Save | PreviewAction::Copy | PreviewAction::CopyAs(_)
+    ) {
         crate::notification::send(outcome.toast_message);
     } else {
         show_toast_for_capture(
@@ -715,7 +1009,7 @@ fn set_preview_cursor(
 }
 
 fn requires_main_thread_preview_action(action: PreviewAction) -> bool {
-    matches!(action, PreviewAction::Copy)
+    matches!(action, PreviewAction::Copy | PreviewAction::CopyAs(_))
 }
 
 fn close_preview_when_session_empty(
@@ -741,6 +1035,7 @@ fn matches_preview_action(action: PreviewAction, event: &PreviewEvent) -> bool {
         (action, event),
         (PreviewAction::Save, PreviewEvent::Save { .. })
             | (PreviewAction::Copy, PreviewEvent::Copy { .. })
+            | (PreviewAction::CopyAs(_), PreviewEvent::Copy { .. })
             | (PreviewAction::Edit, PreviewEvent::Edit { .. })
             | (PreviewAction::Delete, PreviewEvent::Delete { .. })
             | (PreviewAction::Close, PreviewEvent::Close { .. })
@@ -752,7 +1047,7 @@ fn preview_event_capture_id(event: &PreviewEvent) -> &str {
         PreviewEvent::Save { capture_id }
         | PreviewEvent::Copy { capture_id }
         | PreviewEvent::Edit { capture_id }
-        | PreviewEvent::Delete { capture_id }
+        | PreviewEvent::Delete { capture_id, .. }
         | PreviewEvent::Close { capture_id } => capture_id,
     }
 }
@@ -950,6 +1245,9 @@ mod tests {
     #[test]
     fn copy_requires_main_thread_execution() {
         assert!(requires_main_thread_preview_action(PreviewAction::Copy));
+        assert!(requires_main_thread_preview_action(PreviewAction::CopyAs(
+            CopyProfile::HtmlDataUri
+        )));
         assert!(!requires_main_thread_preview_action(PreviewAction::Save));
         assert!(!requires_main_thread_preview_action(PreviewAction::Delete));
     }
@@ -15,19 +15,23 @@ use gtk4::{Application, ApplicationWindow, Button};
 
 mod actions;
 mod adaptive;
+mod barcode_results;
 mod bootstrap;
 mod editor_history;
 mod editor_popup;
+mod editor_recovery;
 mod editor_runtime;
 mod editor_text_runtime;
 mod editor_viewport;
 mod hover_controls;
 mod hypr;
 mod input_bridge;
+mod jobs;
 mod launchpad;
 mod launchpad_actions;
 mod layout;
 mod lifecycle;
+mod ocr_overlay;
 mod ocr_support;
 mod preview_pin;
 mod preview_runtime;
@@ -38,10 +42,12 @@ mod worker;
 
 use self::bootstrap::*;
 use self::editor_popup::*;
+use self::editor_recovery::EditorRecoveryRuntime;
 use self::editor_runtime::*;
 use self::launchpad::*;
 use self::launchpad_actions::*;
 use self::lifecycle::*;
+use self::ocr_support::SharedOcrLanguages;
 use self::preview_runtime::*;
 use self::runtime_support::*;
 use self::window_state::*;
@@ -205,13 +211,32 @@ fn shortcut_editor_tool_switch(action: ShortcutAction) -> Option<(ToolKind, &'st
         ShortcutAction::EditorEnterPan => Some((ToolKind::Pan, "editor pan tool armed")),
         ShortcutAction::EditorEnterBlur => Some((ToolKind::Blur, "editor blur tool armed")),
         ShortcutAction::EditorEnterPen => Some((ToolKind::Pen, "editor pen tool armed")),
+        ShortcutAction::EditorEnterHighlighter => {
+            Some((ToolKind::Highlighter, "editor highlighter tool armed"))
+        }
         ShortcutAction::EditorEnterArrow => Some((ToolKind::Arrow, "editor arrow tool armed")),
+        ShortcutAction::EditorEnterLine => Some((ToolKind::Line, "editor line tool armed")),
         ShortcutAction::EditorEnterRectangle => {
             Some((ToolKind::Rectangle, "editor rectangle tool armed"))
         }
+        ShortcutAction::EditorEnterEllipse => {
+            Some((ToolKind::Ellipse, "editor ellipse tool armed"))
+        }
+        ShortcutAction::EditorEnterBadge => Some((ToolKind::Badge, "editor badge tool armed")),
+        ShortcutAction::EditorEnterSpotlight => {
+            Some((ToolKind::Spotlight, "editor spotlight tool armed"))
+        }
+        ShortcutAction::EditorEnterMagnifier => {
+            Some((ToolKind::Magnifier, "editor magnifier tool armed"))
+        }
+        ShortcutAction::EditorEnterCallout => {
+            Some((ToolKind::Callout, "editor callout tool armed"))
+        }
+        ShortcutAction::EditorEnterImage => Some((ToolKind::Image, "editor image tool armed")),
         ShortcutAction::EditorEnterCrop => Some((ToolKind::Crop, "editor crop interaction armed")),
         ShortcutAction::EditorEnterText => Some((ToolKind::Text, "editor text tool armed")),
         ShortcutAction::EditorEnterOcr => Some((ToolKind::Ocr, "editor OCR tool armed")),
+        ShortcutAction::EditorEnterDecode => Some((ToolKind::Decode, "editor decode tool armed")),
         _ => None,
     }
 }
@@ -237,6 +262,7 @@ struct EditorRuntimeState {
     close_dialog_open: Rc<RefCell<bool>>,
     toast: Rc<RefCell<Option<ToastRuntime>>>,
     input_mode: Rc<RefCell<editor::EditorInputMode>>,
+    recovery: EditorRecoveryRuntime,
 }
 
 impl EditorRuntimeState {
@@ -247,6 +273,7 @@ impl EditorRuntimeState {
             close_dialog_open: Rc::new(RefCell::new(false)),
             toast: Rc::new(RefCell::new(None)),
             input_mode: Rc::new(RefCell::new(editor::EditorInputMode::new())),
+            recovery: EditorRecoveryRuntime::default(),
         }
     }
 
@@ -259,6 +286,7 @@ impl EditorRuntimeState {
     fn clear_runtime_state(&self) {
         *self.capture_id.borrow_mut() = None;
         *self.toast.borrow_mut() = None;
+        self.recovery.end_session();
         self.reset_session_state();
     }
 }
@@ -303,6 +331,8 @@ struct EditorOutputActionRuntime {
     pending_crop: Rc<RefCell<Option<CropElement>>>,
     editor_source_pixbuf: Option<gtk4::gdk_pixbuf::Pixbuf>,
     editor_has_unsaved_changes: Rc<RefCell<bool>>,
+    editor_recovery: EditorRecoveryRuntime,
+    copy_profile: crate::clipboard::CopyProfile,
     toast_duration_ms: u32,
 }
 
@@ -335,18 +365,37 @@ impl EditorOutputActionRuntime {
         };
 
         let tools = self.editor_tools.borrow();
-        execute_editor_output_action(EditorOutputActionContext {
+        let succeeded = execute_editor_output_action(EditorOutputActionContext {
             action,
             active_capture: &active_capture,
             editor_tools: &tools,
             pending_crop: self.pending_crop.borrow().as_ref().copied(),
             source_pixbuf,
             storage_service: service,
+            copy_profile: self.copy_profile,
             status_log: &self.status_log,
             editor_toast: &self.editor_toast,
             toast_duration_ms: self.toast_duration_ms,
             editor_has_unsaved_changes: &self.editor_has_unsaved_changes,
-        })
+        });
+        if succeeded && !*self.editor_has_unsaved_changes.borrow() {
+            self.editor_recovery.mark_saved();
+        }
+        succeeded
+    }
+
+    /// Where [`EditorAction::Save`] writes the active capture.
+    fn saved_capture_path(&self) -> Option<std::path::PathBuf> {
+        let capture_id = self
+            .runtime_session
+            .borrow()
+            .active_capture()
+            .map(|artifact| artifact.capture_id.clone())?;
+        self.storage_service
+            .as_ref()
+            .as_ref()?
+            .allocate_target_path(&capture_id)
+            .ok()
     }
 }
 
@@ -388,6 +437,7 @@ fn should_release_headless_startup_hold(
     has_active_capture: bool,
     preview_window_count: usize,
     editor_window_open: bool,
+    delete_undo_pending: bool,
 ) -> bool {
     hold_active
         && startup_capture_completed
@@ -395,6 +445,7 @@ fn should_release_headless_startup_hold(
         && !has_active_capture
         && preview_window_count == 0
         && !editor_window_open
+        && !delete_undo_pending
 }
 
 pub struct App {
@@ -462,6 +513,7 @@ impl App {
             let headless_hold_guard =
                 Rc::new(RefCell::new(None::<gtk4::gio::ApplicationHoldGuard>));
             let startup_capture_completed = Rc::new(Cell::new(!headless_startup_capture));
+            let pending_delete_undos: SharedPendingDeleteUndos = Rc::new(Cell::new(0));
             if headless_startup_capture {
                 tracing::info!("holding app lifecycle for headless startup capture");
                 let hold_guard =
@@ -490,7 +542,12 @@ impl App {
                 .editor_theme_overrides
                 .default_stroke_width;
             let editor_tool_option_presets = resolved_theme_runtime.editor_tool_option_presets;
-            let ocr_language = resolved_theme_runtime.ocr_language;
+            let ocr_languages: SharedOcrLanguages =
+                Rc::new(RefCell::new(resolved_theme_runtime.ocr_languages));
+            let ocr_installed_languages = Rc::new(resolved_theme_runtime.ocr_installed_languages);
+            let copy_profile = resolved_theme_runtime.copy_profile;
+            let redaction_rules = Rc::new(resolved_theme_runtime.redaction_rules);
+            let default_blur_mode = resolved_theme_runtime.default_blur_mode;
             tracing::info!(
                 requested_mode = ?theme_config.mode,
                 resolved_mode = ?theme_mode,
@@ -513,11 +570,7 @@ impl App {
 
             let settings_info = {
                 let theme_label = format!("{:?} → {:?}", theme_config.mode, theme_mode);
-                let ocr_language_label = format!(
-                    "{} ({})",
-                    ocr_language.display_name(),
-                    ocr_language.as_str()
-                );
+                let ocr_language_label = crate::ocr::format_ocr_languages(&ocr_languages.borrow());
                 let ocr_model_dir_label = crate::ocr::resolve_model_dir()
                     .map(|p| p.display().to_string())
                     .unwrap_or_else(|| "not found".to_string());
@@ -556,20 +609,30 @@ impl App {
                 }
             };
             let launchpad = build_launchpad_ui(style_tokens, show_launchpad, &settings_info);
-            let launchpad_toast_runtime = ToastRuntime::new(&launchpad.toast_label);
+            let launchpad_toast_runtime = ToastRuntime::with_action_button(
+                &launchpad.toast_label,
+                &launchpad.toast_action_button,
+            );
             let open_editor_button = launchpad.open_editor_button.clone();
             let close_preview_button = launchpad.close_preview_button.clone();
             let close_editor_button = launchpad.close_editor_button.clone();
             let save_button = launchpad.save_button.clone();
             let copy_button = launchpad.copy_button.clone();
+            let copy_as_buttons = launchpad
+                .copy_as_buttons
+                .iter()
+                .map(|(_, button)| button.clone())
+                .collect::<Vec<_>>();
             let ocr_button = launchpad.ocr_button.clone();
+            let decode_button = launchpad.decode_button.clone();
             let delete_button = launchpad.delete_button.clone();
 
             window.set_child(Some(&launchpad.root));
             let ocr_available = crate::ocr::resolve_model_dir().is_some();
-            let ocr_engine: Rc<RefCell<Option<crate::ocr::OcrEngine>>> =
-                Rc::new(RefCell::new(None));
-            let ocr_in_progress = Rc::new(Cell::new(false));
+            let ocr_jobs = jobs::JobQueue::new();
+            if ocr_available {
+                ocr_support::queue_ocr_warm_up(&ocr_jobs, &ocr_languages);
+            }
             let app_for_preview = app.clone();
             let app_for_lifecycle = app.clone();
             let preview_render_context = PreviewRenderContext::new(
@@ -579,7 +642,9 @@ impl App {
                 status_log_for_activate.clone(),
                 save_button.clone(),
                 copy_button.clone(),
+                copy_as_buttons,
                 ocr_button.clone(),
+                decode_button.clone(),
                 open_editor_button.clone(),
                 close_preview_button.clone(),
                 delete_button.clone(),
@@ -609,6 +674,7 @@ impl App {
                 default_tool_color_override,
                 default_text_size_override,
                 default_stroke_width_override,
+                default_blur_mode,
                 editor_tool_option_presets: editor_tool_option_presets.clone(),
                 editor_navigation_bindings: editor_navigation_bindings.clone(),
                 status_log_for_render: status_log_for_activate.clone(),
@@ -619,10 +685,12 @@ impl App {
                 close_editor_button: close_editor_button.clone(),
                 storage_service: storage_service_for_activate.clone(),
                 shared_machine: machine_for_activate.clone(),
-                ocr_engine: ocr_engine.clone(),
-                ocr_language,
-                ocr_in_progress: ocr_in_progress.clone(),
+                ocr_jobs: ocr_jobs.clone(),
+                ocr_languages: ocr_languages.clone(),
+                ocr_installed_languages: ocr_installed_languages.clone(),
                 ocr_available,
+                copy_profile,
+                redaction_rules,
             };
 
             let render = {
@@ -640,6 +708,7 @@ impl App {
                 let app_for_lifecycle = app_for_lifecycle.clone();
                 let headless_hold_guard = headless_hold_guard.clone();
                 let startup_capture_completed = startup_capture_completed.clone();
+                let pending_delete_undos = pending_delete_undos.clone();
 
                 Rc::new(move || {
                     let runtime = runtime_session.borrow();
@@ -691,6 +760,7 @@ impl App {
                         has_capture,
                         preview_window_count,
                         editor_window_open,
+                        pending_delete_undos.get() > 0,
                     ) {
                         tracing::info!("releasing headless startup capture hold");
                         let _ = headless_hold_guard.borrow_mut().take();
@@ -709,11 +779,20 @@ impl App {
                 runtime_window_state.clone(),
                 launchpad_toast_runtime.clone(),
                 style_tokens.toast_duration_ms,
-                ocr_engine.clone(),
-                ocr_language,
-                ocr_in_progress.clone(),
+                copy_profile,
+                ocr_jobs.clone(),
+                ocr_languages.clone(),
+                editor_runtime.recovery.clone(),
+                pending_delete_undos.clone(),
             );
             connect_launchpad_default_buttons(&launchpad, &launchpad_actions, &render);
+            let recoverable_session_count = editor_runtime.recovery.recoverable_sessions().len();
+            launchpad.set_recoverable_session_count(recoverable_session_count);
+            if recoverable_session_count > 0 && !show_launchpad {
+                crate::notification::send(format!(
+                    "{recoverable_session_count} unsaved editor session(s) can be restored from the launchpad (chalkak --launchpad)"
+                ));
+            }
 
             {
                 let render = render.clone();
@@ -858,6 +937,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn format_recoverable_sessions_uses_singular_for_one_session() {
+        assert_eq!(
+            format_recoverable_sessions(1),
+            "1 unsaved editor session was left behind by a previous run."
+        );
+        assert_eq!(
+            format_recoverable_sessions(3),
+            "3 unsaved editor sessions were left behind by a previous run."
+        );
+    }
+
     #[test]
     fn shortcut_editor_tool_switch_maps_tool_shortcuts() {
         assert_eq!(
@@ -876,6 +967,10 @@ mod tests {
             shortcut_editor_tool_switch(ShortcutAction::EditorEnterPen),
             Some((ToolKind::Pen, "editor pen tool armed"))
         );
+        assert_eq!(
+            shortcut_editor_tool_switch(ShortcutAction::EditorEnterHighlighter),
+            Some((ToolKind::Highlighter, "editor highlighter tool armed"))
+        );
         assert_eq!(
             shortcut_editor_tool_switch(ShortcutAction::EditorEnterArrow),
             Some((ToolKind::Arrow, "editor arrow tool armed"))
@@ -884,6 +979,34 @@ mod tests {
             shortcut_editor_tool_switch(ShortcutAction::EditorEnterRectangle),
             Some((ToolKind::Rectangle, "editor rectangle tool armed"))
         );
+        assert_eq!(
+            shortcut_editor_tool_switch(ShortcutAction::EditorEnterLine),
+            Some((ToolKind::Line, "editor line tool armed"))
+        );
+        assert_eq!(
+            shortcut_editor_tool_switch(ShortcutAction::EditorEnterEllipse),
+            Some((ToolKind::Ellipse, "editor ellipse tool armed"))
+        );
+        assert_eq!(
+            shortcut_editor_tool_switch(ShortcutAction::EditorEnterBadge),
+            Some((ToolKind::Badge, "editor badge tool armed"))
+        );
+        assert_eq!(
+            shortcut_editor_tool_switch(ShortcutAction::EditorEnterSpotlight),
+            Some((ToolKind::Spotlight, "editor spotlight tool armed"))
+        );
+        assert_eq!(
+            shortcut_editor_tool_switch(ShortcutAction::EditorEnterMagnifier),
+            Some((ToolKind::Magnifier, "editor magnifier tool armed"))
+        );
+        assert_eq!(
+            shortcut_editor_tool_switch(ShortcutAction::EditorEnterCallout),
+            Some((ToolKind::Callout, "editor callout tool armed"))
+        );
+        assert_eq!(
+            shortcut_editor_tool_switch(ShortcutAction::EditorEnterImage),
+            Some((ToolKind::Image, "editor image tool armed"))
+        );
         assert_eq!(
             shortcut_editor_tool_switch(ShortcutAction::EditorEnterCrop),
             Some((ToolKind::Crop, "editor crop interaction armed"))
@@ -896,6 +1019,10 @@ mod tests {
             shortcut_editor_tool_switch(ShortcutAction::EditorEnterOcr),
             Some((ToolKind::Ocr, "editor OCR tool armed"))
         );
+        assert_eq!(
+            shortcut_editor_tool_switch(ShortcutAction::EditorEnterDecode),
+            Some((ToolKind::Decode, "editor decode tool armed"))
+        );
     }
 
     #[test]
@@ -942,6 +1069,7 @@ mod tests {
             AppState::Idle,
             false,
             0,
+            false,
             false
         ));
 
@@ -951,6 +1079,7 @@ mod tests {
             AppState::Idle,
             false,
             0,
+            false,
             false
         ));
         assert!(!should_release_headless_startup_hold(
@@ -959,6 +1088,7 @@ mod tests {
             AppState::Idle,
             false,
             0,
+            false,
             false
         ));
         assert!(!should_release_headless_startup_hold(
@@ -967,6 +1097,7 @@ mod tests {
             AppState::Preview,
             false,
             0,
+            false,
             false
         ));
         assert!(!should_release_headless_startup_hold(
@@ -975,6 +1106,7 @@ mod tests {
             AppState::Idle,
             true,
             0,
+            false,
             false
         ));
         assert!(!should_release_headless_startup_hold(
@@ -983,14 +1115,29 @@ mod tests {
             AppState::Idle,
             false,
             1,
+            false,
+            false
+        ));
+        assert!(!should_release_headless_startup_hold(
+            true,
+            true,
+            AppState::Idle,
+            false,
+            0,
+            true,
             false
         ));
+    }
+
+    #[test]
+    fn should_keep_headless_startup_hold_while_delete_undo_is_pending() {
         assert!(!should_release_headless_startup_hold(
             true,
             true,
             AppState::Idle,
             false,
             0,
+            false,
             true
         ));
     }
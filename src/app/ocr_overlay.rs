@@ -0,0 +1,579 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gtk4::prelude::*;
+use gtk4::DrawingArea;
+
+use crate::ocr::{OcrDocument, OcrRect, OcrSpan, OcrTextLayer, OcrTextPosition, OcrTextSelection};
+
+use super::editor_popup::RgbaColor;
+use super::launchpad_actions::{set_status, SharedStatusLog};
+
+const OCR_LINE_FILL: RgbaColor = RgbaColor::new(0xFF, 0xFF, 0xFF, 0x1A);
+const OCR_LINE_STROKE: RgbaColor = RgbaColor::new(0xFF, 0xFF, 0xFF, 0x59);
+const OCR_SELECTION_FILL: RgbaColor = RgbaColor::new(0x24, 0x61, 0xFF, 0x66);
+const OCR_FIND_MATCH_FILL: RgbaColor = RgbaColor::new(0xFF, 0xD6, 0x0A, 0x59);
+const OCR_FIND_CURRENT_FILL: RgbaColor = RgbaColor::new(0xFF, 0x8A, 0x00, 0x80);
+const OCR_FIND_CURRENT_STROKE: RgbaColor = RgbaColor::new(0xFF, 0x8A, 0x00, 0xFF);
+
+/// Recognized text shown over a capture and the user's selection in it.
+/// Coordinates are image pixels.
+#[derive(Debug, Default)]
+pub(crate) struct OcrOverlayState {
+    layer: OcrTextLayer,
+    selection: Option<OcrTextSelection>,
+    drag_anchor: Option<OcrTextPosition>,
+    /// Whether `layer` came from the whole image rather than a region.
+    covers_full_image: bool,
+    /// Boxes of the last region recognized with the OCR tool, in image
+    /// coordinates, kept for table extraction.
+    region_spans: Vec<OcrSpan>,
+    find_query: String,
+    find_matches: Vec<OcrTextSelection>,
+    find_current: usize,
+}
+
+pub(crate) type SharedOcrOverlay = Rc<RefCell<OcrOverlayState>>;
+
+impl OcrOverlayState {
+    pub(crate) fn set_layer(&mut self, layer: OcrTextLayer) {
+        self.layer = layer;
+        self.selection = None;
+        self.drag_anchor = None;
+        self.covers_full_image = false;
+        self.region_spans.clear();
+        self.refresh_find_matches();
+    }
+
+    /// Shows text recognized inside the region at (`offset_x`, `offset_y`)
+    /// and keeps its boxes for [`Self::region_table`].
+    pub(crate) fn set_region_document(
+        &mut self,
+        document: &OcrDocument,
+        offset_x: i32,
+        offset_y: i32,
+    ) {
+        self.set_layer(OcrTextLayer::from_document(document, offset_x, offset_y));
+        self.region_spans = document
+            .spans()
+            .map(|span| {
+                let mut span = span.clone();
+                span.bbox.x = span.bbox.x.saturating_add(offset_x);
+                span.bbox.y = span.bbox.y.saturating_add(offset_y);
+                span
+            })
+            .collect();
+    }
+
+    /// Grid inferred from the last OCR region, or `None` before any region
+    /// has been recognized.
+    pub(crate) fn region_table(&self) -> Option<crate::ocr::OcrTable> {
+        let table = crate::ocr::infer_table(&self.region_spans);
+        (!table.is_empty()).then_some(table)
+    }
+
+    /// Like [`Self::set_layer`] for text recognized from the whole image,
+    /// which find needs to search everything.
+    pub(crate) fn set_full_image_layer(&mut self, layer: OcrTextLayer) {
+        self.set_layer(layer);
+        self.covers_full_image = true;
+    }
+
+    pub(crate) fn covers_full_image(&self) -> bool {
+        self.covers_full_image
+    }
+
+    pub(crate) fn has_text(&self) -> bool {
+        !self.layer.is_empty()
+    }
+
+    pub(crate) fn hits_text(&self, x: f64, y: f64) -> bool {
+        self.layer.position_at(x, y).is_some()
+    }
+
+    /// Starts a drag selection when the point is over recognized text. The
+    /// current selection is kept until the pointer actually moves.
+    pub(crate) fn begin_drag(&mut self, x: f64, y: f64) -> bool {
+        self.drag_anchor = self.layer.position_at(x, y);
+        self.drag_anchor.is_some()
+    }
+
+    pub(crate) fn update_drag(&mut self, x: f64, y: f64) -> bool {
+        let Some(anchor) = self.drag_anchor else {
+            return false;
+        };
+        if let Some(focus) = self.layer.nearest_position(x, y) {
+            self.selection = Some(OcrTextSelection::new(anchor, focus));
+        }
+        true
+    }
+
+    pub(crate) fn end_drag(&mut self) -> bool {
+        self.drag_anchor.take().is_some()
+    }
+
+    pub(crate) fn is_dragging(&self) -> bool {
+        self.drag_anchor.is_some()
+    }
+
+    /// Single click clears the selection, double click selects a word and
+    /// triple click a line. Returns whether the point was over text.
+    pub(crate) fn click(&mut self, x: f64, y: f64, n_press: i32) -> bool {
+        let Some(position) = self.layer.position_at(x, y) else {
+            if n_press == 1 {
+                self.selection = None;
+            }
+            return false;
+        };
+        self.selection = match n_press {
+            1 => None,
+            2 => Some(self.layer.word_at(position)),
+            _ => Some(self.layer.line_at(position.line)),
+        };
+        true
+    }
+
+    pub(crate) fn clear_selection(&mut self) -> bool {
+        self.drag_anchor = None;
+        self.selection.take().is_some()
+    }
+
+    pub(crate) fn selected_text(&self) -> Option<String> {
+        let text = self.layer.selected_text(self.selection?);
+        (!text.is_empty()).then_some(text)
+    }
+
+    /// Searches the layer for `query` and makes the first match current.
+    /// Returns the number of matches.
+    pub(crate) fn set_find_query(&mut self, query: &str) -> usize {
+        query.clone_into(&mut self.find_query);
+        self.refresh_find_matches();
+        self.find_matches.len()
+    }
+
+    fn refresh_find_matches(&mut self) {
+        self.find_matches = self.layer.find(&self.find_query);
+        self.find_current = 0;
+    }
+
+    /// Moves to the next (or previous) match, wrapping around, and returns
+    /// its bounds.
+    pub(crate) fn step_find(&mut self, forward: bool) -> Option<OcrRect> {
+        let count = self.find_matches.len();
+        if count == 0 {
+            return None;
+        }
+        self.find_current = if forward {
+            (self.find_current + 1) % count
+        } else {
+            (self.find_current + count - 1) % count
+        };
+        self.current_find_bounds()
+    }
+
+    pub(crate) fn current_find_bounds(&self) -> Option<OcrRect> {
+        let selection = *self.find_matches.get(self.find_current)?;
+        self.layer.selection_bounds(selection)
+    }
+
+    /// Highlight rectangles of the current match, one per line.
+    pub(crate) fn current_find_rects(&self) -> Vec<OcrRect> {
+        self.find_matches
+            .get(self.find_current)
+            .map(|selection| self.layer.selection_rects(*selection))
+            .unwrap_or_default()
+    }
+
+    /// Match counter for the find bar, e.g. "2 of 5".
+    pub(crate) fn find_summary(&self) -> String {
+        if self.find_query.trim().is_empty() {
+            String::new()
+        } else if self.find_matches.is_empty() {
+            "No matches".to_string()
+        } else {
+            format!("{} of {}", self.find_current + 1, self.find_matches.len())
+        }
+    }
+
+    pub(crate) fn clear_find(&mut self) {
+        self.find_query.clear();
+        self.find_matches.clear();
+        self.find_current = 0;
+    }
+}
+
+/// Draws line boxes and the selection highlight. `context` must already be
+/// transformed to image coordinates.
+pub(crate) fn draw_ocr_overlay(context: &gtk4::cairo::Context, state: &OcrOverlayState) {
+    if !state.has_text() {
+        return;
+    }
+    context.save().ok();
+    context.set_line_width(1.0);
+    for bbox in state.layer.line_boxes() {
+        context.rectangle(
+            f64::from(bbox.x),
+            f64::from(bbox.y),
+            f64::from(bbox.width),
+            f64::from(bbox.height),
+        );
+        set_source_rgba(context, OCR_LINE_FILL);
+        let _ = context.fill_preserve();
+        set_source_rgba(context, OCR_LINE_STROKE);
+        let _ = context.stroke();
+    }
+    if let Some(selection) = state.selection {
+        set_source_rgba(context, OCR_SELECTION_FILL);
+        for rect in state.layer.selection_rects(selection) {
+            context.rectangle(
+                f64::from(rect.x),
+                f64::from(rect.y),
+                f64::from(rect.width),
+                f64::from(rect.height),
+            );
+        }
+        let _ = context.fill();
+    }
+    context.restore().ok();
+}
+
+/// Draws every find match, the current one emphasized. Unlike
+/// [`draw_ocr_overlay`] this is shown regardless of the active tool.
+pub(crate) fn draw_ocr_find_matches(context: &gtk4::cairo::Context, state: &OcrOverlayState) {
+    if state.find_matches.is_empty() {
+        return;
+    }
+    context.save().ok();
+    set_source_rgba(context, OCR_FIND_MATCH_FILL);
+    for (index, selection) in state.find_matches.iter().enumerate() {
+        if index == state.find_current {
+            continue;
+        }
+        for rect in state.layer.selection_rects(*selection) {
+            add_rect_path(context, rect);
+        }
+    }
+    let _ = context.fill();
+    for rect in state.current_find_rects() {
+        add_rect_path(context, rect);
+    }
+    set_source_rgba(context, OCR_FIND_CURRENT_FILL);
+    let _ = context.fill_preserve();
+    context.set_line_width(2.0);
+    set_source_rgba(context, OCR_FIND_CURRENT_STROKE);
+    let _ = context.stroke();
+    context.restore().ok();
+}
+
+fn add_rect_path(context: &gtk4::cairo::Context, rect: OcrRect) {
+    context.rectangle(
+        f64::from(rect.x),
+        f64::from(rect.y),
+        f64::from(rect.width),
+        f64::from(rect.height),
+    );
+}
+
+fn set_source_rgba(context: &gtk4::cairo::Context, color: RgbaColor) {
+    let (red, green, blue, alpha) = color.to_cairo_rgba();
+    context.set_source_rgba(red, green, blue, alpha);
+}
+
+/// Copies the selected text to the clipboard. Returns `false` without
+/// touching the clipboard when nothing is selected.
+pub(crate) fn copy_ocr_selection(status_log: &SharedStatusLog, state: &OcrOverlayState) -> bool {
+    let Some(text) = state.selected_text() else {
+        return false;
+    };
+    if let Some(display) = gtk4::gdk::Display::default() {
+        display.clipboard().set_text(&text);
+    }
+    set_status(
+        status_log,
+        format!("OCR selection copied {} chars", text.chars().count()),
+    );
+    true
+}
+
+/// Scale and offset that fit an `image_width`x`image_height` image inside a
+/// `bounds_width`x`bounds_height` box while keeping its aspect ratio, the way
+/// `gtk4::Picture` lays out its paintable.
+fn contain_transform(
+    bounds_width: f64,
+    bounds_height: f64,
+    image_width: f64,
+    image_height: f64,
+) -> Option<(f64, f64, f64)> {
+    if bounds_width <= 0.0 || bounds_height <= 0.0 || image_width <= 0.0 || image_height <= 0.0 {
+        return None;
+    }
+    let scale = (bounds_width / image_width).min(bounds_height / image_height);
+    let offset_x = (bounds_width - image_width * scale) / 2.0;
+    let offset_y = (bounds_height - image_height * scale) / 2.0;
+    Some((scale, offset_x, offset_y))
+}
+
+/// Text overlay stacked above a preview picture. Gestures on the overlay
+/// area drive the selection; clicks away from recognized text pass through.
+#[derive(Clone)]
+pub(crate) struct PreviewOcrOverlay {
+    pub(crate) state: SharedOcrOverlay,
+    pub(crate) area: DrawingArea,
+}
+
+impl PreviewOcrOverlay {
+    pub(crate) fn new(picture: &gtk4::Picture, image_width: u32, image_height: u32) -> Self {
+        let overlay = Self {
+            state: Rc::new(RefCell::new(OcrOverlayState::default())),
+            area: DrawingArea::new(),
+        };
+        overlay.area.set_hexpand(true);
+        overlay.area.set_vexpand(true);
+        let image_size = (f64::from(image_width), f64::from(image_height));
+        {
+            let state = overlay.state.clone();
+            let picture = picture.clone();
+            overlay.area.set_draw_func(move |area, context, _, _| {
+                let state = state.borrow();
+                if !state.has_text() {
+                    return;
+                }
+                let Some((scale, offset_x, offset_y)) =
+                    picture_transform(&picture, area, image_size)
+                else {
+                    return;
+                };
+                context.save().ok();
+                context.translate(offset_x, offset_y);
+                context.scale(scale, scale);
+                draw_ocr_overlay(context, &state);
+                context.restore().ok();
+            });
+        }
+        let to_image = {
+            let picture = picture.clone();
+            let area = overlay.area.clone();
+            move |x: f64, y: f64| {
+                let (scale, offset_x, offset_y) = picture_transform(&picture, &area, image_size)?;
+                Some(((x - offset_x) / scale, (y - offset_y) / scale))
+            }
+        };
+
+        let drag = gtk4::GestureDrag::new();
+        drag.set_button(gtk4::gdk::BUTTON_PRIMARY);
+        {
+            let state = overlay.state.clone();
+            let to_image = to_image.clone();
+            drag.connect_drag_begin(move |gesture, x, y| {
+                let started =
+                    to_image(x, y).is_some_and(|(x, y)| state.borrow_mut().begin_drag(x, y));
+                gesture.set_state(if started {
+                    gtk4::EventSequenceState::Claimed
+                } else {
+                    gtk4::EventSequenceState::Denied
+                });
+            });
+        }
+        {
+            let state = overlay.state.clone();
+            let area = overlay.area.clone();
+            let to_image = to_image.clone();
+            drag.connect_drag_update(move |gesture, offset_x, offset_y| {
+                let Some((start_x, start_y)) = gesture.start_point() else {
+                    return;
+                };
+                if let Some((x, y)) = to_image(start_x + offset_x, start_y + offset_y) {
+                    if state.borrow_mut().update_drag(x, y) {
+                        area.queue_draw();
+                    }
+                }
+            });
+        }
+        {
+            let state = overlay.state.clone();
+            drag.connect_drag_end(move |_, _, _| {
+                state.borrow_mut().end_drag();
+            });
+        }
+        overlay.area.add_controller(drag);
+
+        let click = gtk4::GestureClick::new();
+        click.set_button(gtk4::gdk::BUTTON_PRIMARY);
+        {
+            let state = overlay.state.clone();
+            let area = overlay.area.clone();
+            let to_image = to_image.clone();
+            click.connect_pressed(move |_, n_press, x, y| {
+                if !state.borrow().has_text() {
+                    return;
+                }
+                if let Some((x, y)) = to_image(x, y) {
+                    state.borrow_mut().click(x, y, n_press);
+                    area.queue_draw();
+                }
+            });
+        }
+        overlay.area.add_controller(click);
+
+        let motion = gtk4::EventControllerMotion::new();
+        {
+            let state = overlay.state.clone();
+            let area = overlay.area.clone();
+            motion.connect_motion(move |_, x, y| {
+                let over_text = {
+                    let state = state.borrow();
+                    state.is_dragging()
+                        || to_image(x, y).is_some_and(|(x, y)| state.hits_text(x, y))
+                };
+                area.set_cursor_from_name(over_text.then_some("text"));
+            });
+        }
+        overlay.area.add_controller(motion);
+
+        overlay
+    }
+
+    pub(crate) fn set_layer(&self, layer: OcrTextLayer) {
+        self.state.borrow_mut().set_layer(layer);
+        self.area.queue_draw();
+    }
+
+    pub(crate) fn clear_selection(&self) -> bool {
+        let cleared = self.state.borrow_mut().clear_selection();
+        self.area.queue_draw();
+        cleared
+    }
+}
+
+fn picture_transform(
+    picture: &gtk4::Picture,
+    area: &DrawingArea,
+    (image_width, image_height): (f64, f64),
+) -> Option<(f64, f64, f64)> {
+    let bounds = picture.compute_bounds(area)?;
+    let (scale, offset_x, offset_y) = contain_transform(
+        f64::from(bounds.width()),
+        f64::from(bounds.height()),
+        image_width,
+        image_height,
+    )?;
+    Some((
+        scale,
+        f64::from(bounds.x()) + offset_x,
+        f64::from(bounds.y()) + offset_y,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overlay_state() -> OcrOverlayState {
+        let document = OcrDocument::from_spans(vec![
+            OcrSpan::new("copy this", OcrRect::new(0, 0, 90, 20), 0.9),
+            OcrSpan::new("next line", OcrRect::new(0, 26, 90, 20), 0.9),
+        ]);
+        let mut state = OcrOverlayState::default();
+        state.set_layer(OcrTextLayer::from_document(&document, 0, 0));
+        state
+    }
+
+    #[test]
+    fn drag_selects_from_anchor_to_pointer() {
+        let mut state = overlay_state();
+
+        assert!(state.begin_drag(50.0, 10.0));
+        assert!(state.update_drag(40.0, 36.0));
+        assert!(state.end_drag());
+
+        assert_eq!(state.selected_text().as_deref(), Some("this\nnext"));
+    }
+
+    #[test]
+    fn drag_outside_text_is_ignored() {
+        let mut state = overlay_state();
+
+        assert!(!state.begin_drag(200.0, 200.0));
+        assert!(!state.update_drag(10.0, 10.0));
+        assert_eq!(state.selected_text(), None);
+    }
+
+    #[test]
+    fn multi_click_selects_word_then_line() {
+        let mut state = overlay_state();
+
+        assert!(state.click(15.0, 10.0, 2));
+        assert_eq!(state.selected_text().as_deref(), Some("copy"));
+        assert!(state.click(15.0, 10.0, 3));
+        assert_eq!(state.selected_text().as_deref(), Some("copy this"));
+        assert!(!state.click(300.0, 300.0, 1));
+        assert_eq!(state.selected_text(), None);
+    }
+
+    #[test]
+    fn find_steps_through_matches_and_wraps() {
+        let mut state = overlay_state();
+
+        assert_eq!(state.set_find_query("I"), 2);
+        assert_eq!(state.find_summary(), "1 of 2");
+        assert_eq!(state.current_find_bounds().map(|rect| rect.y), Some(0));
+        assert_eq!(state.step_find(true).map(|rect| rect.y), Some(26));
+        assert_eq!(state.find_summary(), "2 of 2");
+        state.step_find(true);
+        assert_eq!(state.find_summary(), "1 of 2");
+        state.step_find(false);
+        assert_eq!(state.find_summary(), "2 of 2");
+    }
+
+    #[test]
+    fn find_reruns_when_layer_changes() {
+        let mut state = overlay_state();
+
+        assert_eq!(state.set_find_query("missing"), 0);
+        assert_eq!(state.find_summary(), "No matches");
+        assert_eq!(state.step_find(true), None);
+
+        let document = OcrDocument::from_spans(vec![OcrSpan::new(
+            "missing piece",
+            OcrRect::new(0, 0, 130, 20),
+            0.9,
+        )]);
+        state.set_full_image_layer(OcrTextLayer::from_document(&document, 0, 0));
+        assert!(state.covers_full_image());
+        assert_eq!(state.find_summary(), "1 of 1");
+
+        state.clear_find();
+        assert_eq!(state.find_summary(), "");
+        assert_eq!(state.current_find_bounds(), None);
+    }
+
+    #[test]
+    fn region_table_uses_last_region_until_layer_changes() {
+        let document = OcrDocument::from_spans(vec![
+            OcrSpan::new("Key", OcrRect::new(0, 0, 40, 20), 0.9),
+            OcrSpan::new("Value", OcrRect::new(120, 0, 50, 20), 0.9),
+            OcrSpan::new("a", OcrRect::new(0, 30, 10, 20), 0.9),
+            OcrSpan::new("1", OcrRect::new(120, 30, 10, 20), 0.9),
+        ]);
+        let mut state = OcrOverlayState::default();
+        assert_eq!(state.region_table(), None);
+
+        state.set_region_document(&document, 100, 50);
+        let table = state.region_table().expect("region table");
+        assert_eq!(table.to_tsv(), "Key\tValue\na\t1\n");
+        assert!(state.hits_text(105.0, 55.0));
+
+        state.set_full_image_layer(OcrTextLayer::default());
+        assert_eq!(state.region_table(), None);
+    }
+
+    #[test]
+    fn contain_transform_centers_letterboxed_image() {
+        let (scale, offset_x, offset_y) = contain_transform(400.0, 100.0, 200.0, 100.0).unwrap();
+
+        assert!((scale - 1.0).abs() < f64::EPSILON);
+        assert!((offset_x - 100.0).abs() < f64::EPSILON);
+        assert!(offset_y.abs() < f64::EPSILON);
+        assert_eq!(contain_transform(0.0, 100.0, 200.0, 100.0), None);
+    }
+}
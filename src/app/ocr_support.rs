@@ -1,22 +1,35 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use gtk4::prelude::*;
 
+use super::jobs::{JobHandle, JobKey, JobOutcome, JobProgress, JobQueue};
 use super::launchpad_actions::{set_status, SharedStatusLog};
+use super::runtime_support::ToastRuntime;
+
+/// Candidate OCR languages shared by preview and editor. The editor's
+/// language switcher edits the list in place; each run reads a snapshot.
+pub(super) type SharedOcrLanguages = Rc<RefCell<Vec<crate::ocr::OcrLanguage>>>;
 
-/// Initialise the OCR engine if it is `None`, otherwise return the existing
-/// one. Designed to run on a **worker thread** — all arguments are `Send`.
+/// Initialise the OCR engine if it is `None`, otherwise point the existing
+/// one at `languages` (loading only recognizers it has not cached yet).
+/// Designed to run on a **worker thread** — all arguments are `Send`.
 pub(super) fn resolve_or_init_engine(
     engine: Option<crate::ocr::OcrEngine>,
-    language: crate::ocr::OcrLanguage,
+    languages: &[crate::ocr::OcrLanguage],
 ) -> Result<crate::ocr::OcrEngine, crate::ocr::OcrError> {
-    if let Some(engine) = engine {
+    if let Some(mut engine) = engine {
+        engine.set_languages(languages)?;
         return Ok(engine);
     }
 
-    let model_dir =
-        crate::ocr::resolve_model_dir().ok_or_else(|| crate::ocr::OcrError::EngineInit {
+    let model_dirs = crate::ocr::resolve_model_dirs();
+    if model_dirs.is_empty() {
+        return Err(crate::ocr::OcrError::EngineInit {
             message: "model directory not found".to_string(),
-        })?;
-    crate::ocr::create_engine(&model_dir, language)
+        });
+    }
+    crate::ocr::create_engine(&model_dirs, languages)
 }
 
 pub(super) fn pixbuf_region_to_dynamic_image(
@@ -81,6 +94,217 @@ pub(super) fn pixbuf_region_to_dynamic_image(
     Ok(image::DynamicImage::ImageRgb8(img_buf))
 }
 
+/// How long a progress toast stays up without further progress.
+const OCR_PROGRESS_TOAST_MS: u32 = 30_000;
+
+/// Where a queued OCR job reports to: the status line follows each stage and
+/// the toast, when there is one, shows it with a "Cancel" action.
+#[derive(Clone)]
+pub(super) struct OcrJobFeedback {
+    pub(super) status_log: SharedStatusLog,
+    pub(super) toast: Option<ToastRuntime>,
+    pub(super) toast_duration_ms: u32,
+}
+
+impl OcrJobFeedback {
+    fn started(&self, jobs: &JobQueue, handle: &JobHandle) {
+        let message = match jobs.jobs_ahead(handle) {
+            0 => ocr_processing_status(jobs.ocr_engine_ready()).to_string(),
+            ahead => ocr_queued_status(ahead),
+        };
+        self.report(handle, message);
+    }
+
+    fn progress(&self, handle: &JobHandle, progress: &JobProgress) {
+        self.report(handle, progress.describe());
+    }
+
+    fn report(&self, handle: &JobHandle, message: String) {
+        set_status(&self.status_log, message.clone());
+        let Some(toast) = self.toast.as_ref() else {
+            return;
+        };
+        let handle = handle.clone();
+        let status_log = self.status_log.clone();
+        toast.show_with_action(message, "Cancel", OCR_PROGRESS_TOAST_MS, move || {
+            handle.cancel();
+            set_status(&status_log, "Cancelling OCR...");
+        });
+    }
+
+    fn finished(&self, cancelled: bool) {
+        if cancelled {
+            set_status(&self.status_log, "OCR cancelled");
+        }
+        if let Some(toast) = self.toast.as_ref() {
+            if cancelled {
+                toast.show("OCR cancelled", self.toast_duration_ms);
+            } else {
+                toast.dismiss();
+            }
+        }
+    }
+}
+
+pub(super) enum OcrInput {
+    Image(image::DynamicImage),
+    File(std::path::PathBuf),
+}
+
+/// Queues recognition of `input` with a snapshot of `ocr_languages`, or joins
+/// an identical request already queued under `key`. `on_done` runs on the
+/// main thread; a cancelled job ends with [`crate::ocr::OcrError::Cancelled`].
+pub(super) fn queue_ocr(
+    jobs: &JobQueue,
+    key: JobKey,
+    input: OcrInput,
+    ocr_languages: &SharedOcrLanguages,
+    feedback: OcrJobFeedback,
+    on_done: impl FnOnce(crate::ocr::OcrResult<crate::ocr::OcrDocument>) + 'static,
+) -> JobHandle {
+    let languages = ocr_languages.borrow().clone();
+    let handle = jobs.submit(
+        key,
+        move |context| {
+            let image = match input {
+                OcrInput::Image(image) => image,
+                OcrInput::File(path) => crate::ocr::open_image(&path)?,
+            };
+            context.recognize(&languages, &image)
+        },
+        {
+            let feedback = feedback.clone();
+            move |handle, progress| feedback.progress(handle, progress)
+        },
+        {
+            let feedback = feedback.clone();
+            move |outcome| match outcome {
+                JobOutcome::Finished(result) => {
+                    let cancelled = matches!(result, Err(crate::ocr::OcrError::Cancelled));
+                    feedback.finished(cancelled);
+                    on_done(result);
+                }
+                JobOutcome::Cancelled => {
+                    feedback.finished(true);
+                    on_done(Err(crate::ocr::OcrError::Cancelled));
+                }
+            }
+        },
+    );
+    feedback.started(jobs, &handle);
+    handle
+}
+
+/// Job key for recognizing a whole capture, shared by preview OCR, find and
+/// auto-redact so they reuse one run.
+pub(super) fn full_image_ocr_key(
+    capture_id: &str,
+    languages: &[crate::ocr::OcrLanguage],
+) -> JobKey {
+    JobKey::for_capture(
+        capture_id,
+        format!("ocr:{}", crate::ocr::format_ocr_languages(languages)),
+    )
+}
+
+/// Queues recognition of the whole `pixbuf` of `capture_id`; see [`queue_ocr`].
+pub(super) fn queue_full_image_ocr(
+    jobs: &JobQueue,
+    capture_id: &str,
+    pixbuf: &gtk4::gdk_pixbuf::Pixbuf,
+    ocr_languages: &SharedOcrLanguages,
+    feedback: OcrJobFeedback,
+    on_done: impl FnOnce(crate::ocr::OcrResult<crate::ocr::OcrDocument>) + 'static,
+) -> crate::ocr::OcrResult<JobHandle> {
+    let image = pixbuf_region_to_dynamic_image(
+        pixbuf,
+        0,
+        0,
+        pixbuf.width().max(0) as u32,
+        pixbuf.height().max(0) as u32,
+    )?;
+    let key = full_image_ocr_key(capture_id, &ocr_languages.borrow());
+    Ok(queue_ocr(
+        jobs,
+        key,
+        OcrInput::Image(image),
+        ocr_languages,
+        feedback,
+        on_done,
+    ))
+}
+
+/// Queues recognition of the image file at `image_path` and writes the
+/// `format` export next to it. `on_done` receives the written path.
+pub(super) fn queue_ocr_export(
+    jobs: &JobQueue,
+    image_path: std::path::PathBuf,
+    format: crate::ocr::OcrExportFormat,
+    ocr_languages: &SharedOcrLanguages,
+    feedback: OcrJobFeedback,
+    on_done: impl FnOnce(JobOutcome<Result<std::path::PathBuf, String>>) + 'static,
+) -> JobHandle {
+    let languages = ocr_languages.borrow().clone();
+    let key = JobKey::global(format!(
+        "ocr-export:{}:{}",
+        format.extension(),
+        image_path.display()
+    ));
+    let handle = jobs.submit(
+        key,
+        move |context| {
+            let image = crate::ocr::open_image(&image_path).map_err(|err| err.to_string())?;
+            let document = context
+                .recognize(&languages, &image)
+                .map_err(|err| err.to_string())?;
+            if context.is_cancelled() {
+                return Err(crate::ocr::OcrError::Cancelled.to_string());
+            }
+            crate::ocr::export::write_export(format, &document, &image, &image_path).map_err(
+                |err| {
+                    format!(
+                        "failed to write {}: {err}",
+                        format.sidecar_path(&image_path).display()
+                    )
+                },
+            )
+        },
+        {
+            let feedback = feedback.clone();
+            move |handle, progress| feedback.progress(handle, progress)
+        },
+        {
+            let feedback = feedback.clone();
+            move |outcome| {
+                feedback.finished(matches!(outcome, JobOutcome::Cancelled));
+                on_done(outcome);
+            }
+        },
+    );
+    feedback.started(jobs, &handle);
+    handle
+}
+
+/// Loads the OCR models for `ocr_languages` in the background so the first
+/// recognition does not pay for it.
+pub(super) fn queue_ocr_warm_up(jobs: &JobQueue, ocr_languages: &SharedOcrLanguages) {
+    let languages = ocr_languages.borrow().clone();
+    jobs.submit(
+        JobKey::global("ocr-warm-up"),
+        move |context| {
+            context
+                .warm_up_ocr(&languages)
+                .map_err(|err| err.to_string())
+        },
+        |_, _| {},
+        |outcome| match outcome {
+            JobOutcome::Finished(Ok(())) => tracing::debug!("OCR engine warmed up"),
+            JobOutcome::Finished(Err(err)) => tracing::warn!("OCR warm-up failed: {err}"),
+            JobOutcome::Cancelled => {}
+        },
+    );
+}
+
 /// Handle a successful OCR text result on the **main thread**: copy to
 /// clipboard, update status, and send a desktop notification.
 pub(super) fn handle_ocr_text_result(status_log: &SharedStatusLog, text: String) {
@@ -106,6 +330,34 @@ pub(super) fn handle_ocr_text_result(status_log: &SharedStatusLog, text: String)
     crate::notification::send(format!("Copied: {preview_text}"));
 }
 
+/// Blur region covering `rect` clipped to the image, or `None` when the box
+/// lies entirely outside it.
+pub(super) fn blur_region_within_image(
+    rect: crate::ocr::OcrRect,
+    image_width: i32,
+    image_height: i32,
+) -> Option<crate::editor::tools::BlurRegion> {
+    let left = rect.x.clamp(0, image_width);
+    let top = rect.y.clamp(0, image_height);
+    let right = rect.right().clamp(0, image_width);
+    let bottom = rect.bottom().clamp(0, image_height);
+    let region = crate::editor::tools::BlurRegion::new(
+        left,
+        top,
+        (right - left) as u32,
+        (bottom - top) as u32,
+    );
+    region.is_valid().then_some(region)
+}
+
+/// Status for an OCR job waiting behind `ahead` others.
+pub(super) fn ocr_queued_status(ahead: usize) -> String {
+    match ahead {
+        1 => "OCR queued behind 1 job".to_string(),
+        ahead => format!("OCR queued behind {ahead} jobs"),
+    }
+}
+
 /// Return a user-facing status message for the start of an OCR operation.
 pub(super) fn ocr_processing_status(engine_available: bool) -> &'static str {
     if engine_available {
@@ -118,8 +370,6 @@ pub(super) fn ocr_processing_status(engine_available: bool) -> &'static str {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::cell::RefCell;
-    use std::rc::Rc;
 
     #[test]
     fn ocr_processing_status_indicates_engine_state() {
@@ -127,6 +377,31 @@ mod tests {
         assert_eq!(ocr_processing_status(false), "Initializing OCR engine...");
     }
 
+    #[test]
+    fn ocr_queued_status_counts_jobs_ahead() {
+        assert_eq!(ocr_queued_status(1), "OCR queued behind 1 job");
+        assert_eq!(ocr_queued_status(3), "OCR queued behind 3 jobs");
+    }
+
+    #[test]
+    fn blur_region_within_image_clips_padded_boxes_to_image_bounds() {
+        use crate::editor::tools::BlurRegion;
+        use crate::ocr::OcrRect;
+
+        assert_eq!(
+            blur_region_within_image(OcrRect::new(-2, -2, 20, 10), 100, 50),
+            Some(BlurRegion::new(0, 0, 18, 8))
+        );
+        assert_eq!(
+            blur_region_within_image(OcrRect::new(95, 45, 20, 10), 100, 50),
+            Some(BlurRegion::new(95, 45, 5, 5))
+        );
+        assert_eq!(
+            blur_region_within_image(OcrRect::new(120, 10, 20, 10), 100, 50),
+            None
+        );
+    }
+
     #[test]
     fn handle_ocr_text_result_sets_no_text_found_for_empty_input() {
         let status_log = Rc::new(RefCell::new(String::new()));
@@ -152,7 +427,7 @@ mod tests {
         std::env::set_var("XDG_DATA_HOME", "/tmp/chalkak-test-nonexistent-dir");
         std::env::set_var("HOME", "/tmp/chalkak-test-nonexistent-home");
 
-        let result = resolve_or_init_engine(None, crate::ocr::OcrLanguage::English);
+        let result = resolve_or_init_engine(None, &[crate::ocr::OcrLanguage::English]);
 
         // Restore environment before asserting so later tests are unaffected.
         match prev_xdg {
@@ -3,9 +3,10 @@ use std::collections::HashMap;
 use std::rc::Rc;
 
 use crate::capture;
+use crate::clipboard::CopyProfile;
 use crate::input::{resolve_shortcut, InputContext, InputMode, ShortcutAction};
 use crate::preview;
-use crate::ui::{icon_button, icon_toggle_button, StyleTokens};
+use crate::ui::{icon_button, icon_menu_button, icon_toggle_button, StyleTokens};
 use gtk4::prelude::*;
 use gtk4::{
     Align, Application, ApplicationWindow, Box as GtkBox, Button, Frame, Orientation, Overflow,
@@ -16,6 +17,7 @@ use super::hover_controls::{connect_overlay_hover, set_revealer_visibility};
 use super::hypr::request_window_floating_with_geometry;
 use super::input_bridge::{normalize_shortcut_key, shortcut_modifiers};
 use super::layout::{clamp_window_geometry_to_current_monitors, compute_initial_preview_placement};
+use super::ocr_overlay::{copy_ocr_selection, PreviewOcrOverlay};
 use super::preview_pin::setup_preview_pin_toggle;
 use super::runtime_support::{
     close_all_preview_windows, close_preview_window_for_capture, PreviewWindowRuntime, ToastRuntime,
@@ -31,7 +33,9 @@ pub(super) struct PreviewRenderContext {
     status_log: Rc<RefCell<String>>,
     save_button: Button,
     copy_button: Button,
+    copy_as_buttons: Vec<Button>,
     ocr_button: Button,
+    decode_button: Button,
     open_editor_button: Button,
     close_preview_button: Button,
     delete_button: Button,
@@ -53,7 +57,9 @@ impl PreviewRenderContext {
         status_log: Rc<RefCell<String>>,
         save_button: Button,
         copy_button: Button,
+        copy_as_buttons: Vec<Button>,
         ocr_button: Button,
+        decode_button: Button,
         open_editor_button: Button,
         close_preview_button: Button,
         delete_button: Button,
@@ -72,7 +78,9 @@ impl PreviewRenderContext {
             status_log,
             save_button,
             copy_button,
+            copy_as_buttons,
             ocr_button,
+            decode_button,
             open_editor_button,
             close_preview_button,
             delete_button,
@@ -209,6 +217,7 @@ struct PreviewLaunchpadButtons {
     save_button: Button,
     copy_button: Button,
     ocr_button: Button,
+    decode_button: Button,
     open_editor_button: Button,
     close_preview_button: Button,
     delete_button: Button,
@@ -220,6 +229,7 @@ enum PreviewShortcutTarget {
     Save,
     Copy,
     Ocr,
+    Decode,
     Edit,
     Delete,
     Close,
@@ -230,6 +240,7 @@ fn preview_shortcut_target(action: ShortcutAction) -> Option<PreviewShortcutTarg
         ShortcutAction::PreviewSave => Some(PreviewShortcutTarget::Save),
         ShortcutAction::PreviewCopy => Some(PreviewShortcutTarget::Copy),
         ShortcutAction::PreviewOcr => Some(PreviewShortcutTarget::Ocr),
+        ShortcutAction::PreviewDecode => Some(PreviewShortcutTarget::Decode),
         ShortcutAction::PreviewEdit => Some(PreviewShortcutTarget::Edit),
         ShortcutAction::PreviewDelete => Some(PreviewShortcutTarget::Delete),
         ShortcutAction::PreviewClose => Some(PreviewShortcutTarget::Close),
@@ -243,6 +254,7 @@ impl PreviewLaunchpadButtons {
             save_button: context.save_button.clone(),
             copy_button: context.copy_button.clone(),
             ocr_button: context.ocr_button.clone(),
+            decode_button: context.decode_button.clone(),
             open_editor_button: context.open_editor_button.clone(),
             close_preview_button: context.close_preview_button.clone(),
             delete_button: context.delete_button.clone(),
@@ -261,6 +273,7 @@ impl PreviewLaunchpadButtons {
                     return false;
                 }
             }
+            Some(PreviewShortcutTarget::Decode) => self.decode_button.emit_clicked(),
             Some(PreviewShortcutTarget::Edit) => self.open_editor_button.emit_clicked(),
             Some(PreviewShortcutTarget::Delete) => self.delete_button.emit_clicked(),
             Some(PreviewShortcutTarget::Close) => self.close_preview_button.emit_clicked(),
@@ -278,12 +291,16 @@ struct PreviewWindowBuild {
     overlay: Overlay,
     controls_revealer: Revealer,
     preview_surface: Frame,
+    ocr_overlay: PreviewOcrOverlay,
     toast_label: gtk4::Label,
+    toast_action_button: Button,
     opacity_slider: Scale,
     copy_button: Button,
+    copy_as_buttons: Vec<Button>,
     save_button: Button,
     edit_button: Button,
     ocr_button: Button,
+    decode_button: Button,
     close_button: Button,
 }
 
@@ -292,9 +309,11 @@ struct PreviewControlsBuild {
     controls_revealer: Revealer,
     opacity_slider: Scale,
     copy_button: Button,
+    copy_as_buttons: Vec<Button>,
     save_button: Button,
     edit_button: Button,
     ocr_button: Button,
+    decode_button: Button,
     close_button: Button,
 }
 
@@ -333,6 +352,14 @@ fn build_preview_controls(
         context.style_tokens.control_size as i32,
         &["preview-icon-button"],
     );
+    let copy_as_labels = CopyProfile::ALL.map(CopyProfile::label);
+    let (preview_copy_as_button, preview_copy_as_buttons) = icon_menu_button(
+        "chevron-down-symbolic",
+        "Copy as…",
+        context.style_tokens.control_size as i32,
+        &["preview-icon-button"],
+        &copy_as_labels,
+    );
 
     let preview_save_button = icon_button(
         "save-symbolic",
@@ -360,10 +387,19 @@ fn build_preview_controls(
         preview_ocr_button.set_tooltip_text(Some("OCR models not installed"));
     }
 
+    let preview_decode_button = icon_button(
+        "qr-code-symbolic",
+        "Decode QR code or barcode",
+        context.style_tokens.control_size as i32,
+        &["preview-icon-button"],
+    );
+
     top_center_actions.append(&preview_copy_button);
+    top_center_actions.append(&preview_copy_as_button);
     top_center_actions.append(&preview_save_button);
     top_center_actions.append(&preview_edit_button);
     top_center_actions.append(&preview_ocr_button);
+    top_center_actions.append(&preview_decode_button);
 
     let preview_close_button = icon_button(
         "x-symbolic",
@@ -424,9 +460,11 @@ fn build_preview_controls(
         controls_revealer,
         opacity_slider,
         copy_button: preview_copy_button,
+        copy_as_buttons: preview_copy_as_buttons,
         save_button: preview_save_button,
         edit_button: preview_edit_button,
         ocr_button: preview_ocr_button,
+        decode_button: preview_decode_button,
         close_button: preview_close_button,
     }
 }
@@ -497,6 +535,8 @@ fn build_preview_window(
     preview_surface.set_opacity(preview_shell.borrow().transparency() as f64);
     preview_surface.set_child(Some(&preview_image));
     preview_overlay.set_child(Some(&preview_surface));
+    let ocr_overlay = PreviewOcrOverlay::new(&preview_image, artifact.width, artifact.height);
+    preview_overlay.add_overlay(&ocr_overlay.area);
     preview_overlay.add_overlay(&preview_controls.controls_revealer);
 
     let preview_toast_anchor = GtkBox::new(Orientation::Vertical, 0);
@@ -506,10 +546,17 @@ fn build_preview_window(
     preview_toast_anchor.set_margin_bottom(context.style_tokens.spacing_12);
     preview_toast_anchor.set_margin_start(context.style_tokens.spacing_12);
     preview_toast_anchor.set_margin_end(context.style_tokens.spacing_12);
+    let preview_toast_row = GtkBox::new(Orientation::Horizontal, context.style_tokens.spacing_8);
     let preview_toast_label = gtk4::Label::new(Some(""));
     preview_toast_label.add_css_class("toast-badge");
     preview_toast_label.set_visible(false);
-    preview_toast_anchor.append(&preview_toast_label);
+    let preview_toast_action_button = Button::with_label("");
+    preview_toast_action_button.add_css_class("toast-action-button");
+    preview_toast_action_button.set_valign(Align::Center);
+    preview_toast_action_button.set_visible(false);
+    preview_toast_row.append(&preview_toast_label);
+    preview_toast_row.append(&preview_toast_action_button);
+    preview_toast_anchor.append(&preview_toast_row);
     preview_overlay.add_overlay(&preview_toast_anchor);
 
     preview_window_instance.set_child(Some(&preview_overlay));
@@ -523,12 +570,16 @@ fn build_preview_window(
         overlay: preview_overlay,
         controls_revealer: preview_controls.controls_revealer,
         preview_surface,
+        ocr_overlay,
         toast_label: preview_toast_label,
+        toast_action_button: preview_toast_action_button,
         opacity_slider: preview_controls.opacity_slider,
         copy_button: preview_controls.copy_button,
+        copy_as_buttons: preview_controls.copy_as_buttons,
         save_button: preview_controls.save_button,
         edit_button: preview_controls.edit_button,
         ocr_button: preview_controls.ocr_button,
+        decode_button: preview_controls.decode_button,
         close_button: preview_controls.close_button,
     }
 }
@@ -543,17 +594,30 @@ fn connect_preview_window_action_wiring(
             (&build.save_button, &context.save_button),
             (&build.copy_button, &context.copy_button),
             (&build.ocr_button, &context.ocr_button),
+            (&build.decode_button, &context.decode_button),
             (&build.edit_button, &context.open_editor_button),
             (&build.close_button, &context.close_preview_button),
         ],
         &context.preview_action_target_capture_id,
         capture_id,
     );
+    let copy_as_bridges = build
+        .copy_as_buttons
+        .iter()
+        .zip(&context.copy_as_buttons)
+        .collect::<Vec<_>>();
+    connect_preview_action_bridges(
+        &copy_as_bridges,
+        &context.preview_action_target_capture_id,
+        capture_id,
+    );
 
     let launchpad_buttons = PreviewLaunchpadButtons::from_context(context);
     {
         let preview_action_target_capture_id = context.preview_action_target_capture_id.clone();
         let capture_id = capture_id.to_string();
+        let ocr_overlay = build.ocr_overlay.clone();
+        let status_log = context.status_log.clone();
         let key_controller = gtk4::EventControllerKey::new();
         key_controller.connect_key_pressed(move |_, key, keycode, modifier| {
             let Some(shortcut_key) = normalize_shortcut_key(key, keycode) else {
@@ -569,6 +633,16 @@ fn connect_preview_window_action_wiring(
             let Some(action) = shortcut else {
                 return gtk4::glib::Propagation::Proceed;
             };
+            match action {
+                ShortcutAction::PreviewCopyText => {
+                    copy_ocr_selection(&status_log, &ocr_overlay.state.borrow());
+                    return gtk4::glib::Propagation::Stop;
+                }
+                ShortcutAction::PreviewClose if ocr_overlay.clear_selection() => {
+                    return gtk4::glib::Propagation::Stop;
+                }
+                _ => {}
+            }
 
             *preview_action_target_capture_id.borrow_mut() = Some(capture_id.clone());
             if launchpad_buttons.emit_shortcut_action(action) {
@@ -659,7 +733,8 @@ fn create_preview_window_for_capture(
             shell: build.shell,
             preview_surface: build.preview_surface,
             controls: build.controls_revealer,
-            toast: ToastRuntime::new(&build.toast_label),
+            toast: ToastRuntime::with_action_button(&build.toast_label, &build.toast_action_button),
+            ocr_overlay: build.ocr_overlay,
             close_guard,
         },
     );
@@ -683,6 +758,10 @@ mod tests {
             preview_shortcut_target(ShortcutAction::PreviewEdit),
             Some(PreviewShortcutTarget::Edit)
         );
+        assert_eq!(
+            preview_shortcut_target(ShortcutAction::PreviewDecode),
+            Some(PreviewShortcutTarget::Decode)
+        );
         assert_eq!(
             preview_shortcut_target(ShortcutAction::PreviewDelete),
             Some(PreviewShortcutTarget::Delete)
@@ -114,6 +114,21 @@ window.floating-editor-window {{
 .icon-button:disabled:active {{
   box-shadow: none;
 }}
+menubutton.icon-button > button {{
+  min-width: {control_size}px;
+  min-height: {control_size}px;
+  padding: 0;
+  border-color: transparent;
+  background: transparent;
+  box-shadow: none;
+}}
+.menu-popover-item {{
+  padding: {spacing_4}px {spacing_8}px;
+}}
+.menu-popover-item-checked {{
+  font-weight: 700;
+  box-shadow: inset 3px 0 0 {focus_ring_color};
+}}
 .editor-toolbar button.tool-active {{
   background-image: linear-gradient(
                       rgba(0, 0, 0, 0.24),
@@ -265,6 +280,14 @@ button.stroke-chip-active {{
 .editor-zoom-slider {{
   min-width: 160px;
 }}
+.editor-find-entry {{
+  min-width: 220px;
+}}
+.editor-find-count {{
+  min-width: 72px;
+  padding: 0 {spacing_8}px;
+  opacity: 0.72;
+}}
 .editor-canvas {{
   border-radius: {panel_radius}px;
   border: {border_width}px solid {border_color};
@@ -424,6 +447,16 @@ button.launchpad-danger-button:active {{
               0 1px 3px rgba(0, 0, 0, 0.08);
 }}
 
+button.toast-action-button {{
+  border-radius: {control_radius}px;
+  border: {border_width}px solid {border_color};
+  background: {panel_background};
+  color: {text_color};
+  padding: {spacing_4}px {spacing_12}px;
+  font-size: 13px;
+  font-weight: 600;
+}}
+
 /* ── Focus visible ── */
 .chalkak-root button:focus-visible,
 .chalkak-root scale:focus-visible {{
@@ -7,5 +7,5 @@ pub(super) use session::RuntimeSession;
 pub(super) use startup::{StartupCaptureMode, StartupConfig};
 pub(super) use window_runtime::{
     close_all_preview_windows, close_editor_window_if_open, close_preview_window_for_capture,
-    show_toast_for_capture, PreviewWindowRuntime, ToastRuntime,
+    show_action_toast_for_capture, show_toast_for_capture, PreviewWindowRuntime, ToastRuntime,
 };
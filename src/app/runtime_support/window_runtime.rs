@@ -5,15 +5,20 @@ use std::time::Duration;
 
 use crate::preview;
 use gtk4::prelude::*;
-use gtk4::{ApplicationWindow, Frame, Label, Revealer};
+use gtk4::{ApplicationWindow, Button, Frame, Label, Revealer};
 
 use super::super::hypr::current_window_geometry;
 use super::super::layout::read_window_geometry;
+use super::super::ocr_overlay::PreviewOcrOverlay;
 use super::super::window_state::{RuntimeWindowGeometry, RuntimeWindowKind, RuntimeWindowState};
 
+type ToastActionHandler = Rc<RefCell<Option<Rc<dyn Fn()>>>>;
+
 #[derive(Clone)]
 pub(crate) struct ToastRuntime {
     label: Label,
+    action_button: Option<Button>,
+    action_handler: ToastActionHandler,
     sequence: Rc<Cell<u64>>,
 }
 
@@ -21,29 +26,96 @@ impl ToastRuntime {
     pub(crate) fn new(label: &Label) -> Self {
         Self {
             label: label.clone(),
+            action_button: None,
+            action_handler: Rc::new(RefCell::new(None)),
             sequence: Rc::new(Cell::new(0)),
         }
     }
 
+    pub(crate) fn with_action_button(label: &Label, action_button: &Button) -> Self {
+        let runtime = Self {
+            action_button: Some(action_button.clone()),
+            ..Self::new(label)
+        };
+        action_button.set_visible(false);
+        {
+            let runtime = runtime.clone();
+            action_button.connect_clicked(move |_| {
+                let handler = runtime.action_handler.borrow_mut().take();
+                runtime.hide();
+                if let Some(handler) = handler {
+                    handler();
+                }
+            });
+        }
+        runtime
+    }
+
     pub(crate) fn show(&self, message: impl Into<String>, duration_ms: u32) {
-        let message = message.into();
+        self.action_handler.borrow_mut().take();
+        if let Some(button) = self.action_button.as_ref() {
+            button.set_visible(false);
+        }
+        self.present(message.into(), duration_ms);
+    }
+
+    /// Shows the toast with a trailing action button. Toasts built without an
+    /// action button fall back to the plain message.
+    pub(crate) fn show_with_action(
+        &self,
+        message: impl Into<String>,
+        action_label: &str,
+        duration_ms: u32,
+        on_action: impl Fn() + 'static,
+    ) {
+        let Some(button) = self.action_button.as_ref() else {
+            self.show(message, duration_ms);
+            return;
+        };
+        button.set_label(action_label);
+        button.set_visible(true);
+        *self.action_handler.borrow_mut() = Some(Rc::new(on_action));
+        self.present(message.into(), duration_ms);
+    }
+
+    /// Whether the window hosting the toast is shown, so a message would be
+    /// seen. The launchpad toast is off screen in headless capture mode.
+    pub(crate) fn is_on_screen(&self) -> bool {
+        self.label.root().is_some_and(|root| root.is_visible())
+    }
+
+    /// Hides the toast now, dropping any pending action.
+    pub(crate) fn dismiss(&self) {
+        self.action_handler.borrow_mut().take();
+        self.sequence.set(self.sequence.get().saturating_add(1));
+        self.hide();
+    }
+
+    fn present(&self, message: String, duration_ms: u32) {
         self.label.set_text(&message);
         self.label.set_visible(true);
 
         let sequence = self.sequence.get().saturating_add(1);
         self.sequence.set(sequence);
 
-        let label = self.label.clone();
-        let latest_sequence = self.sequence.clone();
+        let runtime = self.clone();
         gtk4::glib::timeout_add_local_once(
             Duration::from_millis(u64::from(duration_ms)),
             move || {
-                if latest_sequence.get() == sequence {
-                    label.set_visible(false);
+                if runtime.sequence.get() == sequence {
+                    runtime.action_handler.borrow_mut().take();
+                    runtime.hide();
                 }
             },
         );
     }
+
+    fn hide(&self) {
+        self.label.set_visible(false);
+        if let Some(button) = self.action_button.as_ref() {
+            button.set_visible(false);
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -53,6 +125,7 @@ pub(crate) struct PreviewWindowRuntime {
     pub(crate) preview_surface: Frame,
     pub(crate) controls: Revealer,
     pub(crate) toast: ToastRuntime,
+    pub(crate) ocr_overlay: PreviewOcrOverlay,
     pub(crate) close_guard: Rc<Cell<bool>>,
 }
 
@@ -71,6 +144,23 @@ pub(crate) fn show_toast_for_capture(
     }
 }
 
+pub(crate) fn show_action_toast_for_capture(
+    preview_windows: &Rc<RefCell<HashMap<String, PreviewWindowRuntime>>>,
+    capture_id: Option<&str>,
+    fallback: &ToastRuntime,
+    message: impl Into<String>,
+    action_label: &str,
+    duration_ms: u32,
+    on_action: impl Fn() + 'static,
+) {
+    let message = message.into();
+    let windows = preview_windows.borrow();
+    let toast = capture_id
+        .and_then(|capture_id| windows.get(capture_id))
+        .map_or(fallback, |runtime| &runtime.toast);
+    toast.show_with_action(message, action_label, duration_ms, on_action);
+}
+
 pub(crate) fn close_preview_window_for_capture(
     preview_windows: &Rc<RefCell<HashMap<String, PreviewWindowRuntime>>>,
     capture_id: &str,
@@ -0,0 +1,192 @@
+//! QR code and barcode decoding for captures.
+
+use std::path::Path;
+
+use image::DynamicImage;
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum BarcodeError {
+    #[error("image load failed: {message}")]
+    ImageLoad { message: String },
+    #[error("decoding failed: {message}")]
+    Decode { message: String },
+}
+
+pub type BarcodeResult<T> = Result<T, BarcodeError>;
+
+/// Symbologies ChalKak reports. Other formats the decoder happens to find
+/// (Code 128, PDF417, …) are dropped so results stay predictable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BarcodeFormat {
+    QrCode,
+    DataMatrix,
+    Ean13,
+    Ean8,
+    UpcA,
+    UpcE,
+}
+
+impl BarcodeFormat {
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::QrCode => "QR code",
+            Self::DataMatrix => "Data Matrix",
+            Self::Ean13 => "EAN-13",
+            Self::Ean8 => "EAN-8",
+            Self::UpcA => "UPC-A",
+            Self::UpcE => "UPC-E",
+        }
+    }
+
+    fn from_rxing(format: &rxing::BarcodeFormat) -> Option<Self> {
+        match format {
+            rxing::BarcodeFormat::QR_CODE => Some(Self::QrCode),
+            rxing::BarcodeFormat::DATA_MATRIX => Some(Self::DataMatrix),
+            rxing::BarcodeFormat::EAN_13 => Some(Self::Ean13),
+            rxing::BarcodeFormat::EAN_8 => Some(Self::Ean8),
+            rxing::BarcodeFormat::UPC_A => Some(Self::UpcA),
+            rxing::BarcodeFormat::UPC_E => Some(Self::UpcE),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedBarcode {
+    pub format: BarcodeFormat,
+    pub text: String,
+}
+
+impl DecodedBarcode {
+    /// The payload as an `http(s)` link, or `None` for any other content.
+    pub fn url(&self) -> Option<&str> {
+        web_url(&self.text)
+    }
+}
+
+fn web_url(text: &str) -> Option<&str> {
+    let text = text.trim();
+    if text.chars().any(char::is_whitespace) {
+        return None;
+    }
+    ["https://", "http://"]
+        .into_iter()
+        .any(|scheme| {
+            text.len() > scheme.len()
+                && text
+                    .get(..scheme.len())
+                    .is_some_and(|prefix| prefix.eq_ignore_ascii_case(scheme))
+        })
+        .then_some(text)
+}
+
+/// Decodes every supported code in `image`. An image without codes yields an
+/// empty list rather than an error.
+pub fn decode_image(image: &DynamicImage) -> BarcodeResult<Vec<DecodedBarcode>> {
+    let luma = image.to_luma8();
+    let (width, height) = luma.dimensions();
+    let results = match rxing::helpers::detect_multiple_in_luma(luma.into_raw(), width, height) {
+        Ok(results) => results,
+        Err(rxing::Exceptions::NotFoundException(_)) => return Ok(Vec::new()),
+        Err(err) => {
+            return Err(BarcodeError::Decode {
+                message: err.to_string(),
+            })
+        }
+    };
+    Ok(collect_decoded(results.iter().filter_map(|result| {
+        BarcodeFormat::from_rxing(result.getBarcodeFormat())
+            .map(|format| (format, result.getText()))
+    })))
+}
+
+pub fn decode_image_file(path: &Path) -> BarcodeResult<Vec<DecodedBarcode>> {
+    let image = image::open(path).map_err(|err| BarcodeError::ImageLoad {
+        message: format!("{}: {err}", path.display()),
+    })?;
+    decode_image(&image)
+}
+
+/// Drops empty payloads and repeats of the same code, keeping decoder order.
+fn collect_decoded<'a>(
+    found: impl IntoIterator<Item = (BarcodeFormat, &'a str)>,
+) -> Vec<DecodedBarcode> {
+    let mut decoded: Vec<DecodedBarcode> = Vec::new();
+    for (format, text) in found {
+        if text.is_empty()
+            || decoded
+                .iter()
+                .any(|existing| existing.format == format && existing.text == text)
+        {
+            continue;
+        }
+        decoded.push(DecodedBarcode {
+            format,
+            text: text.to_string(),
+        });
+    }
+    decoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decoded(format: BarcodeFormat, text: &str) -> DecodedBarcode {
+        DecodedBarcode {
+            format,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn url_accepts_only_http_links_without_whitespace() {
+        assert_eq!(
+            decoded(BarcodeFormat::QrCode, " HTTPS://example.com/a?b=1 \n").url(),
+            Some("HTTPS://example.com/a?b=1")
+        );
+        assert_eq!(
+            decoded(BarcodeFormat::QrCode, "http://example.com").url(),
+            Some("http://example.com")
+        );
+        assert_eq!(decoded(BarcodeFormat::QrCode, "https://").url(), None);
+        assert_eq!(
+            decoded(BarcodeFormat::QrCode, "https://example.com and more").url(),
+            None
+        );
+        assert_eq!(
+            decoded(BarcodeFormat::QrCode, "WIFI:T:WPA;S:home;P:secret;;").url(),
+            None
+        );
+        assert_eq!(decoded(BarcodeFormat::Ean13, "4006381333931").url(), None);
+    }
+
+    #[test]
+    fn collect_decoded_drops_empty_and_repeated_payloads() {
+        let collected = collect_decoded([
+            (BarcodeFormat::QrCode, "https://example.com"),
+            (BarcodeFormat::Ean13, "4006381333931"),
+            (BarcodeFormat::QrCode, ""),
+            (BarcodeFormat::QrCode, "https://example.com"),
+            (BarcodeFormat::DataMatrix, "https://example.com"),
+        ]);
+
+        assert_eq!(
+            collected,
+            vec![
+                decoded(BarcodeFormat::QrCode, "https://example.com"),
+                decoded(BarcodeFormat::Ean13, "4006381333931"),
+                decoded(BarcodeFormat::DataMatrix, "https://example.com"),
+            ]
+        );
+    }
+
+    #[test]
+    fn decode_image_returns_empty_list_for_blank_image() {
+        let image = DynamicImage::new_luma8(64, 64);
+
+        let decoded = decode_image(&image).expect("blank image should not be an error");
+
+        assert!(decoded.is_empty());
+    }
+}
@@ -0,0 +1,88 @@
+//! Standard (RFC 4648) base64 with padding, for embedding binary payloads in
+//! text formats such as data URIs and JSON.
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub(crate) fn encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied().unwrap_or(0);
+        let b2 = chunk.get(2).copied().unwrap_or(0);
+        let triple = (u32::from(b0) << 16) | (u32::from(b1) << 8) | u32::from(b2);
+        encoded.push(ALPHABET[(triple >> 18) as usize & 0x3F] as char);
+        encoded.push(ALPHABET[(triple >> 12) as usize & 0x3F] as char);
+        encoded.push(if chunk.len() > 1 {
+            ALPHABET[(triple >> 6) as usize & 0x3F] as char
+        } else {
+            '='
+        });
+        encoded.push(if chunk.len() > 2 {
+            ALPHABET[triple as usize & 0x3F] as char
+        } else {
+            '='
+        });
+    }
+    encoded
+}
+
+/// Decodes padded base64. Returns `None` for any character outside the
+/// alphabet or a length that is not a multiple of four.
+pub(crate) fn decode(text: &str) -> Option<Vec<u8>> {
+    let bytes = text.as_bytes();
+    if !bytes.len().is_multiple_of(4) {
+        return None;
+    }
+
+    let mut decoded = Vec::with_capacity(bytes.len() / 4 * 3);
+    for chunk in bytes.chunks(4) {
+        let padding = chunk.iter().rev().take_while(|byte| **byte == b'=').count();
+        if padding > 2 {
+            return None;
+        }
+        let mut triple = 0u32;
+        for (index, byte) in chunk.iter().enumerate() {
+            let value = if index >= 4 - padding {
+                0
+            } else {
+                ALPHABET.iter().position(|candidate| candidate == byte)? as u32
+            };
+            triple = (triple << 6) | value;
+        }
+        decoded.push((triple >> 16) as u8);
+        if padding < 2 {
+            decoded.push((triple >> 8) as u8);
+        }
+        if padding < 1 {
+            decoded.push(triple as u8);
+        }
+    }
+    Some(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_matches_rfc4648_vectors() {
+        assert_eq!(encode(b""), "");
+        assert_eq!(encode(b"f"), "Zg==");
+        assert_eq!(encode(b"fo"), "Zm8=");
+        assert_eq!(encode(b"foo"), "Zm9v");
+        assert_eq!(encode(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn decode_reverses_encode_and_rejects_malformed_input() {
+        for sample in [&b""[..], b"f", b"fo", b"foo", b"foob", b"fooba", b"foobar"] {
+            assert_eq!(decode(&encode(sample)).as_deref(), Some(sample));
+        }
+        let binary: Vec<u8> = (0..=255).collect();
+        assert_eq!(decode(&encode(&binary)), Some(binary));
+
+        assert_eq!(decode("Zm9"), None);
+        assert_eq!(decode("Zm9*"), None);
+        assert_eq!(decode("Z==="), None);
+    }
+}
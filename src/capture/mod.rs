@@ -8,11 +8,12 @@ use self::hyprland::{
 };
 use crate::storage::create_temp_capture;
 use image::GenericImageView;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 mod hyprland;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CaptureArtifact {
     pub capture_id: String,
     pub temp_path: PathBuf,
@@ -6,11 +6,18 @@ use gtk4::gdk::prelude::*;
 use gtk4::glib;
 use thiserror::Error;
 
+mod profile;
+
+use profile::{data_uri, encode_jpeg, html_img_payload, markdown_image_payload, JPEG_QUALITY};
+pub use profile::{parse_copy_profile, resolve_copy_profile, CopyProfile};
+
 const MIME_TEXT_URI_LIST: &str = "text/uri-list";
 const MIME_GNOME_COPIED_FILES: &str = "x-special/gnome-copied-files";
 const MIME_TEXT_PLAIN: &str = "text/plain";
 const MIME_TEXT_PLAIN_UTF8: &str = "text/plain;charset=utf-8";
 const MIME_IMAGE_PNG: &str = "image/png";
+const MIME_IMAGE_JPEG: &str = "image/jpeg";
+const MIME_TEXT_HTML: &str = "text/html";
 
 #[derive(Debug, Error)]
 pub enum ClipboardError {
@@ -32,6 +39,12 @@ pub enum ClipboardError {
         #[source]
         source: io::Error,
     },
+    #[error("failed to encode {path} as JPEG: {source}")]
+    EncodeImage {
+        path: PathBuf,
+        #[source]
+        source: image::ImageError,
+    },
     #[error("failed to access default display for clipboard operations")]
     DisplayUnavailable,
     #[error("failed to set clipboard content: {source}")]
@@ -45,29 +58,43 @@ pub type ClipboardResult<T> = std::result::Result<T, ClipboardError>;
 
 pub trait ClipboardBackend {
     fn copy(&self, path: &Path) -> ClipboardResult<()>;
+
+    /// Copies with an explicit payload profile. Backends that only offer a
+    /// single payload fall back to [`ClipboardBackend::copy`].
+    fn copy_as(&self, path: &Path, profile: CopyProfile) -> ClipboardResult<()> {
+        let _ = profile;
+        self.copy(path)
+    }
 }
 
 #[derive(Debug, Default)]
-pub struct WlCopyBackend;
+pub struct WlCopyBackend {
+    profile: CopyProfile,
+}
+
+impl WlCopyBackend {
+    /// Backend whose plain [`ClipboardBackend::copy`] uses `profile`.
+    pub const fn with_profile(profile: CopyProfile) -> Self {
+        Self { profile }
+    }
+}
 
 fn uri_list_payload(path: &Path) -> ClipboardResult<String> {
-    let absolute_path = resolve_absolute_path(path)?;
-    let uri =
-        glib::filename_to_uri(&absolute_path, None).map_err(|err| ClipboardError::PathToUri {
-            path: absolute_path.clone(),
-            source: err,
-        })?;
-    Ok(format!("{uri}\r\n"))
+    Ok(format!("{}\r\n", file_uri(path)?))
 }
 
 fn gnome_copied_files_payload(path: &Path) -> ClipboardResult<String> {
+    Ok(format!("copy\n{}", file_uri(path)?))
+}
+
+fn file_uri(path: &Path) -> ClipboardResult<String> {
     let absolute_path = resolve_absolute_path(path)?;
-    let uri =
-        glib::filename_to_uri(&absolute_path, None).map_err(|err| ClipboardError::PathToUri {
-            path: absolute_path.clone(),
+    glib::filename_to_uri(&absolute_path, None)
+        .map(|uri| uri.to_string())
+        .map_err(|err| ClipboardError::PathToUri {
+            path: absolute_path,
             source: err,
-        })?;
-    Ok(format!("copy\n{uri}"))
+        })
 }
 
 fn plain_text_path_payload(path: &Path) -> ClipboardResult<String> {
@@ -93,49 +120,112 @@ fn resolve_absolute_path(path: &Path) -> ClipboardResult<PathBuf> {
         })
 }
 
+fn is_jpeg_path(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("jpg") || ext.eq_ignore_ascii_case("jpeg"))
+}
+
+fn read_image_bytes(path: &Path) -> ClipboardResult<Vec<u8>> {
+    std::fs::read(path).map_err(|source| ClipboardError::ReadFile {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn image_alt_text(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "capture".to_string())
+}
+
+/// Builds the `(mime type, bytes)` pairs advertised for `profile`, in the
+/// order they are offered to the compositor.
+fn clipboard_payloads(
+    path: &Path,
+    profile: CopyProfile,
+) -> ClipboardResult<Vec<(&'static str, Vec<u8>)>> {
+    let absolute_path = resolve_absolute_path(path)?;
+    let alt = image_alt_text(&absolute_path);
+    let text_payload = match profile {
+        CopyProfile::Markdown => markdown_image_payload(&alt, &file_uri(path)?),
+        _ => plain_text_path_payload(path)?,
+    };
+    let mut payloads = vec![
+        (
+            MIME_GNOME_COPIED_FILES,
+            gnome_copied_files_payload(path)?.into_bytes(),
+        ),
+        (MIME_TEXT_URI_LIST, uri_list_payload(path)?.into_bytes()),
+        (MIME_TEXT_PLAIN_UTF8, text_payload.clone().into_bytes()),
+        (MIME_TEXT_PLAIN, text_payload.into_bytes()),
+    ];
+
+    match profile {
+        CopyProfile::Standard => {
+            if is_png_path(&absolute_path) {
+                payloads.push((MIME_IMAGE_PNG, read_image_bytes(&absolute_path)?));
+            }
+        }
+        CopyProfile::HtmlDataUri => {
+            let image_mime = if is_png_path(&absolute_path) {
+                Some(MIME_IMAGE_PNG)
+            } else if is_jpeg_path(&absolute_path) {
+                Some(MIME_IMAGE_JPEG)
+            } else {
+                None
+            };
+            if let Some(image_mime) = image_mime {
+                let image_bytes = read_image_bytes(&absolute_path)?;
+                let html = html_img_payload(&data_uri(image_mime, &image_bytes), &alt);
+                payloads.push((MIME_TEXT_HTML, html.into_bytes()));
+                payloads.push((image_mime, image_bytes));
+            } else {
+                let html = html_img_payload(&file_uri(path)?, &alt);
+                payloads.push((MIME_TEXT_HTML, html.into_bytes()));
+            }
+        }
+        CopyProfile::HtmlPath => {
+            let html = html_img_payload(&file_uri(path)?, &alt);
+            payloads.push((MIME_TEXT_HTML, html.into_bytes()));
+            if is_png_path(&absolute_path) {
+                payloads.push((MIME_IMAGE_PNG, read_image_bytes(&absolute_path)?));
+            }
+        }
+        // Markdown editors prefer an image payload over text, so the image is
+        // left out to make the link win.
+        CopyProfile::Markdown => {}
+        CopyProfile::Jpeg => {
+            let image_bytes = read_image_bytes(&absolute_path)?;
+            let jpeg_bytes = encode_jpeg(&image_bytes, JPEG_QUALITY).map_err(|source| {
+                ClipboardError::EncodeImage {
+                    path: absolute_path.clone(),
+                    source,
+                }
+            })?;
+            payloads.push((MIME_IMAGE_JPEG, jpeg_bytes));
+        }
+    }
+
+    Ok(payloads)
+}
+
 impl ClipboardBackend for WlCopyBackend {
     fn copy(&self, path: &Path) -> ClipboardResult<()> {
-        let absolute_path = resolve_absolute_path(path)?;
-        let uri_list_payload = uri_list_payload(path)?;
-        let gnome_payload = gnome_copied_files_payload(path)?;
-        let text_path_payload = plain_text_path_payload(path)?;
+        self.copy_as(path, self.profile)
+    }
+
+    fn copy_as(&self, path: &Path, profile: CopyProfile) -> ClipboardResult<()> {
+        let payloads = clipboard_payloads(path, profile)?;
         let display = gdk::Display::default().ok_or(ClipboardError::DisplayUnavailable)?;
         let clipboard = display.clipboard();
 
-        let gnome_provider = gdk::ContentProvider::for_bytes(
-            MIME_GNOME_COPIED_FILES,
-            &glib::Bytes::from_owned(gnome_payload.into_bytes()),
-        );
-        let uri_provider = gdk::ContentProvider::for_bytes(
-            MIME_TEXT_URI_LIST,
-            &glib::Bytes::from_owned(uri_list_payload.clone().into_bytes()),
-        );
-        let text_provider = gdk::ContentProvider::for_bytes(
-            MIME_TEXT_PLAIN_UTF8,
-            &glib::Bytes::from_owned(text_path_payload.clone().into_bytes()),
-        );
-        let text_plain_provider = gdk::ContentProvider::for_bytes(
-            MIME_TEXT_PLAIN,
-            &glib::Bytes::from_owned(text_path_payload.into_bytes()),
-        );
-        let mut providers = vec![
-            gnome_provider,
-            uri_provider,
-            text_provider,
-            text_plain_provider,
-        ];
-        if is_png_path(&absolute_path) {
-            let image_bytes =
-                std::fs::read(&absolute_path).map_err(|source| ClipboardError::ReadFile {
-                    path: absolute_path,
-                    source,
-                })?;
-            let image_provider = gdk::ContentProvider::for_bytes(
-                MIME_IMAGE_PNG,
-                &glib::Bytes::from_owned(image_bytes),
-            );
-            providers.push(image_provider);
-        }
+        let providers = payloads
+            .into_iter()
+            .map(|(mime_type, bytes)| {
+                gdk::ContentProvider::for_bytes(mime_type, &glib::Bytes::from_owned(bytes))
+            })
+            .collect::<Vec<_>>();
         let provider = gdk::ContentProvider::new_union(&providers);
         clipboard
             .set_content(Some(&provider))
@@ -198,6 +288,83 @@ mod tests {
         let _ = std::fs::remove_file(file_path);
     }
 
+    fn payload_mime_types(payloads: &[(&'static str, Vec<u8>)]) -> Vec<&'static str> {
+        payloads.iter().map(|(mime_type, _)| *mime_type).collect()
+    }
+
+    fn payload_text<'a>(payloads: &'a [(&'static str, Vec<u8>)], mime_type: &str) -> &'a str {
+        let (_, bytes) = payloads
+            .iter()
+            .find(|(candidate, _)| *candidate == mime_type)
+            .expect("payload present");
+        std::str::from_utf8(bytes).expect("utf-8 payload")
+    }
+
+    fn write_test_png(name: &str) -> PathBuf {
+        let file_path = env::temp_dir().join(name);
+        image::RgbaImage::from_pixel(2, 2, image::Rgba([1, 2, 3, 255]))
+            .save(&file_path)
+            .expect("write png");
+        file_path
+    }
+
+    #[test]
+    fn standard_profile_offers_file_references_and_png() {
+        let file_path = write_test_png("chalkak-profile-standard.png");
+        let payloads = clipboard_payloads(&file_path, CopyProfile::Standard).expect("payloads");
+        assert_eq!(
+            payload_mime_types(&payloads),
+            vec![
+                MIME_GNOME_COPIED_FILES,
+                MIME_TEXT_URI_LIST,
+                MIME_TEXT_PLAIN_UTF8,
+                MIME_TEXT_PLAIN,
+                MIME_IMAGE_PNG,
+            ]
+        );
+        let _ = std::fs::remove_file(file_path);
+    }
+
+    #[test]
+    fn html_data_uri_profile_embeds_png_as_base64() {
+        let file_path = write_test_png("chalkak-profile-html.png");
+        let payloads = clipboard_payloads(&file_path, CopyProfile::HtmlDataUri).expect("payloads");
+        let html = payload_text(&payloads, MIME_TEXT_HTML);
+        assert!(html.starts_with("<img src=\"data:image/png;base64,"));
+        assert!(html.ends_with("alt=\"chalkak-profile-html\">"));
+        assert!(payload_mime_types(&payloads).contains(&MIME_IMAGE_PNG));
+        let _ = std::fs::remove_file(file_path);
+    }
+
+    #[test]
+    fn html_path_profile_points_img_at_file_uri() {
+        let file_path = write_test_png("chalkak-profile-html-path.png");
+        let payloads = clipboard_payloads(&file_path, CopyProfile::HtmlPath).expect("payloads");
+        let html = payload_text(&payloads, MIME_TEXT_HTML);
+        assert!(html.starts_with("<img src=\"file://"));
+        let _ = std::fs::remove_file(file_path);
+    }
+
+    #[test]
+    fn markdown_profile_replaces_plain_text_and_omits_image() {
+        let file_path = write_test_png("chalkak-profile-markdown.png");
+        let payloads = clipboard_payloads(&file_path, CopyProfile::Markdown).expect("payloads");
+        let text = payload_text(&payloads, MIME_TEXT_PLAIN);
+        assert!(text.starts_with("![chalkak-profile-markdown](file://"));
+        assert!(!payload_mime_types(&payloads).contains(&MIME_IMAGE_PNG));
+        let _ = std::fs::remove_file(file_path);
+    }
+
+    #[test]
+    fn jpeg_profile_offers_jpeg_instead_of_png() {
+        let file_path = write_test_png("chalkak-profile-jpeg.png");
+        let payloads = clipboard_payloads(&file_path, CopyProfile::Jpeg).expect("payloads");
+        let mime_types = payload_mime_types(&payloads);
+        assert!(mime_types.contains(&MIME_IMAGE_JPEG));
+        assert!(!mime_types.contains(&MIME_IMAGE_PNG));
+        let _ = std::fs::remove_file(file_path);
+    }
+
     #[test]
     fn is_png_path_detects_case_insensitive_png_extension() {
         assert!(is_png_path(Path::new("/tmp/capture.PNG")));
@@ -0,0 +1,184 @@
+use std::io::Cursor;
+
+/// Payload bundle advertised when a capture is copied.
+///
+/// Every profile except [`CopyProfile::Markdown`] keeps the file-reference
+/// payloads so file managers still receive a pasteable file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CopyProfile {
+    /// PNG image plus file references.
+    #[default]
+    Standard,
+    /// Standard payloads plus `text/html` with an inline data-URI `<img>`.
+    HtmlDataUri,
+    /// Standard payloads plus `text/html` with an `<img>` pointing at the file.
+    HtmlPath,
+    /// Markdown image link as plain text, for Markdown editors and chat tools.
+    Markdown,
+    /// JPEG re-encode plus file references, for apps that refuse large PNGs.
+    Jpeg,
+}
+
+impl CopyProfile {
+    pub const ALL: [Self; 5] = [
+        Self::Standard,
+        Self::HtmlDataUri,
+        Self::HtmlPath,
+        Self::Markdown,
+        Self::Jpeg,
+    ];
+
+    /// Config string used in `config.json`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Standard => "standard",
+            Self::HtmlDataUri => "html_data_uri",
+            Self::HtmlPath => "html_path",
+            Self::Markdown => "markdown",
+            Self::Jpeg => "jpeg",
+        }
+    }
+
+    /// Label shown in "Copy as…" menus.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Standard => "Image (PNG)",
+            Self::HtmlDataUri => "HTML (embedded image)",
+            Self::HtmlPath => "HTML (file path)",
+            Self::Markdown => "Markdown link",
+            Self::Jpeg => "Image (JPEG)",
+        }
+    }
+}
+
+/// Parse a config string into a [`CopyProfile`]. Returns `None` for
+/// unrecognised values.
+pub fn parse_copy_profile(value: &str) -> Option<CopyProfile> {
+    match value.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+        "standard" | "png" | "image" => Some(CopyProfile::Standard),
+        "html_data_uri" | "html" | "data_uri" => Some(CopyProfile::HtmlDataUri),
+        "html_path" => Some(CopyProfile::HtmlPath),
+        "markdown" | "md" => Some(CopyProfile::Markdown),
+        "jpeg" | "jpg" => Some(CopyProfile::Jpeg),
+        _ => None,
+    }
+}
+
+/// Resolve the default copy profile from an optional user config value.
+/// Falls back to [`CopyProfile::Standard`] when absent or invalid.
+pub fn resolve_copy_profile(config_value: Option<&str>) -> CopyProfile {
+    let Some(value) = config_value else {
+        return CopyProfile::default();
+    };
+    parse_copy_profile(value).unwrap_or_else(|| {
+        tracing::warn!(value, "unknown copy_profile in config.json; using standard");
+        CopyProfile::default()
+    })
+}
+
+pub(super) const JPEG_QUALITY: u8 = 90;
+
+pub(super) fn html_img_payload(src: &str, alt: &str) -> String {
+    format!(
+        "<img src=\"{}\" alt=\"{}\">",
+        escape_html_attribute(src),
+        escape_html_attribute(alt)
+    )
+}
+
+pub(super) fn markdown_image_payload(alt: &str, target: &str) -> String {
+    let alt = alt.replace('[', "\\[").replace(']', "\\]");
+    let target = target.replace('(', "%28").replace(')', "%29");
+    format!("![{alt}]({target})")
+}
+
+pub(super) fn data_uri(mime_type: &str, bytes: &[u8]) -> String {
+    format!("data:{mime_type};base64,{}", crate::base64::encode(bytes))
+}
+
+/// Re-encodes an image as JPEG. Transparency is flattened since JPEG has no
+/// alpha channel.
+pub(super) fn encode_jpeg(image_bytes: &[u8], quality: u8) -> image::ImageResult<Vec<u8>> {
+    let rgb = image::load_from_memory(image_bytes)?.to_rgb8();
+    let mut output = Cursor::new(Vec::new());
+    let encoder = image::codecs::jpeg::JpegEncoder::new_with_quality(&mut output, quality);
+    rgb.write_with_encoder(encoder)?;
+    Ok(output.into_inner())
+}
+
+fn escape_html_attribute(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_copy_profile_accepts_aliases() {
+        assert_eq!(parse_copy_profile("png"), Some(CopyProfile::Standard));
+        assert_eq!(parse_copy_profile("HTML"), Some(CopyProfile::HtmlDataUri));
+        assert_eq!(parse_copy_profile("html-path"), Some(CopyProfile::HtmlPath));
+        assert_eq!(parse_copy_profile("md"), Some(CopyProfile::Markdown));
+        assert_eq!(parse_copy_profile("jpg"), Some(CopyProfile::Jpeg));
+        assert_eq!(parse_copy_profile("gif"), None);
+    }
+
+    #[test]
+    fn parse_copy_profile_round_trips_config_strings() {
+        for profile in CopyProfile::ALL {
+            assert_eq!(parse_copy_profile(profile.as_str()), Some(profile));
+        }
+    }
+
+    #[test]
+    fn resolve_copy_profile_falls_back_to_standard() {
+        assert_eq!(resolve_copy_profile(None), CopyProfile::Standard);
+        assert_eq!(resolve_copy_profile(Some("bogus")), CopyProfile::Standard);
+        assert_eq!(resolve_copy_profile(Some("jpeg")), CopyProfile::Jpeg);
+    }
+
+    #[test]
+    fn html_img_payload_escapes_attribute_values() {
+        assert_eq!(
+            html_img_payload("file:///tmp/a\"b.png", "<capture>"),
+            "<img src=\"file:///tmp/a&quot;b.png\" alt=\"&lt;capture&gt;\">"
+        );
+    }
+
+    #[test]
+    fn markdown_image_payload_escapes_link_delimiters() {
+        assert_eq!(
+            markdown_image_payload("shot [1]", "file:///tmp/shot(1).png"),
+            "![shot \\[1\\]](file:///tmp/shot%281%29.png)"
+        );
+    }
+
+    #[test]
+    fn data_uri_prefixes_mime_type() {
+        assert_eq!(data_uri("image/png", b"foo"), "data:image/png;base64,Zm9v");
+    }
+
+    #[test]
+    fn encode_jpeg_produces_jpeg_signature() {
+        let image = image::RgbaImage::from_pixel(4, 4, image::Rgba([10, 20, 30, 128]));
+        let mut png = Cursor::new(Vec::new());
+        image
+            .write_to(&mut png, image::ImageFormat::Png)
+            .expect("encode png");
+
+        let jpeg = encode_jpeg(png.get_ref(), JPEG_QUALITY).expect("encode jpeg");
+
+        assert_eq!(&jpeg[..3], &[0xFF, 0xD8, 0xFF]);
+    }
+}
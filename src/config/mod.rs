@@ -14,7 +14,54 @@ const APP_CONFIG_FILE: &str = "config.json";
 #[derive(Debug, Clone, Default, Deserialize)]
 pub(crate) struct AppConfig {
     #[serde(default)]
-    pub(crate) ocr_language: Option<String>,
+    pub(crate) ocr_language: Option<OcrLanguageConfig>,
+    #[serde(default)]
+    pub(crate) copy_profile: Option<String>,
+    #[serde(default)]
+    pub(crate) auto_redact: Option<AutoRedactConfig>,
+    /// Redaction mode new blur regions start in, e.g. `"secure"`.
+    #[serde(default)]
+    pub(crate) blur_mode: Option<String>,
+}
+
+/// `ocr_language`: one name (`"ko"`), a comma-separated list (`"ko,en"`) or
+/// an array (`["ko", "en"]`), in preference order.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum OcrLanguageConfig {
+    One(String),
+    List(Vec<String>),
+}
+
+impl OcrLanguageConfig {
+    pub(crate) fn names(&self) -> Vec<&str> {
+        let names: Vec<&str> = match self {
+            Self::One(value) => value.split(',').collect(),
+            Self::List(values) => values.iter().map(String::as_str).collect(),
+        };
+        names
+            .into_iter()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .collect()
+    }
+}
+
+/// `auto_redact` section: which built-in patterns run and any extra
+/// user-supplied ones.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(crate) struct AutoRedactConfig {
+    /// Built-in pattern names; `None` enables all of them.
+    #[serde(default)]
+    pub(crate) builtin: Option<Vec<String>>,
+    #[serde(default)]
+    pub(crate) custom: Vec<CustomRedactPattern>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct CustomRedactPattern {
+    pub(crate) name: String,
+    pub(crate) pattern: String,
 }
 
 pub(crate) fn load_app_config() -> AppConfig {
@@ -77,6 +124,31 @@ fn config_root(
 mod tests {
     use super::*;
 
+    #[test]
+    fn ocr_language_config_accepts_string_list_and_array() {
+        let names = |json: &str| {
+            let config: AppConfig = serde_json::from_str(json).expect("config should parse");
+            config
+                .ocr_language
+                .map(|languages| {
+                    languages
+                        .names()
+                        .into_iter()
+                        .map(str::to_string)
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        };
+
+        assert_eq!(names(r#"{"ocr_language": "ko"}"#), vec!["ko"]);
+        assert_eq!(names(r#"{"ocr_language": "ko, en"}"#), vec!["ko", "en"]);
+        assert_eq!(
+            names(r#"{"ocr_language": ["en", " ru ", ""]}"#),
+            vec!["en", "ru"]
+        );
+        assert!(names("{}").is_empty());
+    }
+
     #[test]
     fn app_config_path_prefers_xdg_config_home() {
         let path = app_config_path(
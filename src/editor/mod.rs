@@ -2,7 +2,7 @@
 
 pub mod tools;
 
-use crate::clipboard::ClipboardError;
+use crate::clipboard::{ClipboardError, CopyProfile};
 use crate::storage::StorageError;
 use thiserror::Error;
 
@@ -25,6 +25,8 @@ pub struct EditorInputMode {
 pub enum EditorAction {
     Save,
     Copy,
+    /// Copy with an explicit payload profile picked from the "Copy as…" menu.
+    CopyAs(CopyProfile),
     CloseRequested,
 }
 
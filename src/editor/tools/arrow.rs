@@ -1,10 +1,13 @@
-use super::{Color, ToolPoint};
+use super::{Color, LineDash, ToolPoint};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ArrowOptions {
     pub color: Color,
     pub thickness: u8,
     pub head_size: u8,
+    #[serde(default)]
+    pub dash: LineDash,
 }
 
 impl Default for ArrowOptions {
@@ -13,6 +16,7 @@ impl Default for ArrowOptions {
             color: Color::new(0, 0, 0),
             thickness: 3,
             head_size: 8,
+            dash: LineDash::Solid,
         }
     }
 }
@@ -29,9 +33,13 @@ impl ArrowOptions {
     pub fn set_head_size(&mut self, head_size: u8) {
         self.head_size = clamp_u8_range(head_size, 1, 255);
     }
+
+    pub fn set_dash(&mut self, dash: LineDash) {
+        self.dash = dash;
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ArrowElement {
     pub id: u64,
     pub start: ToolPoint,
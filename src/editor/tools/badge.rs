@@ -0,0 +1,202 @@
+use super::{Color, ToolPoint};
+use serde::{Deserialize, Serialize};
+
+pub const BADGE_MIN_START: u32 = 1;
+pub const BADGE_MAX_START: u32 = 999;
+
+/// How a step badge spells its position in the sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BadgeStyle {
+    #[default]
+    Numbers,
+    Letters,
+    Roman,
+}
+
+impl BadgeStyle {
+    pub const ALL: [Self; 3] = [Self::Numbers, Self::Letters, Self::Roman];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Numbers => "1 2 3",
+            Self::Letters => "A B C",
+            Self::Roman => "I II III",
+        }
+    }
+
+    /// Spells `value`. Values the style cannot express (zero, or Roman
+    /// numerals past 3999) fall back to decimal digits.
+    pub fn format(self, value: u32) -> String {
+        match self {
+            Self::Numbers => value.to_string(),
+            Self::Letters => letters(value).unwrap_or_else(|| value.to_string()),
+            Self::Roman => roman(value).unwrap_or_else(|| value.to_string()),
+        }
+    }
+}
+
+/// Spreadsheet-style column letters: A..Z, then AA, AB and so on.
+fn letters(value: u32) -> Option<String> {
+    if value == 0 {
+        return None;
+    }
+    let mut remaining = value;
+    let mut reversed = Vec::new();
+    while remaining > 0 {
+        remaining -= 1;
+        reversed.push(char::from(b'A' + (remaining % 26) as u8));
+        remaining /= 26;
+    }
+    Some(reversed.into_iter().rev().collect())
+}
+
+fn roman(value: u32) -> Option<String> {
+    const NUMERALS: [(u32, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+    if value == 0 || value > 3999 {
+        return None;
+    }
+    let mut remaining = value;
+    let mut spelled = String::new();
+    for (amount, numeral) in NUMERALS {
+        while remaining >= amount {
+            spelled.push_str(numeral);
+            remaining -= amount;
+        }
+    }
+    Some(spelled)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BadgeOptions {
+    pub color: Color,
+    /// Badge diameter in image pixels.
+    pub size: u8,
+    pub style: BadgeStyle,
+    pub start: u32,
+}
+
+impl Default for BadgeOptions {
+    fn default() -> Self {
+        Self {
+            color: Color::new(0, 0, 0),
+            size: 28,
+            style: BadgeStyle::Numbers,
+            start: BADGE_MIN_START,
+        }
+    }
+}
+
+impl BadgeOptions {
+    pub fn set_color(&mut self, color: Color) {
+        self.color = color;
+    }
+
+    pub fn set_size(&mut self, size: u8) {
+        self.size = size.max(12);
+    }
+
+    pub fn set_style(&mut self, style: BadgeStyle) {
+        self.style = style;
+    }
+
+    pub fn set_start(&mut self, start: u32) {
+        self.start = start.clamp(BADGE_MIN_START, BADGE_MAX_START);
+    }
+
+    /// Leader target for a badge placed at `center` by a drag released at
+    /// `target`; drags that end inside the badge place it without a leader.
+    pub fn leader_for(self, center: ToolPoint, target: ToolPoint) -> Option<ToolPoint> {
+        let dx = f64::from(target.x) - f64::from(center.x);
+        let dy = f64::from(target.y) - f64::from(center.y);
+        (dx.hypot(dy) > f64::from(self.size) / 2.0).then_some(target)
+    }
+}
+
+/// A filled circle showing its step in the badge sequence, optionally with
+/// a leader arrow pointing from the badge at `leader`.
+///
+/// Badges do not store their number: it is derived from their order among
+/// the editor's badges, so deleting or reordering badges renumbers the rest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BadgeElement {
+    pub id: u64,
+    pub center: ToolPoint,
+    #[serde(default)]
+    pub leader: Option<ToolPoint>,
+    pub options: BadgeOptions,
+}
+
+impl BadgeElement {
+    pub fn new(
+        id: u64,
+        center: ToolPoint,
+        leader: Option<ToolPoint>,
+        options: BadgeOptions,
+    ) -> Self {
+        Self {
+            id,
+            center,
+            leader,
+            options,
+        }
+    }
+
+    /// Text for the badge at `index` (zero-based) in the sequence.
+    pub fn label_at(&self, index: usize) -> String {
+        let offset = u32::try_from(index).unwrap_or(u32::MAX);
+        self.options
+            .style
+            .format(self.options.start.saturating_add(offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn badge_styles_spell_sequence_values() {
+        assert_eq!(BadgeStyle::Numbers.format(12), "12");
+        assert_eq!(BadgeStyle::Letters.format(1), "A");
+        assert_eq!(BadgeStyle::Letters.format(26), "Z");
+        assert_eq!(BadgeStyle::Letters.format(27), "AA");
+        assert_eq!(BadgeStyle::Letters.format(703), "AAA");
+        assert_eq!(BadgeStyle::Roman.format(4), "IV");
+        assert_eq!(BadgeStyle::Roman.format(1994), "MCMXCIV");
+        assert_eq!(BadgeStyle::Roman.format(4000), "4000");
+    }
+
+    #[test]
+    fn badge_label_counts_from_start_value() {
+        let mut options = BadgeOptions::default();
+        options.set_start(3);
+        options.set_style(BadgeStyle::Letters);
+        let badge = BadgeElement::new(1, ToolPoint::new(0, 0), None, options);
+        assert_eq!(badge.label_at(0), "C");
+        assert_eq!(badge.label_at(2), "E");
+    }
+
+    #[test]
+    fn badge_start_is_clamped() {
+        let mut options = BadgeOptions::default();
+        options.set_start(0);
+        assert_eq!(options.start, BADGE_MIN_START);
+        options.set_start(5000);
+        assert_eq!(options.start, BADGE_MAX_START);
+    }
+}
@@ -1,6 +1,15 @@
-use super::ToolPoint;
+use super::{Color, ToolPoint};
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub const BLUR_MIN_BLOCK_SIZE: u8 = 2;
+const DEFAULT_BLOCK_SIZE: u8 = 12;
+/// Secure redaction never uses blocks finer than this, whatever the block
+/// size option says.
+const SECURE_MIN_BLOCK_SIZE: u8 = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BlurRegion {
     pub x: i32,
     pub y: i32,
@@ -23,9 +32,59 @@ impl BlurRegion {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// How a blur region hides what is underneath it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BlurMode {
+    #[default]
+    Gaussian,
+    Pixelate,
+    SolidFill,
+    /// Pixelates, then adds noise so block averages cannot be matched
+    /// against candidate text.
+    Secure,
+}
+
+impl BlurMode {
+    pub const ALL: [Self; 4] = [
+        Self::Gaussian,
+        Self::Pixelate,
+        Self::SolidFill,
+        Self::Secure,
+    ];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Gaussian => "Blur",
+            Self::Pixelate => "Pixelate",
+            Self::SolidFill => "Solid",
+            Self::Secure => "Secure",
+        }
+    }
+
+    /// Parse a config string into a [`BlurMode`]. Returns `None` for
+    /// unrecognised values.
+    pub fn from_config_name(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "gaussian" | "blur" => Some(Self::Gaussian),
+            "pixelate" | "mosaic" => Some(Self::Pixelate),
+            "solid_fill" | "solid" | "fill" => Some(Self::SolidFill),
+            "secure" => Some(Self::Secure),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BlurOptions {
     pub intensity: u8,
+    #[serde(default)]
+    pub mode: BlurMode,
+    /// Mosaic block edge in image pixels for pixelate and secure modes.
+    #[serde(default = "default_block_size")]
+    pub block_size: u8,
+    #[serde(default = "default_fill_color")]
+    pub fill_color: Color,
 }
 
 impl Default for BlurOptions {
@@ -38,12 +97,45 @@ impl BlurOptions {
     pub fn new(intensity: u8) -> Self {
         Self {
             intensity: clamp_intensity(intensity),
+            mode: BlurMode::default(),
+            block_size: DEFAULT_BLOCK_SIZE,
+            fill_color: default_fill_color(),
         }
     }
 
     pub fn set_intensity(&mut self, intensity: u8) {
         self.intensity = clamp_intensity(intensity);
     }
+
+    pub fn set_mode(&mut self, mode: BlurMode) {
+        self.mode = mode;
+    }
+
+    pub fn set_block_size(&mut self, block_size: u8) {
+        self.block_size = block_size.max(BLUR_MIN_BLOCK_SIZE);
+    }
+
+    pub fn set_fill_color(&mut self, color: Color) {
+        self.fill_color = color;
+    }
+
+    /// Block edge actually used when pixelating in the current mode.
+    pub fn mosaic_block_size(self) -> u8 {
+        let block_size = self.block_size.max(BLUR_MIN_BLOCK_SIZE);
+        if self.mode == BlurMode::Secure {
+            block_size.max(SECURE_MIN_BLOCK_SIZE)
+        } else {
+            block_size
+        }
+    }
+}
+
+const fn default_block_size() -> u8 {
+    DEFAULT_BLOCK_SIZE
+}
+
+const fn default_fill_color() -> Color {
+    Color::new(0, 0, 0)
 }
 
 const fn clamp_intensity(intensity: u8) -> u8 {
@@ -56,12 +148,16 @@ const fn clamp_intensity(intensity: u8) -> u8 {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BlurElement {
     pub id: u64,
     pub region: BlurRegion,
     pub options: BlurOptions,
     pub anchor: ToolPoint,
+    /// Seeds the secure-mode noise so every redraw and export of this
+    /// element produces the same pixels.
+    #[serde(default)]
+    pub noise_seed: u64,
 }
 
 impl BlurElement {
@@ -74,6 +170,62 @@ impl BlurElement {
                 x: region.x,
                 y: region.y,
             },
+            noise_seed: random_noise_seed(id),
         }
     }
 }
+
+/// Unpredictable per-element seed, so the noise cannot be regenerated and
+/// subtracted from an exported image.
+fn random_noise_seed(id: u64) -> u64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(id);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blur_mode_parses_config_names() {
+        assert_eq!(
+            BlurMode::from_config_name("Pixelate"),
+            Some(BlurMode::Pixelate)
+        );
+        assert_eq!(
+            BlurMode::from_config_name("mosaic"),
+            Some(BlurMode::Pixelate)
+        );
+        assert_eq!(
+            BlurMode::from_config_name("solid-fill"),
+            Some(BlurMode::SolidFill)
+        );
+        assert_eq!(
+            BlurMode::from_config_name(" secure "),
+            Some(BlurMode::Secure)
+        );
+        assert_eq!(BlurMode::from_config_name("opaque"), None);
+    }
+
+    #[test]
+    fn secure_mode_enforces_minimum_block_size() {
+        let mut options = BlurOptions::default();
+        options.set_block_size(0);
+        assert_eq!(options.block_size, BLUR_MIN_BLOCK_SIZE);
+        options.set_block_size(4);
+        assert_eq!(options.mosaic_block_size(), 4);
+        options.set_mode(BlurMode::Secure);
+        assert_eq!(options.mosaic_block_size(), SECURE_MIN_BLOCK_SIZE);
+    }
+
+    #[test]
+    fn blur_options_from_older_snapshots_default_to_gaussian() {
+        let options: BlurOptions =
+            serde_json::from_str(r#"{"intensity":40}"#).expect("legacy options should parse");
+        assert_eq!(options.intensity, 40);
+        assert_eq!(options.mode, BlurMode::Gaussian);
+        assert_eq!(options.block_size, DEFAULT_BLOCK_SIZE);
+        assert_eq!(options.fill_color, Color::new(0, 0, 0));
+    }
+}
@@ -0,0 +1,185 @@
+use super::{Color, ToolBounds, ToolPoint};
+use super::{TextElement, TextOptions};
+use serde::{Deserialize, Serialize};
+
+/// Space between the text and the bubble outline.
+pub const CALLOUT_PADDING: u32 = 10;
+pub const CALLOUT_CORNER_RADIUS: u8 = 10;
+/// Half the width of the tail where it meets the bubble.
+const TAIL_HALF_WIDTH: f64 = 10.0;
+/// Where the tail points when the bubble was placed with a plain click.
+const DEFAULT_TAIL_OFFSET: (i32, i32) = (-40, 56);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CalloutOptions {
+    /// Outline and tail color; the text keeps its own color.
+    pub color: Color,
+    pub thickness: u8,
+    pub fill: Color,
+}
+
+impl Default for CalloutOptions {
+    fn default() -> Self {
+        Self {
+            color: Color::new(0, 0, 0),
+            thickness: 2,
+            fill: Color::new(255, 255, 255),
+        }
+    }
+}
+
+impl CalloutOptions {
+    pub fn set_color(&mut self, color: Color) {
+        self.color = color;
+    }
+
+    pub fn set_thickness(&mut self, thickness: u8) {
+        self.thickness = thickness.max(1);
+    }
+
+    pub fn set_fill(&mut self, fill: Color) {
+        self.fill = fill;
+    }
+}
+
+/// A rounded speech bubble around an editable text box, with a tail whose
+/// tip stays on `tail` however the bubble moves. The text shares the
+/// callout id so the regular text editing path can address it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CalloutElement {
+    pub id: u64,
+    pub text: TextElement,
+    pub tail: ToolPoint,
+    pub options: CalloutOptions,
+}
+
+impl CalloutElement {
+    /// `text_anchor` is the top-left corner of the text inside the bubble.
+    /// When it equals `tail` (a click rather than a drag) the tail gets a
+    /// default offset so it stays visible.
+    pub fn new(
+        id: u64,
+        tail: ToolPoint,
+        text_anchor: ToolPoint,
+        text_options: TextOptions,
+        options: CalloutOptions,
+    ) -> Self {
+        let tail = if tail == text_anchor {
+            ToolPoint::new(
+                tail.x.saturating_add(DEFAULT_TAIL_OFFSET.0),
+                tail.y.saturating_add(DEFAULT_TAIL_OFFSET.1),
+            )
+        } else {
+            tail
+        };
+        Self {
+            id,
+            text: TextElement::new(id, text_anchor, text_options),
+            tail,
+            options,
+        }
+    }
+
+    /// Bubble around a text box of `text_size`; the size comes from font
+    /// measurement, which only the renderer can do.
+    pub fn bubble_bounds(&self, text_size: (u32, u32)) -> ToolBounds {
+        let padding = i32::try_from(CALLOUT_PADDING).unwrap_or(0);
+        ToolBounds::new(
+            self.text.x.saturating_sub(padding),
+            self.text.y.saturating_sub(padding),
+            text_size.0.saturating_add(CALLOUT_PADDING * 2),
+            text_size.1.saturating_add(CALLOUT_PADDING * 2),
+        )
+    }
+
+    /// The two points where the tail leaves `bubble`, on the side facing the
+    /// tip, or `None` when the tip sits inside the bubble.
+    pub fn tail_base(&self, bubble: ToolBounds) -> Option<[ToolPoint; 2]> {
+        let left = f64::from(bubble.x);
+        let top = f64::from(bubble.y);
+        let width = f64::from(bubble.width);
+        let height = f64::from(bubble.height);
+        let (tip_x, tip_y) = (f64::from(self.tail.x), f64::from(self.tail.y));
+        if tip_x >= left && tip_x <= left + width && tip_y >= top && tip_y <= top + height {
+            return None;
+        }
+        let center_x = left + width / 2.0;
+        let center_y = top + height / 2.0;
+        let delta_x = tip_x - center_x;
+        let delta_y = tip_y - center_y;
+        let radius = f64::from(CALLOUT_CORNER_RADIUS);
+        let point = |x: f64, y: f64| ToolPoint::new(x.round() as i32, y.round() as i32);
+
+        if delta_x.abs() * height >= delta_y.abs() * width {
+            let edge_x = if delta_x < 0.0 { left } else { left + width };
+            let half = TAIL_HALF_WIDTH.min(height / 4.0);
+            let along = center_y + delta_y * (width / 2.0) / delta_x.abs();
+            let along = clamp_along(along, top + radius + half, top + height - radius - half);
+            Some([point(edge_x, along - half), point(edge_x, along + half)])
+        } else {
+            let edge_y = if delta_y < 0.0 { top } else { top + height };
+            let half = TAIL_HALF_WIDTH.min(width / 4.0);
+            let along = center_x + delta_x * (height / 2.0) / delta_y.abs();
+            let along = clamp_along(along, left + radius + half, left + width - radius - half);
+            Some([point(along - half, edge_y), point(along + half, edge_y)])
+        }
+    }
+}
+
+/// Keeps the tail off the rounded corners; a bubble too small for that
+/// centres the tail on the edge.
+fn clamp_along(value: f64, min: f64, max: f64) -> f64 {
+    if min > max {
+        (min + max) / 2.0
+    } else {
+        value.clamp(min, max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn callout(tail: ToolPoint) -> CalloutElement {
+        CalloutElement::new(
+            1,
+            tail,
+            ToolPoint::new(110, 110),
+            TextOptions::default(),
+            CalloutOptions::default(),
+        )
+    }
+
+    #[test]
+    fn bubble_wraps_text_with_padding_and_click_gets_default_tail() {
+        let placed = callout(ToolPoint::new(0, 0));
+        assert_eq!(
+            placed.bubble_bounds((80, 20)),
+            ToolBounds::new(100, 100, 100, 40)
+        );
+        assert_eq!(placed.text.id, placed.id);
+
+        let clicked = callout(ToolPoint::new(110, 110));
+        assert_eq!(clicked.tail, ToolPoint::new(70, 166));
+    }
+
+    #[test]
+    fn tail_leaves_the_side_facing_its_tip() {
+        let bubble = ToolBounds::new(100, 100, 100, 40);
+
+        assert_eq!(
+            callout(ToolPoint::new(300, 120)).tail_base(bubble),
+            Some([ToolPoint::new(200, 110), ToolPoint::new(200, 130)])
+        );
+        assert_eq!(
+            callout(ToolPoint::new(150, 300)).tail_base(bubble),
+            Some([ToolPoint::new(140, 140), ToolPoint::new(160, 140)])
+        );
+        assert_eq!(
+            callout(ToolPoint::new(0, 60)).tail_base(bubble),
+            Some([ToolPoint::new(100, 110), ToolPoint::new(100, 130)]),
+            "steep tips on a short side stay off the rounded corners"
+        );
+        assert_eq!(callout(ToolPoint::new(150, 120)).tail_base(bubble), None);
+    }
+}
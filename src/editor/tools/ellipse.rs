@@ -0,0 +1,111 @@
+use super::{Color, ToolPoint};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EllipseOptions {
+    pub color: Color,
+    pub thickness: u8,
+    pub fill_enabled: bool,
+}
+
+impl Default for EllipseOptions {
+    fn default() -> Self {
+        Self {
+            color: Color::new(0, 0, 0),
+            thickness: 3,
+            fill_enabled: false,
+        }
+    }
+}
+
+impl EllipseOptions {
+    pub fn set_color(&mut self, color: Color) {
+        self.color = color;
+    }
+
+    pub fn set_thickness(&mut self, thickness: u8) {
+        self.thickness = clamp_u8_range(thickness, 1, 255);
+    }
+
+    pub fn set_fill_enabled(&mut self, fill_enabled: bool) {
+        self.fill_enabled = fill_enabled;
+    }
+}
+
+/// An axis-aligned ellipse inscribed in its bounding box.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EllipseElement {
+    pub id: u64,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub options: EllipseOptions,
+}
+
+impl EllipseElement {
+    pub fn new(id: u64, x: i32, y: i32, width: u32, height: u32, options: EllipseOptions) -> Self {
+        Self {
+            id,
+            x,
+            y,
+            width,
+            height,
+            options,
+        }
+    }
+}
+
+/// Moves `end` so the drag from `start` spans a square box, keeping the
+/// drag direction. Used while Shift is held to draw circles.
+pub fn constrain_to_circle(start: ToolPoint, end: ToolPoint) -> ToolPoint {
+    let dx = i64::from(end.x) - i64::from(start.x);
+    let dy = i64::from(end.y) - i64::from(start.y);
+    let side = dx.abs().max(dy.abs());
+    let offset = |delta: i64, origin: i32| {
+        let signed = if delta < 0 { -side } else { side };
+        let value = i64::from(origin) + signed;
+        i32::try_from(value.clamp(i64::from(i32::MIN), i64::from(i32::MAX)))
+            .expect("clamped coordinate must fit i32")
+    };
+    ToolPoint::new(offset(dx, start.x), offset(dy, start.y))
+}
+
+const fn clamp_u8_range(value: u8, min: u8, max: u8) -> u8 {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constrain_to_circle_uses_longer_side_and_keeps_direction() {
+        let start = ToolPoint::new(10, 10);
+        assert_eq!(
+            constrain_to_circle(start, ToolPoint::new(40, 20)),
+            ToolPoint::new(40, 40)
+        );
+        assert_eq!(
+            constrain_to_circle(start, ToolPoint::new(5, -20)),
+            ToolPoint::new(-20, -20)
+        );
+        assert_eq!(
+            constrain_to_circle(start, ToolPoint::new(-5, 22)),
+            ToolPoint::new(-5, 25)
+        );
+    }
+
+    #[test]
+    fn ellipse_options_clamp_thickness_to_at_least_one() {
+        let mut options = EllipseOptions::default();
+        options.set_thickness(0);
+        assert_eq!(options.thickness, 1);
+    }
+}
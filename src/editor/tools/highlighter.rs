@@ -0,0 +1,104 @@
+use super::{Color, ToolPoint};
+use serde::{Deserialize, Serialize};
+
+/// Nib height relative to the shared stroke width, so the highlighter stays
+/// wide enough to cover a line of text at every stroke preset.
+pub const HIGHLIGHTER_WIDTH_SCALE: u8 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HighlighterOptions {
+    pub color: Color,
+    pub opacity: u8,
+    pub thickness: u8,
+}
+
+impl Default for HighlighterOptions {
+    fn default() -> Self {
+        Self {
+            color: Color::new(255, 211, 51),
+            opacity: 45,
+            thickness: 24,
+        }
+    }
+}
+
+impl HighlighterOptions {
+    pub fn set_color(&mut self, color: Color) {
+        self.color = color;
+    }
+
+    pub fn set_opacity(&mut self, opacity: u8) {
+        self.opacity = clamp_u8_range(opacity, 1, 100);
+    }
+
+    pub fn set_thickness(&mut self, thickness: u8) {
+        self.thickness = clamp_u8_range(thickness, 1, 255);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HighlighterStroke {
+    pub id: u64,
+    pub points: Vec<ToolPoint>,
+    pub options: HighlighterOptions,
+}
+
+impl HighlighterStroke {
+    pub fn new(id: u64, start: ToolPoint, options: HighlighterOptions) -> Self {
+        Self {
+            id,
+            points: vec![start],
+            options,
+        }
+    }
+
+    /// Extends the stroke to `point`. With `snap_horizontal` the stroke is
+    /// replaced by a straight segment at the height of its first point.
+    pub fn append_point(&mut self, point: ToolPoint, snap_horizontal: bool) {
+        let first = self.points[0];
+        if snap_horizontal {
+            self.points.truncate(1);
+            self.points.push(ToolPoint::new(point.x, first.y));
+        } else if self.points.last() != Some(&point) {
+            self.points.push(point);
+        }
+    }
+}
+
+const fn clamp_u8_range(value: u8, min: u8, max: u8) -> u8 {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn highlighter_snap_replaces_stroke_with_horizontal_segment() {
+        let options = HighlighterOptions::default();
+        let mut stroke = HighlighterStroke::new(1, ToolPoint::new(10, 40), options);
+        stroke.append_point(ToolPoint::new(20, 44), false);
+        stroke.append_point(ToolPoint::new(30, 37), false);
+        assert_eq!(stroke.points.len(), 3);
+
+        stroke.append_point(ToolPoint::new(80, 52), true);
+        assert_eq!(
+            stroke.points,
+            vec![ToolPoint::new(10, 40), ToolPoint::new(80, 40)]
+        );
+    }
+
+    #[test]
+    fn highlighter_skips_repeated_points() {
+        let mut stroke =
+            HighlighterStroke::new(1, ToolPoint::new(0, 0), HighlighterOptions::default());
+        stroke.append_point(ToolPoint::new(0, 0), false);
+        assert_eq!(stroke.points.len(), 1);
+    }
+}
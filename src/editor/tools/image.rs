@@ -0,0 +1,452 @@
+use std::fmt;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use super::{constrain_to_circle, ImageBounds, ToolBounds, ToolError, ToolPoint};
+use serde::{Deserialize, Serialize};
+
+/// Largest side accepted for an inserted picture, in pixels.
+pub const IMAGE_MAX_SIDE: u32 = 16_384;
+/// Smallest side an image object can be placed or resized to.
+pub const IMAGE_MIN_SIZE: u32 = 8;
+/// Side of a stamp placed with a single click, in image pixels.
+pub const STAMP_SIZE: u32 = 64;
+/// Share of the capture a newly inserted picture may cover in each direction.
+const PICTURE_MAX_SHARE: f64 = 0.75;
+
+/// Built-in vector stamps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stamp {
+    #[default]
+    Check,
+    Cross,
+    Warning,
+    Question,
+}
+
+impl Stamp {
+    pub const ALL: [Self; 4] = [Self::Check, Self::Cross, Self::Warning, Self::Question];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Check => "Check",
+            Self::Cross => "Cross",
+            Self::Warning => "Warning",
+            Self::Question => "Question",
+        }
+    }
+}
+
+/// Encoded picture data (PNG, JPEG, ...) carried by an image object. The
+/// bytes are shared, so undo snapshots do not copy them, and are written
+/// out as base64 so saved objects stay self-contained.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmbeddedImage {
+    #[serde(with = "base64_data")]
+    data: Arc<[u8]>,
+    width: u32,
+    height: u32,
+}
+
+impl EmbeddedImage {
+    /// Checks that `data` is an image the decoder understands and reads its
+    /// pixel size.
+    pub fn from_encoded(data: Vec<u8>) -> Result<Self, ToolError> {
+        let (width, height) = image::ImageReader::new(Cursor::new(&data))
+            .with_guessed_format()
+            .ok()
+            .and_then(|reader| reader.into_dimensions().ok())
+            .ok_or(ToolError::InvalidImageData)?;
+        if width == 0 || height == 0 || width.max(height) > IMAGE_MAX_SIDE {
+            return Err(ToolError::InvalidImageData);
+        }
+        Ok(Self {
+            data: data.into(),
+            width,
+            height,
+        })
+    }
+
+    pub fn data(&self) -> &Arc<[u8]> {
+        &self.data
+    }
+
+    pub const fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+}
+
+impl fmt::Debug for EmbeddedImage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EmbeddedImage")
+            .field("bytes", &self.data.len())
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .finish()
+    }
+}
+
+mod base64_data {
+    use std::sync::Arc;
+
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(
+        data: &Arc<[u8]>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&crate::base64::encode(data))
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Arc<[u8]>, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        crate::base64::decode(&text)
+            .map(Arc::from)
+            .ok_or_else(|| de::Error::custom("image data is not valid base64"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageSource {
+    Stamp(Stamp),
+    Picture(EmbeddedImage),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageOptions {
+    pub opacity: u8,
+    /// Clockwise rotation about the center, in degrees within -179..=180.
+    pub rotation: i16,
+    /// Keeps the width-to-height ratio when a corner handle is dragged.
+    pub lock_aspect: bool,
+}
+
+impl Default for ImageOptions {
+    fn default() -> Self {
+        Self {
+            opacity: 100,
+            rotation: 0,
+            lock_aspect: true,
+        }
+    }
+}
+
+impl ImageOptions {
+    pub fn set_opacity(&mut self, opacity: u8) {
+        self.opacity = opacity.clamp(1, 100);
+    }
+
+    pub fn set_rotation(&mut self, rotation: i16) {
+        self.rotation = normalize_rotation(i32::from(rotation));
+    }
+
+    pub fn set_lock_aspect(&mut self, lock_aspect: bool) {
+        self.lock_aspect = lock_aspect;
+    }
+}
+
+/// Maps any angle onto -179..=180 degrees.
+pub fn normalize_rotation(degrees: i32) -> i16 {
+    let wrapped = degrees.rem_euclid(360);
+    let signed = if wrapped > 180 {
+        wrapped - 360
+    } else {
+        wrapped
+    };
+    signed as i16
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageElement {
+    pub id: u64,
+    /// Unrotated frame; `options.rotation` turns it about its center.
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub source: ImageSource,
+    pub options: ImageOptions,
+}
+
+impl ImageElement {
+    pub fn new(id: u64, source: ImageSource, frame: ToolBounds, options: ImageOptions) -> Self {
+        Self {
+            id,
+            x: frame.x,
+            y: frame.y,
+            width: frame.width.max(1),
+            height: frame.height.max(1),
+            source,
+            options,
+        }
+    }
+
+    pub fn center(&self) -> (f64, f64) {
+        (
+            f64::from(self.x) + f64::from(self.width) / 2.0,
+            f64::from(self.y) + f64::from(self.height) / 2.0,
+        )
+    }
+
+    /// Sine and cosine of the rotation, exact for quarter turns so rotated
+    /// bounds do not pick up rounding slivers.
+    pub fn rotation_sin_cos(&self) -> (f64, f64) {
+        match self.options.rotation {
+            0 => (0.0, 1.0),
+            90 => (1.0, 0.0),
+            180 => (0.0, -1.0),
+            -90 => (-1.0, 0.0),
+            rotation => f64::from(rotation).to_radians().sin_cos(),
+        }
+    }
+
+    /// Axis-aligned box around the rotated frame.
+    pub fn bounds(&self) -> ToolBounds {
+        let (sin, cos) = self.rotation_sin_cos();
+        let half_width = f64::from(self.width) / 2.0;
+        let half_height = f64::from(self.height) / 2.0;
+        let extent_x = (half_width * cos).abs() + (half_height * sin).abs();
+        let extent_y = (half_width * sin).abs() + (half_height * cos).abs();
+        let (center_x, center_y) = self.center();
+        let left = (center_x - extent_x).floor();
+        let top = (center_y - extent_y).floor();
+        let right = (center_x + extent_x).ceil();
+        let bottom = (center_y + extent_y).ceil();
+        ToolBounds::new(
+            left as i32,
+            top as i32,
+            (right - left) as u32,
+            (bottom - top) as u32,
+        )
+    }
+
+    /// Whether `point` falls on the rotated frame, with a few pixels of slack.
+    pub fn contains(&self, point: ToolPoint) -> bool {
+        const SLACK: f64 = 4.0;
+        let (sin, cos) = self.rotation_sin_cos();
+        let (center_x, center_y) = self.center();
+        let dx = f64::from(point.x) - center_x;
+        let dy = f64::from(point.y) - center_y;
+        let local_x = dx * cos + dy * sin;
+        let local_y = -dx * sin + dy * cos;
+        local_x.abs() <= f64::from(self.width) / 2.0 + SLACK
+            && local_y.abs() <= f64::from(self.height) / 2.0 + SLACK
+    }
+
+    /// Scales the frame so its rotated box fills `bounds`, centered on it.
+    /// The frame is stretched per axis only when the aspect is unlocked and
+    /// the image sits at a quarter turn; otherwise it scales uniformly.
+    pub fn fit_to_bounds(&mut self, bounds: ToolBounds) {
+        let current = self.bounds();
+        let scale_x = f64::from(bounds.width) / f64::from(current.width.max(1));
+        let scale_y = f64::from(bounds.height) / f64::from(current.height.max(1));
+        let (sin, cos) = self.rotation_sin_cos();
+        let (scale_width, scale_height) = if !self.options.lock_aspect && sin == 0.0 {
+            (scale_x, scale_y)
+        } else if !self.options.lock_aspect && cos == 0.0 {
+            (scale_y, scale_x)
+        } else {
+            let uniform = scale_x.min(scale_y);
+            (uniform, uniform)
+        };
+        let min_size = f64::from(IMAGE_MIN_SIZE);
+        let width = (f64::from(self.width) * scale_width).round().max(min_size);
+        let height = (f64::from(self.height) * scale_height)
+            .round()
+            .max(min_size);
+        let center_x = f64::from(bounds.x) + f64::from(bounds.width) / 2.0;
+        let center_y = f64::from(bounds.y) + f64::from(bounds.height) / 2.0;
+        self.x = (center_x - width / 2.0).round() as i32;
+        self.y = (center_y - height / 2.0).round() as i32;
+        self.width = width as u32;
+        self.height = height as u32;
+    }
+}
+
+/// Frame for a stamp dragged from `start` to `end`. The drag is kept square;
+/// a click places a `STAMP_SIZE` stamp centered on `start`.
+pub fn stamp_frame(start: ToolPoint, end: ToolPoint, image_bounds: ImageBounds) -> ToolBounds {
+    let end = constrain_to_circle(start, end);
+    let side = end.x.abs_diff(start.x);
+    if side < IMAGE_MIN_SIZE {
+        return centered_frame(start, STAMP_SIZE, STAMP_SIZE, image_bounds);
+    }
+    ToolBounds::new(start.x.min(end.x), start.y.min(end.y), side, side)
+}
+
+/// Frame for a newly inserted picture: centered on the capture, at its own
+/// size unless that would cover more than three quarters of the capture.
+pub fn picture_frame(picture: &EmbeddedImage, image_bounds: ImageBounds) -> ToolBounds {
+    let max_width = f64::from(image_bounds.width.max(1)) * PICTURE_MAX_SHARE;
+    let max_height = f64::from(image_bounds.height.max(1)) * PICTURE_MAX_SHARE;
+    let width = f64::from(picture.width());
+    let height = f64::from(picture.height());
+    let scale = (max_width / width).min(max_height / height).min(1.0);
+    let min_size = f64::from(IMAGE_MIN_SIZE);
+    let fitted_width = (width * scale).round().max(min_size) as u32;
+    let fitted_height = (height * scale).round().max(min_size) as u32;
+    let center = ToolPoint::new(image_bounds.width / 2, image_bounds.height / 2);
+    centered_frame(center, fitted_width, fitted_height, image_bounds)
+}
+
+/// A `width` x `height` box centered on `center`, shifted back inside the
+/// capture where it fits.
+fn centered_frame(
+    center: ToolPoint,
+    width: u32,
+    height: u32,
+    image_bounds: ImageBounds,
+) -> ToolBounds {
+    let place = |center: i32, size: u32, limit: i32| {
+        let size = i32::try_from(size).unwrap_or(i32::MAX);
+        let start = center.saturating_sub(size / 2);
+        if size >= limit {
+            start
+        } else {
+            start.clamp(0, limit - size)
+        }
+    };
+    ToolBounds::new(
+        place(center.x, width, image_bounds.width),
+        place(center.y, height, image_bounds.height),
+        width,
+        height,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png_bytes(width: u32, height: u32) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        image::RgbaImage::new(width, height)
+            .write_to(&mut Cursor::new(&mut bytes), image::ImageFormat::Png)
+            .expect("png should encode");
+        bytes
+    }
+
+    fn element(frame: ToolBounds, rotation: i16, lock_aspect: bool) -> ImageElement {
+        ImageElement::new(
+            1,
+            ImageSource::Stamp(Stamp::Check),
+            frame,
+            ImageOptions {
+                rotation,
+                lock_aspect,
+                ..ImageOptions::default()
+            },
+        )
+    }
+
+    #[test]
+    fn stamp_click_centers_default_stamp_inside_capture() {
+        let image = ImageBounds::new(200, 100);
+
+        assert_eq!(
+            stamp_frame(ToolPoint::new(100, 50), ToolPoint::new(102, 51), image),
+            ToolBounds::new(68, 18, STAMP_SIZE, STAMP_SIZE)
+        );
+        assert_eq!(
+            stamp_frame(ToolPoint::new(5, 95), ToolPoint::new(5, 95), image),
+            ToolBounds::new(0, 36, STAMP_SIZE, STAMP_SIZE)
+        );
+    }
+
+    #[test]
+    fn stamp_drag_keeps_a_square() {
+        let image = ImageBounds::new(400, 400);
+
+        assert_eq!(
+            stamp_frame(ToolPoint::new(100, 100), ToolPoint::new(40, 130), image),
+            ToolBounds::new(40, 100, 60, 60)
+        );
+    }
+
+    #[test]
+    fn picture_frame_keeps_small_pictures_and_shrinks_large_ones() {
+        let image = ImageBounds::new(800, 600);
+        let small = EmbeddedImage::from_encoded(png_bytes(40, 20)).expect("png should decode");
+        let large = EmbeddedImage::from_encoded(png_bytes(1600, 400)).expect("png should decode");
+
+        assert_eq!(
+            picture_frame(&small, image),
+            ToolBounds::new(380, 290, 40, 20)
+        );
+        assert_eq!(
+            picture_frame(&large, image),
+            ToolBounds::new(100, 225, 600, 150)
+        );
+    }
+
+    #[test]
+    fn rotated_frame_bounds_and_hits_follow_the_rotation() {
+        let upright = element(ToolBounds::new(0, 0, 100, 40), 0, true);
+        let turned = element(ToolBounds::new(0, 0, 100, 40), 90, true);
+
+        assert_eq!(upright.bounds(), ToolBounds::new(0, 0, 100, 40));
+        assert_eq!(turned.bounds(), ToolBounds::new(30, -30, 40, 100));
+        assert!(upright.contains(ToolPoint::new(95, 20)));
+        assert!(!turned.contains(ToolPoint::new(95, 20)));
+        assert!(turned.contains(ToolPoint::new(50, 65)));
+
+        let tilted = element(ToolBounds::new(0, 0, 100, 100), 45, true);
+        let bounds = tilted.bounds();
+        assert!(bounds.width > 140 && bounds.width < 143);
+        assert!(!tilted.contains(ToolPoint::new(2, 2)));
+    }
+
+    #[test]
+    fn fit_to_bounds_scales_uniformly_unless_unlocked_at_a_quarter_turn() {
+        let mut locked = element(ToolBounds::new(0, 0, 100, 50), 0, true);
+        locked.fit_to_bounds(ToolBounds::new(10, 10, 200, 200));
+        assert_eq!(
+            (locked.x, locked.y, locked.width, locked.height),
+            (10, 60, 200, 100)
+        );
+
+        let mut stretched = element(ToolBounds::new(0, 0, 100, 50), 90, false);
+        stretched.fit_to_bounds(ToolBounds::new(0, 0, 100, 300));
+        assert_eq!((stretched.width, stretched.height), (300, 100));
+        assert_eq!(stretched.bounds(), ToolBounds::new(0, 0, 100, 300));
+    }
+
+    #[test]
+    fn rotation_is_normalized_into_a_half_turn_either_way() {
+        assert_eq!(normalize_rotation(0), 0);
+        assert_eq!(normalize_rotation(180), 180);
+        assert_eq!(normalize_rotation(-180), 180);
+        assert_eq!(normalize_rotation(270), -90);
+        assert_eq!(normalize_rotation(-405), -45);
+    }
+
+    #[test]
+    fn pictures_reject_garbage_and_round_trip_through_json() {
+        assert_eq!(
+            EmbeddedImage::from_encoded(b"not an image".to_vec()),
+            Err(ToolError::InvalidImageData)
+        );
+
+        let picture = EmbeddedImage::from_encoded(png_bytes(3, 2)).expect("png should decode");
+        assert_eq!((picture.width(), picture.height()), (3, 2));
+        assert!(format!("{picture:?}").starts_with("EmbeddedImage { bytes: "));
+
+        let image = ImageElement::new(
+            7,
+            ImageSource::Picture(picture),
+            ToolBounds::new(1, 2, 30, 20),
+            ImageOptions::default(),
+        );
+        let json = serde_json::to_string(&image).expect("image should serialize");
+        let restored: ImageElement = serde_json::from_str(&json).expect("image should deserialize");
+        assert_eq!(restored, image);
+    }
+}
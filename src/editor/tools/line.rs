@@ -0,0 +1,158 @@
+use super::{Color, ToolPoint};
+use serde::{Deserialize, Serialize};
+
+/// Stroke pattern shared by lines, arrows and rectangles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LineDash {
+    #[default]
+    Solid,
+    Dashed,
+    Dotted,
+}
+
+impl LineDash {
+    pub const ALL: [Self; 3] = [Self::Solid, Self::Dashed, Self::Dotted];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Solid => "Solid",
+            Self::Dashed => "Dashed",
+            Self::Dotted => "Dotted",
+        }
+    }
+
+    /// Cairo dash lengths scaled to the stroke width; empty for solid strokes.
+    /// Dotted strokes use zero-length dashes and rely on round or square caps
+    /// to draw the dots.
+    pub fn pattern(self, thickness: u8) -> Vec<f64> {
+        let width = f64::from(thickness.max(1));
+        match self {
+            Self::Solid => Vec::new(),
+            Self::Dashed => vec![width * 3.0, width * 3.0],
+            Self::Dotted => vec![0.0, width * 2.0],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LineCap {
+    #[default]
+    Round,
+    Square,
+}
+
+impl LineCap {
+    pub const ALL: [Self; 2] = [Self::Round, Self::Square];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Round => "Round",
+            Self::Square => "Square",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LineOptions {
+    pub color: Color,
+    pub thickness: u8,
+    pub dash: LineDash,
+    pub cap: LineCap,
+}
+
+impl Default for LineOptions {
+    fn default() -> Self {
+        Self {
+            color: Color::new(0, 0, 0),
+            thickness: 3,
+            dash: LineDash::Solid,
+            cap: LineCap::Round,
+        }
+    }
+}
+
+impl LineOptions {
+    pub fn set_color(&mut self, color: Color) {
+        self.color = color;
+    }
+
+    pub fn set_thickness(&mut self, thickness: u8) {
+        self.thickness = clamp_u8_range(thickness, 1, 255);
+    }
+
+    pub fn set_dash(&mut self, dash: LineDash) {
+        self.dash = dash;
+    }
+
+    pub fn set_cap(&mut self, cap: LineCap) {
+        self.cap = cap;
+    }
+}
+
+/// A straight line or polyline through `points`, in click order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LineElement {
+    pub id: u64,
+    pub points: Vec<ToolPoint>,
+    pub options: LineOptions,
+}
+
+impl LineElement {
+    pub fn new(id: u64, start: ToolPoint, options: LineOptions) -> Self {
+        Self {
+            id,
+            points: vec![start],
+            options,
+        }
+    }
+
+    /// Appends a vertex unless it repeats the last one, so the second press
+    /// of a finishing double-click does not add a zero-length segment.
+    pub fn push_vertex(&mut self, point: ToolPoint) -> bool {
+        if self.points.last() == Some(&point) {
+            return false;
+        }
+        self.points.push(point);
+        true
+    }
+
+    pub fn is_drawable(&self) -> bool {
+        self.points.len() >= 2
+    }
+}
+
+const fn clamp_u8_range(value: u8, min: u8, max: u8) -> u8 {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_dash_pattern_scales_with_thickness() {
+        assert!(LineDash::Solid.pattern(4).is_empty());
+        assert_eq!(LineDash::Dashed.pattern(4), vec![12.0, 12.0]);
+        assert_eq!(LineDash::Dotted.pattern(4), vec![0.0, 8.0]);
+        assert_eq!(LineDash::Dashed.pattern(0), vec![3.0, 3.0]);
+    }
+
+    #[test]
+    fn line_vertex_ignores_repeated_point() {
+        let mut line = LineElement::new(1, ToolPoint::new(0, 0), LineOptions::default());
+        assert!(!line.is_drawable());
+        assert!(line.push_vertex(ToolPoint::new(10, 0)));
+        assert!(!line.push_vertex(ToolPoint::new(10, 0)));
+        assert!(line.push_vertex(ToolPoint::new(10, 10)));
+        assert_eq!(line.points.len(), 3);
+        assert!(line.is_drawable());
+    }
+}
@@ -0,0 +1,387 @@
+use super::{constrain_to_circle, Color, ImageBounds, ToolBounds, ToolPoint};
+use serde::{Deserialize, Serialize};
+
+const MAGNIFIER_MIN_ZOOM_PERCENT: u16 = 150;
+const MAGNIFIER_MAX_ZOOM_PERCENT: u16 = 800;
+/// Space left between the source region and a newly placed callout.
+const CALLOUT_GAP: i64 = 24;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MagnifierShape {
+    #[default]
+    Circle,
+    Rectangle,
+}
+
+impl MagnifierShape {
+    pub const ALL: [Self; 2] = [Self::Circle, Self::Rectangle];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Circle => "Circle",
+            Self::Rectangle => "Rectangle",
+        }
+    }
+
+    /// Circular lenses select a square source so the callout is a circle.
+    pub fn constrain_drag(self, start: ToolPoint, end: ToolPoint) -> ToolPoint {
+        match self {
+            Self::Circle => constrain_to_circle(start, end),
+            Self::Rectangle => end,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MagnifierOptions {
+    pub color: Color,
+    pub thickness: u8,
+    /// Callout size relative to the source region, in percent.
+    pub zoom_percent: u16,
+    pub shape: MagnifierShape,
+    pub connector: bool,
+}
+
+impl Default for MagnifierOptions {
+    fn default() -> Self {
+        Self {
+            color: Color::new(0, 0, 0),
+            thickness: 3,
+            zoom_percent: 200,
+            shape: MagnifierShape::Circle,
+            connector: true,
+        }
+    }
+}
+
+impl MagnifierOptions {
+    pub fn set_color(&mut self, color: Color) {
+        self.color = color;
+    }
+
+    pub fn set_thickness(&mut self, thickness: u8) {
+        self.thickness = thickness.max(1);
+    }
+
+    pub fn set_zoom_percent(&mut self, zoom_percent: u16) {
+        self.zoom_percent =
+            zoom_percent.clamp(MAGNIFIER_MIN_ZOOM_PERCENT, MAGNIFIER_MAX_ZOOM_PERCENT);
+    }
+
+    pub fn set_shape(&mut self, shape: MagnifierShape) {
+        self.shape = shape;
+    }
+
+    pub fn set_connector(&mut self, connector: bool) {
+        self.connector = connector;
+    }
+}
+
+/// The two independently movable boxes of a magnifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MagnifierPart {
+    Source,
+    Callout,
+}
+
+/// A zoomed copy of `source` drawn at the callout position. The callout
+/// size always follows from the source size and the zoom.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MagnifierElement {
+    pub id: u64,
+    pub source_x: i32,
+    pub source_y: i32,
+    pub source_width: u32,
+    pub source_height: u32,
+    pub callout_x: i32,
+    pub callout_y: i32,
+    pub options: MagnifierOptions,
+}
+
+impl MagnifierElement {
+    /// Places the callout beside the source where it fits in the image.
+    pub fn new(
+        id: u64,
+        source: ToolBounds,
+        image_bounds: ImageBounds,
+        options: MagnifierOptions,
+    ) -> Self {
+        let mut magnifier = Self {
+            id,
+            source_x: source.x,
+            source_y: source.y,
+            source_width: source.width,
+            source_height: source.height,
+            callout_x: 0,
+            callout_y: 0,
+            options,
+        };
+        let origin = place_callout(
+            source,
+            magnifier.callout_size_at(options.zoom_percent),
+            image_bounds,
+        );
+        magnifier.callout_x = origin.x;
+        magnifier.callout_y = origin.y;
+        magnifier
+    }
+
+    pub const fn source_bounds(&self) -> ToolBounds {
+        ToolBounds::new(
+            self.source_x,
+            self.source_y,
+            self.source_width,
+            self.source_height,
+        )
+    }
+
+    pub fn callout_bounds(&self) -> ToolBounds {
+        let (width, height) = self.callout_size_at(self.options.zoom_percent);
+        ToolBounds::new(self.callout_x, self.callout_y, width, height)
+    }
+
+    pub fn callout_size_at(&self, zoom_percent: u16) -> (u32, u32) {
+        (
+            zoomed_length(self.source_width, zoom_percent),
+            zoomed_length(self.source_height, zoom_percent),
+        )
+    }
+
+    /// Zoom that makes the callout cover `width` x `height`, within limits.
+    pub fn zoom_for_callout_size(&self, width: u32, height: u32) -> u16 {
+        let ratio = |length: u32, source: u32| u64::from(length) * 100 / u64::from(source.max(1));
+        let zoom = ratio(width, self.source_width).max(ratio(height, self.source_height));
+        u16::try_from(zoom)
+            .unwrap_or(u16::MAX)
+            .clamp(MAGNIFIER_MIN_ZOOM_PERCENT, MAGNIFIER_MAX_ZOOM_PERCENT)
+    }
+
+    /// The part under `point`; the callout is drawn last, so it wins.
+    pub fn part_at(&self, point: ToolPoint) -> Option<MagnifierPart> {
+        if bounds_contain(self.callout_bounds(), point) {
+            Some(MagnifierPart::Callout)
+        } else if bounds_contain(self.source_bounds(), point) {
+            Some(MagnifierPart::Source)
+        } else {
+            None
+        }
+    }
+
+    /// Box around the source and the callout.
+    pub fn bounds(&self) -> ToolBounds {
+        let source = self.source_bounds();
+        let callout = self.callout_bounds();
+        let left = source.x.min(callout.x);
+        let top = source.y.min(callout.y);
+        let right = box_right(source).max(box_right(callout));
+        let bottom = box_bottom(source).max(box_bottom(callout));
+        ToolBounds::new(
+            left,
+            top,
+            u32::try_from(right - i64::from(left)).unwrap_or(u32::MAX),
+            u32::try_from(bottom - i64::from(top)).unwrap_or(u32::MAX),
+        )
+    }
+
+    /// Line from the source outline to the callout outline, or `None` when
+    /// the connector is off or the two boxes overlap.
+    pub fn connector(&self) -> Option<(ToolPoint, ToolPoint)> {
+        let source = self.source_bounds();
+        let callout = self.callout_bounds();
+        if !self.options.connector || boxes_overlap(source, callout) {
+            return None;
+        }
+        let (source_x, source_y) = box_center(source);
+        let (callout_x, callout_y) = box_center(callout);
+        let shape = self.options.shape;
+        Some((
+            edge_point(source, shape, (callout_x - source_x, callout_y - source_y)),
+            edge_point(callout, shape, (source_x - callout_x, source_y - callout_y)),
+        ))
+    }
+
+    /// Keeps the callout inside the image after the source or zoom changed.
+    pub(super) fn clamp_callout(&mut self, image_bounds: ImageBounds) {
+        let callout = self.callout_bounds();
+        let origin = clamp_origin(
+            (i64::from(callout.x), i64::from(callout.y)),
+            (i64::from(callout.width), i64::from(callout.height)),
+            image_bounds,
+        );
+        self.callout_x = origin.x;
+        self.callout_y = origin.y;
+    }
+}
+
+fn zoomed_length(length: u32, zoom_percent: u16) -> u32 {
+    let zoomed = u64::from(length) * u64::from(zoom_percent) / 100;
+    u32::try_from(zoomed).unwrap_or(u32::MAX).max(1)
+}
+
+fn box_right(bounds: ToolBounds) -> i64 {
+    i64::from(bounds.x) + i64::from(bounds.width)
+}
+
+fn box_bottom(bounds: ToolBounds) -> i64 {
+    i64::from(bounds.y) + i64::from(bounds.height)
+}
+
+fn box_center(bounds: ToolBounds) -> (f64, f64) {
+    (
+        f64::from(bounds.x) + f64::from(bounds.width) / 2.0,
+        f64::from(bounds.y) + f64::from(bounds.height) / 2.0,
+    )
+}
+
+fn bounds_contain(bounds: ToolBounds, point: ToolPoint) -> bool {
+    point.x >= bounds.x
+        && point.y >= bounds.y
+        && i64::from(point.x) <= box_right(bounds)
+        && i64::from(point.y) <= box_bottom(bounds)
+}
+
+fn boxes_overlap(a: ToolBounds, b: ToolBounds) -> bool {
+    i64::from(a.x) < box_right(b)
+        && i64::from(b.x) < box_right(a)
+        && i64::from(a.y) < box_bottom(b)
+        && i64::from(b.y) < box_bottom(a)
+}
+
+/// Where a ray from the centre of `bounds` along `direction` leaves the
+/// outline; the circle shape uses the ellipse inscribed in the box.
+fn edge_point(bounds: ToolBounds, shape: MagnifierShape, direction: (f64, f64)) -> ToolPoint {
+    let (center_x, center_y) = box_center(bounds);
+    let (delta_x, delta_y) = direction;
+    let radius_x = f64::from(bounds.width) / 2.0;
+    let radius_y = f64::from(bounds.height) / 2.0;
+    let scale = match shape {
+        MagnifierShape::Circle => {
+            1.0 / ((delta_x / radius_x).powi(2) + (delta_y / radius_y).powi(2)).sqrt()
+        }
+        MagnifierShape::Rectangle => (radius_x / delta_x.abs()).min(radius_y / delta_y.abs()),
+    };
+    ToolPoint::new(
+        (center_x + delta_x * scale).round() as i32,
+        (center_y + delta_y * scale).round() as i32,
+    )
+}
+
+fn clamp_origin(origin: (i64, i64), size: (i64, i64), image_bounds: ImageBounds) -> ToolPoint {
+    let limit_x = (i64::from(image_bounds.width) - size.0).max(0);
+    let limit_y = (i64::from(image_bounds.height) - size.1).max(0);
+    ToolPoint::new(
+        i32::try_from(origin.0.clamp(0, limit_x)).unwrap_or(i32::MAX),
+        i32::try_from(origin.1.clamp(0, limit_y)).unwrap_or(i32::MAX),
+    )
+}
+
+/// Tries right of, left of, below and above the source in that order and
+/// takes the first spot that fits the image; otherwise the right-hand spot
+/// is pushed inside the image.
+fn place_callout(source: ToolBounds, size: (u32, u32), image_bounds: ImageBounds) -> ToolPoint {
+    let (width, height) = (i64::from(size.0), i64::from(size.1));
+    let (image_width, image_height) = (
+        i64::from(image_bounds.width),
+        i64::from(image_bounds.height),
+    );
+    let beside_y = (i64::from(source.y) + i64::from(source.height) / 2 - height / 2)
+        .clamp(0, (image_height - height).max(0));
+    let beside_x = (i64::from(source.x) + i64::from(source.width) / 2 - width / 2)
+        .clamp(0, (image_width - width).max(0));
+    let candidates = [
+        (box_right(source) + CALLOUT_GAP, beside_y),
+        (i64::from(source.x) - CALLOUT_GAP - width, beside_y),
+        (beside_x, box_bottom(source) + CALLOUT_GAP),
+        (beside_x, i64::from(source.y) - CALLOUT_GAP - height),
+    ];
+    let origin = candidates
+        .into_iter()
+        .find(|&(x, y)| x >= 0 && y >= 0 && x + width <= image_width && y + height <= image_height)
+        .unwrap_or(candidates[0]);
+    clamp_origin(origin, (width, height), image_bounds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn magnifier(source: ToolBounds, image: ImageBounds) -> MagnifierElement {
+        MagnifierElement::new(1, source, image, MagnifierOptions::default())
+    }
+
+    #[test]
+    fn callout_goes_right_of_source_and_falls_back_to_the_left() {
+        let image = ImageBounds::new(400, 300);
+        let left_side = magnifier(ToolBounds::new(20, 100, 40, 40), image);
+        assert_eq!(
+            left_side.callout_bounds(),
+            ToolBounds::new(84, 80, 80, 80),
+            "source centre row, one gap to the right"
+        );
+
+        let right_side = magnifier(ToolBounds::new(340, 100, 40, 40), image);
+        assert_eq!(
+            right_side.callout_bounds(),
+            ToolBounds::new(236, 80, 80, 80)
+        );
+    }
+
+    #[test]
+    fn callout_is_clamped_into_small_images() {
+        let placed = magnifier(ToolBounds::new(10, 10, 60, 60), ImageBounds::new(100, 100));
+        let callout = placed.callout_bounds();
+        assert_eq!((callout.x, callout.y), (0, 0));
+        assert_eq!((callout.width, callout.height), (120, 120));
+    }
+
+    #[test]
+    fn connector_runs_between_outlines_and_hides_on_overlap() {
+        let mut placed = magnifier(ToolBounds::new(0, 80, 40, 40), ImageBounds::new(400, 300));
+        placed.options.shape = MagnifierShape::Rectangle;
+        placed.callout_x = 100;
+        placed.callout_y = 60;
+        assert_eq!(
+            placed.connector(),
+            Some((ToolPoint::new(40, 100), ToolPoint::new(100, 100)))
+        );
+
+        placed.options.shape = MagnifierShape::Circle;
+        assert_eq!(
+            placed.connector(),
+            Some((ToolPoint::new(40, 100), ToolPoint::new(100, 100)))
+        );
+
+        placed.callout_x = 20;
+        assert_eq!(placed.connector(), None);
+        placed.callout_x = 100;
+        placed.options.connector = false;
+        assert_eq!(placed.connector(), None);
+    }
+
+    #[test]
+    fn part_at_prefers_callout_and_zoom_follows_callout_size() {
+        let mut placed = magnifier(ToolBounds::new(0, 0, 40, 20), ImageBounds::new(400, 300));
+        placed.callout_x = 30;
+        placed.callout_y = 10;
+        assert_eq!(
+            placed.part_at(ToolPoint::new(35, 15)),
+            Some(MagnifierPart::Callout)
+        );
+        assert_eq!(
+            placed.part_at(ToolPoint::new(5, 5)),
+            Some(MagnifierPart::Source)
+        );
+        assert_eq!(placed.part_at(ToolPoint::new(300, 200)), None);
+
+        assert_eq!(placed.zoom_for_callout_size(120, 30), 300);
+        assert_eq!(
+            placed.zoom_for_callout_size(40, 20),
+            MAGNIFIER_MIN_ZOOM_PERCENT
+        );
+        assert_eq!(
+            placed.zoom_for_callout_size(4000, 20),
+            MAGNIFIER_MAX_ZOOM_PERCENT
+        );
+    }
+}
@@ -1,27 +1,60 @@
 mod arrow;
+mod badge;
 mod blur;
+mod callout;
 mod crop;
+mod ellipse;
+mod highlighter;
+mod image;
+mod line;
+mod magnifier;
 mod operations;
 mod pen;
 mod query;
 mod rectangle;
 mod selection;
+mod spotlight;
+mod stroke_fit;
 mod text;
 
+use serde::{Deserialize, Serialize};
+
+pub use self::image::{
+    normalize_rotation, picture_frame, stamp_frame, EmbeddedImage, ImageElement, ImageOptions,
+    ImageSource, Stamp, IMAGE_MAX_SIDE, IMAGE_MIN_SIZE,
+};
 pub use crate::geometry::{Color, ImageBounds, ToolBounds, ToolPoint};
 pub use arrow::{ArrowElement, ArrowOptions};
-pub use blur::{BlurElement, BlurOptions, BlurRegion};
+pub use badge::{BadgeElement, BadgeOptions, BadgeStyle, BADGE_MAX_START, BADGE_MIN_START};
+pub use blur::{BlurElement, BlurMode, BlurOptions, BlurRegion};
+pub use callout::{CalloutElement, CalloutOptions, CALLOUT_CORNER_RADIUS, CALLOUT_PADDING};
 pub use crop::{CropElement, CropOptions, CropPreset, CROP_MIN_SIZE};
-pub use pen::{PenOptions, PenPoint, PenStroke};
+pub use ellipse::{constrain_to_circle, EllipseElement, EllipseOptions};
+pub use highlighter::{HighlighterOptions, HighlighterStroke, HIGHLIGHTER_WIDTH_SCALE};
+pub use line::{LineCap, LineDash, LineElement, LineOptions};
+pub use magnifier::{MagnifierElement, MagnifierOptions, MagnifierPart, MagnifierShape};
+pub use pen::{PenOptions, PenPoint, PenSmoothing, PenStroke, PressureCurve, StylusSample};
 pub use rectangle::{RectangleElement, RectangleOptions};
+pub use spotlight::{SpotlightElement, SpotlightHole, SpotlightOptions, SpotlightShape};
+pub use stroke_fit::{recognize_shape, smooth_points, SnappedShape};
 pub use text::{TextElement, TextFontFamily, TextOptions};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ToolOptionVisibility {
     pub has_color: bool,
     pub has_stroke_width: bool,
+    pub has_fill: bool,
+    pub has_dash: bool,
+    pub has_line_cap: bool,
     pub has_text_size: bool,
     pub has_crop_preset: bool,
+    pub has_badge: bool,
+    pub has_redaction: bool,
+    pub has_spotlight: bool,
+    pub has_magnifier: bool,
+    pub has_callout: bool,
+    pub has_pen: bool,
+    pub has_image: bool,
 }
 
 impl ToolOptionVisibility {
@@ -29,10 +62,33 @@ impl ToolOptionVisibility {
         let Self {
             has_color,
             has_stroke_width,
+            has_fill,
+            has_dash,
+            has_line_cap,
             has_text_size,
             has_crop_preset,
+            has_badge,
+            has_redaction,
+            has_spotlight,
+            has_magnifier,
+            has_callout,
+            has_pen,
+            has_image,
         } = *self;
-        has_color || has_stroke_width || has_text_size || has_crop_preset
+        has_color
+            || has_stroke_width
+            || has_fill
+            || has_dash
+            || has_line_cap
+            || has_text_size
+            || has_crop_preset
+            || has_badge
+            || has_redaction
+            || has_spotlight
+            || has_magnifier
+            || has_callout
+            || has_pen
+            || has_image
     }
 }
 
@@ -42,50 +98,130 @@ pub enum ToolKind {
     Pan,
     Blur,
     Pen,
+    Highlighter,
     Arrow,
+    Line,
     Rectangle,
+    Ellipse,
+    Badge,
+    Spotlight,
+    Magnifier,
+    Callout,
+    Image,
     Crop,
     Text,
     Ocr,
+    Decode,
 }
 
 impl ToolKind {
     pub const fn option_visibility(self) -> ToolOptionVisibility {
+        const NONE: ToolOptionVisibility = ToolOptionVisibility {
+            has_color: false,
+            has_stroke_width: false,
+            has_fill: false,
+            has_dash: false,
+            has_line_cap: false,
+            has_text_size: false,
+            has_crop_preset: false,
+            has_badge: false,
+            has_redaction: false,
+            has_spotlight: false,
+            has_magnifier: false,
+            has_callout: false,
+            has_pen: false,
+            has_image: false,
+        };
         match self {
-            Self::Pen | Self::Arrow | Self::Rectangle => ToolOptionVisibility {
+            Self::Blur => ToolOptionVisibility {
+                has_redaction: true,
+                ..NONE
+            },
+            Self::Pen => ToolOptionVisibility {
+                has_color: true,
+                has_stroke_width: true,
+                has_pen: true,
+                ..NONE
+            },
+            Self::Highlighter => ToolOptionVisibility {
+                has_color: true,
+                has_stroke_width: true,
+                ..NONE
+            },
+            Self::Arrow | Self::Rectangle => ToolOptionVisibility {
+                has_color: true,
+                has_stroke_width: true,
+                has_dash: true,
+                ..NONE
+            },
+            Self::Line => ToolOptionVisibility {
+                has_color: true,
+                has_stroke_width: true,
+                has_dash: true,
+                has_line_cap: true,
+                ..NONE
+            },
+            Self::Ellipse => ToolOptionVisibility {
+                has_color: true,
+                has_stroke_width: true,
+                has_fill: true,
+                ..NONE
+            },
+            Self::Badge => ToolOptionVisibility {
+                has_color: true,
+                has_badge: true,
+                ..NONE
+            },
+            Self::Spotlight => ToolOptionVisibility {
+                has_spotlight: true,
+                ..NONE
+            },
+            Self::Magnifier => ToolOptionVisibility {
                 has_color: true,
                 has_stroke_width: true,
-                has_text_size: false,
-                has_crop_preset: false,
+                has_magnifier: true,
+                ..NONE
+            },
+            Self::Callout => ToolOptionVisibility {
+                has_color: true,
+                has_stroke_width: true,
+                has_text_size: true,
+                has_callout: true,
+                ..NONE
+            },
+            Self::Image => ToolOptionVisibility {
+                has_image: true,
+                ..NONE
             },
             Self::Text => ToolOptionVisibility {
                 has_color: true,
-                has_stroke_width: false,
                 has_text_size: true,
-                has_crop_preset: false,
+                ..NONE
             },
             Self::Crop => ToolOptionVisibility {
-                has_color: false,
-                has_stroke_width: false,
-                has_text_size: false,
                 has_crop_preset: true,
+                ..NONE
             },
-            Self::Select | Self::Pan | Self::Blur | Self::Ocr => ToolOptionVisibility {
-                has_color: false,
-                has_stroke_width: false,
-                has_text_size: false,
-                has_crop_preset: false,
-            },
+            Self::Select | Self::Pan | Self::Ocr | Self::Decode => NONE,
         }
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
 pub enum ToolObject {
     Blur(BlurElement),
     Pen(PenStroke),
+    Highlighter(HighlighterStroke),
     Arrow(ArrowElement),
+    Line(LineElement),
     Rectangle(RectangleElement),
+    Ellipse(EllipseElement),
+    Badge(BadgeElement),
+    Spotlight(SpotlightElement),
+    Magnifier(MagnifierElement),
+    Callout(CalloutElement),
+    Image(ImageElement),
     Crop(CropElement),
     Text(TextElement),
 }
@@ -95,8 +231,16 @@ impl ToolObject {
         match self {
             Self::Blur(blur) => blur.id,
             Self::Pen(stroke) => stroke.id,
+            Self::Highlighter(stroke) => stroke.id,
             Self::Arrow(arrow) => arrow.id,
+            Self::Line(line) => line.id,
             Self::Rectangle(rectangle) => rectangle.id,
+            Self::Ellipse(ellipse) => ellipse.id,
+            Self::Badge(badge) => badge.id,
+            Self::Spotlight(spotlight) => spotlight.id,
+            Self::Magnifier(magnifier) => magnifier.id,
+            Self::Callout(callout) => callout.id,
+            Self::Image(image) => image.id,
             Self::Crop(crop) => crop.id,
             Self::Text(text) => text.id,
         }
@@ -116,6 +260,20 @@ impl ToolObject {
         }
     }
 
+    fn as_highlighter_mut(&mut self) -> Option<&mut HighlighterStroke> {
+        match self {
+            Self::Highlighter(stroke) => Some(stroke),
+            _ => None,
+        }
+    }
+
+    fn as_line_mut(&mut self) -> Option<&mut LineElement> {
+        match self {
+            Self::Line(line) => Some(line),
+            _ => None,
+        }
+    }
+
     fn as_rectangle_mut(&mut self) -> Option<&mut RectangleElement> {
         match self {
             Self::Rectangle(rectangle) => Some(rectangle),
@@ -123,6 +281,48 @@ impl ToolObject {
         }
     }
 
+    fn as_ellipse_mut(&mut self) -> Option<&mut EllipseElement> {
+        match self {
+            Self::Ellipse(ellipse) => Some(ellipse),
+            _ => None,
+        }
+    }
+
+    fn as_badge(&self) -> Option<&BadgeElement> {
+        match self {
+            Self::Badge(badge) => Some(badge),
+            _ => None,
+        }
+    }
+
+    fn as_spotlight_mut(&mut self) -> Option<&mut SpotlightElement> {
+        match self {
+            Self::Spotlight(spotlight) => Some(spotlight),
+            _ => None,
+        }
+    }
+
+    fn as_magnifier_mut(&mut self) -> Option<&mut MagnifierElement> {
+        match self {
+            Self::Magnifier(magnifier) => Some(magnifier),
+            _ => None,
+        }
+    }
+
+    fn as_callout_mut(&mut self) -> Option<&mut CalloutElement> {
+        match self {
+            Self::Callout(callout) => Some(callout),
+            _ => None,
+        }
+    }
+
+    fn as_image_mut(&mut self) -> Option<&mut ImageElement> {
+        match self {
+            Self::Image(image) => Some(image),
+            _ => None,
+        }
+    }
+
     fn as_crop(&self) -> Option<&CropElement> {
         match self {
             Self::Crop(crop) => Some(crop),
@@ -137,9 +337,11 @@ impl ToolObject {
         }
     }
 
+    /// Text boxes and the text inside callouts are edited the same way.
     fn as_text(&self) -> Option<&TextElement> {
         match self {
             Self::Text(text) => Some(text),
+            Self::Callout(callout) => Some(&callout.text),
             _ => None,
         }
     }
@@ -147,6 +349,7 @@ impl ToolObject {
     fn as_text_mut(&mut self) -> Option<&mut TextElement> {
         match self {
             Self::Text(text) => Some(text),
+            Self::Callout(callout) => Some(&mut callout.text),
             _ => None,
         }
     }
@@ -156,8 +359,14 @@ impl ToolObject {
 pub enum ToolError {
     InvalidBlurRegion,
     InvalidArrowGeometry,
+    InvalidLineGeometry,
     InvalidRectangleGeometry,
+    InvalidEllipseGeometry,
+    InvalidSpotlightGeometry,
+    InvalidMagnifierGeometry,
     InvalidCropGeometry,
+    InvalidImageGeometry,
+    InvalidImageData,
     EmptyPenStroke,
     PenStrokeNotFound,
     ObjectNotFound,
@@ -169,13 +378,24 @@ pub struct EditorTools {
     active_tool: ToolKind,
     blur_options: BlurOptions,
     pen_options: PenOptions,
+    highlighter_options: HighlighterOptions,
     arrow_options: ArrowOptions,
+    line_options: LineOptions,
     rectangle_options: RectangleOptions,
+    ellipse_options: EllipseOptions,
+    badge_options: BadgeOptions,
+    spotlight_options: SpotlightOptions,
+    magnifier_options: MagnifierOptions,
+    callout_options: CalloutOptions,
+    image_options: ImageOptions,
+    image_stamp: Stamp,
     crop_options: CropOptions,
     text_options: TextOptions,
     objects: Vec<ToolObject>,
     next_id: u64,
     active_pen_stroke: Option<u64>,
+    active_highlighter_stroke: Option<u64>,
+    active_line: Option<u64>,
     active_text_box: Option<u64>,
 }
 
@@ -231,8 +451,19 @@ impl EditorTools {
                     self.active_pen_stroke = None;
                 }
             }
-            ToolObject::Text(text) => {
-                if self.active_text_box == Some(text.id) {
+            ToolObject::Highlighter(stroke) => {
+                if self.active_highlighter_stroke == Some(stroke.id) {
+                    self.active_highlighter_stroke = None;
+                }
+            }
+            ToolObject::Line(line) => {
+                if self.active_line == Some(line.id) {
+                    self.active_line = None;
+                }
+            }
+            ToolObject::Text(TextElement { id, .. })
+            | ToolObject::Callout(CalloutElement { id, .. }) => {
+                if self.active_text_box == Some(*id) {
                     self.active_text_box = None;
                 }
             }
@@ -245,29 +476,87 @@ impl EditorTools {
             active_tool: ToolKind::Select,
             blur_options: BlurOptions::default(),
             pen_options: PenOptions::default(),
+            highlighter_options: HighlighterOptions::default(),
             arrow_options: ArrowOptions::default(),
+            line_options: LineOptions::default(),
             rectangle_options: RectangleOptions::default(),
+            ellipse_options: EllipseOptions::default(),
+            badge_options: BadgeOptions::default(),
+            spotlight_options: SpotlightOptions::default(),
+            magnifier_options: MagnifierOptions::default(),
+            callout_options: CalloutOptions::default(),
+            image_options: ImageOptions::default(),
+            image_stamp: Stamp::default(),
             crop_options: CropOptions::default(),
             text_options: TextOptions::default(),
             objects: Vec::new(),
             next_id: 1,
             active_pen_stroke: None,
+            active_highlighter_stroke: None,
+            active_line: None,
             active_text_box: None,
         }
     }
 
     pub fn select_tool(&mut self, tool: ToolKind) {
+        if tool != ToolKind::Line {
+            let _ = self.finish_active_line();
+        }
         self.active_tool = tool;
     }
 
+    pub fn pen_options(&self) -> PenOptions {
+        self.pen_options
+    }
+
+    pub fn blur_options(&self) -> BlurOptions {
+        self.blur_options
+    }
+
+    pub fn highlighter_options(&self) -> HighlighterOptions {
+        self.highlighter_options
+    }
+
     pub fn arrow_options(&self) -> ArrowOptions {
         self.arrow_options
     }
 
+    pub fn line_options(&self) -> LineOptions {
+        self.line_options
+    }
+
     pub fn rectangle_options(&self) -> RectangleOptions {
         self.rectangle_options
     }
 
+    pub fn ellipse_options(&self) -> EllipseOptions {
+        self.ellipse_options
+    }
+
+    pub fn badge_options(&self) -> BadgeOptions {
+        self.badge_options
+    }
+
+    pub fn spotlight_options(&self) -> SpotlightOptions {
+        self.spotlight_options
+    }
+
+    pub fn magnifier_options(&self) -> MagnifierOptions {
+        self.magnifier_options
+    }
+
+    pub fn callout_options(&self) -> CalloutOptions {
+        self.callout_options
+    }
+
+    pub fn image_options(&self) -> ImageOptions {
+        self.image_options
+    }
+
+    pub fn image_stamp(&self) -> Stamp {
+        self.image_stamp
+    }
+
     pub fn crop_options(&self) -> CropOptions {
         self.crop_options
     }
@@ -276,6 +565,18 @@ impl EditorTools {
         self.text_options
     }
 
+    pub fn set_blur_mode(&mut self, mode: BlurMode) {
+        self.blur_options.set_mode(mode);
+    }
+
+    pub fn set_blur_block_size(&mut self, block_size: u8) {
+        self.blur_options.set_block_size(block_size);
+    }
+
+    pub fn set_blur_fill_color(&mut self, color: Color) {
+        self.blur_options.set_fill_color(color);
+    }
+
     fn set_pen_color(&mut self, color: Color) {
         self.pen_options.set_color(color);
     }
@@ -284,6 +585,31 @@ impl EditorTools {
         self.pen_options.set_thickness(thickness);
     }
 
+    pub fn set_pen_smoothing(&mut self, smoothing: PenSmoothing) {
+        self.pen_options.set_smoothing(smoothing);
+    }
+
+    pub fn set_pen_shape_snap(&mut self, shape_snap: bool) {
+        self.pen_options.set_shape_snap(shape_snap);
+    }
+
+    pub fn set_pen_pressure_enabled(&mut self, pressure_enabled: bool) {
+        self.pen_options.set_pressure_enabled(pressure_enabled);
+    }
+
+    pub fn set_pen_pressure_curve(&mut self, pressure_curve: PressureCurve) {
+        self.pen_options.set_pressure_curve(pressure_curve);
+    }
+
+    fn set_highlighter_color(&mut self, color: Color) {
+        self.highlighter_options.set_color(color);
+    }
+
+    fn set_highlighter_thickness(&mut self, thickness: u8) {
+        self.highlighter_options
+            .set_thickness(thickness.saturating_mul(HIGHLIGHTER_WIDTH_SCALE));
+    }
+
     fn set_arrow_color(&mut self, color: Color) {
         self.arrow_options.set_color(color);
     }
@@ -296,6 +622,18 @@ impl EditorTools {
         self.arrow_options.set_head_size(head_size);
     }
 
+    fn set_line_color(&mut self, color: Color) {
+        self.line_options.set_color(color);
+    }
+
+    fn set_line_thickness(&mut self, thickness: u8) {
+        self.line_options.set_thickness(thickness);
+    }
+
+    pub fn set_line_cap(&mut self, cap: LineCap) {
+        self.line_options.set_cap(cap);
+    }
+
     fn set_rectangle_color(&mut self, color: Color) {
         self.rectangle_options.set_border_color(color);
     }
@@ -308,6 +646,58 @@ impl EditorTools {
         self.rectangle_options.set_border_radius(border_radius);
     }
 
+    fn set_ellipse_color(&mut self, color: Color) {
+        self.ellipse_options.set_color(color);
+    }
+
+    fn set_ellipse_thickness(&mut self, thickness: u8) {
+        self.ellipse_options.set_thickness(thickness);
+    }
+
+    pub fn set_ellipse_fill(&mut self, fill_enabled: bool) {
+        self.ellipse_options.set_fill_enabled(fill_enabled);
+    }
+
+    pub fn set_badge_size(&mut self, size: u8) {
+        self.badge_options.set_size(size);
+    }
+
+    pub fn set_spotlight_shape(&mut self, shape: SpotlightShape) {
+        self.spotlight_options.set_shape(shape);
+    }
+
+    pub fn set_magnifier_zoom(&mut self, zoom_percent: u16) {
+        self.magnifier_options.set_zoom_percent(zoom_percent);
+    }
+
+    pub fn set_magnifier_shape(&mut self, shape: MagnifierShape) {
+        self.magnifier_options.set_shape(shape);
+    }
+
+    pub fn set_magnifier_connector(&mut self, connector: bool) {
+        self.magnifier_options.set_connector(connector);
+    }
+
+    pub fn set_callout_fill(&mut self, fill: Color) {
+        self.callout_options.set_fill(fill);
+    }
+
+    pub fn set_image_stamp(&mut self, stamp: Stamp) {
+        self.image_stamp = stamp;
+    }
+
+    pub fn set_image_opacity(&mut self, opacity: u8) {
+        self.image_options.set_opacity(opacity);
+    }
+
+    pub fn set_image_rotation(&mut self, rotation: i16) {
+        self.image_options.set_rotation(rotation);
+    }
+
+    pub fn set_image_lock_aspect(&mut self, lock_aspect: bool) {
+        self.image_options.set_lock_aspect(lock_aspect);
+    }
+
     pub fn set_crop_preset(&mut self, preset: CropPreset) {
         self.crop_options.set_preset(preset);
     }
@@ -318,15 +708,32 @@ impl EditorTools {
 
     pub fn set_shared_stroke_color(&mut self, color: Color) {
         self.set_pen_color(color);
+        self.set_highlighter_color(color);
         self.set_arrow_color(color);
+        self.set_line_color(color);
         self.set_rectangle_color(color);
+        self.set_ellipse_color(color);
+        self.badge_options.set_color(color);
+        self.magnifier_options.set_color(color);
+        self.callout_options.set_color(color);
         self.set_text_color(color);
     }
 
     pub fn set_shared_stroke_thickness(&mut self, thickness: u8) {
         self.set_pen_thickness(thickness);
+        self.set_highlighter_thickness(thickness);
         self.set_arrow_thickness(thickness);
+        self.set_line_thickness(thickness);
         self.set_rectangle_thickness(thickness);
+        self.set_ellipse_thickness(thickness);
+        self.magnifier_options.set_thickness(thickness);
+        self.callout_options.set_thickness(thickness);
+    }
+
+    pub fn set_shared_dash(&mut self, dash: LineDash) {
+        self.line_options.set_dash(dash);
+        self.arrow_options.set_dash(dash);
+        self.rectangle_options.set_dash(dash);
     }
 
     pub fn set_text_size(&mut self, size: u8) {
@@ -379,6 +786,13 @@ impl ToolObject {
         }
     }
 
+    fn as_highlighter(&self) -> Option<&HighlighterStroke> {
+        match self {
+            Self::Highlighter(stroke) => Some(stroke),
+            _ => None,
+        }
+    }
+
     fn as_arrow(&self) -> Option<&ArrowElement> {
         match self {
             Self::Arrow(arrow) => Some(arrow),
@@ -386,12 +800,54 @@ impl ToolObject {
         }
     }
 
+    fn as_line(&self) -> Option<&LineElement> {
+        match self {
+            Self::Line(line) => Some(line),
+            _ => None,
+        }
+    }
+
     fn as_rectangle(&self) -> Option<&RectangleElement> {
         match self {
             Self::Rectangle(rectangle) => Some(rectangle),
             _ => None,
         }
     }
+
+    fn as_ellipse(&self) -> Option<&EllipseElement> {
+        match self {
+            Self::Ellipse(ellipse) => Some(ellipse),
+            _ => None,
+        }
+    }
+
+    fn as_spotlight(&self) -> Option<&SpotlightElement> {
+        match self {
+            Self::Spotlight(spotlight) => Some(spotlight),
+            _ => None,
+        }
+    }
+
+    fn as_magnifier(&self) -> Option<&MagnifierElement> {
+        match self {
+            Self::Magnifier(magnifier) => Some(magnifier),
+            _ => None,
+        }
+    }
+
+    fn as_callout(&self) -> Option<&CalloutElement> {
+        match self {
+            Self::Callout(callout) => Some(callout),
+            _ => None,
+        }
+    }
+
+    fn as_image(&self) -> Option<&ImageElement> {
+        match self {
+            Self::Image(image) => Some(image),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -412,10 +868,6 @@ impl EditorTools {
         self.blur_options.set_intensity(intensity);
     }
 
-    fn pen_options(&self) -> PenOptions {
-        self.pen_options
-    }
-
     fn set_pen_opacity(&mut self, opacity: u8) {
         self.pen_options.set_opacity(opacity);
     }
@@ -444,10 +896,26 @@ impl EditorTools {
         self.count_objects(|object| matches!(object, ToolObject::Arrow(_)))
     }
 
+    fn line_count(&self) -> usize {
+        self.count_objects(|object| matches!(object, ToolObject::Line(_)))
+    }
+
     fn rectangle_count(&self) -> usize {
         self.count_objects(|object| matches!(object, ToolObject::Rectangle(_)))
     }
 
+    fn badge_count(&self) -> usize {
+        self.count_objects(|object| matches!(object, ToolObject::Badge(_)))
+    }
+
+    fn spotlight_count(&self) -> usize {
+        self.count_objects(|object| matches!(object, ToolObject::Spotlight(_)))
+    }
+
+    fn ellipse_count(&self) -> usize {
+        self.count_objects(|object| matches!(object, ToolObject::Ellipse(_)))
+    }
+
     fn crop_count(&self) -> usize {
         self.count_objects(|object| matches!(object, ToolObject::Crop(_)))
     }
@@ -464,13 +932,41 @@ impl EditorTools {
         self.find_object_ref(id, ToolObject::as_pen)
     }
 
+    fn get_highlighter_stroke(&self, id: u64) -> Option<&HighlighterStroke> {
+        self.find_object_ref(id, ToolObject::as_highlighter)
+    }
+
     fn get_arrow(&self, id: u64) -> Option<&ArrowElement> {
         self.find_object_ref(id, ToolObject::as_arrow)
     }
 
+    fn get_spotlight(&self, id: u64) -> Option<&SpotlightElement> {
+        self.find_object_ref(id, ToolObject::as_spotlight)
+    }
+
+    fn get_magnifier(&self, id: u64) -> Option<&MagnifierElement> {
+        self.find_object_ref(id, ToolObject::as_magnifier)
+    }
+
+    fn get_callout(&self, id: u64) -> Option<&CalloutElement> {
+        self.find_object_ref(id, ToolObject::as_callout)
+    }
+
+    fn get_image(&self, id: u64) -> Option<&ImageElement> {
+        self.find_object_ref(id, ToolObject::as_image)
+    }
+
+    fn get_line(&self, id: u64) -> Option<&LineElement> {
+        self.find_object_ref(id, ToolObject::as_line)
+    }
+
     fn get_rectangle(&self, id: u64) -> Option<&RectangleElement> {
         self.find_object_ref(id, ToolObject::as_rectangle)
     }
+
+    fn get_ellipse(&self, id: u64) -> Option<&EllipseElement> {
+        self.find_object_ref(id, ToolObject::as_ellipse)
+    }
 }
 
 #[cfg(test)]
@@ -479,16 +975,105 @@ mod tests {
 
     #[test]
     fn pen_arrow_rectangle_show_color_and_stroke_width() {
-        for tool in [ToolKind::Pen, ToolKind::Arrow, ToolKind::Rectangle] {
+        for tool in [
+            ToolKind::Pen,
+            ToolKind::Highlighter,
+            ToolKind::Arrow,
+            ToolKind::Rectangle,
+        ] {
             let vis = tool.option_visibility();
             assert!(vis.has_color, "{tool:?} should have color");
             assert!(vis.has_stroke_width, "{tool:?} should have stroke width");
             assert!(!vis.has_text_size, "{tool:?} should not have text size");
             assert!(!vis.has_crop_preset, "{tool:?} should not have crop preset");
+            assert!(!vis.has_fill, "{tool:?} should not have fill toggle");
             assert!(vis.has_any());
         }
     }
 
+    #[test]
+    fn line_arrow_rectangle_show_dash_and_only_line_shows_cap() {
+        for tool in [ToolKind::Line, ToolKind::Arrow, ToolKind::Rectangle] {
+            assert!(
+                tool.option_visibility().has_dash,
+                "{tool:?} should have dash"
+            );
+        }
+        assert!(ToolKind::Line.option_visibility().has_line_cap);
+        assert!(!ToolKind::Arrow.option_visibility().has_line_cap);
+        assert!(!ToolKind::Pen.option_visibility().has_dash);
+    }
+
+    #[test]
+    fn ellipse_shows_color_stroke_width_and_fill() {
+        let vis = ToolKind::Ellipse.option_visibility();
+        assert!(vis.has_color);
+        assert!(vis.has_stroke_width);
+        assert!(vis.has_fill);
+        assert!(!vis.has_text_size);
+        assert!(!vis.has_crop_preset);
+    }
+
+    #[test]
+    fn badge_shows_color_and_badge_options_only() {
+        let vis = ToolKind::Badge.option_visibility();
+        assert!(vis.has_color);
+        assert!(vis.has_badge);
+        assert!(!vis.has_stroke_width);
+        assert!(!vis.has_text_size);
+        assert!(!ToolKind::Ellipse.option_visibility().has_badge);
+    }
+
+    #[test]
+    fn spotlight_shows_only_spotlight_options() {
+        let vis = ToolKind::Spotlight.option_visibility();
+        assert!(vis.has_spotlight);
+        assert!(!vis.has_color);
+        assert!(!vis.has_fill);
+        assert!(vis.has_any());
+    }
+
+    #[test]
+    fn pen_alone_shows_pen_options() {
+        let vis = ToolKind::Pen.option_visibility();
+        assert!(vis.has_color);
+        assert!(vis.has_stroke_width);
+        assert!(vis.has_pen);
+        assert!(!ToolKind::Highlighter.option_visibility().has_pen);
+    }
+
+    #[test]
+    fn magnifier_shows_stroke_style_and_magnifier_options() {
+        let vis = ToolKind::Magnifier.option_visibility();
+        assert!(vis.has_color);
+        assert!(vis.has_stroke_width);
+        assert!(vis.has_magnifier);
+        assert!(!vis.has_fill);
+        assert!(!vis.has_dash);
+        assert!(!ToolKind::Spotlight.option_visibility().has_magnifier);
+    }
+
+    #[test]
+    fn callout_shows_stroke_style_text_size_and_callout_options() {
+        let vis = ToolKind::Callout.option_visibility();
+        assert!(vis.has_color);
+        assert!(vis.has_stroke_width);
+        assert!(vis.has_text_size);
+        assert!(vis.has_callout);
+        assert!(!vis.has_dash);
+        assert!(!ToolKind::Text.option_visibility().has_callout);
+    }
+
+    #[test]
+    fn image_shows_only_image_options() {
+        let vis = ToolKind::Image.option_visibility();
+        assert!(vis.has_image);
+        assert!(vis.has_any());
+        assert!(!vis.has_color);
+        assert!(!vis.has_stroke_width);
+        assert!(!ToolKind::Callout.option_visibility().has_image);
+    }
+
     #[test]
     fn text_shows_color_and_text_size() {
         let vis = ToolKind::Text.option_visibility();
@@ -510,12 +1095,22 @@ mod tests {
     }
 
     #[test]
-    fn select_pan_blur_ocr_have_no_options() {
+    fn blur_shows_only_redaction_options() {
+        let vis = ToolKind::Blur.option_visibility();
+        assert!(vis.has_redaction);
+        assert!(!vis.has_color);
+        assert!(!vis.has_stroke_width);
+        assert!(!vis.has_fill);
+        assert!(vis.has_any());
+    }
+
+    #[test]
+    fn select_pan_ocr_decode_have_no_options() {
         for tool in [
             ToolKind::Select,
             ToolKind::Pan,
-            ToolKind::Blur,
             ToolKind::Ocr,
+            ToolKind::Decode,
         ] {
             let vis = tool.option_visibility();
             assert!(!vis.has_any(), "{tool:?} should have no options");
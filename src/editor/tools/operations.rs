@@ -47,6 +47,184 @@ impl EditorTools {
         Ok(id)
     }
 
+    pub fn add_ellipse(&mut self, start: ToolPoint, end: ToolPoint) -> Result<u64, ToolError> {
+        let dx = i64::from(end.x) - i64::from(start.x);
+        let dy = i64::from(end.y) - i64::from(start.y);
+        let width = dx.abs();
+        let height = dy.abs();
+
+        if width == 0 || height == 0 {
+            return Err(ToolError::InvalidEllipseGeometry);
+        }
+
+        let id = self.allocate_id();
+        let ellipse = EllipseElement::new(
+            id,
+            start.x.min(end.x),
+            start.y.min(end.y),
+            u32::try_from(width).expect("ellipse width must fit u32"),
+            u32::try_from(height).expect("ellipse height must fit u32"),
+            self.ellipse_options,
+        );
+        self.objects.push(ToolObject::Ellipse(ellipse));
+        Ok(id)
+    }
+
+    /// Places the next badge at `center`. Releasing the drag outside the
+    /// badge adds a leader arrow pointing at `target`.
+    pub fn add_badge(&mut self, center: ToolPoint, target: ToolPoint) -> u64 {
+        let leader = self.badge_options.leader_for(center, target);
+        let id = self.allocate_id();
+        let badge = BadgeElement::new(id, center, leader, self.badge_options);
+        self.objects.push(ToolObject::Badge(badge));
+        id
+    }
+
+    /// Sets the numbering style for new badges and for the badges already
+    /// placed, so the sequence stays uniform. Returns whether any placed
+    /// badge changed.
+    pub fn set_badge_style(&mut self, style: BadgeStyle) -> bool {
+        self.badge_options.set_style(style);
+        self.update_placed_badges(|options| options.set_style(style))
+    }
+
+    /// Sets the value the first badge counts from. Like the style, this
+    /// applies to the whole sequence.
+    pub fn set_badge_start(&mut self, start: u32) -> bool {
+        self.badge_options.set_start(start);
+        self.update_placed_badges(|options| options.set_start(start))
+    }
+
+    fn update_placed_badges(&mut self, update: impl Fn(&mut BadgeOptions)) -> bool {
+        let mut changed = false;
+        for object in &mut self.objects {
+            if let ToolObject::Badge(badge) = object {
+                let before = badge.options;
+                update(&mut badge.options);
+                changed |= badge.options != before;
+            }
+        }
+        changed
+    }
+
+    /// Swaps a badge with its neighbour in the sequence, so the two trade
+    /// numbers. Returns `false` when the badge is already first or last.
+    pub fn move_badge_in_sequence(&mut self, id: u64, later: bool) -> Result<bool, ToolError> {
+        let badge_indices = self
+            .objects
+            .iter()
+            .enumerate()
+            .filter(|(_, object)| matches!(object, ToolObject::Badge(_)))
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        let position = badge_indices
+            .iter()
+            .position(|&index| self.objects[index].id() == id)
+            .ok_or(ToolError::ObjectNotFound)?;
+        let neighbour = if later {
+            badge_indices.get(position + 1)
+        } else {
+            position
+                .checked_sub(1)
+                .and_then(|previous| badge_indices.get(previous))
+        };
+        let Some(&neighbour) = neighbour else {
+            return Ok(false);
+        };
+        self.objects.swap(badge_indices[position], neighbour);
+        Ok(true)
+    }
+
+    /// Adds a hole spanning the drag from `start` to `end`. The first hole
+    /// creates the spotlight; later ones join it. Returns the spotlight id.
+    pub fn add_spotlight_hole(
+        &mut self,
+        start: ToolPoint,
+        end: ToolPoint,
+    ) -> Result<u64, ToolError> {
+        let width = (i64::from(end.x) - i64::from(start.x)).unsigned_abs();
+        let height = (i64::from(end.y) - i64::from(start.y)).unsigned_abs();
+        if width == 0 || height == 0 {
+            return Err(ToolError::InvalidSpotlightGeometry);
+        }
+
+        let hole = SpotlightHole::new(
+            self.spotlight_options.shape,
+            ToolBounds::new(
+                start.x.min(end.x),
+                start.y.min(end.y),
+                u32::try_from(width).expect("spotlight hole width must fit u32"),
+                u32::try_from(height).expect("spotlight hole height must fit u32"),
+            ),
+        );
+        if let Some(spotlight) = self
+            .objects
+            .iter_mut()
+            .find_map(ToolObject::as_spotlight_mut)
+        {
+            spotlight.holes.push(hole);
+            return Ok(spotlight.id);
+        }
+        let id = self.allocate_id();
+        let spotlight = SpotlightElement::new(id, hole, self.spotlight_options);
+        self.objects.push(ToolObject::Spotlight(spotlight));
+        Ok(id)
+    }
+
+    /// Sets how dark the area outside the holes is, for the next spotlight
+    /// and the placed one. Returns whether the placed spotlight changed.
+    pub fn set_spotlight_opacity(&mut self, opacity: u8) -> bool {
+        self.spotlight_options.set_opacity(opacity);
+        self.update_placed_spotlight(|options| options.set_opacity(opacity))
+    }
+
+    /// Toggles blurring the dimmed area; applies like the opacity.
+    pub fn set_spotlight_blur_outside(&mut self, blur_outside: bool) -> bool {
+        self.spotlight_options.set_blur_outside(blur_outside);
+        self.update_placed_spotlight(|options| options.set_blur_outside(blur_outside))
+    }
+
+    fn update_placed_spotlight(&mut self, update: impl Fn(&mut SpotlightOptions)) -> bool {
+        let Some(spotlight) = self
+            .objects
+            .iter_mut()
+            .find_map(ToolObject::as_spotlight_mut)
+        else {
+            return false;
+        };
+        let before = spotlight.options;
+        update(&mut spotlight.options);
+        spotlight.options != before
+    }
+
+    /// Adds a magnifier for the source region dragged from `start` to `end`,
+    /// with its callout placed beside the source inside `image_bounds`.
+    pub fn add_magnifier(
+        &mut self,
+        start: ToolPoint,
+        end: ToolPoint,
+        image_bounds: ImageBounds,
+    ) -> Result<u64, ToolError> {
+        let options = self.magnifier_options;
+        let end = options.shape.constrain_drag(start, end);
+        let width = (i64::from(end.x) - i64::from(start.x)).unsigned_abs();
+        let height = (i64::from(end.y) - i64::from(start.y)).unsigned_abs();
+        if width == 0 || height == 0 {
+            return Err(ToolError::InvalidMagnifierGeometry);
+        }
+
+        let source = ToolBounds::new(
+            start.x.min(end.x),
+            start.y.min(end.y),
+            u32::try_from(width).expect("magnifier source width must fit u32"),
+            u32::try_from(height).expect("magnifier source height must fit u32"),
+        );
+        let id = self.allocate_id();
+        let magnifier = MagnifierElement::new(id, source, image_bounds, options);
+        self.objects.push(ToolObject::Magnifier(magnifier));
+        Ok(id)
+    }
+
     pub fn add_crop_in_bounds(
         &mut self,
         start: ToolPoint,
@@ -91,6 +269,50 @@ impl EditorTools {
         id
     }
 
+    /// Places a speech bubble whose text starts at `text_anchor` and whose
+    /// tail points at `tail`, and starts editing its text.
+    pub fn add_callout(&mut self, tail: ToolPoint, text_anchor: ToolPoint) -> u64 {
+        let id = self.allocate_id();
+        let callout = CalloutElement::new(
+            id,
+            tail,
+            text_anchor,
+            self.text_options,
+            self.callout_options,
+        );
+        self.objects.push(ToolObject::Callout(callout));
+        self.active_text_box = Some(id);
+        id
+    }
+
+    /// Places the current stamp over the square dragged from `start` to
+    /// `end`, or a default-sized one centered on a click.
+    pub fn add_stamp(
+        &mut self,
+        start: ToolPoint,
+        end: ToolPoint,
+        image_bounds: ImageBounds,
+    ) -> Result<u64, ToolError> {
+        let frame = stamp_frame(start, end, image_bounds);
+        if frame.width < IMAGE_MIN_SIZE || frame.height < IMAGE_MIN_SIZE {
+            return Err(ToolError::InvalidImageGeometry);
+        }
+        let id = self.allocate_id();
+        let source = ImageSource::Stamp(self.image_stamp);
+        let image = ImageElement::new(id, source, frame, self.image_options);
+        self.objects.push(ToolObject::Image(image));
+        Ok(id)
+    }
+
+    /// Inserts a pasted or opened picture centered on the capture.
+    pub fn add_picture(&mut self, picture: EmbeddedImage, image_bounds: ImageBounds) -> u64 {
+        let id = self.allocate_id();
+        let frame = picture_frame(&picture, image_bounds);
+        let image = ImageElement::new(id, ImageSource::Picture(picture), frame, self.image_options);
+        self.objects.push(ToolObject::Image(image));
+        id
+    }
+
     pub fn add_text_box(&mut self, at: ToolPoint) -> u64 {
         let id = self.allocate_id();
         self.push_text_element(TextElement::new(id, at, self.text_options))
@@ -178,15 +400,23 @@ impl EditorTools {
         }
     }
 
-    pub fn begin_pen_stroke(&mut self, start: ToolPoint) -> u64 {
+    /// Starts a pen stroke. `stylus` carries pressure and tilt when the
+    /// input came from a tablet pen, and is `None` for mouse input.
+    pub fn begin_pen_stroke(&mut self, start: ToolPoint, stylus: Option<StylusSample>) -> u64 {
         let id = self.allocate_id();
-        let stroke = PenStroke::new(id, PenPoint::new(start.x, start.y), self.pen_options);
+        let start = PenPoint::new(start.x, start.y).with_stylus(stylus);
+        let stroke = PenStroke::new(id, start, self.pen_options);
         self.objects.push(ToolObject::Pen(stroke));
         self.active_pen_stroke = Some(id);
         id
     }
 
-    pub fn append_pen_point(&mut self, stroke_id: u64, point: ToolPoint) -> Result<(), ToolError> {
+    pub fn append_pen_point(
+        &mut self,
+        stroke_id: u64,
+        point: ToolPoint,
+        stylus: Option<StylusSample>,
+    ) -> Result<(), ToolError> {
         if self.active_pen_stroke.is_none() {
             return Err(ToolError::ToolNotSelected);
         }
@@ -194,22 +424,196 @@ impl EditorTools {
         let stroke = self
             .find_object_mut(stroke_id, ToolObject::as_pen_mut)
             .ok_or(ToolError::PenStrokeNotFound)?;
-        stroke.append_point(PenPoint::new(point.x, point.y));
+        stroke.append_point(PenPoint::new(point.x, point.y).with_stylus(stylus));
         Ok(())
     }
 
+    /// Adds a highlighter stroke across the box, in the highlighter's color
+    /// and opacity with a nib as tall as the box. It is an ordinary
+    /// highlighter object, so it can be selected, moved and deleted like one.
+    pub fn add_highlight(
+        &mut self,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+    ) -> Result<u64, ToolError> {
+        if width == 0 || height == 0 {
+            return Err(ToolError::InvalidRectangleGeometry);
+        }
+        let options = HighlighterOptions {
+            thickness: u8::try_from(height).unwrap_or(u8::MAX),
+            ..self.highlighter_options
+        };
+        let center_y = y.saturating_add((height / 2) as i32);
+        let right = x.saturating_add(i32::try_from(width).unwrap_or(i32::MAX));
+
+        let id = self.allocate_id();
+        let mut stroke = HighlighterStroke::new(id, ToolPoint::new(x, center_y), options);
+        stroke.append_point(ToolPoint::new(right, center_y), true);
+        self.objects.push(ToolObject::Highlighter(stroke));
+        Ok(id)
+    }
+
+    /// Finalizes the stroke and applies the pen's clean-up options. With
+    /// shape snap on, a recognized stroke is replaced in place by the shape
+    /// it resembles and keeps its id; otherwise the points are smoothed.
     pub fn finish_pen_stroke(&mut self, stroke_id: u64) -> Result<(), ToolError> {
-        {
+        let snapped = {
             let stroke = self
                 .find_object_mut(stroke_id, ToolObject::as_pen_mut)
                 .ok_or(ToolError::PenStrokeNotFound)?;
             stroke.finalize();
+            // Shapes have no opacity of their own, so translucent strokes stay
+            // freehand instead of turning opaque.
+            let snapped = (stroke.options.shape_snap && stroke.options.opacity >= 100)
+                .then(|| recognize_shape(&stroke.points))
+                .flatten();
+            if snapped.is_none() {
+                if let Some(tolerance) = stroke.options.smoothing.tolerance() {
+                    stroke.points = smooth_points(&stroke.points, tolerance);
+                }
+            }
+            snapped.map(|shape| (shape, stroke.options))
+        };
+        if let Some((shape, pen)) = snapped {
+            let object = self.snapped_shape_object(stroke_id, shape, pen);
+            if let Some(slot) = self
+                .objects
+                .iter_mut()
+                .find(|object| object.id() == stroke_id)
+            {
+                *slot = object;
+            }
         }
         if self.active_pen_stroke == Some(stroke_id) {
             self.active_pen_stroke = None;
         }
         Ok(())
     }
+
+    /// Builds the object a snapped stroke turns into, using that tool's
+    /// current options with the pen's color and thickness. Closed shapes are
+    /// never filled, so they outline what was circled instead of covering it.
+    fn snapped_shape_object(&self, id: u64, shape: SnappedShape, pen: PenOptions) -> ToolObject {
+        match shape {
+            SnappedShape::Line { start, end } => {
+                let options = LineOptions {
+                    color: pen.color,
+                    thickness: pen.thickness,
+                    ..self.line_options
+                };
+                let mut line = LineElement::new(id, start, options);
+                line.push_vertex(end);
+                ToolObject::Line(line)
+            }
+            SnappedShape::Arrow { start, end } => {
+                let options = ArrowOptions {
+                    color: pen.color,
+                    thickness: pen.thickness,
+                    ..self.arrow_options
+                };
+                ToolObject::Arrow(ArrowElement::new(id, start, end, options))
+            }
+            SnappedShape::Rectangle(bounds) => {
+                let options = RectangleOptions {
+                    color: pen.color,
+                    thickness: pen.thickness,
+                    fill_enabled: false,
+                    ..self.rectangle_options
+                };
+                ToolObject::Rectangle(RectangleElement::new(
+                    id,
+                    bounds.x,
+                    bounds.y,
+                    bounds.width,
+                    bounds.height,
+                    options,
+                ))
+            }
+            SnappedShape::Ellipse(bounds) => {
+                let options = EllipseOptions {
+                    color: pen.color,
+                    thickness: pen.thickness,
+                    fill_enabled: false,
+                };
+                ToolObject::Ellipse(EllipseElement::new(
+                    id,
+                    bounds.x,
+                    bounds.y,
+                    bounds.width,
+                    bounds.height,
+                    options,
+                ))
+            }
+        }
+    }
+
+    pub fn begin_highlighter_stroke(&mut self, start: ToolPoint) -> u64 {
+        let id = self.allocate_id();
+        let stroke = HighlighterStroke::new(id, start, self.highlighter_options);
+        self.objects.push(ToolObject::Highlighter(stroke));
+        self.active_highlighter_stroke = Some(id);
+        id
+    }
+
+    pub fn append_highlighter_point(
+        &mut self,
+        stroke_id: u64,
+        point: ToolPoint,
+        snap_horizontal: bool,
+    ) -> Result<(), ToolError> {
+        if self.active_highlighter_stroke != Some(stroke_id) {
+            return Err(ToolError::ToolNotSelected);
+        }
+
+        let stroke = self
+            .find_object_mut(stroke_id, ToolObject::as_highlighter_mut)
+            .ok_or(ToolError::ObjectNotFound)?;
+        stroke.append_point(point, snap_horizontal);
+        Ok(())
+    }
+
+    pub fn finish_highlighter_stroke(&mut self, stroke_id: u64) -> Result<(), ToolError> {
+        if self.active_highlighter_stroke == Some(stroke_id) {
+            self.active_highlighter_stroke = None;
+        }
+        self.find_object_mut(stroke_id, ToolObject::as_highlighter_mut)
+            .map(|_| ())
+            .ok_or(ToolError::ObjectNotFound)
+    }
+
+    /// Adds a vertex to the polyline being drawn, starting a new one when
+    /// none is active. Returns the id of the line that received the vertex.
+    pub fn add_line_vertex(&mut self, point: ToolPoint) -> u64 {
+        if let Some(line_id) = self.active_line {
+            if let Some(line) = self.find_object_mut(line_id, ToolObject::as_line_mut) {
+                line.push_vertex(point);
+                return line_id;
+            }
+        }
+
+        let id = self.allocate_id();
+        let line = LineElement::new(id, point, self.line_options);
+        self.objects.push(ToolObject::Line(line));
+        self.active_line = Some(id);
+        id
+    }
+
+    /// Ends the active polyline. A line with fewer than two vertices is
+    /// removed instead of being kept as an invisible object.
+    pub fn finish_active_line(&mut self) -> Result<u64, ToolError> {
+        let line_id = self.active_line.take().ok_or(ToolError::ToolNotSelected)?;
+        let drawable = self
+            .find_object_mut(line_id, ToolObject::as_line_mut)
+            .map(|line| line.is_drawable())
+            .ok_or(ToolError::ObjectNotFound)?;
+        if !drawable {
+            self.remove_object(line_id);
+            return Err(ToolError::InvalidLineGeometry);
+        }
+        Ok(line_id)
+    }
 }
 
 fn clamp_crop_bounds(value: i64, min: i64, max: i64) -> i64 {
@@ -334,6 +738,18 @@ mod tests {
         assert_eq!(rectangle.color, Color::new(12, 24, 36));
         assert_eq!(rectangle.thickness, 7);
 
+        let ellipse = tools.ellipse_options();
+        assert_eq!(ellipse.color, Color::new(12, 24, 36));
+        assert_eq!(ellipse.thickness, 7);
+
+        let line = tools.line_options();
+        assert_eq!(line.color, Color::new(12, 24, 36));
+        assert_eq!(line.thickness, 7);
+
+        let highlighter = tools.highlighter_options();
+        assert_eq!(highlighter.color, Color::new(12, 24, 36));
+        assert_eq!(highlighter.thickness, 7 * HIGHLIGHTER_WIDTH_SCALE);
+
         let text = tools.text_options();
         assert_eq!(text.color, Color::new(12, 24, 36));
     }
@@ -357,6 +773,29 @@ mod tests {
         assert_eq!(blur.region, BlurRegion::new(3, 4, 20, 12));
     }
 
+    #[test]
+    fn blur_redaction_mode_is_stored_per_element() {
+        let mut tools = session();
+        tools.set_blur_mode(BlurMode::Pixelate);
+        tools.set_blur_block_size(16);
+        let pixelated = tools
+            .add_blur(BlurRegion::new(0, 0, 20, 20))
+            .expect("blur region should be valid");
+
+        tools.set_blur_mode(BlurMode::SolidFill);
+        tools.set_blur_fill_color(Color::new(255, 255, 255));
+        let filled = tools
+            .add_blur(BlurRegion::new(30, 0, 20, 20))
+            .expect("blur region should be valid");
+
+        let pixelated = tools.get_blur(pixelated).expect("blur should exist");
+        assert_eq!(pixelated.options.mode, BlurMode::Pixelate);
+        assert_eq!(pixelated.options.block_size, 16);
+        let filled = tools.get_blur(filled).expect("blur should exist");
+        assert_eq!(filled.options.mode, BlurMode::SolidFill);
+        assert_eq!(filled.options.fill_color, Color::new(255, 255, 255));
+    }
+
     #[test]
     fn tool_blur_pen_pen_stroke_records_points_and_sticky_options() {
         let mut tools = session();
@@ -364,12 +803,12 @@ mod tests {
         tools.set_pen_opacity(88);
         tools.set_pen_thickness(7);
 
-        let stroke_id = tools.begin_pen_stroke(ToolPoint::new(1, 1));
+        let stroke_id = tools.begin_pen_stroke(ToolPoint::new(1, 1), None);
         tools
-            .append_pen_point(stroke_id, ToolPoint::new(2, 2))
+            .append_pen_point(stroke_id, ToolPoint::new(2, 2), None)
             .expect("pen stroke should be active");
         tools
-            .append_pen_point(stroke_id, ToolPoint::new(3, 4))
+            .append_pen_point(stroke_id, ToolPoint::new(3, 4), None)
             .expect("pen stroke should be active");
         tools
             .finish_pen_stroke(stroke_id)
@@ -388,6 +827,149 @@ mod tests {
         assert_eq!(tools.pen_stroke_count(), 1);
     }
 
+    #[test]
+    fn tool_pen_records_stylus_samples_per_point() {
+        let mut tools = session();
+        let light = StylusSample::from_axes(0.3, Some(0.1), Some(-0.2));
+
+        let stroke_id = tools.begin_pen_stroke(ToolPoint::new(5, 5), Some(light));
+        tools
+            .append_pen_point(stroke_id, ToolPoint::new(9, 9), None)
+            .expect("pen stroke should be active");
+        tools
+            .finish_pen_stroke(stroke_id)
+            .expect("pen stroke should finish");
+        let stroke = tools
+            .get_pen_stroke(stroke_id)
+            .expect("pen stroke should exist");
+
+        assert_eq!(stroke.points[0].stylus, Some(light));
+        assert_eq!(stroke.points[1].stylus, None);
+        assert!(stroke.has_varying_width());
+
+        tools.set_pen_pressure_enabled(false);
+        let mouse_id = tools.begin_pen_stroke(ToolPoint::new(5, 5), Some(light));
+        let mouse = tools
+            .get_pen_stroke(mouse_id)
+            .expect("pen stroke should exist");
+        assert!(!mouse.has_varying_width());
+    }
+
+    fn draw_pen_stroke(tools: &mut EditorTools, corners: &[(i32, i32)]) -> u64 {
+        let stroke_id = tools.begin_pen_stroke(ToolPoint::new(corners[0].0, corners[0].1), None);
+        for pair in corners.windows(2) {
+            let (start, end) = (pair[0], pair[1]);
+            for step in 1..=10 {
+                let point = ToolPoint::new(
+                    start.0 + (end.0 - start.0) * step / 10,
+                    start.1 + (end.1 - start.1) * step / 10 + step % 2,
+                );
+                tools
+                    .append_pen_point(stroke_id, point, None)
+                    .expect("pen stroke should be active");
+            }
+        }
+        tools
+            .finish_pen_stroke(stroke_id)
+            .expect("pen stroke should finish");
+        stroke_id
+    }
+
+    #[test]
+    fn tool_pen_smoothing_flattens_jitter_and_keeps_end_points() {
+        let mut tools = session();
+        tools.set_pen_smoothing(PenSmoothing::Medium);
+
+        let stroke_id = draw_pen_stroke(&mut tools, &[(0, 0), (100, 0)]);
+        let stroke = tools
+            .get_pen_stroke(stroke_id)
+            .expect("pen stroke should exist");
+
+        assert_eq!(stroke.points.first(), Some(&PenPoint::new(0, 0)));
+        assert_eq!(stroke.points.last(), Some(&PenPoint::new(100, 0)));
+        assert!(stroke.points.iter().all(|point| point.y == 0));
+    }
+
+    #[test]
+    fn tool_pen_shape_snap_replaces_stroke_with_shape_under_same_id() {
+        let mut tools = session();
+        tools.set_pen_color(Color::new(200, 0, 0));
+        tools.set_pen_thickness(5);
+        tools.set_pen_shape_snap(true);
+        tools.set_rectangle_fill(true);
+
+        let stroke_id = draw_pen_stroke(
+            &mut tools,
+            &[(10, 10), (110, 10), (110, 70), (10, 70), (10, 14)],
+        );
+        let rectangle = tools
+            .get_rectangle(stroke_id)
+            .expect("closed box should snap to a rectangle");
+
+        assert_eq!(tools.pen_stroke_count(), 0);
+        assert_eq!((rectangle.x, rectangle.y), (10, 10));
+        assert_eq!(rectangle.options.color, Color::new(200, 0, 0));
+        assert_eq!(rectangle.options.thickness, 5);
+        assert!(!rectangle.options.fill_enabled);
+        assert!(tools.active_pen_stroke.is_none());
+    }
+
+    #[test]
+    fn tool_pen_shape_snap_keeps_translucent_strokes() {
+        let mut tools = session();
+        tools.set_pen_shape_snap(true);
+        tools.set_pen_opacity(60);
+
+        let stroke_id = draw_pen_stroke(
+            &mut tools,
+            &[(10, 10), (110, 10), (110, 70), (10, 70), (10, 14)],
+        );
+
+        assert_eq!(
+            tools.get_pen_stroke(stroke_id).map(|s| s.options.opacity),
+            Some(60)
+        );
+        assert!(tools.get_rectangle(stroke_id).is_none());
+    }
+
+    #[test]
+    fn tool_pen_shape_snap_keeps_unrecognized_strokes() {
+        let mut tools = session();
+        tools.set_pen_shape_snap(true);
+
+        let stroke_id = draw_pen_stroke(&mut tools, &[(0, 0), (60, 40), (10, 50), (80, 5)]);
+
+        assert!(tools.get_pen_stroke(stroke_id).is_some());
+        assert_eq!(tools.pen_stroke_count(), 1);
+    }
+
+    #[test]
+    fn add_highlight_draws_highlighter_stroke_across_box() {
+        let mut tools = session();
+        tools.set_shared_stroke_color(Color::new(1, 2, 3));
+
+        let id = tools
+            .add_highlight(10, 20, 100, 16)
+            .expect("highlight box should be valid");
+        assert!(matches!(tools.object(id), Some(ToolObject::Highlighter(_))));
+        let stroke = tools
+            .get_highlighter_stroke(id)
+            .expect("highlight should exist");
+
+        assert_eq!(stroke.options.color, Color::new(1, 2, 3));
+        assert_eq!(stroke.options.opacity, tools.highlighter_options().opacity);
+        assert_eq!(stroke.options.thickness, 16);
+        assert_eq!(
+            stroke.points,
+            vec![ToolPoint::new(10, 28), ToolPoint::new(110, 28)]
+        );
+        assert_eq!(tools.active_highlighter_stroke, None);
+        assert!(matches!(
+            tools.add_highlight(0, 0, 10, 0),
+            Err(ToolError::InvalidRectangleGeometry)
+        ));
+    }
+
     #[test]
     fn tool_blur_pen_rejects_invalid_blur_region() {
         let mut tools = session();
@@ -489,6 +1071,246 @@ mod tests {
         assert!(matches!(err, ToolError::InvalidRectangleGeometry));
     }
 
+    #[test]
+    fn tool_ellipse_is_normalized_from_drag_and_keeps_style() {
+        let mut tools = session();
+        tools.select_tool(ToolKind::Ellipse);
+        tools.set_shared_stroke_color(Color::new(40, 50, 60));
+        tools.set_shared_stroke_thickness(5);
+        tools.set_ellipse_fill(true);
+
+        let ellipse_id = tools
+            .add_ellipse(ToolPoint::new(50, 10), ToolPoint::new(20, 70))
+            .expect("valid ellipse drag should create object");
+        let ellipse = tools
+            .get_ellipse(ellipse_id)
+            .expect("ellipse should be stored after add");
+
+        assert_eq!((ellipse.x, ellipse.y), (20, 10));
+        assert_eq!((ellipse.width, ellipse.height), (30, 60));
+        assert_eq!(ellipse.options.color, Color::new(40, 50, 60));
+        assert_eq!(ellipse.options.thickness, 5);
+        assert!(ellipse.options.fill_enabled);
+        assert_eq!(tools.ellipse_count(), 1);
+    }
+
+    #[test]
+    fn tool_badges_renumber_after_delete_and_reorder() {
+        let mut tools = session();
+        let first = tools.add_badge(ToolPoint::new(10, 10), ToolPoint::new(10, 10));
+        let second = tools.add_badge(ToolPoint::new(50, 10), ToolPoint::new(51, 11));
+        let third = tools.add_badge(ToolPoint::new(90, 10), ToolPoint::new(90, 10));
+        assert_eq!(tools.badge_count(), 3);
+        assert_eq!(tools.next_badge_label(), "4");
+
+        assert!(tools.remove_object(second).is_some());
+        assert_eq!(
+            tools.badge_labels(),
+            vec![(first, "1".to_string()), (third, "2".to_string())]
+        );
+
+        assert_eq!(tools.move_badge_in_sequence(third, false), Ok(true));
+        assert_eq!(
+            tools.badge_labels(),
+            vec![(third, "1".to_string()), (first, "2".to_string())]
+        );
+        assert_eq!(tools.move_badge_in_sequence(third, false), Ok(false));
+        assert_eq!(
+            tools.move_badge_in_sequence(999, true),
+            Err(ToolError::ObjectNotFound)
+        );
+    }
+
+    #[test]
+    fn tool_badge_style_and_start_apply_to_placed_badges() {
+        let mut tools = session();
+        let first = tools.add_badge(ToolPoint::new(10, 10), ToolPoint::new(10, 10));
+        let second = tools.add_badge(ToolPoint::new(50, 10), ToolPoint::new(50, 10));
+
+        assert!(tools.set_badge_style(BadgeStyle::Roman));
+        assert!(tools.set_badge_start(3));
+        assert!(!tools.set_badge_start(3));
+        assert_eq!(
+            tools.badge_labels(),
+            vec![(first, "III".to_string()), (second, "IV".to_string())]
+        );
+        assert_eq!(tools.next_badge_label(), "V");
+    }
+
+    #[test]
+    fn tool_badge_adds_leader_only_when_dragged_outside_badge() {
+        let mut tools = session();
+        tools.set_badge_size(20);
+        let short = tools.add_badge(ToolPoint::new(10, 10), ToolPoint::new(18, 10));
+        let long = tools.add_badge(ToolPoint::new(10, 10), ToolPoint::new(60, 40));
+
+        let leader_of = |id| match tools.object(id) {
+            Some(ToolObject::Badge(badge)) => badge.leader,
+            _ => panic!("badge should exist"),
+        };
+        assert_eq!(leader_of(short), None);
+        assert_eq!(leader_of(long), Some(ToolPoint::new(60, 40)));
+    }
+
+    #[test]
+    fn tool_spotlight_holes_join_a_single_object() {
+        let mut tools = session();
+        let first = tools
+            .add_spotlight_hole(ToolPoint::new(40, 30), ToolPoint::new(10, 10))
+            .expect("hole should be valid");
+        tools.set_spotlight_shape(SpotlightShape::Ellipse);
+        let second = tools
+            .add_spotlight_hole(ToolPoint::new(60, 60), ToolPoint::new(80, 90))
+            .expect("hole should be valid");
+
+        assert_eq!(first, second);
+        assert_eq!(tools.spotlight_count(), 1);
+        let spotlight = tools.get_spotlight(first).expect("spotlight should exist");
+        assert_eq!(
+            spotlight.holes,
+            vec![
+                SpotlightHole::new(SpotlightShape::Rectangle, ToolBounds::new(10, 10, 30, 20)),
+                SpotlightHole::new(SpotlightShape::Ellipse, ToolBounds::new(60, 60, 20, 30)),
+            ]
+        );
+        assert_eq!(
+            tools.add_spotlight_hole(ToolPoint::new(5, 5), ToolPoint::new(5, 40)),
+            Err(ToolError::InvalidSpotlightGeometry)
+        );
+    }
+
+    #[test]
+    fn tool_magnifier_squares_circle_sources_and_rejects_empty_drags() {
+        let mut tools = session();
+        let image = ImageBounds::new(400, 300);
+        let circle_id = tools
+            .add_magnifier(ToolPoint::new(10, 10), ToolPoint::new(50, 30), image)
+            .expect("magnifier should be inserted");
+        let circle = tools.get_magnifier(circle_id).expect("magnifier exists");
+        assert_eq!(circle.source_bounds(), ToolBounds::new(10, 10, 40, 40));
+        assert_eq!(circle.callout_bounds(), ToolBounds::new(74, 0, 80, 80));
+
+        tools.set_magnifier_shape(MagnifierShape::Rectangle);
+        tools.set_magnifier_zoom(300);
+        let rectangle_id = tools
+            .add_magnifier(ToolPoint::new(10, 10), ToolPoint::new(50, 30), image)
+            .expect("magnifier should be inserted");
+        let rectangle = tools.get_magnifier(rectangle_id).expect("magnifier exists");
+        assert_eq!(rectangle.source_bounds(), ToolBounds::new(10, 10, 40, 20));
+        assert_eq!(rectangle.callout_bounds().width, 120);
+
+        assert_eq!(
+            tools.add_magnifier(ToolPoint::new(5, 5), ToolPoint::new(5, 40), image),
+            Err(ToolError::InvalidMagnifierGeometry)
+        );
+    }
+
+    #[test]
+    fn tool_spotlight_dimming_applies_to_placed_spotlight() {
+        let mut tools = session();
+        assert!(!tools.set_spotlight_opacity(80));
+        let id = tools
+            .add_spotlight_hole(ToolPoint::new(0, 0), ToolPoint::new(10, 10))
+            .expect("hole should be valid");
+        assert_eq!(
+            tools
+                .get_spotlight(id)
+                .map(|spotlight| spotlight.options.opacity),
+            Some(80)
+        );
+
+        assert!(tools.set_spotlight_blur_outside(true));
+        assert!(!tools.set_spotlight_blur_outside(true));
+        assert!(tools.set_spotlight_opacity(40));
+        let spotlight = tools.get_spotlight(id).expect("spotlight should exist");
+        assert!(spotlight.options.blur_outside);
+        assert_eq!(spotlight.options.opacity, 40);
+    }
+
+    #[test]
+    fn tool_highlighter_stroke_snaps_and_finishes() {
+        let mut tools = session();
+        tools.select_tool(ToolKind::Highlighter);
+        let stroke_id = tools.begin_highlighter_stroke(ToolPoint::new(5, 50));
+        tools
+            .append_highlighter_point(stroke_id, ToolPoint::new(25, 55), false)
+            .expect("active stroke should accept points");
+        tools
+            .append_highlighter_point(stroke_id, ToolPoint::new(90, 61), true)
+            .expect("active stroke should accept snapped points");
+        tools
+            .finish_highlighter_stroke(stroke_id)
+            .expect("stroke should finish");
+
+        let stroke = tools
+            .get_highlighter_stroke(stroke_id)
+            .expect("highlighter stroke should be stored");
+        assert_eq!(
+            stroke.points,
+            vec![ToolPoint::new(5, 50), ToolPoint::new(90, 50)]
+        );
+        assert_eq!(tools.active_highlighter_stroke_id(), None);
+        assert_eq!(
+            tools.append_highlighter_point(stroke_id, ToolPoint::new(1, 1), false),
+            Err(ToolError::ToolNotSelected)
+        );
+    }
+
+    #[test]
+    fn tool_line_collects_clicked_vertices_until_finished() {
+        let mut tools = session();
+        tools.select_tool(ToolKind::Line);
+        tools.set_shared_dash(LineDash::Dashed);
+        tools.set_line_cap(LineCap::Square);
+
+        let line_id = tools.add_line_vertex(ToolPoint::new(0, 0));
+        assert_eq!(tools.add_line_vertex(ToolPoint::new(20, 0)), line_id);
+        assert_eq!(tools.add_line_vertex(ToolPoint::new(20, 0)), line_id);
+        assert_eq!(tools.add_line_vertex(ToolPoint::new(20, 30)), line_id);
+        assert_eq!(tools.active_line_id(), Some(line_id));
+        assert_eq!(tools.finish_active_line(), Ok(line_id));
+        assert_eq!(tools.active_line_id(), None);
+
+        let line = tools.get_line(line_id).expect("line should be stored");
+        assert_eq!(line.points.len(), 3);
+        assert_eq!(line.options.dash, LineDash::Dashed);
+        assert_eq!(line.options.cap, LineCap::Square);
+
+        let next_id = tools.add_line_vertex(ToolPoint::new(5, 5));
+        assert_ne!(next_id, line_id);
+    }
+
+    #[test]
+    fn tool_line_with_single_vertex_is_dropped_on_tool_switch() {
+        let mut tools = session();
+        tools.select_tool(ToolKind::Line);
+        tools.add_line_vertex(ToolPoint::new(3, 4));
+        assert_eq!(tools.line_count(), 1);
+
+        tools.select_tool(ToolKind::Select);
+        assert_eq!(tools.line_count(), 0);
+        assert_eq!(tools.active_line_id(), None);
+        assert_eq!(tools.finish_active_line(), Err(ToolError::ToolNotSelected));
+    }
+
+    #[test]
+    fn shared_dash_updates_line_arrow_and_rectangle_options() {
+        let mut tools = session();
+        tools.set_shared_dash(LineDash::Dotted);
+        assert_eq!(tools.line_options().dash, LineDash::Dotted);
+        assert_eq!(tools.arrow_options().dash, LineDash::Dotted);
+        assert_eq!(tools.rectangle_options().dash, LineDash::Dotted);
+    }
+
+    #[test]
+    fn tool_ellipse_rejects_flat_drag() {
+        let mut tools = session();
+        let err = tools
+            .add_ellipse(ToolPoint::new(4, 4), ToolPoint::new(4, 30))
+            .expect_err("flat ellipse should be invalid");
+        assert!(matches!(err, ToolError::InvalidEllipseGeometry));
+    }
+
     #[test]
     fn tool_crop_fixed_ratio_reduces_size_and_keeps_boundary_minimum() {
         let mut tools = session();
@@ -591,6 +1413,73 @@ mod tests {
         assert_eq!(tools.text_count(), 1);
     }
 
+    #[test]
+    fn tool_callout_takes_typed_text_without_leaving_its_tool() {
+        let mut tools = session();
+        tools.select_tool(ToolKind::Callout);
+        tools.set_shared_stroke_color(Color::new(9, 8, 7));
+
+        let id = tools.add_callout(ToolPoint::new(10, 90), ToolPoint::new(40, 20));
+        assert_eq!(tools.active_text_id(), Some(id));
+        assert_eq!(tools.active_tool(), ToolKind::Callout);
+        tools.apply_text_input(TextInputEvent::Character('o'));
+        tools.apply_text_input(TextInputEvent::Character('k'));
+
+        let callout = tools.get_callout(id).expect("callout should be stored");
+        assert_eq!(callout.text.content, "ok");
+        assert_eq!(callout.tail, ToolPoint::new(10, 90));
+        assert_eq!(callout.options.color, Color::new(9, 8, 7));
+        assert_eq!(tools.text_count(), 0);
+
+        assert!(tools.remove_object(id).is_some());
+        assert_eq!(tools.active_text_id(), None);
+    }
+
+    #[test]
+    fn tool_stamp_uses_current_stamp_and_image_options() {
+        let mut tools = session();
+        tools.set_image_stamp(Stamp::Warning);
+        tools.set_image_opacity(60);
+        tools.set_image_rotation(400);
+
+        let id = tools
+            .add_stamp(
+                ToolPoint::new(50, 50),
+                ToolPoint::new(50, 50),
+                ImageBounds::new(200, 200),
+            )
+            .expect("stamp should be placed");
+        let image = tools.get_image(id).expect("stamp should be stored");
+        assert_eq!(image.source, ImageSource::Stamp(Stamp::Warning));
+        assert_eq!(
+            (image.x, image.y, image.width, image.height),
+            (18, 18, 64, 64)
+        );
+        assert_eq!(image.options.opacity, 60);
+        assert_eq!(image.options.rotation, 40);
+    }
+
+    #[test]
+    fn tool_picture_is_centered_on_the_capture() {
+        let mut tools = session();
+        let mut bytes = Vec::new();
+        ::image::RgbaImage::new(20, 10)
+            .write_to(
+                &mut std::io::Cursor::new(&mut bytes),
+                ::image::ImageFormat::Png,
+            )
+            .expect("png should encode");
+        let picture = EmbeddedImage::from_encoded(bytes).expect("png should decode");
+
+        let id = tools.add_picture(picture.clone(), ImageBounds::new(100, 100));
+        let image = tools.get_image(id).expect("picture should be stored");
+        assert_eq!(image.source, ImageSource::Picture(picture));
+        assert_eq!(
+            (image.x, image.y, image.width, image.height),
+            (40, 45, 20, 10)
+        );
+    }
+
     #[test]
     fn tool_text_input_handles_enter_shift_enter_and_character_input() {
         let mut tools = session();
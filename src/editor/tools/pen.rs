@@ -1,22 +1,158 @@
 use super::Color;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Share of the pen thickness drawn at the lightest touch, so faint strokes
+/// never vanish.
+const MIN_PRESSURE_WIDTH: f64 = 0.15;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PenPoint {
     pub x: i32,
     pub y: i32,
+    /// Present only for samples that came from a pressure-sensitive stylus.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stylus: Option<StylusSample>,
 }
 
 impl PenPoint {
     pub const fn new(x: i32, y: i32) -> Self {
-        Self { x, y }
+        Self { x, y, stylus: None }
+    }
+
+    pub const fn with_stylus(mut self, stylus: Option<StylusSample>) -> Self {
+        self.stylus = stylus;
+        self
+    }
+}
+
+/// Stylus readings for one pen sample, kept in thousandths so points stay
+/// comparable with `Eq`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct StylusSample {
+    /// 0 (barely touching) to 1000 (full pressure).
+    pub pressure: u16,
+    /// Tilt towards each axis, -1000 to 1000; 0 when the device reports none.
+    #[serde(default)]
+    pub tilt_x: i16,
+    #[serde(default)]
+    pub tilt_y: i16,
+}
+
+impl StylusSample {
+    /// Converts GDK axis values: pressure in 0–1 and tilt in -1–1.
+    pub fn from_axes(pressure: f64, tilt_x: Option<f64>, tilt_y: Option<f64>) -> Self {
+        let tilt =
+            |value: Option<f64>| (value.unwrap_or(0.0).clamp(-1.0, 1.0) * 1000.0).round() as i16;
+        Self {
+            pressure: (pressure.clamp(0.0, 1.0) * 1000.0).round() as u16,
+            tilt_x: tilt(tilt_x),
+            tilt_y: tilt(tilt_y),
+        }
+    }
+
+    pub fn pressure(self) -> f64 {
+        f64::from(self.pressure.min(1000)) / 1000.0
+    }
+
+    /// Blends two samples, used when smoothing resamples a stroke.
+    pub(super) fn lerp(self, other: Self, t: f64) -> Self {
+        let mix = |a: f64, b: f64| a + (b - a) * t;
+        Self {
+            pressure: mix(f64::from(self.pressure), f64::from(other.pressure)).round() as u16,
+            tilt_x: mix(f64::from(self.tilt_x), f64::from(other.tilt_x)).round() as i16,
+            tilt_y: mix(f64::from(self.tilt_y), f64::from(other.tilt_y)).round() as i16,
+        }
+    }
+}
+
+/// Maps stylus pressure to stroke width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PressureCurve {
+    /// Reaches full width with a light touch.
+    Soft,
+    #[default]
+    Linear,
+    /// Needs a firm press before the stroke gets wide.
+    Firm,
+}
+
+impl PressureCurve {
+    pub const ALL: [Self; 3] = [Self::Soft, Self::Linear, Self::Firm];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Soft => "Soft",
+            Self::Linear => "Linear",
+            Self::Firm => "Firm",
+        }
+    }
+
+    pub fn apply(self, pressure: f64) -> f64 {
+        let pressure = pressure.clamp(0.0, 1.0);
+        match self {
+            Self::Soft => pressure.sqrt(),
+            Self::Linear => pressure,
+            Self::Firm => pressure * pressure,
+        }
+    }
+}
+
+/// How strongly a finished stroke is cleaned up before it is kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PenSmoothing {
+    #[default]
+    Off,
+    Low,
+    Medium,
+    High,
+}
+
+impl PenSmoothing {
+    pub const ALL: [Self; 4] = [Self::Off, Self::Low, Self::Medium, Self::High];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Off => "Off",
+            Self::Low => "Low",
+            Self::Medium => "Medium",
+            Self::High => "High",
+        }
+    }
+
+    /// Largest wobble, in image pixels, that smoothing may flatten out.
+    pub const fn tolerance(self) -> Option<f64> {
+        match self {
+            Self::Off => None,
+            Self::Low => Some(1.0),
+            Self::Medium => Some(2.0),
+            Self::High => Some(4.0),
+        }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PenOptions {
     pub color: Color,
     pub opacity: u8,
     pub thickness: u8,
+    #[serde(default)]
+    pub smoothing: PenSmoothing,
+    /// Replaces strokes that look like a line, arrow, rectangle or ellipse
+    /// with that shape when they are finished.
+    #[serde(default)]
+    pub shape_snap: bool,
+    /// Varies the width with stylus pressure. Points without stylus data,
+    /// such as mouse input, always use the full thickness.
+    #[serde(default = "default_pressure_enabled")]
+    pub pressure_enabled: bool,
+    #[serde(default)]
+    pub pressure_curve: PressureCurve,
+}
+
+const fn default_pressure_enabled() -> bool {
+    true
 }
 
 impl Default for PenOptions {
@@ -25,6 +161,10 @@ impl Default for PenOptions {
             color: Color::new(0, 0, 0),
             opacity: 100,
             thickness: 3,
+            smoothing: PenSmoothing::Off,
+            shape_snap: false,
+            pressure_enabled: true,
+            pressure_curve: PressureCurve::Linear,
         }
     }
 }
@@ -41,6 +181,34 @@ impl PenOptions {
     pub fn set_thickness(&mut self, thickness: u8) {
         self.thickness = clamp_u8_range(thickness, 1, 255);
     }
+
+    pub fn set_smoothing(&mut self, smoothing: PenSmoothing) {
+        self.smoothing = smoothing;
+    }
+
+    pub fn set_shape_snap(&mut self, shape_snap: bool) {
+        self.shape_snap = shape_snap;
+    }
+
+    pub fn set_pressure_enabled(&mut self, pressure_enabled: bool) {
+        self.pressure_enabled = pressure_enabled;
+    }
+
+    pub fn set_pressure_curve(&mut self, pressure_curve: PressureCurve) {
+        self.pressure_curve = pressure_curve;
+    }
+
+    /// Stroke width at a sample.
+    pub fn width_at(&self, point: PenPoint) -> f64 {
+        let thickness = f64::from(self.thickness.max(1));
+        match point.stylus {
+            Some(stylus) if self.pressure_enabled => {
+                let scale = self.pressure_curve.apply(stylus.pressure());
+                thickness * (MIN_PRESSURE_WIDTH + (1.0 - MIN_PRESSURE_WIDTH) * scale)
+            }
+            _ => thickness,
+        }
+    }
 }
 
 const fn clamp_u8_range(value: u8, min: u8, max: u8) -> u8 {
@@ -53,7 +221,7 @@ const fn clamp_u8_range(value: u8, min: u8, max: u8) -> u8 {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PenStroke {
     pub id: u64,
     pub points: Vec<PenPoint>,
@@ -78,4 +246,81 @@ impl PenStroke {
     pub fn finalize(&mut self) {
         self.finalized = true;
     }
+
+    /// Whether the stroke needs per-segment widths when drawn.
+    pub fn has_varying_width(&self) -> bool {
+        self.options.pressure_enabled && self.points.iter().any(|point| point.stylus.is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stylus(pressure: f64) -> Option<StylusSample> {
+        Some(StylusSample::from_axes(pressure, None, None))
+    }
+
+    #[test]
+    fn width_follows_pressure_through_the_curve() {
+        let options = PenOptions {
+            thickness: 20,
+            ..PenOptions::default()
+        };
+        let light = PenPoint::new(0, 0).with_stylus(stylus(0.25));
+        let full = PenPoint::new(0, 0).with_stylus(stylus(1.0));
+
+        assert!((options.width_at(full) - 20.0).abs() < 1e-9);
+        assert!((options.width_at(light) - 20.0 * (0.15 + 0.85 * 0.25)).abs() < 1e-9);
+
+        let soft = PenOptions {
+            pressure_curve: PressureCurve::Soft,
+            ..options
+        };
+        let firm = PenOptions {
+            pressure_curve: PressureCurve::Firm,
+            ..options
+        };
+        assert!(soft.width_at(light) > options.width_at(light));
+        assert!(firm.width_at(light) < options.width_at(light));
+    }
+
+    #[test]
+    fn mouse_points_and_disabled_pressure_use_full_thickness() {
+        let options = PenOptions {
+            thickness: 8,
+            ..PenOptions::default()
+        };
+        let pressed = PenPoint::new(0, 0).with_stylus(stylus(0.0));
+
+        assert_eq!(options.width_at(PenPoint::new(0, 0)), 8.0);
+        assert!(options.width_at(pressed) < 8.0);
+
+        let disabled = PenOptions {
+            pressure_enabled: false,
+            ..options
+        };
+        assert_eq!(disabled.width_at(pressed), 8.0);
+    }
+
+    #[test]
+    fn stylus_axes_are_clamped_and_omitted_from_mouse_points() {
+        let sample = StylusSample::from_axes(1.4, Some(-0.5), None);
+        assert_eq!(
+            sample,
+            StylusSample {
+                pressure: 1000,
+                tilt_x: -500,
+                tilt_y: 0,
+            }
+        );
+
+        let json = serde_json::to_string(&PenPoint::new(3, 4)).expect("point should serialize");
+        assert_eq!(json, r#"{"x":3,"y":4}"#);
+        let restored: PenPoint = serde_json::from_str(
+            r#"{"x":3,"y":4,"stylus":{"pressure":500,"tilt_x":0,"tilt_y":0}}"#,
+        )
+        .expect("point should deserialize");
+        assert_eq!(restored.stylus.map(StylusSample::pressure), Some(0.5));
+    }
 }
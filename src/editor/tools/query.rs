@@ -5,6 +5,30 @@ impl EditorTools {
         self.collect_objects(ToolObject::as_crop)
     }
 
+    /// Label of every badge, in sequence order, paired with its id.
+    pub fn badge_labels(&self) -> Vec<(u64, String)> {
+        self.objects
+            .iter()
+            .filter_map(ToolObject::as_badge)
+            .enumerate()
+            .map(|(index, badge)| (badge.id, badge.label_at(index)))
+            .collect()
+    }
+
+    /// Label the next placed badge will show.
+    pub fn next_badge_label(&self) -> String {
+        let placed = self.objects.iter().filter_map(ToolObject::as_badge).count();
+        BadgeElement::new(0, ToolPoint::new(0, 0), None, self.badge_options).label_at(placed)
+    }
+
+    pub fn active_highlighter_stroke_id(&self) -> Option<u64> {
+        self.active_highlighter_stroke
+    }
+
+    pub fn active_line_id(&self) -> Option<u64> {
+        self.active_line
+    }
+
     pub fn active_text_id(&self) -> Option<u64> {
         self.active_text_box
     }
@@ -1,11 +1,14 @@
-use super::Color;
+use super::{Color, LineDash};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RectangleOptions {
     pub color: Color,
     pub thickness: u8,
     pub fill_enabled: bool,
     pub border_radius: u16,
+    #[serde(default)]
+    pub dash: LineDash,
 }
 
 impl Default for RectangleOptions {
@@ -15,6 +18,7 @@ impl Default for RectangleOptions {
             thickness: 3,
             fill_enabled: false,
             border_radius: DEFAULT_RECTANGLE_BORDER_RADIUS,
+            dash: LineDash::Solid,
         }
     }
 }
@@ -35,9 +39,13 @@ impl RectangleOptions {
     pub fn set_border_radius(&mut self, border_radius: u16) {
         self.border_radius = border_radius;
     }
+
+    pub fn set_dash(&mut self, dash: LineDash) {
+        self.dash = dash;
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RectangleElement {
     pub id: u64,
     pub x: i32,
@@ -95,4 +103,13 @@ mod tests {
         options.set_border_radius(14);
         assert_eq!(options.border_radius, 14);
     }
+
+    #[test]
+    fn rectangle_options_without_dash_deserialize_as_solid() {
+        let options: RectangleOptions = serde_json::from_str(
+            r#"{"color":{"r":1,"g":2,"b":3},"thickness":2,"fill_enabled":false,"border_radius":4}"#,
+        )
+        .expect("options saved before dash styles should still load");
+        assert_eq!(options.dash, LineDash::Solid);
+    }
 }
@@ -63,6 +63,12 @@ impl EditorTools {
                 arrow.end.x = arrow.end.x.saturating_add(bounded_delta_x);
                 arrow.end.y = arrow.end.y.saturating_add(bounded_delta_y);
             }
+            ToolObject::Highlighter(stroke) => {
+                translate_tool_points(&mut stroke.points, (delta_x, delta_y), (max_x, max_y));
+            }
+            ToolObject::Line(line) => {
+                translate_tool_points(&mut line.points, (delta_x, delta_y), (max_x, max_y));
+            }
             ToolObject::Rectangle(rectangle) => {
                 move_box_by(
                     (&mut rectangle.x, &mut rectangle.y),
@@ -71,6 +77,38 @@ impl EditorTools {
                     image_bounds,
                 );
             }
+            ToolObject::Ellipse(ellipse) => {
+                move_box_by(
+                    (&mut ellipse.x, &mut ellipse.y),
+                    (ellipse.width, ellipse.height),
+                    (delta_x, delta_y),
+                    image_bounds,
+                );
+            }
+            ToolObject::Badge(badge) => {
+                let mut points = [badge.center, badge.leader.unwrap_or(badge.center)];
+                translate_tool_points(&mut points, (delta_x, delta_y), (max_x, max_y));
+                badge.center = points[0];
+                badge.leader = badge.leader.map(|_| points[1]);
+            }
+            ToolObject::Spotlight(spotlight) => {
+                if let Some(bounds) = spotlight.bounds() {
+                    let (bounded_delta_x, bounded_delta_y) =
+                        clamp_box_delta(bounds, (delta_x, delta_y), image_bounds);
+                    for hole in &mut spotlight.holes {
+                        hole.x = hole.x.saturating_add(bounded_delta_x);
+                        hole.y = hole.y.saturating_add(bounded_delta_y);
+                    }
+                }
+            }
+            ToolObject::Magnifier(magnifier) => {
+                let (bounded_delta_x, bounded_delta_y) =
+                    clamp_box_delta(magnifier.bounds(), (delta_x, delta_y), image_bounds);
+                magnifier.source_x = magnifier.source_x.saturating_add(bounded_delta_x);
+                magnifier.source_y = magnifier.source_y.saturating_add(bounded_delta_y);
+                magnifier.callout_x = magnifier.callout_x.saturating_add(bounded_delta_x);
+                magnifier.callout_y = magnifier.callout_y.saturating_add(bounded_delta_y);
+            }
             ToolObject::Crop(crop) => {
                 move_box_by(
                     (&mut crop.x, &mut crop.y),
@@ -83,10 +121,55 @@ impl EditorTools {
                 text.x = text.x.saturating_add(delta_x).clamp(0, max_x);
                 text.y = text.y.saturating_add(delta_y).clamp(0, max_y);
             }
+            ToolObject::Callout(callout) => {
+                callout.text.x = callout.text.x.saturating_add(delta_x).clamp(0, max_x);
+                callout.text.y = callout.text.y.saturating_add(delta_y).clamp(0, max_y);
+            }
+            ToolObject::Image(image) => {
+                // A tilted picture may not fit the capture at all; then only
+                // its center is kept on the image.
+                let bounds = image.bounds();
+                let (bounded_delta_x, bounded_delta_y) = if i64::from(bounds.width)
+                    <= i64::from(image_width)
+                    && i64::from(bounds.height) <= i64::from(image_height)
+                {
+                    clamp_box_delta(bounds, (delta_x, delta_y), image_bounds)
+                } else {
+                    let (center_x, center_y) = image.center();
+                    let center = (center_x.round() as i32, center_y.round() as i32);
+                    (
+                        clamp_translation_delta(delta_x, center.0, center.0, max_x),
+                        clamp_translation_delta(delta_y, center.1, center.1, max_y),
+                    )
+                };
+                image.x = image.x.saturating_add(bounded_delta_x);
+                image.y = image.y.saturating_add(bounded_delta_y);
+            }
         }
         Ok(())
     }
 
+    /// Points a callout's tail somewhere else; the bubble stays where it is.
+    pub fn retarget_callout_tail(
+        &mut self,
+        id: u64,
+        point: ToolPoint,
+        image_bounds: ImageBounds,
+    ) -> Result<(), ToolError> {
+        let callout = self
+            .find_object_mut(id, ToolObject::as_callout_mut)
+            .ok_or(ToolError::ObjectNotFound)?;
+        callout.tail = ToolPoint::new(
+            point
+                .x
+                .clamp(0, image_bounds.width.saturating_sub(1).max(0)),
+            point
+                .y
+                .clamp(0, image_bounds.height.saturating_sub(1).max(0)),
+        );
+        Ok(())
+    }
+
     pub fn resize_rectangle(
         &mut self,
         id: u64,
@@ -107,6 +190,26 @@ impl EditorTools {
         Ok(())
     }
 
+    pub fn resize_ellipse(
+        &mut self,
+        id: u64,
+        bounds: ToolBounds,
+        image_bounds: ImageBounds,
+    ) -> Result<(), ToolError> {
+        if bounds.width == 0 || bounds.height == 0 {
+            return Err(ToolError::InvalidEllipseGeometry);
+        }
+        let ellipse = self
+            .find_object_mut(id, ToolObject::as_ellipse_mut)
+            .ok_or(ToolError::ObjectNotFound)?;
+        let bounded = clamp_bounds_to_image(bounds, image_bounds);
+        ellipse.x = bounded.x;
+        ellipse.y = bounded.y;
+        ellipse.width = bounded.width;
+        ellipse.height = bounded.height;
+        Ok(())
+    }
+
     pub fn resize_blur(
         &mut self,
         id: u64,
@@ -128,6 +231,155 @@ impl EditorTools {
         Ok(())
     }
 
+    /// Scales an image so its rotated outline fills `bounds`; see
+    /// [`ImageElement::fit_to_bounds`].
+    pub fn resize_image(&mut self, id: u64, bounds: ToolBounds) -> Result<(), ToolError> {
+        if bounds.width < IMAGE_MIN_SIZE || bounds.height < IMAGE_MIN_SIZE {
+            return Err(ToolError::InvalidImageGeometry);
+        }
+        let image = self
+            .find_object_mut(id, ToolObject::as_image_mut)
+            .ok_or(ToolError::ObjectNotFound)?;
+        image.fit_to_bounds(bounds);
+        Ok(())
+    }
+
+    /// Applies `update` to the options of every image among `ids`, returning
+    /// whether any of them changed.
+    pub fn restyle_images(&mut self, ids: &[u64], update: impl Fn(&mut ImageOptions)) -> bool {
+        let mut changed = false;
+        for object in &mut self.objects {
+            let ToolObject::Image(image) = object else {
+                continue;
+            };
+            if !ids.contains(&image.id) {
+                continue;
+            }
+            let before = image.options;
+            update(&mut image.options);
+            changed |= image.options != before;
+        }
+        changed
+    }
+
+    /// Moves a single hole of a spotlight, leaving the others in place.
+    pub fn move_spotlight_hole(
+        &mut self,
+        id: u64,
+        hole_index: usize,
+        delta_x: i32,
+        delta_y: i32,
+        image_bounds: ImageBounds,
+    ) -> Result<(), ToolError> {
+        let hole = self
+            .find_object_mut(id, ToolObject::as_spotlight_mut)
+            .and_then(|spotlight| spotlight.holes.get_mut(hole_index))
+            .ok_or(ToolError::ObjectNotFound)?;
+        move_box_by(
+            (&mut hole.x, &mut hole.y),
+            (hole.width, hole.height),
+            (delta_x, delta_y),
+            image_bounds,
+        );
+        Ok(())
+    }
+
+    pub fn resize_spotlight_hole(
+        &mut self,
+        id: u64,
+        hole_index: usize,
+        bounds: ToolBounds,
+        image_bounds: ImageBounds,
+    ) -> Result<(), ToolError> {
+        if bounds.width == 0 || bounds.height == 0 {
+            return Err(ToolError::InvalidSpotlightGeometry);
+        }
+        let hole = self
+            .find_object_mut(id, ToolObject::as_spotlight_mut)
+            .and_then(|spotlight| spotlight.holes.get_mut(hole_index))
+            .ok_or(ToolError::ObjectNotFound)?;
+        *hole = SpotlightHole::new(hole.shape, clamp_bounds_to_image(bounds, image_bounds));
+        Ok(())
+    }
+
+    /// Moves either the magnified region or the callout showing it.
+    pub fn move_magnifier_part(
+        &mut self,
+        id: u64,
+        part: MagnifierPart,
+        delta_x: i32,
+        delta_y: i32,
+        image_bounds: ImageBounds,
+    ) -> Result<(), ToolError> {
+        let magnifier = self
+            .find_object_mut(id, ToolObject::as_magnifier_mut)
+            .ok_or(ToolError::ObjectNotFound)?;
+        match part {
+            MagnifierPart::Source => move_box_by(
+                (&mut magnifier.source_x, &mut magnifier.source_y),
+                (magnifier.source_width, magnifier.source_height),
+                (delta_x, delta_y),
+                image_bounds,
+            ),
+            MagnifierPart::Callout => {
+                let callout = magnifier.callout_bounds();
+                move_box_by(
+                    (&mut magnifier.callout_x, &mut magnifier.callout_y),
+                    (callout.width, callout.height),
+                    (delta_x, delta_y),
+                    image_bounds,
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Changes the magnified region; circular lenses keep a square source.
+    /// The callout grows or shrinks with it at the same zoom.
+    pub fn resize_magnifier_source(
+        &mut self,
+        id: u64,
+        bounds: ToolBounds,
+        image_bounds: ImageBounds,
+    ) -> Result<(), ToolError> {
+        if bounds.width == 0 || bounds.height == 0 {
+            return Err(ToolError::InvalidMagnifierGeometry);
+        }
+        let magnifier = self
+            .find_object_mut(id, ToolObject::as_magnifier_mut)
+            .ok_or(ToolError::ObjectNotFound)?;
+        let mut bounded = clamp_bounds_to_image(bounds, image_bounds);
+        if magnifier.options.shape == MagnifierShape::Circle {
+            let side = bounded.width.min(bounded.height);
+            bounded.width = side;
+            bounded.height = side;
+        }
+        magnifier.source_x = bounded.x;
+        magnifier.source_y = bounded.y;
+        magnifier.source_width = bounded.width;
+        magnifier.source_height = bounded.height;
+        magnifier.clamp_callout(image_bounds);
+        Ok(())
+    }
+
+    /// Moves the callout to `origin` at a new zoom; the source is unchanged.
+    pub fn resize_magnifier_callout(
+        &mut self,
+        id: u64,
+        origin: ToolPoint,
+        zoom_percent: u16,
+        image_bounds: ImageBounds,
+    ) -> Result<(), ToolError> {
+        let magnifier = self
+            .find_object_mut(id, ToolObject::as_magnifier_mut)
+            .ok_or(ToolError::ObjectNotFound)?;
+        magnifier.options.set_zoom_percent(zoom_percent);
+        magnifier.callout_x = origin.x;
+        magnifier.callout_y = origin.y;
+        magnifier.clamp_callout(image_bounds);
+        Ok(())
+    }
+
     pub fn resize_crop(
         &mut self,
         id: u64,
@@ -176,6 +428,13 @@ impl EditorTools {
                 self.active_pen_stroke = None;
             }
         }
+        if let Some(active_id) = self.active_highlighter_stroke {
+            if self.objects.iter().all(|object| {
+                !matches!(object, ToolObject::Highlighter(stroke) if stroke.id == active_id)
+            }) {
+                self.active_highlighter_stroke = None;
+            }
+        }
         if let Some(active_id) = self.active_text_box {
             if self
                 .objects
@@ -220,6 +479,35 @@ fn pen_point_bounds(points: &[PenPoint]) -> Option<(i32, i32, i32, i32)> {
     Some((min_x, max_x, min_y, max_y))
 }
 
+/// Moves every point by the delta, clamped so the whole shape stays inside
+/// the image without being distorted.
+fn translate_tool_points(points: &mut [ToolPoint], delta: (i32, i32), axis_max: (i32, i32)) {
+    let Some((min_x, max_x, min_y, max_y)) = point_bounds(points) else {
+        return;
+    };
+    let bounded_delta_x = clamp_translation_delta(delta.0, min_x, max_x, axis_max.0);
+    let bounded_delta_y = clamp_translation_delta(delta.1, min_y, max_y, axis_max.1);
+    for point in points {
+        point.x = point.x.saturating_add(bounded_delta_x);
+        point.y = point.y.saturating_add(bounded_delta_y);
+    }
+}
+
+fn point_bounds(points: &[ToolPoint]) -> Option<(i32, i32, i32, i32)> {
+    let first = points.first()?;
+    Some(points[1..].iter().fold(
+        (first.x, first.x, first.y, first.y),
+        |(min_x, max_x, min_y, max_y), point| {
+            (
+                min_x.min(point.x),
+                max_x.max(point.x),
+                min_y.min(point.y),
+                max_y.max(point.y),
+            )
+        },
+    ))
+}
+
 fn translate_pen_points(points: &mut [PenPoint], delta_x: i32, delta_y: i32) {
     for point in points {
         point.x = point.x.saturating_add(delta_x);
@@ -246,6 +534,20 @@ fn clamp_bounds_to_image(bounds: ToolBounds, image_bounds: ImageBounds) -> ToolB
     )
 }
 
+/// Clamps a move of everything inside `bounds` so the box stays in the image.
+fn clamp_box_delta(bounds: ToolBounds, delta: (i32, i32), image_bounds: ImageBounds) -> (i32, i32) {
+    let right = bounds
+        .x
+        .saturating_add(i32::try_from(bounds.width).unwrap_or(i32::MAX));
+    let bottom = bounds
+        .y
+        .saturating_add(i32::try_from(bounds.height).unwrap_or(i32::MAX));
+    (
+        clamp_translation_delta(delta.0, bounds.x, right, image_bounds.width),
+        clamp_translation_delta(delta.1, bounds.y, bottom, image_bounds.height),
+    )
+}
+
 fn clamp_translation_delta(delta: i32, min_coord: i32, max_coord: i32, axis_max: i32) -> i32 {
     let min_delta = min_coord.saturating_neg();
     let max_delta = axis_max.saturating_sub(max_coord);
@@ -278,6 +580,187 @@ mod tests {
         assert_eq!(blur.anchor, ToolPoint::new(90, 95));
     }
 
+    #[test]
+    fn tool_ellipse_resize_and_move_stay_within_image_bounds() {
+        let mut tools = session();
+        let ellipse_id = tools
+            .add_ellipse(ToolPoint::new(10, 10), ToolPoint::new(30, 40))
+            .expect("ellipse should be inserted");
+        tools
+            .resize_ellipse(
+                ellipse_id,
+                ToolBounds::new(90, 95, 50, 40),
+                ImageBounds::new(100, 100),
+            )
+            .expect("resize should clamp within image");
+        let ellipse = tools.get_ellipse(ellipse_id).expect("ellipse should exist");
+        assert_eq!((ellipse.x, ellipse.y), (90, 95));
+        assert_eq!((ellipse.width, ellipse.height), (10, 5));
+
+        tools
+            .move_object_by(ellipse_id, -100, -100, 100, 100)
+            .expect("move should clamp to image edge");
+        let ellipse = tools.get_ellipse(ellipse_id).expect("ellipse should exist");
+        assert_eq!((ellipse.x, ellipse.y), (0, 0));
+
+        let err = tools
+            .resize_ellipse(
+                ellipse_id,
+                ToolBounds::new(0, 0, 0, 10),
+                ImageBounds::new(100, 100),
+            )
+            .expect_err("flat ellipse resize should fail");
+        assert_eq!(err, ToolError::InvalidEllipseGeometry);
+    }
+
+    #[test]
+    fn tool_magnifier_parts_move_apart_or_together() {
+        let mut tools = session();
+        let image = ImageBounds::new(200, 100);
+        tools.set_magnifier_shape(MagnifierShape::Rectangle);
+        let id = tools
+            .add_magnifier(ToolPoint::new(10, 10), ToolPoint::new(30, 30), image)
+            .expect("magnifier should be inserted");
+        let boxes = |tools: &EditorTools| {
+            let magnifier = tools.get_magnifier(id).expect("magnifier should exist");
+            (magnifier.source_bounds(), magnifier.callout_bounds())
+        };
+        assert_eq!(boxes(&tools).1, ToolBounds::new(54, 0, 40, 40));
+
+        tools
+            .move_magnifier_part(id, MagnifierPart::Callout, 500, 10, image)
+            .expect("callout should move");
+        tools
+            .move_magnifier_part(id, MagnifierPart::Source, -5, 0, image)
+            .expect("source should move");
+        assert_eq!(
+            boxes(&tools),
+            (
+                ToolBounds::new(5, 10, 20, 20),
+                ToolBounds::new(160, 10, 40, 40)
+            )
+        );
+
+        tools
+            .move_object_by(id, 50, -50, 200, 100)
+            .expect("magnifier should move");
+        assert_eq!(
+            boxes(&tools),
+            (
+                ToolBounds::new(5, 0, 20, 20),
+                ToolBounds::new(160, 0, 40, 40)
+            )
+        );
+
+        tools
+            .resize_magnifier_callout(id, ToolPoint::new(150, 0), 400, image)
+            .expect("zoom should change");
+        assert_eq!(boxes(&tools).1, ToolBounds::new(120, 0, 80, 80));
+        tools
+            .resize_magnifier_source(id, ToolBounds::new(5, 0, 30, 20), image)
+            .expect("source should resize");
+        assert_eq!(boxes(&tools).1, ToolBounds::new(80, 0, 120, 80));
+    }
+
+    #[test]
+    fn tool_callout_moves_its_bubble_and_keeps_the_tail_tip() {
+        let mut tools = session();
+        let id = tools.add_callout(ToolPoint::new(10, 90), ToolPoint::new(40, 20));
+
+        tools
+            .move_object_by(id, 30, -40, 200, 100)
+            .expect("callout should move");
+        let callout = tools.get_callout(id).expect("callout should exist");
+        assert_eq!((callout.text.x, callout.text.y), (70, 0));
+        assert_eq!(callout.tail, ToolPoint::new(10, 90));
+
+        tools
+            .retarget_callout_tail(id, ToolPoint::new(250, -5), ImageBounds::new(200, 100))
+            .expect("tail should move");
+        let callout = tools.get_callout(id).expect("callout should exist");
+        assert_eq!(callout.tail, ToolPoint::new(199, 0));
+        assert_eq!((callout.text.x, callout.text.y), (70, 0));
+    }
+
+    #[test]
+    fn tool_image_moves_inside_capture_resizes_and_restyles() {
+        let mut tools = session();
+        let image_bounds = ImageBounds::new(200, 100);
+        let id = tools
+            .add_stamp(ToolPoint::new(10, 10), ToolPoint::new(50, 50), image_bounds)
+            .expect("stamp should be placed");
+
+        tools
+            .move_object_by(id, 500, -30, 200, 100)
+            .expect("image should move");
+        let image = tools.get_image(id).expect("image should exist");
+        assert_eq!((image.x, image.y), (160, 0));
+
+        tools
+            .resize_image(id, ToolBounds::new(100, 0, 80, 60))
+            .expect("image should resize");
+        let image = tools.get_image(id).expect("image should exist");
+        assert_eq!(
+            (image.x, image.y, image.width, image.height),
+            (110, 0, 60, 60)
+        );
+        assert_eq!(
+            tools.resize_image(id, ToolBounds::new(0, 0, 4, 4)),
+            Err(ToolError::InvalidImageGeometry)
+        );
+
+        let other = tools.add_badge(ToolPoint::new(5, 5), ToolPoint::new(5, 5));
+        assert!(tools.restyle_images(&[id, other], |options| options.set_rotation(90)));
+        assert!(!tools.restyle_images(&[id], |options| options.set_rotation(90)));
+        let image = tools.get_image(id).expect("image should exist");
+        assert_eq!(image.options.rotation, 90);
+    }
+
+    #[test]
+    fn tool_spotlight_moves_one_hole_or_all_of_them() {
+        let mut tools = session();
+        let id = tools
+            .add_spotlight_hole(ToolPoint::new(10, 10), ToolPoint::new(30, 30))
+            .expect("hole should be valid");
+        tools
+            .add_spotlight_hole(ToolPoint::new(50, 50), ToolPoint::new(70, 60))
+            .expect("hole should be valid");
+
+        tools
+            .move_spotlight_hole(id, 1, 100, 0, ImageBounds::new(100, 100))
+            .expect("hole should move");
+        let holes = |tools: &EditorTools| {
+            tools
+                .get_spotlight(id)
+                .expect("spotlight should exist")
+                .holes
+                .iter()
+                .map(|hole| (hole.x, hole.y))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(holes(&tools), vec![(10, 10), (80, 50)]);
+
+        tools
+            .move_object_by(id, -30, 5, 100, 100)
+            .expect("spotlight should move");
+        assert_eq!(holes(&tools), vec![(0, 15), (70, 55)]);
+
+        tools
+            .resize_spotlight_hole(
+                id,
+                0,
+                ToolBounds::new(5, 5, 200, 10),
+                ImageBounds::new(100, 100),
+            )
+            .expect("resize should clamp within image");
+        let spotlight = tools.get_spotlight(id).expect("spotlight should exist");
+        assert_eq!(spotlight.holes[0].bounds(), ToolBounds::new(5, 5, 95, 10));
+        assert_eq!(
+            tools.move_spotlight_hole(id, 5, 1, 1, ImageBounds::new(100, 100)),
+            Err(ToolError::ObjectNotFound)
+        );
+    }
+
     #[test]
     fn tool_crop_resize_enforces_min_size_and_bounds() {
         let mut tools = session();
@@ -311,12 +794,12 @@ mod tests {
     #[test]
     fn tool_move_pen_stroke_hits_edge_without_distorting_shape() {
         let mut tools = session();
-        let stroke_id = tools.begin_pen_stroke(ToolPoint::new(80, 10));
+        let stroke_id = tools.begin_pen_stroke(ToolPoint::new(80, 10), None);
         tools
-            .append_pen_point(stroke_id, ToolPoint::new(90, 20))
+            .append_pen_point(stroke_id, ToolPoint::new(90, 20), None)
             .expect("stroke point should append");
         tools
-            .append_pen_point(stroke_id, ToolPoint::new(95, 25))
+            .append_pen_point(stroke_id, ToolPoint::new(95, 25), None)
             .expect("stroke point should append");
         tools
             .finish_pen_stroke(stroke_id)
@@ -368,6 +851,44 @@ mod tests {
         assert_eq!(arrow.end, ToolPoint::new(99, 70));
     }
 
+    #[test]
+    fn tool_move_polyline_keeps_shape_at_edge() {
+        let mut tools = session();
+        tools.select_tool(ToolKind::Line);
+        let line_id = tools.add_line_vertex(ToolPoint::new(10, 10));
+        tools.add_line_vertex(ToolPoint::new(40, 30));
+        tools.add_line_vertex(ToolPoint::new(90, 20));
+        tools.finish_active_line().expect("line should finish");
+
+        tools
+            .move_object_by(line_id, 50, 5, 100, 100)
+            .expect("move should clamp to image edge");
+        let line = tools.get_line(line_id).expect("line should exist");
+        assert_eq!(
+            line.points,
+            vec![
+                ToolPoint::new(19, 15),
+                ToolPoint::new(49, 35),
+                ToolPoint::new(99, 25)
+            ]
+        );
+    }
+
+    #[test]
+    fn tool_move_badge_carries_leader_target() {
+        let mut tools = session();
+        let badge_id = tools.add_badge(ToolPoint::new(20, 20), ToolPoint::new(80, 60));
+
+        tools
+            .move_object_by(badge_id, 30, -5, 100, 100)
+            .expect("move should clamp to image edge");
+        let Some(ToolObject::Badge(badge)) = tools.object(badge_id) else {
+            panic!("badge should exist");
+        };
+        assert_eq!(badge.center, ToolPoint::new(39, 15));
+        assert_eq!(badge.leader, Some(ToolPoint::new(99, 55)));
+    }
+
     #[test]
     fn tool_move_blur_can_slide_along_edge() {
         let mut tools = session();
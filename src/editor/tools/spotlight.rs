@@ -0,0 +1,182 @@
+use super::{ToolBounds, ToolPoint};
+use serde::{Deserialize, Serialize};
+
+const SPOTLIGHT_MIN_OPACITY: u8 = 10;
+const SPOTLIGHT_MAX_OPACITY: u8 = 95;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpotlightShape {
+    #[default]
+    Rectangle,
+    Ellipse,
+}
+
+impl SpotlightShape {
+    pub const ALL: [Self; 2] = [Self::Rectangle, Self::Ellipse];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Rectangle => "Rectangle",
+            Self::Ellipse => "Ellipse",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpotlightOptions {
+    /// Darkness of the dimmed area in percent.
+    pub opacity: u8,
+    pub blur_outside: bool,
+    /// Shape of the next hole drawn; existing holes keep their own.
+    pub shape: SpotlightShape,
+}
+
+impl Default for SpotlightOptions {
+    fn default() -> Self {
+        Self {
+            opacity: 60,
+            blur_outside: false,
+            shape: SpotlightShape::Rectangle,
+        }
+    }
+}
+
+impl SpotlightOptions {
+    pub fn set_opacity(&mut self, opacity: u8) {
+        self.opacity = opacity.clamp(SPOTLIGHT_MIN_OPACITY, SPOTLIGHT_MAX_OPACITY);
+    }
+
+    pub fn set_blur_outside(&mut self, blur_outside: bool) {
+        self.blur_outside = blur_outside;
+    }
+
+    pub fn set_shape(&mut self, shape: SpotlightShape) {
+        self.shape = shape;
+    }
+}
+
+/// One undimmed area of a spotlight; ellipses are inscribed in the box.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpotlightHole {
+    pub shape: SpotlightShape,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl SpotlightHole {
+    pub const fn new(shape: SpotlightShape, bounds: ToolBounds) -> Self {
+        Self {
+            shape,
+            x: bounds.x,
+            y: bounds.y,
+            width: bounds.width,
+            height: bounds.height,
+        }
+    }
+
+    pub const fn bounds(&self) -> ToolBounds {
+        ToolBounds::new(self.x, self.y, self.width, self.height)
+    }
+
+    fn contains(&self, point: ToolPoint) -> bool {
+        let right = i64::from(self.x) + i64::from(self.width);
+        let bottom = i64::from(self.y) + i64::from(self.height);
+        point.x >= self.x
+            && point.y >= self.y
+            && i64::from(point.x) <= right
+            && i64::from(point.y) <= bottom
+    }
+}
+
+/// Dims the whole image except the union of its holes. An editor holds at
+/// most one spotlight; drawing more holes extends it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpotlightElement {
+    pub id: u64,
+    pub holes: Vec<SpotlightHole>,
+    pub options: SpotlightOptions,
+}
+
+impl SpotlightElement {
+    pub fn new(id: u64, hole: SpotlightHole, options: SpotlightOptions) -> Self {
+        Self {
+            id,
+            holes: vec![hole],
+            options,
+        }
+    }
+
+    /// Index of the most recently drawn hole containing `point`.
+    pub fn hole_index_at(&self, point: ToolPoint) -> Option<usize> {
+        self.holes.iter().rposition(|hole| hole.contains(point))
+    }
+
+    /// Box around every hole.
+    pub fn bounds(&self) -> Option<ToolBounds> {
+        let first = self.holes.first()?.bounds();
+        let (left, top, right, bottom) = self.holes.iter().map(SpotlightHole::bounds).fold(
+            (
+                i64::from(first.x),
+                i64::from(first.y),
+                i64::from(first.x),
+                i64::from(first.y),
+            ),
+            |(left, top, right, bottom), hole| {
+                (
+                    left.min(i64::from(hole.x)),
+                    top.min(i64::from(hole.y)),
+                    right.max(i64::from(hole.x) + i64::from(hole.width)),
+                    bottom.max(i64::from(hole.y) + i64::from(hole.height)),
+                )
+            },
+        );
+        Some(ToolBounds::new(
+            i32::try_from(left).ok()?,
+            i32::try_from(top).ok()?,
+            u32::try_from(right - left).ok()?,
+            u32::try_from(bottom - top).ok()?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hole(x: i32, y: i32, width: u32, height: u32) -> SpotlightHole {
+        SpotlightHole::new(
+            SpotlightShape::Rectangle,
+            ToolBounds::new(x, y, width, height),
+        )
+    }
+
+    #[test]
+    fn hole_index_at_prefers_latest_overlapping_hole() {
+        let mut spotlight =
+            SpotlightElement::new(1, hole(0, 0, 40, 40), SpotlightOptions::default());
+        spotlight.holes.push(hole(20, 20, 40, 40));
+        assert_eq!(spotlight.hole_index_at(ToolPoint::new(5, 5)), Some(0));
+        assert_eq!(spotlight.hole_index_at(ToolPoint::new(30, 30)), Some(1));
+        assert_eq!(spotlight.hole_index_at(ToolPoint::new(80, 5)), None);
+    }
+
+    #[test]
+    fn spotlight_bounds_cover_every_hole() {
+        let mut spotlight =
+            SpotlightElement::new(1, hole(10, 30, 20, 10), SpotlightOptions::default());
+        spotlight.holes.push(hole(50, 5, 10, 10));
+        assert_eq!(spotlight.bounds(), Some(ToolBounds::new(10, 5, 50, 35)));
+    }
+
+    #[test]
+    fn spotlight_opacity_is_clamped() {
+        let mut options = SpotlightOptions::default();
+        options.set_opacity(0);
+        assert_eq!(options.opacity, SPOTLIGHT_MIN_OPACITY);
+        options.set_opacity(100);
+        assert_eq!(options.opacity, SPOTLIGHT_MAX_OPACITY);
+    }
+}
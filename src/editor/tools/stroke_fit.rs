@@ -0,0 +1,474 @@
+//! Geometry used to clean up freehand pen strokes once they are finished:
+//! simplification, spline smoothing and recognition of simple shapes.
+
+use super::{PenPoint, ToolBounds, ToolPoint};
+
+/// Strokes smaller than this are left alone; they are more likely dots or
+/// handwriting than shapes.
+const MIN_SHAPE_SIZE: f64 = 16.0;
+/// Distance between samples taken along the smoothing spline.
+const SPLINE_STEP: f64 = 4.0;
+/// How close the end of a stroke must come back to its start, relative to
+/// the stroke's diagonal, for it to count as a closed shape.
+const CLOSE_RATIO: f64 = 0.2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnappedShape {
+    Line { start: ToolPoint, end: ToolPoint },
+    Arrow { start: ToolPoint, end: ToolPoint },
+    Rectangle(ToolBounds),
+    Ellipse(ToolBounds),
+}
+
+/// Simplifies the stroke and then runs a spline through what is left, so
+/// hand jitter disappears while the overall path keeps its curves.
+pub fn smooth_points(points: &[PenPoint], tolerance: f64) -> Vec<PenPoint> {
+    spline_points(&simplify_points(points, tolerance))
+}
+
+/// Drops samples that deviate less than `tolerance` from the straight path
+/// between the samples kept around them (Ramer–Douglas–Peucker). Both end
+/// points are always kept.
+pub fn simplify_points(points: &[PenPoint], tolerance: f64) -> Vec<PenPoint> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+
+    let last = points.len() - 1;
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[last] = true;
+    let mut spans = vec![(0, last)];
+    while let Some((start, end)) = spans.pop() {
+        if end <= start + 1 {
+            continue;
+        }
+        let (index, deviation) = (start + 1..end)
+            .map(|index| {
+                let deviation = distance_to_segment(points[index], points[start], points[end]);
+                (index, deviation)
+            })
+            .fold((start, -1.0), |best, candidate| {
+                if candidate.1 > best.1 {
+                    candidate
+                } else {
+                    best
+                }
+            });
+        if deviation > tolerance {
+            keep[index] = true;
+            spans.push((start, index));
+            spans.push((index, end));
+        }
+    }
+
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(point, keep)| keep.then_some(*point))
+        .collect()
+}
+
+/// Samples a Catmull-Rom spline through `points`. The spline passes through
+/// every input point, so the stroke still starts and ends where it was drawn.
+/// Stylus readings are interpolated along each span.
+pub fn spline_points(points: &[PenPoint]) -> Vec<PenPoint> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+
+    let last = points.len() - 1;
+    let mut sampled = vec![points[0]];
+    for index in 0..last {
+        let p0 = points[index.saturating_sub(1)];
+        let p1 = points[index];
+        let p2 = points[index + 1];
+        let p3 = points[(index + 2).min(last)];
+        let steps = (distance(p1, p2) / SPLINE_STEP).ceil().max(1.0) as usize;
+        for step in 1..=steps {
+            let t = step as f64 / steps as f64;
+            let stylus = match (p1.stylus, p2.stylus) {
+                (Some(from), Some(to)) => Some(from.lerp(to, t)),
+                (from, to) => from.or(to),
+            };
+            let point = catmull_rom(p0, p1, p2, p3, t).with_stylus(stylus);
+            if sampled.last().map(|last| (last.x, last.y)) != Some((point.x, point.y)) {
+                sampled.push(point);
+            }
+        }
+    }
+    sampled
+}
+
+/// Recognizes strokes that look like a straight line, a one-stroke arrow,
+/// a rectangle or an ellipse. Anything else returns `None`.
+pub fn recognize_shape(points: &[PenPoint]) -> Option<SnappedShape> {
+    let (min_x, min_y, max_x, max_y) = point_extent(points)?;
+    let width = f64::from(max_x - min_x);
+    let height = f64::from(max_y - min_y);
+    let diagonal = width.hypot(height);
+    if diagonal < MIN_SHAPE_SIZE {
+        return None;
+    }
+
+    let first = points[0];
+    let last = points[points.len() - 1];
+    if is_straight(points) {
+        return Some(SnappedShape::Line {
+            start: tool_point(first),
+            end: tool_point(last),
+        });
+    }
+    if let Some(tip) = arrow_tip(points) {
+        return Some(SnappedShape::Arrow {
+            start: tool_point(first),
+            end: tool_point(points[tip]),
+        });
+    }
+    if distance(first, last) > diagonal * CLOSE_RATIO
+        || width < MIN_SHAPE_SIZE / 2.0
+        || height < MIN_SHAPE_SIZE / 2.0
+    {
+        return None;
+    }
+
+    let bounds = ToolBounds::new(min_x, min_y, (max_x - min_x) as u32, (max_y - min_y) as u32);
+    let fill_ratio = polygon_area(points) / (width * height);
+    if fill_ratio >= 0.86 && rectangle_edge_error(points, min_x, min_y, max_x, max_y) <= 0.08 {
+        return Some(SnappedShape::Rectangle(bounds));
+    }
+    if (0.68..0.86).contains(&fill_ratio)
+        && ellipse_error(points, min_x, min_y, max_x, max_y) <= 0.1
+    {
+        return Some(SnappedShape::Ellipse(bounds));
+    }
+    None
+}
+
+/// A stroke is straight when it never strays far from its chord and does
+/// not double back over itself.
+fn is_straight(points: &[PenPoint]) -> bool {
+    let first = points[0];
+    let last = points[points.len() - 1];
+    let chord = distance(first, last);
+    if chord < MIN_SHAPE_SIZE || path_length(points) > chord * 1.15 {
+        return false;
+    }
+    let max_deviation = points
+        .iter()
+        .map(|point| distance_to_segment(*point, first, last))
+        .fold(0.0, f64::max);
+    max_deviation <= (chord * 0.06).max(3.0)
+}
+
+/// Finds the tip of a one-stroke arrow: a straight shaft followed by a short
+/// head drawn around the point farthest from the start.
+fn arrow_tip(points: &[PenPoint]) -> Option<usize> {
+    let first = points[0];
+    let (tip, shaft) = points
+        .iter()
+        .enumerate()
+        .map(|(index, point)| (index, distance(first, *point)))
+        .fold((0, 0.0), |best, candidate| {
+            if candidate.1 > best.1 {
+                candidate
+            } else {
+                best
+            }
+        });
+    if shaft < MIN_SHAPE_SIZE * 2.0 || tip + 1 >= points.len() || !is_straight(&points[..=tip]) {
+        return None;
+    }
+
+    let head = &points[tip..];
+    let head_length = path_length(head);
+    if head_length < shaft * 0.1 || head_length > shaft {
+        return None;
+    }
+    let tip_point = points[tip];
+    let stays_near_tip = head
+        .iter()
+        .all(|point| distance(*point, tip_point) <= shaft * 0.4);
+    let spreads_sideways = head
+        .iter()
+        .map(|point| distance_to_line(*point, first, tip_point))
+        .fold(0.0, f64::max)
+        > shaft * 0.08;
+    (stays_near_tip && spreads_sideways).then_some(tip)
+}
+
+/// Mean distance from each sample to the nearest bounding-box edge,
+/// relative to the shorter side.
+fn rectangle_edge_error(
+    points: &[PenPoint],
+    min_x: i32,
+    min_y: i32,
+    max_x: i32,
+    max_y: i32,
+) -> f64 {
+    let short_side = f64::from((max_x - min_x).min(max_y - min_y));
+    let total: f64 = points
+        .iter()
+        .map(|point| {
+            let to_edge = (point.x - min_x)
+                .min(max_x - point.x)
+                .min(point.y - min_y)
+                .min(max_y - point.y);
+            f64::from(to_edge)
+        })
+        .sum();
+    total / points.len() as f64 / short_side
+}
+
+/// Mean deviation of each sample from the ellipse inscribed in the
+/// bounding box, in units of that ellipse's radius.
+fn ellipse_error(points: &[PenPoint], min_x: i32, min_y: i32, max_x: i32, max_y: i32) -> f64 {
+    let radius_x = f64::from(max_x - min_x) / 2.0;
+    let radius_y = f64::from(max_y - min_y) / 2.0;
+    let center_x = f64::from(min_x) + radius_x;
+    let center_y = f64::from(min_y) + radius_y;
+    let total: f64 = points
+        .iter()
+        .map(|point| {
+            let dx = (f64::from(point.x) - center_x) / radius_x;
+            let dy = (f64::from(point.y) - center_y) / radius_y;
+            (dx.hypot(dy) - 1.0).abs()
+        })
+        .sum();
+    total / points.len() as f64
+}
+
+fn catmull_rom(p0: PenPoint, p1: PenPoint, p2: PenPoint, p3: PenPoint, t: f64) -> PenPoint {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let axis = |a: i32, b: i32, c: i32, d: i32| {
+        let (a, b, c, d) = (f64::from(a), f64::from(b), f64::from(c), f64::from(d));
+        0.5 * (2.0 * b
+            + (c - a) * t
+            + (2.0 * a - 5.0 * b + 4.0 * c - d) * t2
+            + (3.0 * b - a - 3.0 * c + d) * t3)
+    };
+    PenPoint::new(
+        axis(p0.x, p1.x, p2.x, p3.x).round() as i32,
+        axis(p0.y, p1.y, p2.y, p3.y).round() as i32,
+    )
+}
+
+fn point_extent(points: &[PenPoint]) -> Option<(i32, i32, i32, i32)> {
+    let first = points.first()?;
+    Some(points.iter().fold(
+        (first.x, first.y, first.x, first.y),
+        |(min_x, min_y, max_x, max_y), point| {
+            (
+                min_x.min(point.x),
+                min_y.min(point.y),
+                max_x.max(point.x),
+                max_y.max(point.y),
+            )
+        },
+    ))
+}
+
+/// Area enclosed by the stroke, treating it as a closed polygon.
+fn polygon_area(points: &[PenPoint]) -> f64 {
+    let twice_area: f64 = points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(a, b)| f64::from(a.x) * f64::from(b.y) - f64::from(b.x) * f64::from(a.y))
+        .sum();
+    (twice_area / 2.0).abs()
+}
+
+fn path_length(points: &[PenPoint]) -> f64 {
+    points
+        .windows(2)
+        .map(|pair| distance(pair[0], pair[1]))
+        .sum()
+}
+
+fn distance(a: PenPoint, b: PenPoint) -> f64 {
+    f64::from(b.x - a.x).hypot(f64::from(b.y - a.y))
+}
+
+fn distance_to_segment(point: PenPoint, start: PenPoint, end: PenPoint) -> f64 {
+    let dx = f64::from(end.x - start.x);
+    let dy = f64::from(end.y - start.y);
+    let length_squared = dx * dx + dy * dy;
+    if length_squared == 0.0 {
+        return distance(point, start);
+    }
+    let px = f64::from(point.x - start.x);
+    let py = f64::from(point.y - start.y);
+    let t = ((px * dx + py * dy) / length_squared).clamp(0.0, 1.0);
+    (px - t * dx).hypot(py - t * dy)
+}
+
+fn distance_to_line(point: PenPoint, start: PenPoint, end: PenPoint) -> f64 {
+    let dx = f64::from(end.x - start.x);
+    let dy = f64::from(end.y - start.y);
+    let length = dx.hypot(dy);
+    if length == 0.0 {
+        return distance(point, start);
+    }
+    let px = f64::from(point.x - start.x);
+    let py = f64::from(point.y - start.y);
+    (px * dy - py * dx).abs() / length
+}
+
+const fn tool_point(point: PenPoint) -> ToolPoint {
+    ToolPoint::new(point.x, point.y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::editor::tools::StylusSample;
+
+    fn polyline(corners: &[(i32, i32)], step: i32) -> Vec<PenPoint> {
+        let mut points = vec![PenPoint::new(corners[0].0, corners[0].1)];
+        for pair in corners.windows(2) {
+            let (start, end) = (pair[0], pair[1]);
+            let steps = ((end.0 - start.0).abs().max((end.1 - start.1).abs()) / step).max(1);
+            for index in 1..=steps {
+                points.push(PenPoint::new(
+                    start.0 + (end.0 - start.0) * index / steps,
+                    start.1 + (end.1 - start.1) * index / steps,
+                ));
+            }
+        }
+        points
+    }
+
+    #[test]
+    fn simplify_drops_jitter_and_keeps_end_points() {
+        let points: Vec<PenPoint> = (0..=20)
+            .map(|index| PenPoint::new(index * 5, if index % 2 == 0 { 1 } else { -1 }))
+            .collect();
+
+        let simplified = simplify_points(&points, 2.0);
+
+        assert_eq!(simplified, vec![PenPoint::new(0, 1), PenPoint::new(100, 1)]);
+    }
+
+    #[test]
+    fn simplify_keeps_real_corners() {
+        let points = polyline(&[(0, 0), (40, 0), (40, 40)], 4);
+
+        let simplified = simplify_points(&points, 2.0);
+
+        assert_eq!(
+            simplified,
+            vec![
+                PenPoint::new(0, 0),
+                PenPoint::new(40, 0),
+                PenPoint::new(40, 40)
+            ]
+        );
+    }
+
+    #[test]
+    fn spline_passes_through_its_points_and_fills_between_them() {
+        let corners = [
+            PenPoint::new(0, 0),
+            PenPoint::new(40, 0),
+            PenPoint::new(40, 40),
+        ];
+
+        let sampled = spline_points(&corners);
+
+        assert_eq!(sampled.first(), Some(&corners[0]));
+        assert_eq!(sampled.last(), Some(&corners[2]));
+        assert!(sampled.contains(&corners[1]));
+        assert!(sampled.len() > 10);
+        assert!(sampled.windows(2).all(|pair| pair[0] != pair[1]));
+    }
+
+    #[test]
+    fn spline_interpolates_stylus_pressure() {
+        let pressed = |x, y, pressure| {
+            PenPoint::new(x, y).with_stylus(Some(StylusSample::from_axes(pressure, None, None)))
+        };
+        let corners = [
+            pressed(0, 0, 0.2),
+            pressed(40, 0, 0.6),
+            pressed(40, 40, 1.0),
+        ];
+
+        let sampled = spline_points(&corners);
+        let pressures: Vec<u16> = sampled
+            .iter()
+            .map(|point| point.stylus.expect("stylus should carry over").pressure)
+            .collect();
+
+        assert_eq!(pressures.first(), Some(&200));
+        assert_eq!(pressures.last(), Some(&1000));
+        assert!(pressures.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn recognizes_a_wobbly_straight_stroke_as_a_line() {
+        let points: Vec<PenPoint> = (0..=30)
+            .map(|index| PenPoint::new(index * 4, index * 2 + index % 3))
+            .collect();
+
+        assert_eq!(
+            recognize_shape(&points),
+            Some(SnappedShape::Line {
+                start: ToolPoint::new(0, 0),
+                end: ToolPoint::new(120, 60),
+            })
+        );
+    }
+
+    #[test]
+    fn recognizes_a_one_stroke_arrow_at_its_tip() {
+        let points = polyline(&[(0, 0), (100, 0), (85, -12), (100, 0), (85, 12)], 5);
+
+        assert_eq!(
+            recognize_shape(&points),
+            Some(SnappedShape::Arrow {
+                start: ToolPoint::new(0, 0),
+                end: ToolPoint::new(100, 0),
+            })
+        );
+    }
+
+    #[test]
+    fn recognizes_a_nearly_closed_box_as_a_rectangle() {
+        let points = polyline(&[(10, 10), (110, 10), (110, 70), (10, 70), (10, 16)], 5);
+
+        assert_eq!(
+            recognize_shape(&points),
+            Some(SnappedShape::Rectangle(ToolBounds::new(10, 10, 100, 60)))
+        );
+    }
+
+    #[test]
+    fn recognizes_a_loop_as_an_ellipse() {
+        let points: Vec<PenPoint> = (0..40)
+            .map(|index| {
+                let angle = f64::from(index) * std::f64::consts::TAU / 40.0;
+                PenPoint::new(
+                    (60.0 + 50.0 * angle.cos()).round() as i32,
+                    (40.0 + 30.0 * angle.sin()).round() as i32,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            recognize_shape(&points),
+            Some(SnappedShape::Ellipse(ToolBounds::new(10, 10, 100, 60)))
+        );
+    }
+
+    #[test]
+    fn leaves_triangles_scribbles_and_dots_alone() {
+        let triangle = polyline(&[(0, 0), (100, 0), (50, 80), (0, 0)], 5);
+        let scribble = polyline(&[(0, 0), (60, 40), (10, 50), (80, 5), (30, 70)], 5);
+        let dot = polyline(&[(0, 0), (6, 4)], 1);
+
+        assert_eq!(recognize_shape(&triangle), None);
+        assert_eq!(recognize_shape(&scribble), None);
+        assert_eq!(recognize_shape(&dot), None);
+    }
+}
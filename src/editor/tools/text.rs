@@ -1,6 +1,8 @@
 use super::{Color, ToolPoint};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum TextFontFamily {
     Sans,
     Serif,
@@ -15,7 +17,7 @@ impl TextFontFamily {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TextOptions {
     pub color: Color,
     pub size: u8,
@@ -70,12 +72,13 @@ const fn clamp_text_weight(weight: u16) -> u16 {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TextElement {
     pub id: u64,
     pub x: i32,
     pub y: i32,
     pub content: String,
+    #[serde(skip)]
     cursor_chars: usize,
     pub options: TextOptions,
 }
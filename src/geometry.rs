@@ -1,6 +1,8 @@
+use serde::{Deserialize, Serialize};
+
 /// Shared geometric and color primitives used across app and editor modules.
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ToolPoint {
     pub x: i32,
     pub y: i32,
@@ -43,7 +45,7 @@ impl ImageBounds {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Color {
     pub r: u8,
     pub g: u8,
@@ -53,23 +53,37 @@ pub enum ShortcutAction {
     EditorDeleteSelection,
     EditorSave,
     EditorCopyImage,
+    EditorFind,
+    EditorBadgeEarlier,
+    EditorBadgeLater,
     EditorEnterSelect,
     EditorEnterPan,
     EditorEnterBlur,
     EditorEnterPen,
+    EditorEnterHighlighter,
     EditorEnterArrow,
+    EditorEnterLine,
     EditorEnterRectangle,
+    EditorEnterEllipse,
+    EditorEnterBadge,
+    EditorEnterSpotlight,
+    EditorEnterMagnifier,
+    EditorEnterCallout,
+    EditorEnterImage,
     EditorEnterCrop,
     EditorEnterText,
     EditorEnterOcr,
+    EditorEnterDecode,
     EditorToggleToolOptions,
     EditorCloseRequested,
     PreviewSave,
     PreviewCopy,
+    PreviewCopyText,
     PreviewEdit,
     PreviewDelete,
     PreviewClose,
     PreviewOcr,
+    PreviewDecode,
 }
 
 fn resolve_dialog_shortcut(key: ShortcutKey) -> Option<ShortcutAction> {
@@ -104,11 +118,20 @@ fn resolve_editor_tool_shortcut(key: ShortcutKey) -> Option<ShortcutAction> {
         ShortcutKey::Character('h') => Some(ShortcutAction::EditorEnterPan),
         ShortcutKey::Character('b') => Some(ShortcutAction::EditorEnterBlur),
         ShortcutKey::Character('p') => Some(ShortcutAction::EditorEnterPen),
+        ShortcutKey::Character('m') => Some(ShortcutAction::EditorEnterHighlighter),
         ShortcutKey::Character('a') => Some(ShortcutAction::EditorEnterArrow),
+        ShortcutKey::Character('l') => Some(ShortcutAction::EditorEnterLine),
         ShortcutKey::Character('r') => Some(ShortcutAction::EditorEnterRectangle),
+        ShortcutKey::Character('e') => Some(ShortcutAction::EditorEnterEllipse),
+        ShortcutKey::Character('n') => Some(ShortcutAction::EditorEnterBadge),
+        ShortcutKey::Character('d') => Some(ShortcutAction::EditorEnterSpotlight),
+        ShortcutKey::Character('z') => Some(ShortcutAction::EditorEnterMagnifier),
+        ShortcutKey::Character('k') => Some(ShortcutAction::EditorEnterCallout),
+        ShortcutKey::Character('i') => Some(ShortcutAction::EditorEnterImage),
         ShortcutKey::Character('c') => Some(ShortcutAction::EditorEnterCrop),
         ShortcutKey::Character('t') => Some(ShortcutAction::EditorEnterText),
         ShortcutKey::Character('o') => Some(ShortcutAction::EditorEnterOcr),
+        ShortcutKey::Character('q') => Some(ShortcutAction::EditorEnterDecode),
         _ => None,
     }
 }
@@ -126,6 +149,9 @@ fn resolve_editor_shortcut(
         }
         (ShortcutKey::Character('s'), true, _) => Some(ShortcutAction::EditorSave),
         (ShortcutKey::Character('c'), true, _) => Some(ShortcutAction::EditorCopyImage),
+        (ShortcutKey::Character('f'), true, false) => Some(ShortcutAction::EditorFind),
+        (ShortcutKey::Character('['), false, false) => Some(ShortcutAction::EditorBadgeEarlier),
+        (ShortcutKey::Character(']'), false, false) => Some(ShortcutAction::EditorBadgeLater),
         (ShortcutKey::Escape, false, false) => {
             if select_mode {
                 Some(ShortcutAction::EditorCloseRequested)
@@ -146,8 +172,10 @@ fn resolve_preview_shortcut(
     match (key, modifiers.ctrl, modifiers.shift) {
         (ShortcutKey::Character('s'), false, false) => Some(ShortcutAction::PreviewSave),
         (ShortcutKey::Character('c'), false, false) => Some(ShortcutAction::PreviewCopy),
+        (ShortcutKey::Character('c'), true, false) => Some(ShortcutAction::PreviewCopyText),
         (ShortcutKey::Character('e'), false, false) => Some(ShortcutAction::PreviewEdit),
         (ShortcutKey::Character('o'), false, false) => Some(ShortcutAction::PreviewOcr),
+        (ShortcutKey::Character('q'), false, false) => Some(ShortcutAction::PreviewDecode),
         (ShortcutKey::Delete, false, false) => Some(ShortcutAction::PreviewDelete),
         (ShortcutKey::Escape, false, false) => Some(ShortcutAction::PreviewClose),
         _ => None,
@@ -251,6 +279,30 @@ mod tests {
             ),
             Some(ShortcutAction::EditorSave)
         );
+        assert_eq!(
+            resolve_shortcut(
+                ShortcutKey::Character('f'),
+                ShortcutModifiers::new(true, false),
+                context
+            ),
+            Some(ShortcutAction::EditorFind)
+        );
+        assert_eq!(
+            resolve_shortcut(
+                ShortcutKey::Character('['),
+                ShortcutModifiers::default(),
+                context
+            ),
+            Some(ShortcutAction::EditorBadgeEarlier)
+        );
+        assert_eq!(
+            resolve_shortcut(
+                ShortcutKey::Character(']'),
+                ShortcutModifiers::default(),
+                context
+            ),
+            Some(ShortcutAction::EditorBadgeLater)
+        );
         assert_eq!(
             resolve_shortcut(ShortcutKey::Delete, ShortcutModifiers::default(), context),
             Some(ShortcutAction::EditorDeleteSelection)
@@ -311,6 +363,70 @@ mod tests {
             ),
             Some(ShortcutAction::EditorEnterRectangle)
         );
+        assert_eq!(
+            resolve_shortcut(
+                ShortcutKey::Character('e'),
+                ShortcutModifiers::new(false, false),
+                context
+            ),
+            Some(ShortcutAction::EditorEnterEllipse)
+        );
+        assert_eq!(
+            resolve_shortcut(
+                ShortcutKey::Character('n'),
+                ShortcutModifiers::new(false, false),
+                context
+            ),
+            Some(ShortcutAction::EditorEnterBadge)
+        );
+        assert_eq!(
+            resolve_shortcut(
+                ShortcutKey::Character('d'),
+                ShortcutModifiers::new(false, false),
+                context
+            ),
+            Some(ShortcutAction::EditorEnterSpotlight)
+        );
+        assert_eq!(
+            resolve_shortcut(
+                ShortcutKey::Character('z'),
+                ShortcutModifiers::new(false, false),
+                context
+            ),
+            Some(ShortcutAction::EditorEnterMagnifier)
+        );
+        assert_eq!(
+            resolve_shortcut(
+                ShortcutKey::Character('k'),
+                ShortcutModifiers::new(false, false),
+                context
+            ),
+            Some(ShortcutAction::EditorEnterCallout)
+        );
+        assert_eq!(
+            resolve_shortcut(
+                ShortcutKey::Character('i'),
+                ShortcutModifiers::new(false, false),
+                context
+            ),
+            Some(ShortcutAction::EditorEnterImage)
+        );
+        assert_eq!(
+            resolve_shortcut(
+                ShortcutKey::Character('l'),
+                ShortcutModifiers::new(false, false),
+                context
+            ),
+            Some(ShortcutAction::EditorEnterLine)
+        );
+        assert_eq!(
+            resolve_shortcut(
+                ShortcutKey::Character('m'),
+                ShortcutModifiers::new(false, false),
+                context
+            ),
+            Some(ShortcutAction::EditorEnterHighlighter)
+        );
         assert_eq!(
             resolve_shortcut(
                 ShortcutKey::Character('c'),
@@ -335,6 +451,14 @@ mod tests {
             ),
             Some(ShortcutAction::EditorEnterOcr)
         );
+        assert_eq!(
+            resolve_shortcut(
+                ShortcutKey::Character('q'),
+                ShortcutModifiers::new(false, false),
+                context
+            ),
+            Some(ShortcutAction::EditorEnterDecode)
+        );
         assert_eq!(
             resolve_shortcut(ShortcutKey::Tab, ShortcutModifiers::default(), context),
             Some(ShortcutAction::EditorToggleToolOptions)
@@ -377,6 +501,14 @@ mod tests {
             ),
             Some(ShortcutAction::PreviewCopy)
         );
+        assert_eq!(
+            resolve_shortcut(
+                ShortcutKey::Character('c'),
+                ShortcutModifiers::new(true, false),
+                context
+            ),
+            Some(ShortcutAction::PreviewCopyText)
+        );
         assert_eq!(
             resolve_shortcut(
                 ShortcutKey::Character('e'),
@@ -393,6 +525,14 @@ mod tests {
             ),
             Some(ShortcutAction::PreviewOcr)
         );
+        assert_eq!(
+            resolve_shortcut(
+                ShortcutKey::Character('q'),
+                ShortcutModifiers::default(),
+                context
+            ),
+            Some(ShortcutAction::PreviewDecode)
+        );
         assert_eq!(
             resolve_shortcut(ShortcutKey::Delete, ShortcutModifiers::default(), context),
             Some(ShortcutAction::PreviewDelete)
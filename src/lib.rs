@@ -1,4 +1,6 @@
 pub mod app;
+pub mod barcode;
+mod base64;
 pub mod capture;
 pub mod clipboard;
 mod config;
@@ -10,6 +12,7 @@ pub mod logging;
 pub mod notification;
 pub mod ocr;
 pub mod preview;
+pub mod recovery;
 pub mod state;
 pub mod storage;
 pub mod theme;
@@ -29,6 +29,8 @@ fn handle_early_args() -> Option<i32> {
             print_help();
             Some(0)
         }
+        "ocr" => Some(chalkak::ocr::cli::run(std::env::args().skip(2))),
+        "models" => Some(chalkak::ocr::models::cli::run(std::env::args().skip(2))),
         _ => None,
     }
 }
@@ -39,6 +41,12 @@ fn print_help() {
 ChalKak — Hyprland screenshot preview and editor utility
 
 Usage: chalkak [OPTIONS]
+       chalkak ocr [OPTIONS] [FILE]...
+       chalkak models <list|verify|install ARCHIVE>
+
+Commands:
+  ocr                           Recognize text in images and print it (see 'chalkak ocr --help')
+  models                        List, verify and install OCR models (see 'chalkak models --help')
 
 Options:
   --full, --capture-full        Start with full screen capture
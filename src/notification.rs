@@ -1,3 +1,5 @@
+const ACTION_ID: &str = "chalkak-action";
+
 pub fn send(body: impl Into<String>) {
     let body = body.into();
     if let Err(err) = notify_rust::Notification::new()
@@ -9,3 +11,31 @@ pub fn send(body: impl Into<String>) {
         tracing::warn!("system notification failed: {err}");
     }
 }
+
+/// Shows a notification with one action button and blocks until the action is
+/// invoked or the notification closes. Returns whether the action was chosen.
+pub fn send_with_action_blocking(
+    body: impl Into<String>,
+    action_label: &str,
+    timeout_ms: u32,
+) -> bool {
+    let body = body.into();
+    match notify_rust::Notification::new()
+        .appname("ChalKak")
+        .summary("ChalKak")
+        .body(&body)
+        .action(ACTION_ID, action_label)
+        .timeout(notify_rust::Timeout::Milliseconds(timeout_ms))
+        .show()
+    {
+        Ok(handle) => {
+            let mut chosen = false;
+            handle.wait_for_action(|action| chosen = action == ACTION_ID);
+            chosen
+        }
+        Err(err) => {
+            tracing::warn!("system notification failed: {err}");
+            false
+        }
+    }
+}
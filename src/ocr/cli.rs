@@ -0,0 +1,409 @@
+//! `chalkak ocr`: headless text recognition for scripts. Runs without GTK.
+
+use std::io::{IsTerminal, Read, Write};
+use std::path::PathBuf;
+
+use image::DynamicImage;
+
+use super::{OcrDocument, OcrError, OcrExportFormat, OcrLanguage, OcrResult};
+
+pub const EXIT_OK: i32 = 0;
+/// Invalid arguments.
+pub const EXIT_USAGE: i32 = 2;
+/// No model directory was found or the models failed to load.
+pub const EXIT_MODELS_UNAVAILABLE: i32 = 3;
+/// At least one input could not be read or recognized, or its exports could
+/// not be written.
+pub const EXIT_RECOGNITION_FAILED: i32 = 4;
+
+const STDIN_SOURCE: &str = "-";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OcrOutputFormat {
+    #[default]
+    Text,
+    Json,
+    Tsv,
+}
+
+impl OcrOutputFormat {
+    fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "text" | "txt" => Some(Self::Text),
+            "json" => Some(Self::Json),
+            "tsv" => Some(Self::Tsv),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrInput {
+    File(PathBuf),
+    Stdin,
+}
+
+impl OcrInput {
+    fn source(&self) -> String {
+        match self {
+            Self::File(path) => path.display().to_string(),
+            Self::Stdin => STDIN_SOURCE.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OcrCliOptions {
+    /// Explicit `--lang` list in preference order; `None` falls back to
+    /// `config.json` and `LANG`.
+    pub languages: Option<Vec<OcrLanguage>>,
+    pub format: OcrOutputFormat,
+    /// Files written next to each input image, in addition to stdout.
+    pub exports: Vec<OcrExportFormat>,
+    pub inputs: Vec<OcrInput>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrCliCommand {
+    Help,
+    Run(OcrCliOptions),
+}
+
+/// Parses the arguments following `ocr`. No inputs means stdin.
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<OcrCliCommand, String> {
+    let mut languages = None;
+    let mut format = OcrOutputFormat::default();
+    let mut exports = Vec::new();
+    let mut inputs = Vec::new();
+    let mut only_inputs = false;
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        if only_inputs {
+            inputs.push(input_from_arg(arg));
+            continue;
+        }
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag.to_string(), Some(value)),
+            _ => (arg.clone(), None),
+        };
+        let mut value_for = |name: &str| -> Result<String, String> {
+            inline_value
+                .map(str::to_string)
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("{name} requires a value"))
+        };
+        match flag.as_str() {
+            "-h" | "--help" => return Ok(OcrCliCommand::Help),
+            "-l" | "--lang" => {
+                let value = value_for("--lang")?;
+                languages = Some(
+                    super::parse_ocr_languages(&value)
+                        .map_err(|name| format!("unknown language: {name}"))?,
+                );
+            }
+            "-f" | "--format" => {
+                let value = value_for("--format")?;
+                format = OcrOutputFormat::parse(&value).ok_or_else(|| {
+                    format!("unknown format: {value} (expected text, json or tsv)")
+                })?;
+            }
+            "-e" | "--export" => {
+                let value = value_for("--export")?;
+                exports = super::parse_export_formats(&value).map_err(|name| {
+                    format!("unknown export format: {name} (expected hocr, alto or pdf)")
+                })?;
+            }
+            "--" => only_inputs = true,
+            _ if arg.starts_with('-') && arg != STDIN_SOURCE => {
+                return Err(format!("unknown option: {arg}"));
+            }
+            _ => inputs.push(input_from_arg(arg)),
+        }
+    }
+
+    if inputs.is_empty() {
+        inputs.push(OcrInput::Stdin);
+    }
+    Ok(OcrCliCommand::Run(OcrCliOptions {
+        languages,
+        format,
+        exports,
+        inputs,
+    }))
+}
+
+fn input_from_arg(arg: String) -> OcrInput {
+    if arg == STDIN_SOURCE {
+        OcrInput::Stdin
+    } else {
+        OcrInput::File(PathBuf::from(arg))
+    }
+}
+
+/// Entry point for `chalkak ocr ARGS...`; returns the process exit code.
+pub fn run(args: impl IntoIterator<Item = String>) -> i32 {
+    let options = match parse_args(args) {
+        Ok(OcrCliCommand::Help) => {
+            print_help();
+            return EXIT_OK;
+        }
+        Ok(OcrCliCommand::Run(options)) => options,
+        Err(message) => {
+            eprintln!("chalkak ocr: {message}");
+            eprintln!("Try 'chalkak ocr --help' for more information.");
+            return EXIT_USAGE;
+        }
+    };
+    let stdin_inputs = options
+        .inputs
+        .iter()
+        .filter(|input| **input == OcrInput::Stdin)
+        .count();
+    if stdin_inputs > 1 {
+        eprintln!("chalkak ocr: stdin can only be read once");
+        return EXIT_USAGE;
+    }
+    if stdin_inputs == 1 && !options.exports.is_empty() {
+        eprintln!(
+            "chalkak ocr: --export writes next to each input file and cannot be used with stdin"
+        );
+        return EXIT_USAGE;
+    }
+    if stdin_inputs == 1 && std::io::stdin().is_terminal() {
+        eprintln!("chalkak ocr: no input files and stdin is a terminal");
+        eprintln!("Try 'chalkak ocr --help' for more information.");
+        return EXIT_USAGE;
+    }
+
+    let model_dirs = super::resolve_model_dirs();
+    if model_dirs.is_empty() {
+        eprintln!(
+            "chalkak ocr: model directory not found; install chalkak-ocr-models or place the models in ~/.local/share/chalkak/models"
+        );
+        return EXIT_MODELS_UNAVAILABLE;
+    }
+    let languages = options.languages.unwrap_or_else(|| {
+        let config = crate::config::load_app_config();
+        let names = config
+            .ocr_language
+            .as_ref()
+            .map(|languages| languages.names())
+            .unwrap_or_default();
+        super::resolve_ocr_languages(&names)
+    });
+    let engine = match super::create_engine(&model_dirs, &languages) {
+        Ok(engine) => engine,
+        Err(err) => {
+            eprintln!("chalkak ocr: {err}");
+            return EXIT_MODELS_UNAVAILABLE;
+        }
+    };
+
+    let mut stdout = std::io::stdout().lock();
+    if options.format == OcrOutputFormat::Tsv {
+        let _ = writeln!(stdout, "{}", super::render::TSV_HEADER);
+    }
+    let multiple = options.inputs.len() > 1;
+    let mut exit_code = EXIT_OK;
+    for (index, input) in options.inputs.iter().enumerate() {
+        let source = input.source();
+        let (document, image) = match recognize_input(&engine, input) {
+            Ok(result) => result,
+            Err(err) => {
+                eprintln!("chalkak ocr: {source}: {err}");
+                exit_code = EXIT_RECOGNITION_FAILED;
+                continue;
+            }
+        };
+        if let OcrInput::File(path) = input {
+            for format in &options.exports {
+                if let Err(err) = super::export::write_export(*format, &document, &image, path) {
+                    eprintln!(
+                        "chalkak ocr: {source}: failed to write {}: {err}",
+                        format.sidecar_path(path).display()
+                    );
+                    exit_code = EXIT_RECOGNITION_FAILED;
+                }
+            }
+        }
+        let output = match options.format {
+            OcrOutputFormat::Text => {
+                let text = super::render::plain_text(&document);
+                let separator = if multiple && index > 0 { "\n" } else { "" };
+                if multiple {
+                    format!("{separator}==> {source} <==\n{text}\n")
+                } else {
+                    format!("{text}\n")
+                }
+            }
+            OcrOutputFormat::Json => format!("{}\n", super::render::json(&document, &source)),
+            OcrOutputFormat::Tsv => super::render::tsv(&document, &source),
+        };
+        if stdout.write_all(output.as_bytes()).is_err() {
+            // Reader went away (e.g. `| head`); nothing left to report to.
+            break;
+        }
+    }
+    let _ = stdout.flush();
+    exit_code
+}
+
+/// Recognizes `input` and returns the document with the decoded image, which
+/// the exports need.
+fn recognize_input(
+    engine: &super::OcrEngine,
+    input: &OcrInput,
+) -> OcrResult<(OcrDocument, DynamicImage)> {
+    let image = match input {
+        OcrInput::File(path) => image::open(path).map_err(|err| OcrError::ImageConversion {
+            message: format!("failed to open image {}: {err}", path.display()),
+        })?,
+        OcrInput::Stdin => {
+            let mut bytes = Vec::new();
+            std::io::stdin()
+                .read_to_end(&mut bytes)
+                .map_err(|err| OcrError::ImageConversion {
+                    message: format!("failed to read stdin: {err}"),
+                })?;
+            image::load_from_memory(&bytes).map_err(|err| OcrError::ImageConversion {
+                message: format!("failed to decode image from stdin: {err}"),
+            })?
+        }
+    };
+    let document = super::recognize_document(engine, &image)?;
+    Ok((document, image))
+}
+
+fn print_help() {
+    println!(
+        "\
+Recognize text in images without opening a window
+
+Usage: chalkak ocr [OPTIONS] [FILE]...
+
+Reads each FILE, or stdin when no FILE is given or FILE is '-'.
+
+Options:
+  -l, --lang <LANG,...>   Recognition languages in preference order, e.g. ko,en
+                          (ko, en, zh, latin, ru, ar, th, el, hi, ta, te); each text
+                          box keeps the most confident reading
+                          [default: ocr_language from config.json, then $LANG]
+  -f, --format <FORMAT>   Output format: text, json (one object per input), tsv [default: text]
+  -e, --export <FORMAT,...>
+                          Also write hocr (FILE.hocr), alto (FILE.alto.xml) or pdf
+                          (FILE.pdf, the image with an invisible text layer) next
+                          to each input FILE; not available for stdin
+  -h, --help              Print this help message
+
+Exit status:
+  0  all inputs recognized
+  2  invalid arguments
+  3  model directory not found or models failed to load
+  4  an input could not be read or recognized, or an export could not be written"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<OcrCliCommand, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    fn options(args: &[&str]) -> OcrCliOptions {
+        match parse(args) {
+            Ok(OcrCliCommand::Run(options)) => options,
+            other => panic!("expected run options, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_args_defaults_to_text_on_stdin() {
+        let options = options(&[]);
+
+        assert_eq!(options.languages, None);
+        assert_eq!(options.format, OcrOutputFormat::Text);
+        assert!(options.exports.is_empty());
+        assert_eq!(options.inputs, vec![OcrInput::Stdin]);
+    }
+
+    #[test]
+    fn parse_args_accepts_separate_and_inline_values() {
+        let options = options(&["--lang", "ko", "--format=json", "a.png", "-", "b.png"]);
+
+        assert_eq!(options.languages, Some(vec![OcrLanguage::Korean]));
+        assert_eq!(options.format, OcrOutputFormat::Json);
+        assert_eq!(
+            options.inputs,
+            vec![
+                OcrInput::File(PathBuf::from("a.png")),
+                OcrInput::Stdin,
+                OcrInput::File(PathBuf::from("b.png")),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_args_accepts_language_list() {
+        let options = options(&["-l", "ko,en"]);
+
+        assert_eq!(
+            options.languages,
+            Some(vec![OcrLanguage::Korean, OcrLanguage::English])
+        );
+        assert_eq!(
+            parse(&["--lang=en,klingon"]),
+            Err("unknown language: klingon".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_args_accepts_export_list() {
+        assert_eq!(
+            options(&["-e", "pdf,hocr", "a.png"]).exports,
+            vec![OcrExportFormat::Pdf, OcrExportFormat::Hocr]
+        );
+        assert_eq!(
+            options(&["--export=alto", "a.png"]).exports,
+            vec![OcrExportFormat::Alto]
+        );
+        assert_eq!(
+            parse(&["--export=pdf,docx"]),
+            Err("unknown export format: docx (expected hocr, alto or pdf)".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_args_treats_everything_after_double_dash_as_files() {
+        let options = options(&["-f", "tsv", "--", "--lang"]);
+
+        assert_eq!(options.format, OcrOutputFormat::Tsv);
+        assert_eq!(
+            options.inputs,
+            vec![OcrInput::File(PathBuf::from("--lang"))]
+        );
+    }
+
+    #[test]
+    fn parse_args_rejects_bad_values_and_unknown_flags() {
+        assert_eq!(
+            parse(&["--format", "xml"]),
+            Err("unknown format: xml (expected text, json or tsv)".to_string())
+        );
+        assert_eq!(
+            parse(&["--lang", "klingon"]),
+            Err("unknown language: klingon".to_string())
+        );
+        assert_eq!(
+            parse(&["--lang"]),
+            Err("--lang requires a value".to_string())
+        );
+        assert_eq!(
+            parse(&["--verbose"]),
+            Err("unknown option: --verbose".to_string())
+        );
+        assert_eq!(parse(&["a.png", "--help"]), Ok(OcrCliCommand::Help));
+    }
+}
@@ -0,0 +1,199 @@
+/// Axis-aligned box in image pixel coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OcrRect {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl OcrRect {
+    pub const fn new(x: i32, y: i32, width: u32, height: u32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn right(self) -> i32 {
+        self.x
+            .saturating_add(self.width.min(i32::MAX as u32) as i32)
+    }
+
+    pub fn bottom(self) -> i32 {
+        self.y
+            .saturating_add(self.height.min(i32::MAX as u32) as i32)
+    }
+
+    pub fn center_y(self) -> f64 {
+        f64::from(self.y) + f64::from(self.height) / 2.0
+    }
+
+    /// Smallest rectangle containing both `self` and `other`.
+    pub fn union(self, other: Self) -> Self {
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        let right = self.right().max(other.right());
+        let bottom = self.bottom().max(other.bottom());
+        Self::new(x, y, (right - x) as u32, (bottom - y) as u32)
+    }
+
+    /// Length of the shared vertical extent, zero when the boxes do not overlap.
+    pub fn vertical_overlap(self, other: Self) -> u32 {
+        let top = self.y.max(other.y);
+        let bottom = self.bottom().min(other.bottom());
+        (bottom - top).max(0) as u32
+    }
+
+    fn union_all(rects: impl IntoIterator<Item = Self>) -> Self {
+        rects.into_iter().reduce(Self::union).unwrap_or_default()
+    }
+}
+
+/// One text box as reported by the recognition engine.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OcrSpan {
+    pub text: String,
+    pub bbox: OcrRect,
+    pub confidence: f32,
+}
+
+impl OcrSpan {
+    pub fn new(text: impl Into<String>, bbox: OcrRect, confidence: f32) -> Self {
+        Self {
+            text: text.into(),
+            bbox,
+            confidence,
+        }
+    }
+}
+
+/// Spans sharing a baseline, ordered left to right.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OcrLine {
+    pub bbox: OcrRect,
+    pub spans: Vec<OcrSpan>,
+}
+
+impl OcrLine {
+    pub(super) fn from_spans(mut spans: Vec<OcrSpan>) -> Self {
+        spans.sort_by_key(|span| span.bbox.x);
+        let bbox = OcrRect::union_all(spans.iter().map(|span| span.bbox));
+        Self { bbox, spans }
+    }
+
+    pub fn text(&self) -> String {
+        self.spans
+            .iter()
+            .map(|span| span.text.trim())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Mean confidence of the spans in this line.
+    pub fn confidence(&self) -> f32 {
+        if self.spans.is_empty() {
+            return 0.0;
+        }
+        let total: f32 = self.spans.iter().map(|span| span.confidence).sum();
+        total / self.spans.len() as f32
+    }
+}
+
+/// Consecutive lines of one block without a paragraph-sized gap between them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OcrParagraph {
+    pub bbox: OcrRect,
+    pub lines: Vec<OcrLine>,
+}
+
+impl OcrParagraph {
+    pub(super) fn from_lines(lines: Vec<OcrLine>) -> Self {
+        let bbox = OcrRect::union_all(lines.iter().map(|line| line.bbox));
+        Self { bbox, lines }
+    }
+}
+
+/// A column or full-width region of the page.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OcrBlock {
+    pub bbox: OcrRect,
+    pub paragraphs: Vec<OcrParagraph>,
+}
+
+impl OcrBlock {
+    pub(super) fn from_paragraphs(paragraphs: Vec<OcrParagraph>) -> Self {
+        let bbox = OcrRect::union_all(paragraphs.iter().map(|paragraph| paragraph.bbox));
+        Self { bbox, paragraphs }
+    }
+}
+
+/// Recognition result with layout reconstructed into reading order: blocks
+/// (columns and full-width regions), paragraphs, lines and the engine spans.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct OcrDocument {
+    pub blocks: Vec<OcrBlock>,
+}
+
+impl OcrDocument {
+    /// Reconstructs reading order from unordered engine spans. Spans without
+    /// visible text are dropped.
+    pub fn from_spans(spans: Vec<OcrSpan>) -> Self {
+        super::layout::build_document(spans)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    pub fn paragraphs(&self) -> impl Iterator<Item = &OcrParagraph> {
+        self.blocks.iter().flat_map(|block| block.paragraphs.iter())
+    }
+
+    /// Lines in reading order.
+    pub fn lines(&self) -> impl Iterator<Item = &OcrLine> {
+        self.paragraphs()
+            .flat_map(|paragraph| paragraph.lines.iter())
+    }
+
+    pub fn spans(&self) -> impl Iterator<Item = &OcrSpan> {
+        self.lines().flat_map(|line| line.spans.iter())
+    }
+
+    /// Plain-text rendering; see [`super::render::plain_text`].
+    pub fn to_plain_text(&self) -> String {
+        super::render::plain_text(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn union_covers_both_rectangles() {
+        let merged = OcrRect::new(10, 20, 30, 10).union(OcrRect::new(0, 25, 15, 20));
+        assert_eq!(merged, OcrRect::new(0, 20, 40, 25));
+    }
+
+    #[test]
+    fn vertical_overlap_is_zero_for_disjoint_rows() {
+        let top = OcrRect::new(0, 0, 10, 10);
+        assert_eq!(top.vertical_overlap(OcrRect::new(0, 5, 10, 10)), 5);
+        assert_eq!(top.vertical_overlap(OcrRect::new(0, 12, 10, 10)), 0);
+    }
+
+    #[test]
+    fn line_text_joins_spans_left_to_right_and_averages_confidence() {
+        let line = OcrLine::from_spans(vec![
+            OcrSpan::new("world", OcrRect::new(60, 0, 40, 10), 0.8),
+            OcrSpan::new(" hello ", OcrRect::new(0, 0, 40, 10), 0.6),
+        ]);
+
+        assert_eq!(line.text(), "hello world");
+        assert!((line.confidence() - 0.7).abs() < 1e-6);
+        assert_eq!(line.bbox, OcrRect::new(0, 0, 100, 10));
+    }
+}
@@ -0,0 +1,209 @@
+//! One text detector shared by every language, one recognizer per language.
+
+use std::path::PathBuf;
+
+use image::DynamicImage;
+use ocr_rs::{DetModel, RecModel};
+
+use super::models::{find_model_file, DET_MODEL_FILENAME};
+use super::{OcrError, OcrLanguage, OcrRect, OcrResult, OcrSpan, OcrStage};
+
+/// Boxes read per recognizer call; progress and cancellation are checked
+/// between batches.
+const RECOGNITION_BATCH: usize = 16;
+
+/// OCR engine for an ordered list of candidate languages.
+///
+/// Detection runs once per image; every detected box is then read by each
+/// candidate recognizer and the most confident reading wins. Recognizers stay
+/// cached after [`OcrEngine::set_languages`] drops them from the list, so
+/// switching back does not reload the model.
+pub struct OcrEngine {
+    model_dirs: Vec<PathBuf>,
+    detector: DetModel,
+    recognizers: Vec<(OcrLanguage, RecModel)>,
+    languages: Vec<OcrLanguage>,
+}
+
+impl OcrEngine {
+    /// Loads the detector and the recognizers for `languages`, taking each
+    /// file from the first of `model_dirs` that has it.
+    pub fn new(model_dirs: &[PathBuf], languages: &[OcrLanguage]) -> OcrResult<Self> {
+        let det_path = model_path(model_dirs, DET_MODEL_FILENAME)?;
+        let detector =
+            DetModel::from_file(det_path.to_str().unwrap_or_default(), None).map_err(|err| {
+                OcrError::EngineInit {
+                    message: err.to_string(),
+                }
+            })?;
+        let mut engine = Self {
+            model_dirs: model_dirs.to_vec(),
+            detector,
+            recognizers: Vec::new(),
+            languages: Vec::new(),
+        };
+        engine.set_languages(languages)?;
+        Ok(engine)
+    }
+
+    /// Candidate languages in preference order.
+    pub fn languages(&self) -> &[OcrLanguage] {
+        &self.languages
+    }
+
+    /// Replaces the candidate list, loading recognizers that are not cached
+    /// yet. On error the previous list stays in effect.
+    pub fn set_languages(&mut self, languages: &[OcrLanguage]) -> OcrResult<()> {
+        let languages = super::dedup_languages(languages);
+        if languages.is_empty() {
+            return Err(OcrError::EngineInit {
+                message: "no OCR language selected".to_string(),
+            });
+        }
+        for &language in &languages {
+            if !self.is_cached(language) {
+                let recognizer = load_recognizer(&self.model_dirs, language)?;
+                self.recognizers.push((language, recognizer));
+            }
+        }
+        self.languages = languages;
+        Ok(())
+    }
+
+    fn is_cached(&self, language: OcrLanguage) -> bool {
+        self.recognizers
+            .iter()
+            .any(|(cached, _)| *cached == language)
+    }
+
+    fn recognizer(&self, language: OcrLanguage) -> Option<&RecModel> {
+        self.recognizers
+            .iter()
+            .find(|(cached, _)| *cached == language)
+            .map(|(_, recognizer)| recognizer)
+    }
+
+    /// Detects and reads every box, reporting each stage to `on_stage`;
+    /// returning `false` from it stops with [`OcrError::Cancelled`].
+    pub(super) fn recognize_spans(
+        &self,
+        image: &DynamicImage,
+        on_stage: &mut dyn FnMut(OcrStage) -> bool,
+    ) -> OcrResult<Vec<OcrSpan>> {
+        if !on_stage(OcrStage::Detecting) {
+            return Err(OcrError::Cancelled);
+        }
+        let detections = self
+            .detector
+            .detect_and_crop(image)
+            .map_err(recognition_error)?;
+        if detections.is_empty() {
+            return Ok(Vec::new());
+        }
+        let (crops, boxes): (Vec<DynamicImage>, Vec<_>) = detections.into_iter().unzip();
+
+        let total = crops.len() * self.languages.len();
+        let mut done = 0;
+        let mut best: Vec<Option<(String, f32)>> = vec![None; crops.len()];
+        for &language in &self.languages {
+            let Some(recognizer) = self.recognizer(language) else {
+                continue;
+            };
+            for (batch_index, batch) in crops.chunks(RECOGNITION_BATCH).enumerate() {
+                if !on_stage(OcrStage::Recognizing { done, total }) {
+                    return Err(OcrError::Cancelled);
+                }
+                let readings = recognizer
+                    .recognize_batch(batch)
+                    .map_err(recognition_error)?;
+                let slots = best.iter_mut().skip(batch_index * RECOGNITION_BATCH);
+                for (slot, reading) in slots.zip(readings) {
+                    keep_more_confident(slot, reading.text, reading.confidence);
+                }
+                done += batch.len();
+            }
+        }
+
+        Ok(boxes
+            .into_iter()
+            .zip(best)
+            .filter_map(|(text_box, reading)| {
+                let (text, confidence) = reading?;
+                let rect = text_box.rect;
+                Some(OcrSpan::new(
+                    text,
+                    OcrRect::new(rect.left(), rect.top(), rect.width(), rect.height()),
+                    confidence,
+                ))
+            })
+            .collect())
+    }
+}
+
+fn model_path(model_dirs: &[PathBuf], filename: &str) -> OcrResult<PathBuf> {
+    find_model_file(model_dirs, filename).ok_or_else(|| OcrError::EngineInit {
+        message: format!("{filename} not found in model directories"),
+    })
+}
+
+fn load_recognizer(model_dirs: &[PathBuf], language: OcrLanguage) -> OcrResult<RecModel> {
+    let rec_path = model_path(model_dirs, language.rec_model_filename())?;
+    let keys_path = model_path(model_dirs, language.keys_filename())?;
+    RecModel::from_file(
+        rec_path.to_str().unwrap_or_default(),
+        keys_path.to_str().unwrap_or_default(),
+        None,
+    )
+    .map_err(|err| OcrError::EngineInit {
+        message: format!("{} recognizer: {err}", language.display_name()),
+    })
+}
+
+fn recognition_error(err: impl std::fmt::Display) -> OcrError {
+    OcrError::Recognition {
+        message: err.to_string(),
+    }
+}
+
+/// Stores `text` when it is non-blank and strictly more confident than the
+/// current reading, so ties go to the language listed first.
+fn keep_more_confident(slot: &mut Option<(String, f32)>, text: String, confidence: f32) {
+    if text.trim().is_empty() {
+        return;
+    }
+    if slot
+        .as_ref()
+        .is_none_or(|(_, current)| confidence > *current)
+    {
+        *slot = Some((text, confidence));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keep_more_confident_prefers_higher_confidence_and_earlier_ties() {
+        let mut slot = None;
+
+        keep_more_confident(&mut slot, "안녕".to_string(), 0.62);
+        keep_more_confident(&mut slot, "Hello".to_string(), 0.91);
+        keep_more_confident(&mut slot, "Hel1o".to_string(), 0.91);
+        keep_more_confident(&mut slot, "Xello".to_string(), 0.40);
+
+        assert_eq!(slot, Some(("Hello".to_string(), 0.91)));
+    }
+
+    #[test]
+    fn keep_more_confident_ignores_blank_readings() {
+        let mut slot = None;
+
+        keep_more_confident(&mut slot, "  ".to_string(), 0.99);
+        assert_eq!(slot, None);
+
+        keep_more_confident(&mut slot, "text".to_string(), 0.30);
+        keep_more_confident(&mut slot, String::new(), 0.99);
+        assert_eq!(slot, Some(("text".to_string(), 0.30)));
+    }
+}
@@ -0,0 +1,352 @@
+//! Archival exports of an [`OcrDocument`]: hOCR, ALTO XML and a searchable
+//! PDF, written next to the image they describe.
+//!
+//! hOCR and ALTO carry the layout tree (blocks, paragraphs, lines) with one
+//! word element per engine text box. Coordinates are image pixels.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use image::DynamicImage;
+
+use super::document::{OcrDocument, OcrRect};
+
+pub(super) const PRODUCER: &str = concat!("ChalKak ", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OcrExportFormat {
+    Hocr,
+    Alto,
+    Pdf,
+}
+
+impl OcrExportFormat {
+    pub const ALL: [Self; 3] = [Self::Hocr, Self::Alto, Self::Pdf];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Hocr => "hOCR",
+            Self::Alto => "ALTO XML",
+            Self::Pdf => "Searchable PDF",
+        }
+    }
+
+    /// Suffix replacing the image extension, e.g. `shot.png` → `shot.hocr`.
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Hocr => "hocr",
+            Self::Alto => "alto.xml",
+            Self::Pdf => "pdf",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "hocr" => Some(Self::Hocr),
+            "alto" | "xml" => Some(Self::Alto),
+            "pdf" => Some(Self::Pdf),
+            _ => None,
+        }
+    }
+
+    /// Path of the export written for `image_path`.
+    pub fn sidecar_path(self, image_path: &Path) -> PathBuf {
+        image_path.with_extension(self.extension())
+    }
+}
+
+/// Parses a comma-separated export list, dropping repeats. Returns the first
+/// unknown name on failure.
+pub fn parse_export_formats(value: &str) -> Result<Vec<OcrExportFormat>, String> {
+    let mut formats = Vec::new();
+    for name in value.split(',').filter(|name| !name.trim().is_empty()) {
+        let format = OcrExportFormat::parse(name).ok_or_else(|| name.trim().to_string())?;
+        if !formats.contains(&format) {
+            formats.push(format);
+        }
+    }
+    Ok(formats)
+}
+
+/// Writes `document` for the image at `image_path` in `format` and returns
+/// the path written.
+pub fn write_export(
+    format: OcrExportFormat,
+    document: &OcrDocument,
+    image: &DynamicImage,
+    image_path: &Path,
+) -> std::io::Result<PathBuf> {
+    let target = format.sidecar_path(image_path);
+    let image_name = image_path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let bytes = match format {
+        OcrExportFormat::Hocr => {
+            hocr(document, &image_name, image.width(), image.height()).into_bytes()
+        }
+        OcrExportFormat::Alto => {
+            alto(document, &image_name, image.width(), image.height()).into_bytes()
+        }
+        OcrExportFormat::Pdf => super::pdf::searchable_pdf(document, image),
+    };
+    std::fs::write(&target, bytes)?;
+    Ok(target)
+}
+
+/// hOCR 1.2 (XHTML) for an image of `width` × `height` pixels.
+pub fn hocr(document: &OcrDocument, image_name: &str, width: u32, height: u32) -> String {
+    let mut out = String::from(concat!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+        "<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Transitional//EN\" ",
+        "\"http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd\">\n",
+        "<html xmlns=\"http://www.w3.org/1999/xhtml\" xml:lang=\"en\" lang=\"en\">\n",
+        " <head>\n",
+    ));
+    let _ = writeln!(out, "  <title>{}</title>", xml_escape(image_name));
+    out.push_str("  <meta http-equiv=\"Content-Type\" content=\"text/html;charset=utf-8\"/>\n");
+    let _ = writeln!(out, "  <meta name=\"ocr-system\" content=\"{PRODUCER}\"/>");
+    out.push_str(
+        "  <meta name=\"ocr-capabilities\" content=\"ocr_page ocr_carea ocr_par ocr_line ocrx_word\"/>\n",
+    );
+    out.push_str(" </head>\n <body>\n");
+    let _ = writeln!(
+        out,
+        "  <div class=\"ocr_page\" id=\"page_1\" title=\"image &quot;{}&quot;; bbox 0 0 {width} {height}; ppageno 0\">",
+        xml_escape(image_name)
+    );
+    let (mut paragraph_id, mut line_id, mut word_id) = (0, 0, 0);
+    for (block_index, block) in document.blocks.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "   <div class=\"ocr_carea\" id=\"block_{}\" title=\"{}\">",
+            block_index + 1,
+            hocr_bbox(block.bbox)
+        );
+        for paragraph in &block.paragraphs {
+            paragraph_id += 1;
+            let _ = writeln!(
+                out,
+                "    <p class=\"ocr_par\" id=\"par_{paragraph_id}\" title=\"{}\">",
+                hocr_bbox(paragraph.bbox)
+            );
+            for line in &paragraph.lines {
+                line_id += 1;
+                let _ = writeln!(
+                    out,
+                    "     <span class=\"ocr_line\" id=\"line_{line_id}\" title=\"{}\">",
+                    hocr_bbox(line.bbox)
+                );
+                for span in &line.spans {
+                    word_id += 1;
+                    let _ = writeln!(
+                        out,
+                        "      <span class=\"ocrx_word\" id=\"word_{word_id}\" title=\"{}; x_wconf {}\">{}</span>",
+                        hocr_bbox(span.bbox),
+                        percent(span.confidence),
+                        xml_escape(span.text.trim())
+                    );
+                }
+                out.push_str("     </span>\n");
+            }
+            out.push_str("    </p>\n");
+        }
+        out.push_str("   </div>\n");
+    }
+    out.push_str("  </div>\n </body>\n</html>\n");
+    out
+}
+
+fn hocr_bbox(rect: OcrRect) -> String {
+    format!(
+        "bbox {} {} {} {}",
+        rect.x,
+        rect.y,
+        rect.right(),
+        rect.bottom()
+    )
+}
+
+fn percent(confidence: f32) -> u32 {
+    (confidence.clamp(0.0, 1.0) * 100.0).round() as u32
+}
+
+/// ALTO v4 for an image of `width` × `height` pixels. Each paragraph becomes
+/// a `TextBlock`.
+pub fn alto(document: &OcrDocument, image_name: &str, width: u32, height: u32) -> String {
+    let mut out = String::from(concat!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+        "<alto xmlns=\"http://www.loc.gov/standards/alto/ns-v4#\" ",
+        "xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" ",
+        "xsi:schemaLocation=\"http://www.loc.gov/standards/alto/ns-v4# ",
+        "http://www.loc.gov/standards/alto/v4/alto-4-2.xsd\">\n",
+        " <Description>\n",
+        "  <MeasurementUnit>pixel</MeasurementUnit>\n",
+        "  <sourceImageInformation>\n",
+    ));
+    let _ = writeln!(out, "   <fileName>{}</fileName>", xml_escape(image_name));
+    out.push_str(concat!(
+        "  </sourceImageInformation>\n",
+        "  <OCRProcessing ID=\"OCR_0\">\n",
+        "   <ocrProcessingStep>\n",
+        "    <processingSoftware>\n",
+        "     <softwareName>ChalKak</softwareName>\n",
+    ));
+    let _ = writeln!(
+        out,
+        "     <softwareVersion>{}</softwareVersion>",
+        env!("CARGO_PKG_VERSION")
+    );
+    out.push_str(concat!(
+        "    </processingSoftware>\n",
+        "   </ocrProcessingStep>\n",
+        "  </OCRProcessing>\n",
+        " </Description>\n",
+        " <Layout>\n",
+    ));
+    let _ = writeln!(
+        out,
+        "  <Page ID=\"page_1\" PHYSICAL_IMG_NR=\"1\" WIDTH=\"{width}\" HEIGHT=\"{height}\">"
+    );
+    let _ = writeln!(
+        out,
+        "   <PrintSpace HPOS=\"0\" VPOS=\"0\" WIDTH=\"{width}\" HEIGHT=\"{height}\">"
+    );
+    let (mut line_id, mut string_id) = (0, 0);
+    for (paragraph_index, paragraph) in document.paragraphs().enumerate() {
+        let _ = writeln!(
+            out,
+            "    <TextBlock ID=\"block_{}\" {}>",
+            paragraph_index + 1,
+            alto_box(paragraph.bbox)
+        );
+        for line in &paragraph.lines {
+            line_id += 1;
+            let _ = writeln!(
+                out,
+                "     <TextLine ID=\"line_{line_id}\" {}>",
+                alto_box(line.bbox)
+            );
+            for (span_index, span) in line.spans.iter().enumerate() {
+                if span_index > 0 {
+                    out.push_str("      <SP/>\n");
+                }
+                string_id += 1;
+                let _ = writeln!(
+                    out,
+                    "      <String ID=\"string_{string_id}\" {} WC=\"{:.2}\" CONTENT=\"{}\"/>",
+                    alto_box(span.bbox),
+                    span.confidence.clamp(0.0, 1.0),
+                    xml_escape(span.text.trim())
+                );
+            }
+            out.push_str("     </TextLine>\n");
+        }
+        out.push_str("    </TextBlock>\n");
+    }
+    out.push_str("   </PrintSpace>\n  </Page>\n </Layout>\n</alto>\n");
+    out
+}
+
+fn alto_box(rect: OcrRect) -> String {
+    format!(
+        "HPOS=\"{}\" VPOS=\"{}\" WIDTH=\"{}\" HEIGHT=\"{}\"",
+        rect.x, rect.y, rect.width, rect.height
+    )
+}
+
+fn xml_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            // Control characters other than tab and line breaks are not
+            // allowed in XML 1.0.
+            ch if ch.is_control() && !matches!(ch, '\t' | '\n' | '\r') => {}
+            ch => out.push(ch),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::document::OcrSpan;
+    use super::*;
+
+    fn document() -> OcrDocument {
+        OcrDocument::from_spans(vec![
+            OcrSpan::new("Fish & <chips>", OcrRect::new(10, 10, 120, 20), 0.934),
+            OcrSpan::new("\"fresh\"", OcrRect::new(140, 10, 60, 20), 0.5),
+            OcrSpan::new("Daily", OcrRect::new(10, 40, 50, 20), 1.0),
+        ])
+    }
+
+    #[test]
+    fn parse_export_formats_accepts_list_and_rejects_unknown_names() {
+        assert_eq!(
+            parse_export_formats("pdf, hocr,pdf"),
+            Ok(vec![OcrExportFormat::Pdf, OcrExportFormat::Hocr])
+        );
+        assert_eq!(
+            parse_export_formats("alto"),
+            Ok(vec![OcrExportFormat::Alto])
+        );
+        assert_eq!(parse_export_formats("hocr,docx"), Err("docx".to_string()));
+    }
+
+    #[test]
+    fn sidecar_path_replaces_image_extension() {
+        let image = Path::new("/shots/capture-1.png");
+
+        assert_eq!(
+            OcrExportFormat::Hocr.sidecar_path(image),
+            PathBuf::from("/shots/capture-1.hocr")
+        );
+        assert_eq!(
+            OcrExportFormat::Alto.sidecar_path(image),
+            PathBuf::from("/shots/capture-1.alto.xml")
+        );
+        assert_eq!(
+            OcrExportFormat::Pdf.sidecar_path(image),
+            PathBuf::from("/shots/capture-1.pdf")
+        );
+    }
+
+    #[test]
+    fn hocr_nests_layout_with_corner_boxes_and_escaped_text() {
+        let text = hocr(&document(), "shot.png", 400, 100);
+
+        assert!(text.contains(
+            "class=\"ocr_page\" id=\"page_1\" title=\"image &quot;shot.png&quot;; bbox 0 0 400 100; ppageno 0\""
+        ));
+        assert!(text.contains(
+            "<span class=\"ocrx_word\" id=\"word_1\" title=\"bbox 10 10 130 30; x_wconf 93\">Fish &amp; &lt;chips&gt;</span>"
+        ));
+        assert!(text
+            .contains("id=\"word_2\" title=\"bbox 140 10 200 30; x_wconf 50\">&quot;fresh&quot;<"));
+        assert_eq!(text.matches("class=\"ocr_line\"").count(), 2);
+        assert_eq!(
+            text.matches("<span").count(),
+            text.matches("</span>").count()
+        );
+    }
+
+    #[test]
+    fn alto_uses_position_and_size_with_spaces_between_strings() {
+        let text = alto(&document(), "shot.png", 400, 100);
+
+        assert!(text.contains("<fileName>shot.png</fileName>"));
+        assert!(text
+            .contains("<Page ID=\"page_1\" PHYSICAL_IMG_NR=\"1\" WIDTH=\"400\" HEIGHT=\"100\">"));
+        assert!(text.contains(
+            "<String ID=\"string_1\" HPOS=\"10\" VPOS=\"10\" WIDTH=\"120\" HEIGHT=\"20\" WC=\"0.93\" CONTENT=\"Fish &amp; &lt;chips&gt;\"/>\n      <SP/>\n"
+        ));
+        assert!(text.contains("CONTENT=\"Daily\""));
+        assert_eq!(text.matches("<TextLine ").count(), 2);
+    }
+}
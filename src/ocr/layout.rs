@@ -0,0 +1,398 @@
+//! Reading-order reconstruction for unordered OCR spans.
+//!
+//! The page is split recursively (XY-cut): vertical gutters separate columns,
+//! and where a full-width element such as a heading blocks every gutter the
+//! region is first split into horizontal bands. Each leaf region becomes one
+//! block whose spans are clustered into lines and lines into paragraphs.
+//! All thresholds scale with the median span height.
+
+use super::document::{OcrBlock, OcrDocument, OcrLine, OcrParagraph, OcrRect, OcrSpan};
+
+/// Minimum vertical overlap, relative to the shorter box, for two spans to
+/// share a line.
+const LINE_OVERLAP_RATIO: f64 = 0.5;
+/// Minimum empty horizontal run that separates two columns.
+const COLUMN_GUTTER_RATIO: f64 = 2.0;
+/// Vertical gap between lines above which a new paragraph starts.
+const PARAGRAPH_GAP_RATIO: f64 = 0.8;
+/// Each of at least two columns needs this many rows, so a single row with
+/// widely spaced words is not mistaken for a column layout.
+const MIN_ROWS_PER_COLUMN: usize = 2;
+
+#[derive(Debug, Clone, Copy)]
+struct LayoutMetrics {
+    gutter: f64,
+    paragraph_gap: f64,
+}
+
+impl LayoutMetrics {
+    fn from_spans(spans: &[OcrSpan]) -> Self {
+        let median = median_span_height(spans);
+        Self {
+            gutter: median * COLUMN_GUTTER_RATIO,
+            paragraph_gap: median * PARAGRAPH_GAP_RATIO,
+        }
+    }
+}
+
+/// Median span height, the unit all layout thresholds scale with. `spans`
+/// must not be empty.
+pub(super) fn median_span_height<'a>(spans: impl IntoIterator<Item = &'a OcrSpan>) -> f64 {
+    let mut heights = spans
+        .into_iter()
+        .map(|span| span.bbox.height.max(1))
+        .collect::<Vec<_>>();
+    heights.sort_unstable();
+    f64::from(heights[heights.len() / 2])
+}
+
+/// Vertical overlap of two boxes relative to the shorter one, or `None` when
+/// it is too small for them to share a line.
+pub(super) fn line_overlap(a: OcrRect, b: OcrRect) -> Option<f64> {
+    let overlap = f64::from(a.vertical_overlap(b));
+    let shorter = f64::from(a.height.min(b.height).max(1));
+    let ratio = overlap / shorter;
+    (ratio >= LINE_OVERLAP_RATIO).then_some(ratio)
+}
+
+pub(super) fn build_document(spans: Vec<OcrSpan>) -> OcrDocument {
+    let spans = spans
+        .into_iter()
+        .filter(|span| !span.text.trim().is_empty())
+        .collect::<Vec<_>>();
+    if spans.is_empty() {
+        return OcrDocument::default();
+    }
+
+    let metrics = LayoutMetrics::from_spans(&spans);
+    let mut blocks = Vec::new();
+    cut_region(spans, metrics, &mut blocks);
+    OcrDocument { blocks }
+}
+
+fn cut_region(spans: Vec<OcrSpan>, metrics: LayoutMetrics, blocks: &mut Vec<OcrBlock>) {
+    if let Some(columns) = split_columns(&spans, metrics) {
+        let mut spans = spans.into_iter().map(Some).collect::<Vec<_>>();
+        for column in columns {
+            let column_spans = column
+                .into_iter()
+                .filter_map(|index| spans[index].take())
+                .collect();
+            cut_region(column_spans, metrics, blocks);
+        }
+        return;
+    }
+
+    let groups = group_bands(spans, metrics);
+    if groups.len() == 1 {
+        if let Some(group) = groups.into_iter().next() {
+            blocks.push(build_block(group.spans, metrics));
+        }
+        return;
+    }
+    for group in groups {
+        if group.has_columns {
+            cut_region(group.spans, metrics, blocks);
+        } else {
+            blocks.push(build_block(group.spans, metrics));
+        }
+    }
+}
+
+/// Groups span indices into columns separated by gutters, left to right.
+/// Returns `None` unless the split yields a real multi-column layout.
+fn split_columns(spans: &[OcrSpan], metrics: LayoutMetrics) -> Option<Vec<Vec<usize>>> {
+    let mut order = (0..spans.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&index| spans[index].bbox.x);
+
+    let mut columns: Vec<Vec<usize>> = Vec::new();
+    let mut column_right = i32::MIN;
+    for index in order {
+        let bbox = spans[index].bbox;
+        let starts_new_column =
+            columns.is_empty() || f64::from(bbox.x) - f64::from(column_right) >= metrics.gutter;
+        if starts_new_column {
+            columns.push(Vec::new());
+            column_right = bbox.right();
+        } else {
+            column_right = column_right.max(bbox.right());
+        }
+        if let Some(column) = columns.last_mut() {
+            column.push(index);
+        }
+    }
+
+    let multi_row_columns = columns
+        .iter()
+        .filter(|column| {
+            let rects = column.iter().map(|&index| spans[index].bbox);
+            row_ranges(rects).len() >= MIN_ROWS_PER_COLUMN
+        })
+        .count();
+    (columns.len() > 1 && multi_row_columns >= 2).then_some(columns)
+}
+
+/// Merges the vertical extents of `rects` into disjoint `(top, bottom)` rows,
+/// top to bottom.
+fn row_ranges(rects: impl Iterator<Item = OcrRect>) -> Vec<(i32, i32)> {
+    let mut extents = rects
+        .map(|rect| (rect.y, rect.bottom()))
+        .collect::<Vec<_>>();
+    extents.sort_unstable();
+    let mut rows: Vec<(i32, i32)> = Vec::new();
+    for (top, bottom) in extents {
+        match rows.last_mut() {
+            Some(row) if top < row.1 => row.1 = row.1.max(bottom),
+            _ => rows.push((top, bottom)),
+        }
+    }
+    rows
+}
+
+struct BandGroup {
+    spans: Vec<OcrSpan>,
+    has_columns: bool,
+}
+
+/// Splits a region without a global gutter into horizontal bands and merges
+/// neighbouring bands that share a column structure, so a heading above a
+/// two-column body yields a full-width group followed by a column group.
+fn group_bands(mut spans: Vec<OcrSpan>, metrics: LayoutMetrics) -> Vec<BandGroup> {
+    let rows = row_ranges(spans.iter().map(|span| span.bbox));
+    spans.sort_by_key(|span| span.bbox.y);
+
+    let mut bands: Vec<Vec<OcrSpan>> = (0..rows.len()).map(|_| Vec::new()).collect();
+    for span in spans {
+        let row = rows
+            .iter()
+            .position(|&(top, bottom)| span.bbox.y >= top && span.bbox.y < bottom.max(top + 1))
+            .unwrap_or(rows.len() - 1);
+        bands[row].push(span);
+    }
+
+    let mut groups: Vec<BandGroup> = Vec::new();
+    for band in bands {
+        let band_has_columns = has_gutter(&band, metrics);
+        if let Some(group) = groups.last_mut() {
+            if group.has_columns == band_has_columns {
+                let mut merged = group.spans.clone();
+                merged.extend(band.iter().cloned());
+                if !band_has_columns || split_columns(&merged, metrics).is_some() {
+                    group.spans = merged;
+                    continue;
+                }
+            }
+        }
+        groups.push(BandGroup {
+            spans: band,
+            has_columns: band_has_columns,
+        });
+    }
+    groups
+}
+
+/// Whether a band has a column gutter, ignoring the row-count requirement
+/// that [`split_columns`] applies to whole regions.
+fn has_gutter(spans: &[OcrSpan], metrics: LayoutMetrics) -> bool {
+    let mut extents = spans
+        .iter()
+        .map(|span| (span.bbox.x, span.bbox.right()))
+        .collect::<Vec<_>>();
+    extents.sort_unstable();
+    let mut right = match extents.first() {
+        Some(&(_, right)) => right,
+        None => return false,
+    };
+    for &(left, span_right) in &extents[1..] {
+        if f64::from(left) - f64::from(right) >= metrics.gutter {
+            return true;
+        }
+        right = right.max(span_right);
+    }
+    false
+}
+
+fn build_block(spans: Vec<OcrSpan>, metrics: LayoutMetrics) -> OcrBlock {
+    let lines = cluster_lines(spans);
+    let mut paragraphs = Vec::new();
+    let mut current: Vec<OcrLine> = Vec::new();
+    for line in lines {
+        if let Some(previous) = current.last() {
+            let gap = f64::from(line.bbox.y) - f64::from(previous.bbox.bottom());
+            if gap > metrics.paragraph_gap {
+                paragraphs.push(OcrParagraph::from_lines(std::mem::take(&mut current)));
+            }
+        }
+        current.push(line);
+    }
+    if !current.is_empty() {
+        paragraphs.push(OcrParagraph::from_lines(current));
+    }
+    OcrBlock::from_paragraphs(paragraphs)
+}
+
+/// Clusters spans into lines by vertical overlap, so words on slightly
+/// different baselines still read left to right.
+fn cluster_lines(mut spans: Vec<OcrSpan>) -> Vec<OcrLine> {
+    spans.sort_by(|a, b| a.bbox.center_y().total_cmp(&b.bbox.center_y()));
+
+    let mut clusters: Vec<(OcrRect, Vec<OcrSpan>)> = Vec::new();
+    for span in spans {
+        let best = clusters
+            .iter()
+            .enumerate()
+            .filter_map(|(index, (bbox, _))| {
+                line_overlap(*bbox, span.bbox).map(|ratio| (index, ratio))
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(index, _)| index);
+        match best {
+            Some(index) => {
+                let (bbox, members) = &mut clusters[index];
+                *bbox = bbox.union(span.bbox);
+                members.push(span);
+            }
+            None => clusters.push((span.bbox, vec![span])),
+        }
+    }
+
+    let mut lines = clusters
+        .into_iter()
+        .map(|(_, members)| OcrLine::from_spans(members))
+        .collect::<Vec<_>>();
+    lines.sort_by(|a, b| {
+        a.bbox
+            .center_y()
+            .total_cmp(&b.bbox.center_y())
+            .then(a.bbox.x.cmp(&b.bbox.x))
+    });
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(text: &str, x: i32, y: i32, width: u32) -> OcrSpan {
+        OcrSpan::new(text, OcrRect::new(x, y, width, 20), 0.9)
+    }
+
+    fn line_texts(document: &OcrDocument) -> Vec<String> {
+        document.lines().map(OcrLine::text).collect()
+    }
+
+    #[test]
+    fn empty_and_blank_spans_produce_empty_document() {
+        assert!(build_document(Vec::new()).is_empty());
+        assert!(build_document(vec![span("  ", 0, 0, 40)]).is_empty());
+    }
+
+    #[test]
+    fn words_on_slightly_different_baselines_read_left_to_right() {
+        let document = build_document(vec![
+            span("world", 120, 104, 80),
+            span("hello", 10, 100, 80),
+            span("again", 10, 130, 80),
+        ]);
+
+        assert_eq!(line_texts(&document), ["hello world", "again"]);
+    }
+
+    #[test]
+    fn two_columns_are_read_column_by_column() {
+        let document = build_document(vec![
+            span("right one", 300, 10, 200),
+            span("left one", 10, 10, 200),
+            span("right two", 300, 36, 200),
+            span("left two", 10, 36, 200),
+            span("left three", 10, 62, 200),
+        ]);
+
+        assert_eq!(
+            line_texts(&document),
+            [
+                "left one",
+                "left two",
+                "left three",
+                "right one",
+                "right two"
+            ]
+        );
+        assert_eq!(document.blocks.len(), 2);
+    }
+
+    #[test]
+    fn heading_spanning_columns_comes_before_both_columns() {
+        let document = build_document(vec![
+            span("right one", 300, 60, 200),
+            span("left one", 10, 60, 200),
+            span("Heading across the page", 10, 10, 490),
+            span("right two", 300, 86, 200),
+            span("left two", 10, 86, 200),
+        ]);
+
+        assert_eq!(
+            line_texts(&document),
+            [
+                "Heading across the page",
+                "left one",
+                "left two",
+                "right one",
+                "right two"
+            ]
+        );
+        assert_eq!(document.blocks.len(), 3);
+    }
+
+    #[test]
+    fn footer_below_columns_is_read_last() {
+        let document = build_document(vec![
+            span("footer", 10, 200, 490),
+            span("left one", 10, 10, 200),
+            span("right one", 300, 10, 200),
+            span("left two", 10, 36, 200),
+            span("right two", 300, 36, 200),
+        ]);
+
+        assert_eq!(
+            line_texts(&document),
+            ["left one", "left two", "right one", "right two", "footer"]
+        );
+    }
+
+    #[test]
+    fn single_row_with_wide_gap_stays_one_line() {
+        let document = build_document(vec![span("Value", 400, 0, 60), span("Name", 0, 2, 60)]);
+
+        assert_eq!(line_texts(&document), ["Name Value"]);
+    }
+
+    #[test]
+    fn large_vertical_gap_starts_new_paragraph() {
+        let document = build_document(vec![
+            span("one", 0, 0, 100),
+            span("two", 0, 25, 100),
+            span("three", 0, 90, 100),
+            span("four", 0, 115, 100),
+        ]);
+
+        let paragraphs = document
+            .paragraphs()
+            .map(|paragraph| {
+                paragraph
+                    .lines
+                    .iter()
+                    .map(OcrLine::text)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(paragraphs, [vec!["one", "two"], vec!["three", "four"]]);
+    }
+
+    #[test]
+    fn block_bbox_covers_all_of_its_lines() {
+        let document = build_document(vec![span("a", 5, 0, 50), span("b", 0, 24, 80)]);
+
+        assert_eq!(document.blocks[0].bbox, OcrRect::new(0, 0, 80, 44));
+    }
+}
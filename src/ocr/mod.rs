@@ -1,10 +1,29 @@
+pub mod cli;
+mod document;
+mod engine;
+pub mod export;
+mod layout;
+pub mod models;
+mod pdf;
+mod redact;
+pub mod render;
+mod selection;
+mod table;
+
 use std::path::{Path, PathBuf};
 
 use image::DynamicImage;
 
-pub use ocr_rs::OcrEngine;
+pub use document::{OcrBlock, OcrDocument, OcrLine, OcrParagraph, OcrRect, OcrSpan};
+pub use engine::OcrEngine;
+pub use export::{parse_export_formats, OcrExportFormat};
+pub use models::installed_languages;
+pub(crate) use redact::resolve_redaction_rules;
+pub use redact::{find_redactions, RedactionKind, RedactionMatch, RedactionRules};
+pub use selection::{OcrTextLayer, OcrTextPosition, OcrTextSelection};
+pub use table::{infer_table, OcrTable, TableFormat};
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, thiserror::Error)]
 pub enum OcrError {
     #[error("engine initialization failed: {message}")]
     EngineInit { message: String },
@@ -14,10 +33,46 @@ pub enum OcrError {
     Recognition { message: String },
     #[error("invalid region: {message}")]
     InvalidRegion { message: String },
+    /// The progress callback asked to stop.
+    #[error("recognition cancelled")]
+    Cancelled,
 }
 
 pub type OcrResult<T> = Result<T, OcrError>;
 
+/// Share of a run spent in detection, for [`OcrStage::fraction`].
+const DETECTION_SHARE: f64 = 0.2;
+
+/// Point reached by a recognition run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OcrStage {
+    /// Looking for text boxes.
+    Detecting,
+    /// `done` of `total` box readings are finished; every box is read once
+    /// per candidate language.
+    Recognizing { done: usize, total: usize },
+}
+
+impl OcrStage {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Detecting => "Detecting text",
+            Self::Recognizing { .. } => "Recognizing text",
+        }
+    }
+
+    /// Estimated share of the run completed, from 0.0 to 1.0.
+    pub fn fraction(self) -> f64 {
+        match self {
+            Self::Detecting => 0.0,
+            Self::Recognizing { total: 0, .. } => 1.0,
+            Self::Recognizing { done, total } => {
+                DETECTION_SHARE + (1.0 - DETECTION_SHARE) * (done.min(total) as f64 / total as f64)
+            }
+        }
+    }
+}
+
 const SYSTEM_MODEL_DIR: &str = "/usr/share/chalkak/models";
 
 /// Supported OCR language identifiers.
@@ -40,6 +95,20 @@ pub enum OcrLanguage {
 }
 
 impl OcrLanguage {
+    pub const ALL: [Self; 11] = [
+        Self::Korean,
+        Self::English,
+        Self::Chinese,
+        Self::Latin,
+        Self::Cyrillic,
+        Self::Arabic,
+        Self::Thai,
+        Self::Greek,
+        Self::Devanagari,
+        Self::Tamil,
+        Self::Telugu,
+    ];
+
     /// Recognition-model filename inside the model directory.
     fn rec_model_filename(self) -> &'static str {
         match self {
@@ -128,6 +197,59 @@ pub fn parse_ocr_language(value: &str) -> Option<OcrLanguage> {
     }
 }
 
+/// Parse a comma-separated list such as `ko,en`, keeping the given order.
+/// Fails with the first unknown name.
+pub fn parse_ocr_languages(value: &str) -> Result<Vec<OcrLanguage>, String> {
+    let languages = value
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| parse_ocr_language(name).ok_or_else(|| name.to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+    if languages.is_empty() {
+        return Err(value.to_string());
+    }
+    Ok(dedup_languages(&languages))
+}
+
+/// Drops repeated languages, keeping the first occurrence.
+pub(crate) fn dedup_languages(languages: &[OcrLanguage]) -> Vec<OcrLanguage> {
+    let mut unique = Vec::with_capacity(languages.len());
+    for &language in languages {
+        if !unique.contains(&language) {
+            unique.push(language);
+        }
+    }
+    unique
+}
+
+/// Display form of a candidate list, e.g. `Korean, English`.
+pub fn format_ocr_languages(languages: &[OcrLanguage]) -> String {
+    languages
+        .iter()
+        .map(|language| language.display_name())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Adds `language` at the end of the candidate list, or removes it when it
+/// is already listed. The last remaining language is never removed.
+pub fn toggle_ocr_language(languages: &[OcrLanguage], language: OcrLanguage) -> Vec<OcrLanguage> {
+    if !languages.contains(&language) {
+        let mut toggled = languages.to_vec();
+        toggled.push(language);
+        return toggled;
+    }
+    if languages.len() == 1 {
+        return languages.to_vec();
+    }
+    languages
+        .iter()
+        .copied()
+        .filter(|&listed| listed != language)
+        .collect()
+}
+
 /// Detect the OCR language from the system `LANG` environment variable.
 pub fn detect_system_ocr_language() -> OcrLanguage {
     let lang = std::env::var("LANG").unwrap_or_default();
@@ -153,8 +275,30 @@ pub fn resolve_ocr_language(config_value: Option<&str>) -> OcrLanguage {
         .unwrap_or_else(detect_system_ocr_language)
 }
 
-pub fn resolve_model_dir() -> Option<PathBuf> {
-    let user_dir = std::env::var("XDG_DATA_HOME")
+/// Resolve the ordered candidate languages from `ocr_language` config
+/// names. Unknown names are skipped with a warning; when nothing valid
+/// remains, the system language is used.
+pub fn resolve_ocr_languages(config_names: &[&str]) -> Vec<OcrLanguage> {
+    let languages: Vec<OcrLanguage> = config_names
+        .iter()
+        .filter_map(|name| {
+            let language = parse_ocr_language(name);
+            if language.is_none() {
+                tracing::warn!(name, "unknown ocr_language in config.json; skipping");
+            }
+            language
+        })
+        .collect();
+    if languages.is_empty() {
+        return vec![detect_system_ocr_language()];
+    }
+    dedup_languages(&languages)
+}
+
+/// Directory `chalkak models install` writes to:
+/// `$XDG_DATA_HOME/chalkak/models`, or `~/.local/share/chalkak/models`.
+pub fn user_model_dir() -> Option<PathBuf> {
+    std::env::var("XDG_DATA_HOME")
         .ok()
         .filter(|val| !val.is_empty())
         .map(PathBuf::from)
@@ -163,72 +307,71 @@ pub fn resolve_model_dir() -> Option<PathBuf> {
                 .ok()
                 .map(|home| PathBuf::from(home).join(".local/share"))
         })
-        .map(|base| base.join("chalkak/models"));
+        .map(|base| base.join("chalkak/models"))
+}
 
-    if let Some(ref dir) = user_dir {
-        if dir.is_dir() {
-            return Some(dir.clone());
-        }
-    }
+/// Model directories in lookup order, whether or not they exist: the user
+/// directory first, then the system one.
+pub fn model_search_paths() -> Vec<PathBuf> {
+    user_model_dir()
+        .into_iter()
+        .chain(std::iter::once(PathBuf::from(SYSTEM_MODEL_DIR)))
+        .collect()
+}
 
-    let system_dir = PathBuf::from(SYSTEM_MODEL_DIR);
-    if system_dir.is_dir() {
-        return Some(system_dir);
-    }
+/// Existing model directories in lookup order. Each model file is taken from
+/// the first directory that has it, so user-installed languages add to the
+/// system models instead of hiding them.
+pub fn resolve_model_dirs() -> Vec<PathBuf> {
+    model_search_paths()
+        .into_iter()
+        .filter(|dir| dir.is_dir())
+        .collect()
+}
 
-    None
+pub fn resolve_model_dir() -> Option<PathBuf> {
+    resolve_model_dirs().into_iter().next()
 }
 
-pub fn create_engine(model_dir: &Path, language: OcrLanguage) -> OcrResult<OcrEngine> {
-    let det_path = model_dir.join("PP-OCRv5_mobile_det.mnn");
-    let rec_path = model_dir.join(language.rec_model_filename());
-    let keys_path = model_dir.join(language.keys_filename());
-
-    OcrEngine::new(
-        det_path.to_str().unwrap_or_default(),
-        rec_path.to_str().unwrap_or_default(),
-        keys_path.to_str().unwrap_or_default(),
-        None,
-    )
-    .map_err(|err| OcrError::EngineInit {
-        message: err.to_string(),
-    })
+pub fn create_engine(model_dirs: &[PathBuf], languages: &[OcrLanguage]) -> OcrResult<OcrEngine> {
+    OcrEngine::new(model_dirs, languages)
 }
 
-pub fn recognize_text(engine: &OcrEngine, image: &DynamicImage) -> OcrResult<String> {
-    let results = engine
-        .recognize(image)
-        .map_err(|err| OcrError::Recognition {
-            message: err.to_string(),
-        })?;
-
-    if results.is_empty() {
-        return Ok(String::new());
-    }
+/// Runs detection and recognition and reconstructs the reading order of the
+/// recognized boxes.
+pub fn recognize_document(engine: &OcrEngine, image: &DynamicImage) -> OcrResult<OcrDocument> {
+    recognize_document_with(engine, image, &mut |_| true)
+}
 
-    let mut lines: Vec<_> = results
-        .into_iter()
-        .map(|r| {
-            let y = r.bbox.rect.top();
-            (y, r.text)
-        })
-        .collect();
-    lines.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+/// Like [`recognize_document`], calling `on_stage` before detection and after
+/// each recognition batch. Returning `false` stops the run with
+/// [`OcrError::Cancelled`].
+pub fn recognize_document_with(
+    engine: &OcrEngine,
+    image: &DynamicImage,
+    on_stage: &mut dyn FnMut(OcrStage) -> bool,
+) -> OcrResult<OcrDocument> {
+    engine
+        .recognize_spans(image, on_stage)
+        .map(OcrDocument::from_spans)
+}
 
-    let text = lines
-        .into_iter()
-        .map(|(_, text)| text)
-        .collect::<Vec<_>>()
-        .join("\n");
+pub fn recognize_text(engine: &OcrEngine, image: &DynamicImage) -> OcrResult<String> {
+    recognize_document(engine, image).map(|document| render::plain_text(&document))
+}
+
+pub fn open_image(path: &Path) -> OcrResult<DynamicImage> {
+    image::open(path).map_err(|err| OcrError::ImageConversion {
+        message: format!("failed to open image {}: {err}", path.display()),
+    })
+}
 
-    Ok(text)
+pub fn recognize_document_from_file(engine: &OcrEngine, path: &Path) -> OcrResult<OcrDocument> {
+    recognize_document(engine, &open_image(path)?)
 }
 
 pub fn recognize_text_from_file(engine: &OcrEngine, path: &Path) -> OcrResult<String> {
-    let image = image::open(path).map_err(|err| OcrError::ImageConversion {
-        message: format!("failed to open image {}: {err}", path.display()),
-    })?;
-    recognize_text(engine, &image)
+    recognize_document_from_file(engine, path).map(|document| render::plain_text(&document))
 }
 
 #[cfg(test)]
@@ -272,6 +415,83 @@ mod tests {
         assert_eq!(parse_ocr_language(""), None);
     }
 
+    #[test]
+    fn parse_ocr_languages_keeps_order_and_drops_repeats() {
+        assert_eq!(
+            parse_ocr_languages("ko, en,ko"),
+            Ok(vec![OcrLanguage::Korean, OcrLanguage::English])
+        );
+        assert_eq!(parse_ocr_languages("ru"), Ok(vec![OcrLanguage::Cyrillic]));
+        assert_eq!(
+            parse_ocr_languages("en,klingon"),
+            Err("klingon".to_string())
+        );
+        assert_eq!(parse_ocr_languages(" , "), Err(" , ".to_string()));
+    }
+
+    #[test]
+    fn resolve_ocr_languages_skips_unknown_names() {
+        assert_eq!(
+            resolve_ocr_languages(&["en", "klingon", "ru", "en"]),
+            vec![OcrLanguage::English, OcrLanguage::Cyrillic]
+        );
+    }
+
+    #[test]
+    fn resolve_ocr_languages_falls_back_to_one_system_language() {
+        // Only the count is checked: other tests change `LANG` concurrently.
+        assert_eq!(resolve_ocr_languages(&["klingon"]).len(), 1);
+        assert_eq!(resolve_ocr_languages(&[]).len(), 1);
+    }
+
+    #[test]
+    fn format_ocr_languages_joins_display_names() {
+        assert_eq!(
+            format_ocr_languages(&[OcrLanguage::Korean, OcrLanguage::English]),
+            "Korean, English"
+        );
+    }
+
+    #[test]
+    fn toggle_ocr_language_appends_removes_and_keeps_last() {
+        use OcrLanguage::{Chinese, English, Korean};
+
+        assert_eq!(
+            toggle_ocr_language(&[Korean], English),
+            vec![Korean, English]
+        );
+        assert_eq!(
+            toggle_ocr_language(&[Korean, English, Chinese], English),
+            vec![Korean, Chinese]
+        );
+        assert_eq!(toggle_ocr_language(&[Korean], Korean), vec![Korean]);
+    }
+
+    #[test]
+    fn ocr_stage_fraction_covers_detection_then_readings() {
+        assert_eq!(OcrStage::Detecting.fraction(), 0.0);
+        assert_eq!(OcrStage::Recognizing { done: 0, total: 40 }.fraction(), 0.2);
+        assert!(
+            (OcrStage::Recognizing {
+                done: 20,
+                total: 40
+            }
+            .fraction()
+                - 0.6)
+                .abs()
+                < 1e-9
+        );
+        assert_eq!(
+            OcrStage::Recognizing {
+                done: 50,
+                total: 40
+            }
+            .fraction(),
+            1.0
+        );
+        assert_eq!(OcrStage::Recognizing { done: 0, total: 0 }.fraction(), 1.0);
+    }
+
     #[test]
     fn detect_system_ocr_language_uses_lang_env() {
         std::env::set_var("LANG", "ko_KR.UTF-8");
@@ -0,0 +1,272 @@
+//! `chalkak models`: inspect, verify and install OCR model files. Runs
+//! without GTK.
+
+use std::path::{Path, PathBuf};
+
+use super::{FileCheck, ModelDirInventory};
+use crate::ocr::OcrLanguage;
+
+pub const EXIT_OK: i32 = 0;
+/// Invalid arguments.
+pub const EXIT_USAGE: i32 = 2;
+/// No model directory exists.
+pub const EXIT_MODELS_UNAVAILABLE: i32 = 3;
+/// Verification found a damaged or missing file, or installation failed.
+pub const EXIT_VERIFY_FAILED: i32 = 4;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModelsCliCommand {
+    Help,
+    List,
+    Verify,
+    Install(PathBuf),
+}
+
+/// Parses the arguments following `models`.
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<ModelsCliCommand, String> {
+    let args: Vec<String> = args.into_iter().collect();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        return Ok(ModelsCliCommand::Help);
+    }
+    let Some((command, rest)) = args.split_first() else {
+        return Err("missing command (expected list, verify or install)".to_string());
+    };
+    match (command.as_str(), rest) {
+        ("list", []) => Ok(ModelsCliCommand::List),
+        ("verify", []) => Ok(ModelsCliCommand::Verify),
+        ("install", [archive]) => Ok(ModelsCliCommand::Install(PathBuf::from(archive))),
+        ("install", []) => Err("install requires an archive path".to_string()),
+        ("list" | "verify", [extra, ..]) | ("install", [_, extra, ..]) => {
+            Err(format!("unexpected argument: {extra}"))
+        }
+        (other, _) => Err(format!("unknown command: {other}")),
+    }
+}
+
+/// Entry point for `chalkak models ARGS...`; returns the process exit code.
+pub fn run(args: impl IntoIterator<Item = String>) -> i32 {
+    match parse_args(args) {
+        Ok(ModelsCliCommand::Help) => {
+            print_help();
+            EXIT_OK
+        }
+        Ok(ModelsCliCommand::List) => run_list(),
+        Ok(ModelsCliCommand::Verify) => run_verify(),
+        Ok(ModelsCliCommand::Install(archive)) => run_install(&archive),
+        Err(message) => {
+            eprintln!("chalkak models: {message}");
+            eprintln!("Try 'chalkak models --help' for more information.");
+            EXIT_USAGE
+        }
+    }
+}
+
+fn run_list() -> i32 {
+    let search_paths = crate::ocr::model_search_paths();
+    for dir in &search_paths {
+        print!("{}", format_inventory(&super::inventory(dir)));
+    }
+    let existing = crate::ocr::resolve_model_dirs();
+    let installed = super::installed_languages(&existing);
+    if installed.is_empty() {
+        println!("\nNo OCR language is installed.");
+    } else {
+        println!(
+            "\nAvailable languages: {}",
+            crate::ocr::format_ocr_languages(&installed)
+        );
+    }
+    if existing.is_empty() {
+        EXIT_MODELS_UNAVAILABLE
+    } else {
+        EXIT_OK
+    }
+}
+
+fn format_inventory(inventory: &ModelDirInventory) -> String {
+    let dir = inventory.dir.display();
+    if !inventory.exists {
+        return format!("{dir} (not found)\n");
+    }
+    let presence = |present: bool| if present { "installed" } else { "missing" };
+    let mut out = format!("{dir}\n");
+    out.push_str(&format!(
+        "  {:<22} {}\n",
+        "detector",
+        presence(inventory.detector)
+    ));
+    for (language, installed) in &inventory.languages {
+        out.push_str(&format!(
+            "  {:<22} {}\n",
+            language_label(*language),
+            presence(*installed)
+        ));
+    }
+    out.push_str(&format!(
+        "  {:<22} {}\n",
+        super::MANIFEST_FILENAME,
+        presence(inventory.has_manifest)
+    ));
+    out
+}
+
+fn language_label(language: OcrLanguage) -> String {
+    format!("{} ({})", language.display_name(), language.as_str())
+}
+
+fn run_verify() -> i32 {
+    let existing = crate::ocr::resolve_model_dirs();
+    if existing.is_empty() {
+        eprintln!("chalkak models: no model directory found");
+        return EXIT_MODELS_UNAVAILABLE;
+    }
+    let mut exit_code = EXIT_OK;
+    for dir in &existing {
+        println!("{}", dir.display());
+        match super::verify_model_dir(dir) {
+            Ok(results) => {
+                for result in &results {
+                    println!("  {:<9} {}", check_label(result.check), result.file);
+                    if result.check.is_failure() {
+                        exit_code = EXIT_VERIFY_FAILED;
+                    }
+                }
+            }
+            Err(err) => {
+                println!("  cannot verify: {err}");
+                exit_code = EXIT_VERIFY_FAILED;
+            }
+        }
+    }
+    exit_code
+}
+
+fn check_label(check: FileCheck) -> &'static str {
+    match check {
+        FileCheck::Ok => "OK",
+        FileCheck::Mismatch => "MISMATCH",
+        FileCheck::Missing => "MISSING",
+        FileCheck::Unlisted => "UNLISTED",
+    }
+}
+
+fn run_install(archive: &Path) -> i32 {
+    let Some(target) = crate::ocr::user_model_dir() else {
+        eprintln!("chalkak models: cannot determine the user data directory; set XDG_DATA_HOME");
+        return EXIT_VERIFY_FAILED;
+    };
+    match super::install_archive(archive, &target) {
+        Ok(report) => {
+            println!(
+                "Installed {} file(s) into {}",
+                report.files.len(),
+                target.display()
+            );
+            if !report.languages.is_empty() {
+                println!(
+                    "Languages: {}",
+                    crate::ocr::format_ocr_languages(&report.languages)
+                );
+            }
+            EXIT_OK
+        }
+        Err(err) => {
+            eprintln!("chalkak models: {err}");
+            EXIT_VERIFY_FAILED
+        }
+    }
+}
+
+fn print_help() {
+    println!(
+        "\
+Inspect, verify and install OCR model files
+
+Usage: chalkak models <COMMAND>
+
+Commands:
+  list               Show which languages each model directory provides
+  verify             Check model files against each directory's SHA256SUMS
+  install <ARCHIVE>  Install models from a local .tar or .tar.gz archive into
+                     $XDG_DATA_HOME/chalkak/models
+
+Model directories are searched in order: $XDG_DATA_HOME/chalkak/models
+(~/.local/share/chalkak/models), then /usr/share/chalkak/models. Each file is
+taken from the first directory that has it.
+
+Exit status:
+  0  success
+  2  invalid arguments
+  3  no model directory found
+  4  verification failed or the archive could not be installed"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<ModelsCliCommand, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parse_args_accepts_each_command() {
+        assert_eq!(parse(&["list"]), Ok(ModelsCliCommand::List));
+        assert_eq!(parse(&["verify"]), Ok(ModelsCliCommand::Verify));
+        assert_eq!(
+            parse(&["install", "models.tar.gz"]),
+            Ok(ModelsCliCommand::Install(PathBuf::from("models.tar.gz")))
+        );
+        assert_eq!(parse(&["install", "--help"]), Ok(ModelsCliCommand::Help));
+    }
+
+    #[test]
+    fn parse_args_rejects_missing_and_extra_arguments() {
+        assert_eq!(
+            parse(&[]),
+            Err("missing command (expected list, verify or install)".to_string())
+        );
+        assert_eq!(
+            parse(&["install"]),
+            Err("install requires an archive path".to_string())
+        );
+        assert_eq!(
+            parse(&["list", "extra"]),
+            Err("unexpected argument: extra".to_string())
+        );
+        assert_eq!(
+            parse(&["install", "a.tar", "b.tar"]),
+            Err("unexpected argument: b.tar".to_string())
+        );
+        assert_eq!(
+            parse(&["remove"]),
+            Err("unknown command: remove".to_string())
+        );
+    }
+
+    #[test]
+    fn format_inventory_flags_missing_languages() {
+        let inventory = ModelDirInventory {
+            dir: PathBuf::from("/models"),
+            exists: true,
+            detector: true,
+            languages: vec![(OcrLanguage::Korean, true), (OcrLanguage::English, false)],
+            has_manifest: false,
+        };
+
+        let text = format_inventory(&inventory);
+
+        assert!(text.starts_with("/models\n"));
+        assert!(text.contains("  Korean (korean)        installed\n"));
+        assert!(text.contains("  English (en)           missing\n"));
+        assert!(text.contains("  SHA256SUMS             missing\n"));
+        assert_eq!(
+            format_inventory(&ModelDirInventory {
+                exists: false,
+                ..inventory
+            }),
+            "/models (not found)\n"
+        );
+    }
+}
@@ -0,0 +1,509 @@
+//! OCR model files on disk: which languages each model directory provides,
+//! checksum verification against a `SHA256SUMS` manifest, and installing
+//! models from a local archive.
+
+pub mod cli;
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+use super::OcrLanguage;
+
+/// Text detector shared by every language.
+pub const DET_MODEL_FILENAME: &str = "PP-OCRv5_mobile_det.mnn";
+/// Checksum manifest in `sha256sum` format, kept next to the models.
+pub const MANIFEST_FILENAME: &str = "SHA256SUMS";
+
+const GZIP_MAGIC: [u8; 2] = [0x1F, 0x8B];
+
+#[derive(Debug, thiserror::Error)]
+pub enum ModelsError {
+    #[error("{path}: {message}")]
+    Io { path: String, message: String },
+    #[error("invalid manifest {path}: {message}")]
+    Manifest { path: String, message: String },
+    #[error("invalid archive: {message}")]
+    Archive { message: String },
+    #[error("checksum mismatch for {file}")]
+    Checksum { file: String },
+}
+
+pub type ModelsResult<T> = Result<T, ModelsError>;
+
+fn io_error(path: &Path, err: io::Error) -> ModelsError {
+    ModelsError::Io {
+        path: path.display().to_string(),
+        message: err.to_string(),
+    }
+}
+
+/// Every file name a complete model set contains.
+pub fn expected_model_files() -> Vec<&'static str> {
+    std::iter::once(DET_MODEL_FILENAME)
+        .chain(
+            OcrLanguage::ALL
+                .into_iter()
+                .flat_map(|language| [language.rec_model_filename(), language.keys_filename()]),
+        )
+        .collect()
+}
+
+/// The first of `model_dirs` that holds `filename`.
+pub(crate) fn find_model_file(model_dirs: &[PathBuf], filename: &str) -> Option<PathBuf> {
+    model_dirs
+        .iter()
+        .map(|dir| dir.join(filename))
+        .find(|path| path.is_file())
+}
+
+/// Languages whose recognizer and character set are both present somewhere
+/// in `model_dirs`, in [`OcrLanguage::ALL`] order.
+pub fn installed_languages(model_dirs: &[PathBuf]) -> Vec<OcrLanguage> {
+    OcrLanguage::ALL
+        .into_iter()
+        .filter(|language| {
+            find_model_file(model_dirs, language.rec_model_filename()).is_some()
+                && find_model_file(model_dirs, language.keys_filename()).is_some()
+        })
+        .collect()
+}
+
+/// Drops the entries of `languages` that are not in `installed`, unless that
+/// would leave nothing, in which case the list is kept so the missing model
+/// is reported when OCR runs.
+pub fn retain_installed(languages: &[OcrLanguage], installed: &[OcrLanguage]) -> Vec<OcrLanguage> {
+    let retained: Vec<OcrLanguage> = languages
+        .iter()
+        .copied()
+        .filter(|language| installed.contains(language))
+        .collect();
+    if retained.is_empty() {
+        languages.to_vec()
+    } else {
+        retained
+    }
+}
+
+/// What one model directory holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelDirInventory {
+    pub dir: PathBuf,
+    pub exists: bool,
+    pub detector: bool,
+    /// Every language with whether both of its files are in this directory.
+    pub languages: Vec<(OcrLanguage, bool)>,
+    pub has_manifest: bool,
+}
+
+pub fn inventory(dir: &Path) -> ModelDirInventory {
+    let has = |filename: &str| dir.join(filename).is_file();
+    ModelDirInventory {
+        dir: dir.to_path_buf(),
+        exists: dir.is_dir(),
+        detector: has(DET_MODEL_FILENAME),
+        languages: OcrLanguage::ALL
+            .into_iter()
+            .map(|language| {
+                let installed = has(language.rec_model_filename()) && has(language.keys_filename());
+                (language, installed)
+            })
+            .collect(),
+        has_manifest: has(MANIFEST_FILENAME),
+    }
+}
+
+/// Parses `sha256sum` output (`<hex>  <name>` or `<hex> *<name>`) into a map
+/// from file name to lowercase digest. Blank lines and `#` comments are
+/// skipped; names must be plain file names.
+pub fn parse_manifest(text: &str) -> Result<BTreeMap<String, String>, String> {
+    let mut entries = BTreeMap::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line_number = index + 1;
+        let (digest, name) = line
+            .split_once(char::is_whitespace)
+            .ok_or_else(|| format!("line {line_number}: expected '<sha256>  <file>'"))?;
+        let name = name.trim_start();
+        let name = name.strip_prefix('*').unwrap_or(name);
+        if digest.len() != 64 || !digest.chars().all(|ch| ch.is_ascii_hexdigit()) {
+            return Err(format!("line {line_number}: invalid SHA-256 digest"));
+        }
+        if name.is_empty() || name.contains('/') || name == "." || name == ".." {
+            return Err(format!("line {line_number}: invalid file name {name:?}"));
+        }
+        entries.insert(name.to_string(), digest.to_ascii_lowercase());
+    }
+    Ok(entries)
+}
+
+fn format_manifest(entries: &BTreeMap<String, String>) -> String {
+    entries
+        .iter()
+        .map(|(name, digest)| format!("{digest}  {name}\n"))
+        .collect()
+}
+
+fn read_manifest(path: &Path) -> ModelsResult<BTreeMap<String, String>> {
+    let text = fs::read_to_string(path).map_err(|err| io_error(path, err))?;
+    parse_manifest(&text).map_err(|message| ModelsError::Manifest {
+        path: path.display().to_string(),
+        message,
+    })
+}
+
+/// Lowercase hex SHA-256 of the file at `path`.
+pub fn sha256_file(path: &Path) -> ModelsResult<String> {
+    let mut file = File::open(path).map_err(|err| io_error(path, err))?;
+    let mut hasher = Sha256::new();
+    io::copy(&mut file, &mut hasher).map_err(|err| io_error(path, err))?;
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileCheck {
+    Ok,
+    Mismatch,
+    /// Listed in the manifest but absent.
+    Missing,
+    /// A model file the manifest does not mention, so it cannot be checked.
+    Unlisted,
+}
+
+impl FileCheck {
+    pub fn is_failure(self) -> bool {
+        matches!(self, Self::Mismatch | Self::Missing)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileVerification {
+    pub file: String,
+    pub check: FileCheck,
+}
+
+/// Checks the files in `dir` against its `SHA256SUMS`: listed files first,
+/// in manifest order, then model files the manifest does not list.
+pub fn verify_model_dir(dir: &Path) -> ModelsResult<Vec<FileVerification>> {
+    let manifest = read_manifest(&dir.join(MANIFEST_FILENAME))?;
+    let mut results = Vec::with_capacity(manifest.len());
+    for (file, expected) in &manifest {
+        let path = dir.join(file);
+        let check = if !path.is_file() {
+            FileCheck::Missing
+        } else if sha256_file(&path)? == *expected {
+            FileCheck::Ok
+        } else {
+            FileCheck::Mismatch
+        };
+        results.push(FileVerification {
+            file: file.clone(),
+            check,
+        });
+    }
+    for file in expected_model_files() {
+        if !manifest.contains_key(file) && dir.join(file).is_file() {
+            results.push(FileVerification {
+                file: file.to_string(),
+                check: FileCheck::Unlisted,
+            });
+        }
+    }
+    Ok(results)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstallReport {
+    pub files: Vec<String>,
+    /// Languages whose recognizer and character set were both installed.
+    pub languages: Vec<OcrLanguage>,
+}
+
+/// Installs the model files from a `.tar` or `.tar.gz` archive into
+/// `target`, replacing existing copies.
+///
+/// Entries are matched by file name alone, so the archive may keep them in a
+/// subdirectory; anything that is not a known model file is ignored. When
+/// the archive carries its own `SHA256SUMS`, every extracted file it lists
+/// must match. The checksums of the installed files are merged into the
+/// target manifest so `chalkak models verify` can check them later.
+pub fn install_archive(archive: &Path, target: &Path) -> ModelsResult<InstallReport> {
+    fs::create_dir_all(target).map_err(|err| io_error(target, err))?;
+    let staging = target.join(format!(".install-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&staging);
+    fs::create_dir_all(&staging).map_err(|err| io_error(&staging, err))?;
+    let result = install_staged(archive, &staging, target);
+    let _ = fs::remove_dir_all(&staging);
+    result
+}
+
+fn install_staged(archive: &Path, staging: &Path, target: &Path) -> ModelsResult<InstallReport> {
+    let files = extract_model_files(archive, staging)?;
+    if files.is_empty() {
+        return Err(ModelsError::Archive {
+            message: format!("{} contains no ChalKak model files", archive.display()),
+        });
+    }
+
+    let mut checksums = BTreeMap::new();
+    for file in &files {
+        checksums.insert(file.clone(), sha256_file(&staging.join(file))?);
+    }
+    let archive_manifest_path = staging.join(MANIFEST_FILENAME);
+    if archive_manifest_path.is_file() {
+        let archive_manifest = read_manifest(&archive_manifest_path)?;
+        for (file, digest) in &checksums {
+            if archive_manifest
+                .get(file)
+                .is_some_and(|expected| expected != digest)
+            {
+                return Err(ModelsError::Checksum { file: file.clone() });
+            }
+        }
+    }
+
+    let manifest_path = target.join(MANIFEST_FILENAME);
+    let mut manifest = if manifest_path.is_file() {
+        read_manifest(&manifest_path)?
+    } else {
+        BTreeMap::new()
+    };
+    for file in &files {
+        let destination = target.join(file);
+        fs::rename(staging.join(file), &destination).map_err(|err| io_error(&destination, err))?;
+    }
+    manifest.extend(checksums);
+    let manifest_tmp = staging.join("SHA256SUMS.new");
+    fs::write(&manifest_tmp, format_manifest(&manifest))
+        .map_err(|err| io_error(&manifest_tmp, err))?;
+    fs::rename(&manifest_tmp, &manifest_path).map_err(|err| io_error(&manifest_path, err))?;
+
+    let languages = OcrLanguage::ALL
+        .into_iter()
+        .filter(|language| {
+            files
+                .iter()
+                .any(|file| file == language.rec_model_filename())
+                && files.iter().any(|file| file == language.keys_filename())
+        })
+        .collect();
+    Ok(InstallReport { files, languages })
+}
+
+/// Unpacks known model files and the manifest into `staging`, returning the
+/// model file names in archive order.
+fn extract_model_files(archive: &Path, staging: &Path) -> ModelsResult<Vec<String>> {
+    let file = File::open(archive).map_err(|err| io_error(archive, err))?;
+    let mut reader = BufReader::new(file);
+    let is_gzip = reader
+        .fill_buf()
+        .map_err(|err| io_error(archive, err))?
+        .starts_with(&GZIP_MAGIC);
+    let reader: Box<dyn Read> = if is_gzip {
+        Box::new(flate2::read::GzDecoder::new(reader))
+    } else {
+        Box::new(reader)
+    };
+
+    let archive_error = |err: io::Error| ModelsError::Archive {
+        message: format!("{}: {err}", archive.display()),
+    };
+    let known = expected_model_files();
+    let mut files = Vec::new();
+    let mut tar = tar::Archive::new(reader);
+    for entry in tar.entries().map_err(archive_error)? {
+        let mut entry = entry.map_err(archive_error)?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let path = entry.path().map_err(archive_error)?;
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if name != MANIFEST_FILENAME && !known.contains(&name) {
+            continue;
+        }
+        let name = name.to_string();
+        let destination = staging.join(&name);
+        let mut output = File::create(&destination).map_err(|err| io_error(&destination, err))?;
+        io::copy(&mut entry, &mut output).map_err(archive_error)?;
+        if name != MANIFEST_FILENAME && !files.contains(&name) {
+            files.push(name);
+        }
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EMPTY_SHA256: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("chalkak-test-models-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn write_archive(path: &Path, entries: &[(&str, &[u8])]) {
+        let encoder = flate2::write::GzEncoder::new(
+            File::create(path).unwrap(),
+            flate2::Compression::default(),
+        );
+        let mut builder = tar::Builder::new(encoder);
+        for (name, contents) in entries {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(contents.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, name, *contents).unwrap();
+        }
+        builder.into_inner().unwrap().finish().unwrap();
+    }
+
+    #[test]
+    fn parse_manifest_reads_sha256sum_output() {
+        let text = format!(
+            "# models\n{}  ppocr_keys_en.txt\n\n{} *{DET_MODEL_FILENAME}\n",
+            EMPTY_SHA256.to_ascii_uppercase(),
+            EMPTY_SHA256
+        );
+
+        let manifest = parse_manifest(&text).unwrap();
+
+        assert_eq!(manifest.len(), 2);
+        assert_eq!(manifest["ppocr_keys_en.txt"], EMPTY_SHA256);
+        assert_eq!(manifest[DET_MODEL_FILENAME], EMPTY_SHA256);
+    }
+
+    #[test]
+    fn parse_manifest_rejects_bad_digests_and_paths() {
+        assert!(parse_manifest("abc  file.txt").is_err());
+        assert!(parse_manifest(&format!("{EMPTY_SHA256}  ../file.txt")).is_err());
+        assert!(parse_manifest(EMPTY_SHA256).is_err());
+    }
+
+    #[test]
+    fn installed_languages_needs_both_files_across_dirs() {
+        let user = temp_dir("installed-user");
+        let system = temp_dir("installed-system");
+        let korean = OcrLanguage::Korean;
+        let english = OcrLanguage::English;
+        fs::write(user.join(korean.rec_model_filename()), b"").unwrap();
+        fs::write(system.join(korean.keys_filename()), b"").unwrap();
+        fs::write(system.join(english.rec_model_filename()), b"").unwrap();
+
+        let dirs = [user.clone(), system.clone()];
+        assert_eq!(installed_languages(&dirs), vec![korean]);
+        assert!(!inventory(&user).languages.contains(&(korean, true)));
+
+        let _ = fs::remove_dir_all(&user);
+        let _ = fs::remove_dir_all(&system);
+    }
+
+    #[test]
+    fn retain_installed_keeps_list_when_nothing_is_installed() {
+        use OcrLanguage::{English, Korean, Thai};
+
+        assert_eq!(
+            retain_installed(&[Thai, Korean, English], &[English, Korean]),
+            vec![Korean, English]
+        );
+        assert_eq!(retain_installed(&[Thai], &[English]), vec![Thai]);
+    }
+
+    #[test]
+    fn verify_model_dir_reports_each_file() {
+        let dir = temp_dir("verify");
+        fs::write(dir.join(DET_MODEL_FILENAME), b"").unwrap();
+        fs::write(dir.join("ppocr_keys_en.txt"), b"changed").unwrap();
+        fs::write(dir.join("ppocr_keys_korean.txt"), b"").unwrap();
+        fs::write(
+            dir.join(MANIFEST_FILENAME),
+            format!(
+                "{EMPTY_SHA256}  {DET_MODEL_FILENAME}\n{EMPTY_SHA256}  ppocr_keys_en.txt\n{EMPTY_SHA256}  ppocr_keys_th.txt\n"
+            ),
+        )
+        .unwrap();
+
+        let results = verify_model_dir(&dir).unwrap();
+        let check = |file: &str| {
+            results
+                .iter()
+                .find(|result| result.file == file)
+                .map(|result| result.check)
+        };
+
+        assert_eq!(check(DET_MODEL_FILENAME), Some(FileCheck::Ok));
+        assert_eq!(check("ppocr_keys_en.txt"), Some(FileCheck::Mismatch));
+        assert_eq!(check("ppocr_keys_th.txt"), Some(FileCheck::Missing));
+        assert_eq!(check("ppocr_keys_korean.txt"), Some(FileCheck::Unlisted));
+        assert!(!FileCheck::Unlisted.is_failure());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn install_archive_copies_known_files_and_records_checksums() {
+        let dir = temp_dir("install");
+        let archive = dir.join("models.tar.gz");
+        let target = dir.join("models");
+        let english = OcrLanguage::English;
+        write_archive(
+            &archive,
+            &[
+                ("models-v1/README", b"ignored"),
+                ("models-v1/en_PP-OCRv5_mobile_rec_infer.mnn", b"rec"),
+                ("models-v1/ppocr_keys_en.txt", b"keys"),
+                ("models-v1/ppocr_keys_korean.txt", b""),
+            ],
+        );
+
+        let report = install_archive(&archive, &target).unwrap();
+
+        assert_eq!(report.files.len(), 3);
+        assert_eq!(report.languages, vec![english]);
+        assert!(!target.join("README").exists());
+        assert_eq!(fs::read(target.join("ppocr_keys_en.txt")).unwrap(), b"keys");
+        let results = verify_model_dir(&target).unwrap();
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|result| result.check == FileCheck::Ok));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn install_archive_rejects_files_that_fail_the_archive_manifest() {
+        let dir = temp_dir("install-mismatch");
+        let archive = dir.join("models.tar.gz");
+        let target = dir.join("models");
+        let manifest = format!("{EMPTY_SHA256}  ppocr_keys_en.txt\n");
+        write_archive(
+            &archive,
+            &[
+                ("ppocr_keys_en.txt", b"tampered"),
+                (MANIFEST_FILENAME, manifest.as_bytes()),
+            ],
+        );
+
+        let err = install_archive(&archive, &target).unwrap_err();
+
+        assert!(matches!(err, ModelsError::Checksum { ref file } if file == "ppocr_keys_en.txt"));
+        assert!(!target.join("ppocr_keys_en.txt").exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,270 @@
+//! Single-page searchable PDF: the capture as the page image with the OCR
+//! text drawn invisibly (render mode 3) over it, so viewers can search and
+//! select it.
+//!
+//! The text uses a Type0 font with Identity-H encoding whose character codes
+//! are UTF-16 units and whose ToUnicode map is the identity, so any script
+//! round-trips without embedding a font program. Each span is stretched
+//! horizontally to cover its box.
+
+use std::fmt::Write as _;
+use std::io::Write as _;
+
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use image::DynamicImage;
+
+use super::document::OcrDocument;
+
+/// Pixels are mapped to points as if the capture were shown at 96 DPI.
+const POINTS_PER_PIXEL: f64 = 72.0 / 96.0;
+/// Advance width of every glyph, in thousandths of the font size.
+const GLYPH_WIDTH: f64 = 500.0;
+/// Share of the box height below the baseline.
+const DESCENT_RATIO: f64 = 0.2;
+
+const TO_UNICODE_CMAP: &str = "\
+/CIDInit /ProcSet findresource begin
+12 dict begin
+begincmap
+/CIDSystemInfo << /Registry (Adobe) /Ordering (UCS) /Supplement 0 >> def
+/CMapName /Adobe-Identity-UCS def
+/CMapType 2 def
+1 begincodespacerange
+<0000> <FFFF>
+endcodespacerange
+1 beginbfrange
+<0000> <FFFF> <0000>
+endbfrange
+endcmap
+CMapName currentdict /CMap defineresource pop
+end
+end
+";
+
+/// Encodes `image` and the text of `document` as a PDF file.
+pub fn searchable_pdf(document: &OcrDocument, image: &DynamicImage) -> Vec<u8> {
+    let rgb = image.to_rgb8();
+    let (width, height) = rgb.dimensions();
+    let page_width = f64::from(width) * POINTS_PER_PIXEL;
+    let page_height = f64::from(height) * POINTS_PER_PIXEL;
+
+    let mut writer = PdfWriter::default();
+    writer.object(1, b"<< /Type /Catalog /Pages 2 0 R >>".to_vec());
+    writer.object(2, b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_vec());
+    writer.object(
+        3,
+        format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+/Resources << /XObject << /Im0 5 0 R >> /Font << /F0 6 0 R >> >> /Contents 4 0 R >>",
+            number(page_width),
+            number(page_height)
+        )
+        .into_bytes(),
+    );
+    let content = page_content(document, page_width, page_height);
+    writer.stream(4, "", content.as_bytes());
+    writer.stream(
+        5,
+        &format!(
+            "/Type /XObject /Subtype /Image /Width {width} /Height {height} \
+/ColorSpace /DeviceRGB /BitsPerComponent 8 /Filter /FlateDecode"
+        ),
+        &deflate(rgb.as_raw()),
+    );
+    writer.object(
+        6,
+        b"<< /Type /Font /Subtype /Type0 /BaseFont /GlyphLessFont /Encoding /Identity-H \
+/DescendantFonts [7 0 R] /ToUnicode 9 0 R >>"
+            .to_vec(),
+    );
+    writer.object(
+        7,
+        format!(
+            "<< /Type /Font /Subtype /CIDFontType2 /BaseFont /GlyphLessFont \
+/CIDSystemInfo << /Registry (Adobe) /Ordering (Identity) /Supplement 0 >> \
+/FontDescriptor 8 0 R /DW {} /CIDToGIDMap /Identity >>",
+            GLYPH_WIDTH
+        )
+        .into_bytes(),
+    );
+    writer.object(
+        8,
+        b"<< /Type /FontDescriptor /FontName /GlyphLessFont /Flags 5 \
+/FontBBox [0 -200 500 800] /ItalicAngle 0 /Ascent 800 /Descent -200 /CapHeight 800 /StemV 80 >>"
+            .to_vec(),
+    );
+    writer.stream(9, "", TO_UNICODE_CMAP.as_bytes());
+    writer.object(
+        10,
+        format!("<< /Producer ({}) >>", super::export::PRODUCER).into_bytes(),
+    );
+    writer.finish(1, 10)
+}
+
+/// Draws the page image, then one invisible text run per span.
+fn page_content(document: &OcrDocument, page_width: f64, page_height: f64) -> String {
+    let mut content = format!(
+        "q\n{} 0 0 {} 0 0 cm\n/Im0 Do\nQ\nBT\n3 Tr\n",
+        number(page_width),
+        number(page_height)
+    );
+    for span in document.spans() {
+        let units = span.text.trim().encode_utf16().collect::<Vec<_>>();
+        if units.is_empty() || span.bbox.width == 0 || span.bbox.height == 0 {
+            continue;
+        }
+        let font_size = f64::from(span.bbox.height) * POINTS_PER_PIXEL;
+        let box_width = f64::from(span.bbox.width) * POINTS_PER_PIXEL;
+        let natural_width = units.len() as f64 * GLYPH_WIDTH / 1000.0 * font_size;
+        let x = f64::from(span.bbox.x) * POINTS_PER_PIXEL;
+        let baseline = page_height - f64::from(span.bbox.bottom()) * POINTS_PER_PIXEL
+            + font_size * DESCENT_RATIO;
+        let mut hex = String::with_capacity(units.len() * 4);
+        for unit in &units {
+            let _ = write!(hex, "{unit:04X}");
+        }
+        let _ = writeln!(
+            content,
+            "/F0 {} Tf\n{} Tz\n1 0 0 1 {} {} Tm\n<{hex}> Tj",
+            number(font_size),
+            number(100.0 * box_width / natural_width),
+            number(x),
+            number(baseline)
+        );
+    }
+    content.push_str("ET\n");
+    content
+}
+
+/// Decimal with at most three fractional digits and no exponent.
+fn number(value: f64) -> String {
+    let text = format!("{value:.3}");
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    if text == "-0" {
+        "0".to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+fn deflate(bytes: &[u8]) -> Vec<u8> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    // Writing into a Vec cannot fail.
+    let _ = encoder.write_all(bytes);
+    encoder.finish().unwrap_or_default()
+}
+
+/// Appends numbered objects and records their offsets for the xref table.
+#[derive(Default)]
+struct PdfWriter {
+    out: Vec<u8>,
+    offsets: Vec<(u32, usize)>,
+}
+
+impl PdfWriter {
+    fn start(&mut self) {
+        if self.out.is_empty() {
+            // The binary comment marks the file as binary for transfer tools.
+            self.out.extend_from_slice(b"%PDF-1.5\n%\xE2\xE3\xCF\xD3\n");
+        }
+    }
+
+    fn object(&mut self, id: u32, body: Vec<u8>) {
+        self.start();
+        self.offsets.push((id, self.out.len()));
+        self.out
+            .extend_from_slice(format!("{id} 0 obj\n").as_bytes());
+        self.out.extend_from_slice(&body);
+        self.out.extend_from_slice(b"\nendobj\n");
+    }
+
+    fn stream(&mut self, id: u32, dictionary: &str, data: &[u8]) {
+        let separator = if dictionary.is_empty() { "" } else { " " };
+        let mut body = format!(
+            "<< {dictionary}{separator}/Length {} >>\nstream\n",
+            data.len()
+        )
+        .into_bytes();
+        body.extend_from_slice(data);
+        body.extend_from_slice(b"\nendstream");
+        self.object(id, body);
+    }
+
+    fn finish(mut self, root: u32, info: u32) -> Vec<u8> {
+        self.offsets.sort_unstable();
+        let size = self.offsets.last().map_or(0, |(id, _)| id + 1);
+        let xref_offset = self.out.len();
+        let mut xref = format!("xref\n0 {size}\n0000000000 65535 f \n");
+        let mut entries = self.offsets.iter().peekable();
+        for id in 1..size {
+            match entries.next_if(|(entry_id, _)| *entry_id == id) {
+                Some((_, offset)) => {
+                    let _ = writeln!(xref, "{offset:010} 00000 n ");
+                }
+                None => xref.push_str("0000000000 65535 f \n"),
+            }
+        }
+        let _ = write!(
+            xref,
+            "trailer\n<< /Size {size} /Root {root} 0 R /Info {info} 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n"
+        );
+        self.out.extend_from_slice(xref.as_bytes());
+        self.out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::document::{OcrRect, OcrSpan};
+    use super::*;
+
+    fn pdf() -> Vec<u8> {
+        let document = OcrDocument::from_spans(vec![OcrSpan::new(
+            "Hi 한",
+            OcrRect::new(8, 16, 60, 20),
+            0.9,
+        )]);
+        searchable_pdf(&document, &DynamicImage::new_rgb8(96, 48))
+    }
+
+    #[test]
+    fn searchable_pdf_draws_invisible_utf16_text_inside_the_span_box() {
+        let pdf = pdf();
+        let text = String::from_utf8_lossy(&pdf);
+
+        assert!(pdf.starts_with(b"%PDF-1.5\n"));
+        assert!(pdf.ends_with(b"%%EOF\n"));
+        assert!(text.contains("/MediaBox [0 0 72 36]"));
+        assert!(text.contains("3 Tr\n"));
+        // 60 px box, 4 UTF-16 units of half an em at 15 pt: 45 / 30 = 150 %.
+        assert!(text.contains("/F0 15 Tf\n150 Tz\n1 0 0 1 6 12 Tm\n<004800690020D55C> Tj\n"));
+    }
+
+    #[test]
+    fn searchable_pdf_xref_points_at_each_object() {
+        let pdf = pdf();
+        let startxref = pdf
+            .windows(b"startxref\n".len())
+            .rposition(|window| window == b"startxref\n")
+            .unwrap();
+        let tail = std::str::from_utf8(&pdf[startxref..]).unwrap();
+        let xref_offset: usize = tail.lines().nth(1).unwrap().parse().unwrap();
+        let xref = std::str::from_utf8(&pdf[xref_offset..]).unwrap();
+
+        assert!(xref.starts_with("xref\n0 11\n"));
+        for (index, entry) in xref.lines().skip(3).take(10).enumerate() {
+            let offset: usize = entry[..10].parse().unwrap();
+            let header = format!("{} 0 obj\n", index + 1);
+            assert!(pdf[offset..].starts_with(header.as_bytes()));
+        }
+    }
+
+    #[test]
+    fn number_trims_trailing_zeros() {
+        assert_eq!(number(72.0), "72");
+        assert_eq!(number(12.5), "12.5");
+        assert_eq!(number(1.0 / 3.0), "0.333");
+        assert_eq!(number(-0.0001), "0");
+    }
+}
@@ -0,0 +1,175 @@
+use serde_json::{json, Value};
+
+use super::document::{OcrDocument, OcrRect};
+
+/// Column header for [`tsv`] rows.
+pub const TSV_HEADER: &str =
+    "source\tblock\tparagraph\tline\tspan\tleft\ttop\twidth\theight\tconfidence\ttext";
+
+/// Plain text in reading order: lines separated by newlines, paragraphs and
+/// blocks separated by a blank line.
+pub fn plain_text(document: &OcrDocument) -> String {
+    document
+        .paragraphs()
+        .map(|paragraph| {
+            paragraph
+                .lines
+                .iter()
+                .map(|line| line.text())
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Structured result for `source`: the plain text plus blocks, paragraphs,
+/// lines and spans with their boxes and confidences.
+pub fn json(document: &OcrDocument, source: &str) -> Value {
+    let blocks = document
+        .blocks
+        .iter()
+        .map(|block| {
+            let paragraphs = block
+                .paragraphs
+                .iter()
+                .map(|paragraph| {
+                    let lines = paragraph
+                        .lines
+                        .iter()
+                        .map(|line| {
+                            let spans = line
+                                .spans
+                                .iter()
+                                .map(|span| {
+                                    json!({
+                                        "text": span.text.trim(),
+                                        "confidence": span.confidence,
+                                        "bbox": rect_json(span.bbox),
+                                    })
+                                })
+                                .collect::<Vec<_>>();
+                            json!({
+                                "text": line.text(),
+                                "confidence": line.confidence(),
+                                "bbox": rect_json(line.bbox),
+                                "spans": spans,
+                            })
+                        })
+                        .collect::<Vec<_>>();
+                    json!({ "bbox": rect_json(paragraph.bbox), "lines": lines })
+                })
+                .collect::<Vec<_>>();
+            json!({ "bbox": rect_json(block.bbox), "paragraphs": paragraphs })
+        })
+        .collect::<Vec<_>>();
+    json!({
+        "source": source,
+        "text": plain_text(document),
+        "blocks": blocks,
+    })
+}
+
+fn rect_json(rect: OcrRect) -> Value {
+    json!({ "x": rect.x, "y": rect.y, "width": rect.width, "height": rect.height })
+}
+
+/// One tab-separated row per span in reading order, without the header.
+/// Block, paragraph, line and span numbers start at 1.
+pub fn tsv(document: &OcrDocument, source: &str) -> String {
+    let source = tsv_field(source);
+    let mut out = String::new();
+    for (block_index, block) in document.blocks.iter().enumerate() {
+        for (paragraph_index, paragraph) in block.paragraphs.iter().enumerate() {
+            for (line_index, line) in paragraph.lines.iter().enumerate() {
+                for (span_index, span) in line.spans.iter().enumerate() {
+                    let bbox = span.bbox;
+                    out.push_str(&format!(
+                        "{source}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{:.4}\t{}\n",
+                        block_index + 1,
+                        paragraph_index + 1,
+                        line_index + 1,
+                        span_index + 1,
+                        bbox.x,
+                        bbox.y,
+                        bbox.width,
+                        bbox.height,
+                        span.confidence,
+                        tsv_field(span.text.trim()),
+                    ));
+                }
+            }
+        }
+    }
+    out
+}
+
+fn tsv_field(value: &str) -> String {
+    value
+        .chars()
+        .map(|ch| {
+            if matches!(ch, '\t' | '\n' | '\r') {
+                ' '
+            } else {
+                ch
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ocr::OcrSpan;
+
+    #[test]
+    fn plain_text_of_empty_document_is_empty() {
+        assert_eq!(plain_text(&OcrDocument::default()), "");
+    }
+
+    #[test]
+    fn plain_text_separates_paragraphs_with_blank_line() {
+        let document = OcrDocument::from_spans(vec![
+            OcrSpan::new("first", OcrRect::new(0, 0, 100, 20), 0.9),
+            OcrSpan::new("second", OcrRect::new(0, 26, 100, 20), 0.9),
+            OcrSpan::new("third", OcrRect::new(0, 90, 100, 20), 0.9),
+        ]);
+
+        assert_eq!(plain_text(&document), "first\nsecond\n\nthird");
+    }
+
+    #[test]
+    fn json_nests_lines_with_text_and_boxes() {
+        let document = OcrDocument::from_spans(vec![
+            OcrSpan::new("hello", OcrRect::new(0, 0, 40, 20), 0.5),
+            OcrSpan::new("world", OcrRect::new(50, 0, 40, 20), 1.0),
+        ]);
+
+        let value = json(&document, "shot.png");
+
+        assert_eq!(value["source"], "shot.png");
+        assert_eq!(value["text"], "hello world");
+        let line = &value["blocks"][0]["paragraphs"][0]["lines"][0];
+        assert_eq!(line["text"], "hello world");
+        assert_eq!(line["confidence"], 0.75);
+        assert_eq!(line["bbox"]["width"], 90);
+        assert_eq!(line["spans"][1]["text"], "world");
+    }
+
+    #[test]
+    fn tsv_emits_one_row_per_span_and_strips_separators() {
+        let document = OcrDocument::from_spans(vec![
+            OcrSpan::new("a\tb", OcrRect::new(0, 0, 40, 20), 0.5),
+            OcrSpan::new("next", OcrRect::new(0, 26, 40, 20), 1.0),
+        ]);
+
+        let rows = tsv(&document, "-");
+
+        assert_eq!(
+            rows,
+            "-\t1\t1\t1\t1\t0\t0\t40\t20\t0.5000\ta b\n\
+             -\t1\t1\t2\t1\t0\t26\t40\t20\t1.0000\tnext\n"
+        );
+        assert_eq!(TSV_HEADER.split('\t').count(), 11);
+    }
+}
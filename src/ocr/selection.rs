@@ -0,0 +1,430 @@
+use super::document::{OcrDocument, OcrLine, OcrRect};
+
+/// Caret position inside an [`OcrTextLayer`]: a line index in reading order
+/// and a character offset into that line's text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct OcrTextPosition {
+    pub line: usize,
+    pub offset: usize,
+}
+
+impl OcrTextPosition {
+    pub const fn new(line: usize, offset: usize) -> Self {
+        Self { line, offset }
+    }
+}
+
+/// Text selection between the point where it started and the point it was
+/// dragged to. Either end may come first in reading order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OcrTextSelection {
+    pub anchor: OcrTextPosition,
+    pub focus: OcrTextPosition,
+}
+
+impl OcrTextSelection {
+    pub const fn new(anchor: OcrTextPosition, focus: OcrTextPosition) -> Self {
+        Self { anchor, focus }
+    }
+
+    pub const fn caret(position: OcrTextPosition) -> Self {
+        Self::new(position, position)
+    }
+
+    /// Selection ends as `(start, end)` in reading order.
+    pub fn ordered(self) -> (OcrTextPosition, OcrTextPosition) {
+        if self.anchor <= self.focus {
+            (self.anchor, self.focus)
+        } else {
+            (self.focus, self.anchor)
+        }
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.anchor == self.focus
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct LayerLine {
+    bbox: OcrRect,
+    chars: Vec<char>,
+    /// Horizontal caret positions; `edges[i]` is the left edge of `chars[i]`
+    /// and the last entry is the right edge of the line.
+    edges: Vec<f64>,
+}
+
+impl LayerLine {
+    fn from_line(line: &OcrLine, dx: i32, dy: i32) -> Self {
+        let mut chars = Vec::new();
+        let mut edges = Vec::new();
+        let mut previous_right: Option<f64> = None;
+        for span in &line.spans {
+            let span_chars: Vec<char> = span.text.trim().chars().collect();
+            if span_chars.is_empty() {
+                continue;
+            }
+            let left = f64::from(span.bbox.x.saturating_add(dx));
+            let width = f64::from(span.bbox.width);
+            if let Some(right) = previous_right {
+                // The joining space fills the gap between neighbouring spans.
+                chars.push(' ');
+                edges.push(right);
+            }
+            let start = previous_right.map_or(left, |right| left.max(right));
+            let right = (left + width).max(start);
+            let step = (right - start) / span_chars.len() as f64;
+            for (index, ch) in span_chars.into_iter().enumerate() {
+                chars.push(ch);
+                edges.push(start + step * index as f64);
+            }
+            previous_right = Some(right);
+        }
+        edges.push(previous_right.unwrap_or_else(|| f64::from(line.bbox.x.saturating_add(dx))));
+
+        let bbox = OcrRect::new(
+            line.bbox.x.saturating_add(dx),
+            line.bbox.y.saturating_add(dy),
+            line.bbox.width,
+            line.bbox.height,
+        );
+        Self { bbox, chars, edges }
+    }
+
+    fn offset_at(&self, x: f64) -> usize {
+        let mut best = 0;
+        let mut best_distance = f64::INFINITY;
+        for (index, edge) in self.edges.iter().enumerate() {
+            let distance = (x - edge).abs();
+            if distance < best_distance {
+                best = index;
+                best_distance = distance;
+            }
+        }
+        best
+    }
+
+    fn distance_to(&self, x: f64, y: f64) -> f64 {
+        let left = f64::from(self.bbox.x);
+        let top = f64::from(self.bbox.y);
+        let right = f64::from(self.bbox.right());
+        let bottom = f64::from(self.bbox.bottom());
+        let dx = (left - x).max(x - right).max(0.0);
+        let dy = (top - y).max(y - bottom).max(0.0);
+        dx.hypot(dy)
+    }
+
+    fn contains(&self, x: f64, y: f64) -> bool {
+        self.distance_to(x, y) <= 0.0
+    }
+}
+
+/// Hit-testing model for recognized text drawn over a capture: maps points
+/// in image coordinates to caret positions and selections back to rectangles
+/// and text.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct OcrTextLayer {
+    lines: Vec<LayerLine>,
+}
+
+impl OcrTextLayer {
+    /// Builds a layer from `document`, shifting every box by `(dx, dy)` so
+    /// results recognized from a cropped region line up with the full image.
+    pub fn from_document(document: &OcrDocument, dx: i32, dy: i32) -> Self {
+        let lines = document
+            .lines()
+            .map(|line| LayerLine::from_line(line, dx, dy))
+            .filter(|line| !line.chars.is_empty())
+            .collect();
+        Self { lines }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn line_boxes(&self) -> impl Iterator<Item = OcrRect> + '_ {
+        self.lines.iter().map(|line| line.bbox)
+    }
+
+    /// Text of the line at `line` as laid out for selection, spans joined
+    /// by single spaces.
+    pub fn line_text(&self, line: usize) -> Option<String> {
+        self.lines.get(line).map(|line| line.chars.iter().collect())
+    }
+
+    /// Caret position under the point, or `None` outside every line box.
+    pub fn position_at(&self, x: f64, y: f64) -> Option<OcrTextPosition> {
+        let line = self.lines.iter().position(|line| line.contains(x, y))?;
+        Some(OcrTextPosition::new(line, self.lines[line].offset_at(x)))
+    }
+
+    /// Caret position of the line closest to the point, for extending a
+    /// selection while the pointer is between lines.
+    pub fn nearest_position(&self, x: f64, y: f64) -> Option<OcrTextPosition> {
+        let (line, _) = self
+            .lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| (index, line.distance_to(x, y)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))?;
+        Some(OcrTextPosition::new(line, self.lines[line].offset_at(x)))
+    }
+
+    /// Selection covering the word around `position`.
+    pub fn word_at(&self, position: OcrTextPosition) -> OcrTextSelection {
+        let Some(line) = self.lines.get(position.line) else {
+            return OcrTextSelection::caret(position);
+        };
+        let is_word = |index: usize| !line.chars[index].is_whitespace();
+        let pivot = position.offset.min(line.chars.len().saturating_sub(1));
+        if line.chars.is_empty() || !is_word(pivot) {
+            return OcrTextSelection::caret(position);
+        }
+        let mut start = pivot;
+        while start > 0 && is_word(start - 1) {
+            start -= 1;
+        }
+        let mut end = pivot + 1;
+        while end < line.chars.len() && is_word(end) {
+            end += 1;
+        }
+        OcrTextSelection::new(
+            OcrTextPosition::new(position.line, start),
+            OcrTextPosition::new(position.line, end),
+        )
+    }
+
+    /// Selection covering the whole line at `line`.
+    pub fn line_at(&self, line: usize) -> OcrTextSelection {
+        let len = self.lines.get(line).map_or(0, |line| line.chars.len());
+        OcrTextSelection::new(
+            OcrTextPosition::new(line, 0),
+            OcrTextPosition::new(line, len),
+        )
+    }
+
+    /// Selection covering every line.
+    pub fn select_all(&self) -> OcrTextSelection {
+        let last = self.lines.len().saturating_sub(1);
+        let len = self.lines.last().map_or(0, |line| line.chars.len());
+        OcrTextSelection::new(OcrTextPosition::new(0, 0), OcrTextPosition::new(last, len))
+    }
+
+    fn line_ranges(
+        &self,
+        selection: OcrTextSelection,
+    ) -> impl Iterator<Item = (&LayerLine, usize, usize)> + '_ {
+        let (start, end) = selection.ordered();
+        self.lines
+            .iter()
+            .enumerate()
+            .skip(start.line)
+            .take(end.line.saturating_sub(start.line) + 1)
+            .map(move |(index, line)| {
+                let from = if index == start.line { start.offset } else { 0 };
+                let to = if index == end.line {
+                    end.offset
+                } else {
+                    line.chars.len()
+                };
+                let to = to.min(line.chars.len());
+                (line, from.min(to), to)
+            })
+    }
+
+    /// Highlight rectangles for `selection`, one per touched line.
+    pub fn selection_rects(&self, selection: OcrTextSelection) -> Vec<OcrRect> {
+        if selection.is_empty() {
+            return Vec::new();
+        }
+        self.line_ranges(selection)
+            .filter(|(_, from, to)| from < to)
+            .map(|(line, from, to)| {
+                let left = line.edges[from].round() as i32;
+                let right = line.edges[to].round() as i32;
+                OcrRect::new(
+                    left,
+                    line.bbox.y,
+                    (right - left).max(1) as u32,
+                    line.bbox.height,
+                )
+            })
+            .collect()
+    }
+
+    /// Smallest rectangle covering every highlight rectangle of `selection`.
+    pub fn selection_bounds(&self, selection: OcrTextSelection) -> Option<OcrRect> {
+        self.selection_rects(selection)
+            .into_iter()
+            .reduce(|bounds, rect| {
+                let left = bounds.x.min(rect.x);
+                let top = bounds.y.min(rect.y);
+                let right = bounds.right().max(rect.right());
+                let bottom = bounds.bottom().max(rect.bottom());
+                OcrRect::new(
+                    left,
+                    top,
+                    (right - left).max(1) as u32,
+                    (bottom - top).max(1) as u32,
+                )
+            })
+    }
+
+    /// Case-insensitive, non-overlapping occurrences of `query` in reading
+    /// order. Matches never cross a line break.
+    pub fn find(&self, query: &str) -> Vec<OcrTextSelection> {
+        let needle: Vec<char> = query.trim().chars().map(fold_case).collect();
+        if needle.is_empty() {
+            return Vec::new();
+        }
+        let mut matches = Vec::new();
+        for (index, line) in self.lines.iter().enumerate() {
+            let haystack: Vec<char> = line.chars.iter().copied().map(fold_case).collect();
+            let mut offset = 0;
+            while offset + needle.len() <= haystack.len() {
+                if haystack[offset..offset + needle.len()] == needle[..] {
+                    matches.push(OcrTextSelection::new(
+                        OcrTextPosition::new(index, offset),
+                        OcrTextPosition::new(index, offset + needle.len()),
+                    ));
+                    offset += needle.len();
+                } else {
+                    offset += 1;
+                }
+            }
+        }
+        matches
+    }
+
+    /// Selected text with a newline between lines.
+    pub fn selected_text(&self, selection: OcrTextSelection) -> String {
+        if selection.is_empty() {
+            return String::new();
+        }
+        self.line_ranges(selection)
+            .map(|(line, from, to)| line.chars[from..to].iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Per-character lowercase so folded text keeps the original offsets.
+fn fold_case(ch: char) -> char {
+    ch.to_lowercase().next().unwrap_or(ch)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ocr::OcrSpan;
+
+    fn two_line_layer() -> OcrTextLayer {
+        let document = OcrDocument::from_spans(vec![
+            OcrSpan::new("hello", OcrRect::new(0, 0, 50, 20), 0.9),
+            OcrSpan::new("world", OcrRect::new(60, 0, 50, 20), 0.9),
+            OcrSpan::new("second line", OcrRect::new(0, 26, 110, 20), 0.9),
+        ]);
+        OcrTextLayer::from_document(&document, 0, 0)
+    }
+
+    #[test]
+    fn position_at_maps_point_to_nearest_character_edge() {
+        let layer = two_line_layer();
+
+        assert_eq!(
+            layer.position_at(21.0, 10.0),
+            Some(OcrTextPosition::new(0, 2))
+        );
+        assert_eq!(
+            layer.position_at(55.0, 10.0),
+            Some(OcrTextPosition::new(0, 5))
+        );
+        assert_eq!(layer.position_at(300.0, 10.0), None);
+    }
+
+    #[test]
+    fn nearest_position_snaps_to_closest_line() {
+        let layer = two_line_layer();
+
+        assert_eq!(
+            layer.nearest_position(200.0, 30.0),
+            Some(OcrTextPosition::new(1, 11))
+        );
+    }
+
+    #[test]
+    fn selected_text_spans_lines_in_reading_order() {
+        let layer = two_line_layer();
+        let selection =
+            OcrTextSelection::new(OcrTextPosition::new(1, 6), OcrTextPosition::new(0, 6));
+
+        assert_eq!(layer.selected_text(selection), "world\nsecond");
+        assert_eq!(layer.selection_rects(selection).len(), 2);
+    }
+
+    #[test]
+    fn word_at_expands_to_word_boundaries() {
+        let layer = two_line_layer();
+        let word = layer.word_at(OcrTextPosition::new(1, 8));
+
+        assert_eq!(layer.selected_text(word), "line");
+        assert_eq!(layer.selected_text(layer.line_at(0)), "hello world");
+    }
+
+    #[test]
+    fn selection_rects_follow_character_edges() {
+        let layer = two_line_layer();
+        let rects = layer.selection_rects(layer.word_at(OcrTextPosition::new(0, 7)));
+
+        assert_eq!(rects, vec![OcrRect::new(60, 0, 50, 20)]);
+    }
+
+    #[test]
+    fn find_matches_case_insensitively_without_overlap() {
+        let layer = two_line_layer();
+
+        let matches = layer.find(" LINE ");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(layer.selected_text(matches[0]), "line");
+
+        let document =
+            OcrDocument::from_spans(vec![OcrSpan::new("aaaa", OcrRect::new(0, 0, 40, 10), 0.9)]);
+        let layer = OcrTextLayer::from_document(&document, 0, 0);
+        assert_eq!(layer.find("aa").len(), 2);
+        assert!(layer.find("   ").is_empty());
+    }
+
+    #[test]
+    fn selection_bounds_unions_line_rects() {
+        let layer = two_line_layer();
+        let selection =
+            OcrTextSelection::new(OcrTextPosition::new(0, 6), OcrTextPosition::new(1, 6));
+
+        assert_eq!(
+            layer.selection_bounds(selection),
+            Some(OcrRect::new(0, 0, 110, 46))
+        );
+        assert_eq!(layer.selection_bounds(OcrTextSelection::default()), None);
+    }
+
+    #[test]
+    fn layer_offset_shifts_region_results_into_image_space() {
+        let document =
+            OcrDocument::from_spans(vec![OcrSpan::new("abc", OcrRect::new(0, 0, 30, 10), 0.9)]);
+        let layer = OcrTextLayer::from_document(&document, 100, 40);
+
+        assert_eq!(
+            layer.line_boxes().next(),
+            Some(OcrRect::new(100, 40, 30, 10))
+        );
+        assert_eq!(
+            layer.position_at(110.0, 45.0),
+            Some(OcrTextPosition::new(0, 1))
+        );
+    }
+}
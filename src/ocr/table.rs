@@ -0,0 +1,356 @@
+//! Table reconstruction from OCR boxes.
+//!
+//! Spans are clustered into rows by vertical overlap, neighbouring spans in a
+//! row are joined into cell fragments, and columns are the gaps left in the
+//! horizontal projection of those fragments. Only rows with at least two
+//! fragments shape the columns, so a title spanning the table does not merge
+//! them. All thresholds scale with the median span height.
+
+use super::document::{OcrRect, OcrSpan};
+use super::layout::{line_overlap, median_span_height};
+
+/// Horizontal gap below which two spans in a row belong to one cell.
+const CELL_GAP_RATIO: f64 = 0.8;
+/// Horizontal gap below which two fragment projections belong to one column.
+const COLUMN_GAP_RATIO: f64 = 0.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableFormat {
+    Markdown,
+    Csv,
+    Tsv,
+}
+
+impl TableFormat {
+    pub const ALL: [Self; 3] = [Self::Markdown, Self::Csv, Self::Tsv];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Markdown => "Markdown table",
+            Self::Csv => "CSV",
+            Self::Tsv => "TSV",
+        }
+    }
+}
+
+/// Rectangular grid of cell texts, first row first. Cells without text are
+/// empty strings.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct OcrTable {
+    rows: Vec<Vec<String>>,
+}
+
+impl OcrTable {
+    pub fn rows(&self) -> &[Vec<String>] {
+        &self.rows
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn column_count(&self) -> usize {
+        self.rows.first().map_or(0, Vec::len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    pub fn render(&self, format: TableFormat) -> String {
+        match format {
+            TableFormat::Markdown => self.to_markdown(),
+            TableFormat::Csv => self.to_csv(),
+            TableFormat::Tsv => self.to_tsv(),
+        }
+    }
+
+    /// GitHub-flavoured Markdown with the first row as the header.
+    pub fn to_markdown(&self) -> String {
+        let Some((header, body)) = self.rows.split_first() else {
+            return String::new();
+        };
+        let row_line = |cells: &[String]| {
+            let cells = cells
+                .iter()
+                .map(|cell| single_line(cell).replace('|', "\\|"))
+                .collect::<Vec<_>>();
+            format!("| {} |\n", cells.join(" | "))
+        };
+        let mut out = row_line(header);
+        out.push_str(&format!("|{}\n", " --- |".repeat(header.len())));
+        for row in body {
+            out.push_str(&row_line(row));
+        }
+        out
+    }
+
+    /// RFC 4180 CSV; fields with commas, quotes or line breaks are quoted.
+    pub fn to_csv(&self) -> String {
+        self.rows
+            .iter()
+            .map(|row| {
+                let fields = row
+                    .iter()
+                    .map(|cell| {
+                        if cell.contains([',', '"', '\n', '\r']) {
+                            format!("\"{}\"", cell.replace('"', "\"\""))
+                        } else {
+                            cell.clone()
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                format!("{}\n", fields.join(","))
+            })
+            .collect()
+    }
+
+    /// Tab-separated values; tabs and line breaks inside cells become spaces.
+    pub fn to_tsv(&self) -> String {
+        self.rows
+            .iter()
+            .map(|row| {
+                let fields = row
+                    .iter()
+                    .map(|cell| single_line(cell).replace('\t', " "))
+                    .collect::<Vec<_>>();
+                format!("{}\n", fields.join("\t"))
+            })
+            .collect()
+    }
+}
+
+fn single_line(text: &str) -> String {
+    text.split(['\n', '\r'])
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A run of spans in one row that reads as a single cell.
+#[derive(Debug, Clone)]
+struct Fragment {
+    bbox: OcrRect,
+    text: String,
+}
+
+/// Groups `spans` into a grid using their boxes alone.
+pub fn infer_table(spans: &[OcrSpan]) -> OcrTable {
+    let spans = spans
+        .iter()
+        .filter(|span| !span.text.trim().is_empty())
+        .collect::<Vec<_>>();
+    if spans.is_empty() {
+        return OcrTable::default();
+    }
+    let median_height = median_span_height(spans.iter().copied());
+
+    let rows = group_rows(spans)
+        .into_iter()
+        .map(|row| join_fragments(row, median_height * CELL_GAP_RATIO))
+        .collect::<Vec<_>>();
+    let columns = column_bounds(&rows, median_height * COLUMN_GAP_RATIO);
+
+    OcrTable {
+        rows: rows
+            .iter()
+            .map(|fragments| {
+                let mut cells = vec![String::new(); columns.len()];
+                for fragment in fragments {
+                    let cell = &mut cells[column_for(fragment.bbox, &columns)];
+                    if !cell.is_empty() {
+                        cell.push(' ');
+                    }
+                    cell.push_str(&fragment.text);
+                }
+                cells
+            })
+            .collect(),
+    }
+}
+
+/// Rows from top to bottom, each sorted left to right. Spans share a row
+/// under the same overlap rule that groups words into lines.
+fn group_rows(mut spans: Vec<&OcrSpan>) -> Vec<Vec<&OcrSpan>> {
+    spans.sort_by(|a, b| a.bbox.center_y().total_cmp(&b.bbox.center_y()));
+    let mut rows: Vec<(OcrRect, Vec<&OcrSpan>)> = Vec::new();
+    for span in spans {
+        let joins_last = rows
+            .last()
+            .is_some_and(|(bbox, _)| line_overlap(*bbox, span.bbox).is_some());
+        match rows.last_mut() {
+            Some((bbox, members)) if joins_last => {
+                *bbox = bbox.union(span.bbox);
+                members.push(span);
+            }
+            _ => rows.push((span.bbox, vec![span])),
+        }
+    }
+    rows.into_iter()
+        .map(|(_, mut members)| {
+            members.sort_by_key(|span| span.bbox.x);
+            members
+        })
+        .collect()
+}
+
+fn join_fragments(row: Vec<&OcrSpan>, max_gap: f64) -> Vec<Fragment> {
+    let mut fragments: Vec<Fragment> = Vec::new();
+    for span in row {
+        let text = span.text.trim();
+        match fragments.last_mut() {
+            Some(last) if f64::from(span.bbox.x - last.bbox.right()) < max_gap => {
+                last.bbox = last.bbox.union(span.bbox);
+                last.text.push(' ');
+                last.text.push_str(text);
+            }
+            _ => fragments.push(Fragment {
+                bbox: span.bbox,
+                text: text.to_string(),
+            }),
+        }
+    }
+    fragments
+}
+
+/// Horizontal extents `(left, right)` of the columns, left to right.
+fn column_bounds(rows: &[Vec<Fragment>], min_gap: f64) -> Vec<(i32, i32)> {
+    let has_multi_cell_rows = rows.iter().any(|row| row.len() > 1);
+    let mut extents = rows
+        .iter()
+        .filter(|row| !has_multi_cell_rows || row.len() > 1)
+        .flatten()
+        .map(|fragment| (fragment.bbox.x, fragment.bbox.right()))
+        .collect::<Vec<_>>();
+    extents.sort_unstable();
+
+    let mut columns: Vec<(i32, i32)> = Vec::new();
+    for (left, right) in extents {
+        match columns.last_mut() {
+            Some(column) if f64::from(left - column.1) < min_gap => {
+                column.1 = column.1.max(right);
+            }
+            _ => columns.push((left, right)),
+        }
+    }
+    columns
+}
+
+/// The column overlapping `bbox` the most, or the nearest one when it
+/// overlaps none. Ties go to the leftmost column.
+fn column_for(bbox: OcrRect, columns: &[(i32, i32)]) -> usize {
+    let overlap = |(left, right): (i32, i32)| bbox.right().min(right) - bbox.x.max(left);
+    let mut best = 0;
+    for (index, &column) in columns.iter().enumerate().skip(1) {
+        if overlap(column) > overlap(columns[best]) {
+            best = index;
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(text: &str, x: i32, y: i32, width: u32) -> OcrSpan {
+        OcrSpan::new(text, OcrRect::new(x, y, width, 20), 0.9)
+    }
+
+    fn texts(table: &OcrTable) -> Vec<Vec<&str>> {
+        table
+            .rows()
+            .iter()
+            .map(|row| row.iter().map(String::as_str).collect())
+            .collect()
+    }
+
+    #[test]
+    fn infer_table_builds_grid_from_unordered_boxes() {
+        let spans = [
+            span("30", 200, 62, 30),
+            span("Name", 10, 10, 60),
+            span("Alice", 10, 60, 60),
+            span("Age", 200, 11, 40),
+            span("Bob", 10, 110, 50),
+            span("25", 202, 108, 30),
+        ];
+
+        let table = infer_table(&spans);
+
+        assert_eq!(table.row_count(), 3);
+        assert_eq!(table.column_count(), 2);
+        assert_eq!(
+            texts(&table),
+            vec![vec!["Name", "Age"], vec!["Alice", "30"], vec!["Bob", "25"]]
+        );
+    }
+
+    #[test]
+    fn infer_table_joins_words_of_one_cell_and_leaves_gaps_empty() {
+        let spans = [
+            span("City", 10, 10, 40),
+            span("Country", 200, 10, 70),
+            span("Population", 400, 10, 100),
+            span("New", 10, 60, 40),
+            span("York", 58, 60, 40),
+            span("8.3M", 400, 60, 40),
+        ];
+
+        let table = infer_table(&spans);
+
+        assert_eq!(
+            texts(&table),
+            vec![
+                vec!["City", "Country", "Population"],
+                vec!["New York", "", "8.3M"],
+            ]
+        );
+    }
+
+    #[test]
+    fn infer_table_keeps_spanning_title_in_first_column() {
+        let spans = [
+            span("Quarterly revenue by region", 10, 10, 300),
+            span("Region", 10, 60, 60),
+            span("Q1", 200, 60, 30),
+            span("EMEA", 10, 110, 50),
+            span("1.2", 200, 110, 30),
+        ];
+
+        let table = infer_table(&spans);
+
+        assert_eq!(table.column_count(), 2);
+        assert_eq!(
+            texts(&table),
+            vec![
+                vec!["Quarterly revenue by region", ""],
+                vec!["Region", "Q1"],
+                vec!["EMEA", "1.2"],
+            ]
+        );
+    }
+
+    #[test]
+    fn infer_table_of_blank_spans_is_empty() {
+        assert!(infer_table(&[span("  ", 0, 0, 10)]).is_empty());
+        assert_eq!(infer_table(&[]).render(TableFormat::Markdown), "");
+    }
+
+    #[test]
+    fn table_renders_markdown_csv_and_tsv_with_escaping() {
+        let table = OcrTable {
+            rows: vec![
+                vec!["a|b".to_string(), "say \"hi\"".to_string()],
+                vec!["1,5".to_string(), "x\ty".to_string()],
+            ],
+        };
+
+        assert_eq!(
+            table.to_markdown(),
+            "| a\\|b | say \"hi\" |\n| --- | --- |\n| 1,5 | x\ty |\n"
+        );
+        assert_eq!(table.to_csv(), "a|b,\"say \"\"hi\"\"\"\n\"1,5\",x\ty\n");
+        assert_eq!(table.to_tsv(), "a|b\tsay \"hi\"\n1,5\tx y\n");
+    }
+}
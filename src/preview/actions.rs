@@ -1,12 +1,14 @@
 use thiserror::Error;
 
-use crate::clipboard::ClipboardError;
-use crate::storage::StorageError;
+use crate::clipboard::{ClipboardError, CopyProfile};
+use crate::storage::{StorageError, TrashedCapture};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PreviewAction {
     Save,
     Copy,
+    /// Copy with an explicit payload profile picked from the "Copy as…" menu.
+    CopyAs(CopyProfile),
     Edit,
     Delete,
     Close,
@@ -14,11 +16,22 @@ pub enum PreviewAction {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PreviewEvent {
-    Save { capture_id: String },
-    Copy { capture_id: String },
-    Edit { capture_id: String },
-    Delete { capture_id: String },
-    Close { capture_id: String },
+    Save {
+        capture_id: String,
+    },
+    Copy {
+        capture_id: String,
+    },
+    Edit {
+        capture_id: String,
+    },
+    Delete {
+        capture_id: String,
+        trashed: TrashedCapture,
+    },
+    Close {
+        capture_id: String,
+    },
 }
 
 #[derive(Debug, Error)]
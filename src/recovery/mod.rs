@@ -0,0 +1,336 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::capture::CaptureArtifact;
+use crate::editor::ToolObject;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+const RECOVERY_SUBDIR: &str = "chalkak-recovery";
+const SNAPSHOT_FILE: &str = "session.json";
+const SNAPSHOT_TEMP_FILE: &str = "session.json.tmp";
+const SOURCE_IMAGE_FILE: &str = "source.png";
+pub const RECOVERY_FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Error)]
+pub enum RecoveryError {
+    #[error("capture id is empty")]
+    MissingCaptureId,
+    #[error("unsupported recovery format version {found}")]
+    UnsupportedVersion { found: u32 },
+    #[error("recovery source image missing: {path}")]
+    MissingSourceImage { path: PathBuf },
+    #[error("failed to parse recovery snapshot: {0}")]
+    Parse(#[from] serde_json::Error),
+    #[error("io error: {0}")]
+    Io(#[from] io::Error),
+}
+
+pub type RecoveryResult<T> = std::result::Result<T, RecoveryError>;
+
+/// Editor state persisted while a session has unsaved changes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecoverySnapshot {
+    pub version: u32,
+    pub capture: CaptureArtifact,
+    pub objects: Vec<ToolObject>,
+    pub saved_at: u64,
+}
+
+impl RecoverySnapshot {
+    pub fn new(capture: CaptureArtifact, objects: Vec<ToolObject>) -> Self {
+        Self {
+            version: RECOVERY_FORMAT_VERSION,
+            capture,
+            objects,
+            saved_at: unix_now(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecoverableSession {
+    pub snapshot: RecoverySnapshot,
+    pub source_image_path: PathBuf,
+}
+
+impl RecoverableSession {
+    pub fn capture_id(&self) -> &str {
+        &self.snapshot.capture.capture_id
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RecoveryStore {
+    root: PathBuf,
+}
+
+impl RecoveryStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn with_default_path() -> Self {
+        Self::new(crate::storage::default_runtime_temp_dir().join(RECOVERY_SUBDIR))
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn session_dir(&self, capture_id: &str) -> RecoveryResult<PathBuf> {
+        if capture_id.is_empty() {
+            return Err(RecoveryError::MissingCaptureId);
+        }
+        Ok(self.root.join(capture_id))
+    }
+
+    /// Path where the unannotated source image for `capture_id` is kept.
+    pub fn source_image_path(&self, capture_id: &str) -> RecoveryResult<PathBuf> {
+        Ok(self.session_dir(capture_id)?.join(SOURCE_IMAGE_FILE))
+    }
+
+    /// Ensures the session directory exists and reports whether the source
+    /// image still has to be written by the caller.
+    pub fn prepare_session(&self, capture_id: &str) -> RecoveryResult<bool> {
+        let dir = self.session_dir(capture_id)?;
+        fs::create_dir_all(&dir)?;
+        Ok(!dir.join(SOURCE_IMAGE_FILE).is_file())
+    }
+
+    /// Writes the snapshot atomically (temp file + rename), so a crash during
+    /// autosave never leaves a truncated `session.json` behind.
+    pub fn write_snapshot(&self, snapshot: &RecoverySnapshot) -> RecoveryResult<()> {
+        let dir = self.session_dir(&snapshot.capture.capture_id)?;
+        fs::create_dir_all(&dir)?;
+        let temp_path = dir.join(SNAPSHOT_TEMP_FILE);
+        let json = serde_json::to_vec(snapshot)?;
+        fs::write(&temp_path, json)?;
+        fs::rename(&temp_path, dir.join(SNAPSHOT_FILE))?;
+        Ok(())
+    }
+
+    pub fn discard(&self, capture_id: &str) -> RecoveryResult<()> {
+        let dir = self.session_dir(capture_id)?;
+        match fs::remove_dir_all(&dir) {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(RecoveryError::Io(err)),
+        }
+    }
+
+    pub fn load(&self, capture_id: &str) -> RecoveryResult<RecoverableSession> {
+        let dir = self.session_dir(capture_id)?;
+        let raw = fs::read(dir.join(SNAPSHOT_FILE))?;
+        let snapshot: RecoverySnapshot = serde_json::from_slice(&raw)?;
+        if snapshot.version != RECOVERY_FORMAT_VERSION {
+            return Err(RecoveryError::UnsupportedVersion {
+                found: snapshot.version,
+            });
+        }
+        let source_image_path = dir.join(SOURCE_IMAGE_FILE);
+        if !source_image_path.is_file() {
+            return Err(RecoveryError::MissingSourceImage {
+                path: source_image_path,
+            });
+        }
+        Ok(RecoverableSession {
+            snapshot,
+            source_image_path,
+        })
+    }
+
+    /// Lists complete sessions, oldest first. Unreadable entries are skipped
+    /// and logged rather than failing the whole scan.
+    pub fn list(&self) -> RecoveryResult<Vec<RecoverableSession>> {
+        if !self.root.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut sessions = Vec::new();
+        for entry in fs::read_dir(&self.root)? {
+            let entry = entry?;
+            if !entry.path().is_dir() {
+                continue;
+            }
+            let Some(capture_id) = entry.file_name().to_str().map(str::to_string) else {
+                continue;
+            };
+            match self.load(&capture_id) {
+                Ok(session) => sessions.push(session),
+                Err(err) => {
+                    tracing::warn!(capture_id, ?err, "skipping unreadable recovery session");
+                }
+            }
+        }
+        sessions.sort_by_key(|session| session.snapshot.saved_at);
+        Ok(sessions)
+    }
+
+    /// Copies the recovered source image back to `temp_path` and returns the
+    /// artifact pointing at it, ready to be pushed into the runtime session.
+    pub fn restore_source_image(
+        &self,
+        session: &RecoverableSession,
+        temp_path: &Path,
+    ) -> RecoveryResult<CaptureArtifact> {
+        if let Some(parent) = temp_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(&session.source_image_path, temp_path)?;
+        let mut artifact = session.snapshot.capture.clone();
+        artifact.temp_path = temp_path.to_path_buf();
+        Ok(artifact)
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::editor::tools::{
+        ArrowElement, ArrowOptions, BlurElement, BlurOptions, BlurRegion, TextElement, TextOptions,
+        ToolPoint,
+    };
+
+    fn scratch_store(name: &str) -> RecoveryStore {
+        RecoveryStore::new(std::env::temp_dir().join(format!(
+            "chalkak-recovery-{name}-{}-{}",
+            std::process::id(),
+            unix_now()
+        )))
+    }
+
+    fn artifact(id: &str) -> CaptureArtifact {
+        CaptureArtifact {
+            capture_id: id.to_string(),
+            temp_path: PathBuf::from(format!("/tmp/capture_{id}.png")),
+            width: 64,
+            height: 48,
+            screen_x: 0,
+            screen_y: 0,
+            screen_width: 64,
+            screen_height: 48,
+            created_at: 7,
+        }
+    }
+
+    fn sample_objects() -> Vec<ToolObject> {
+        vec![
+            ToolObject::Blur(BlurElement::new(
+                1,
+                BlurRegion::new(2, 3, 10, 12),
+                BlurOptions::default(),
+            )),
+            ToolObject::Arrow(ArrowElement::new(
+                2,
+                ToolPoint::new(0, 0),
+                ToolPoint::new(20, 30),
+                ArrowOptions::default(),
+            )),
+            ToolObject::Text(TextElement::with_text(
+                3,
+                ToolPoint::new(5, 5),
+                "hello\nworld",
+                TextOptions::default(),
+            )),
+        ]
+    }
+
+    #[test]
+    fn write_snapshot_round_trips_objects() {
+        let store = scratch_store("roundtrip");
+        assert!(store.prepare_session("cap-1").unwrap());
+        fs::write(store.source_image_path("cap-1").unwrap(), b"png").unwrap();
+        let snapshot = RecoverySnapshot::new(artifact("cap-1"), sample_objects());
+
+        store.write_snapshot(&snapshot).unwrap();
+        let loaded = store.load("cap-1").unwrap();
+
+        assert!(!store.prepare_session("cap-1").unwrap());
+        assert_eq!(loaded.snapshot.capture, snapshot.capture);
+        assert_eq!(loaded.snapshot.objects.len(), 3);
+        assert_eq!(loaded.snapshot.objects[0], snapshot.objects[0]);
+        assert_eq!(loaded.snapshot.objects[1], snapshot.objects[1]);
+        match &loaded.snapshot.objects[2] {
+            ToolObject::Text(text) => assert_eq!(text.content, "hello\nworld"),
+            other => panic!("expected text object, got {other:?}"),
+        }
+        let _ = fs::remove_dir_all(store.root());
+    }
+
+    #[test]
+    fn list_skips_sessions_without_source_image() {
+        let store = scratch_store("list");
+        store
+            .write_snapshot(&RecoverySnapshot::new(artifact("no-source"), Vec::new()))
+            .unwrap();
+        store.prepare_session("complete").unwrap();
+        fs::write(store.source_image_path("complete").unwrap(), b"png").unwrap();
+        store
+            .write_snapshot(&RecoverySnapshot::new(artifact("complete"), Vec::new()))
+            .unwrap();
+
+        let sessions = store.list().unwrap();
+
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].capture_id(), "complete");
+        let _ = fs::remove_dir_all(store.root());
+    }
+
+    #[test]
+    fn discard_removes_session_directory() {
+        let store = scratch_store("discard");
+        store.prepare_session("cap-2").unwrap();
+        fs::write(store.source_image_path("cap-2").unwrap(), b"png").unwrap();
+        store
+            .write_snapshot(&RecoverySnapshot::new(artifact("cap-2"), Vec::new()))
+            .unwrap();
+
+        store.discard("cap-2").unwrap();
+        store.discard("cap-2").unwrap();
+
+        assert!(store.list().unwrap().is_empty());
+        let _ = fs::remove_dir_all(store.root());
+    }
+
+    #[test]
+    fn restore_source_image_points_artifact_at_new_temp_path() {
+        let store = scratch_store("restore");
+        store.prepare_session("cap-3").unwrap();
+        fs::write(store.source_image_path("cap-3").unwrap(), b"png").unwrap();
+        store
+            .write_snapshot(&RecoverySnapshot::new(artifact("cap-3"), Vec::new()))
+            .unwrap();
+        let session = store.load("cap-3").unwrap();
+        let temp_path = store.root().join("runtime").join("capture_cap-3.png");
+
+        let restored = store.restore_source_image(&session, &temp_path).unwrap();
+
+        assert_eq!(restored.temp_path, temp_path);
+        assert_eq!(fs::read(&temp_path).unwrap(), b"png");
+        let _ = fs::remove_dir_all(store.root());
+    }
+
+    #[test]
+    fn load_rejects_unknown_format_version() {
+        let store = scratch_store("version");
+        let mut snapshot = RecoverySnapshot::new(artifact("cap-4"), Vec::new());
+        snapshot.version = RECOVERY_FORMAT_VERSION + 1;
+        store.write_snapshot(&snapshot).unwrap();
+
+        assert!(matches!(
+            store.load("cap-4"),
+            Err(RecoveryError::UnsupportedVersion { .. })
+        ));
+        let _ = fs::remove_dir_all(store.root());
+    }
+}
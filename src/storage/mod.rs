@@ -6,6 +6,10 @@ use std::time::{Duration, SystemTime};
 use crate::capture::CaptureArtifact;
 use thiserror::Error;
 
+mod trash;
+
+pub use trash::{default_trash_dir, TrashDir, TrashedCapture, TrashedFile};
+
 const DEFAULT_TEMP_PREFIX: &str = "capture_";
 const PREVIEW_SUBDIR: &str = "Pictures";
 const DEFAULT_FALLBACK_TEMP_DIR: &str = "/tmp/chalkak";
@@ -30,12 +34,14 @@ pub struct PruneReport {
 pub trait CaptureStorage {
     fn save_capture(&self, artifact: &CaptureArtifact) -> StorageResult<PathBuf>;
     fn discard_session_artifacts(&self, capture_id: &str) -> StorageResult<()>;
+    fn trash_capture(&self, capture_id: &str) -> StorageResult<TrashedCapture>;
 }
 
 #[derive(Debug, Clone)]
 pub struct StorageService {
     temp_dir: PathBuf,
     pictures_dir: PathBuf,
+    trash_dir: Option<PathBuf>,
 }
 
 impl StorageService {
@@ -43,9 +49,15 @@ impl StorageService {
         Self {
             temp_dir,
             pictures_dir,
+            trash_dir: None,
         }
     }
 
+    pub fn with_trash_dir(mut self, trash_dir: PathBuf) -> Self {
+        self.trash_dir = Some(trash_dir);
+        self
+    }
+
     pub fn with_default_paths() -> StorageResult<Self> {
         let home = std::env::var("HOME").map_err(|_| StorageError::MissingHomeDirectory)?;
         let temp_dir = default_runtime_temp_dir();
@@ -103,6 +115,46 @@ impl StorageService {
         }
     }
 
+    /// Moves the temp artifact and the saved copy (when present) to the
+    /// user trash. The returned record is what [`Self::restore_trashed_capture`]
+    /// needs to undo the deletion.
+    pub fn trash_capture(&self, capture_id: &str) -> StorageResult<TrashedCapture> {
+        let trash = self.trash()?;
+        let candidates = [
+            self.temp_path_for_capture(capture_id)?,
+            self.allocate_target_path(capture_id)?,
+        ];
+        let mut trashed = TrashedCapture::default();
+        for path in candidates {
+            if !path.is_file() {
+                continue;
+            }
+            match trash.trash_file(&path) {
+                Ok(entry) => trashed.files.push(entry),
+                Err(err) => {
+                    let _ = self.restore_trashed_capture(&trashed);
+                    return Err(err);
+                }
+            }
+        }
+        Ok(trashed)
+    }
+
+    pub fn restore_trashed_capture(&self, trashed: &TrashedCapture) -> StorageResult<()> {
+        let trash = self.trash()?;
+        for entry in &trashed.files {
+            trash.restore(entry)?;
+        }
+        Ok(())
+    }
+
+    fn trash(&self) -> StorageResult<TrashDir> {
+        match &self.trash_dir {
+            Some(path) => Ok(TrashDir::new(path.clone())),
+            None => default_trash_dir(),
+        }
+    }
+
     pub fn prune_stale_temp_files(&self, max_age_hours: u64) -> StorageResult<PruneReport> {
         let now = SystemTime::now();
         let mut report = PruneReport::default();
@@ -159,6 +211,10 @@ impl CaptureStorage for StorageService {
     fn discard_session_artifacts(&self, capture_id: &str) -> StorageResult<()> {
         self.discard_session_artifacts(capture_id)
     }
+
+    fn trash_capture(&self, capture_id: &str) -> StorageResult<TrashedCapture> {
+        self.trash_capture(capture_id)
+    }
 }
 
 pub fn create_temp_capture(capture_id: &str) -> PathBuf {
@@ -184,7 +240,7 @@ pub fn prune_stale_temp_files(max_age_hours: u64) -> StorageResult<PruneReport>
     StorageService::with_default_paths()?.prune_stale_temp_files(max_age_hours)
 }
 
-fn default_runtime_temp_dir() -> PathBuf {
+pub(crate) fn default_runtime_temp_dir() -> PathBuf {
     std::env::var("XDG_RUNTIME_DIR")
         .map(PathBuf::from)
         .unwrap_or_else(|_| PathBuf::from(DEFAULT_FALLBACK_TEMP_DIR))
@@ -242,4 +298,49 @@ mod tests {
         assert!(copied_path.exists());
         assert_eq!(std::fs::read(copied_path).unwrap(), source_data);
     }
+
+    #[test]
+    fn trash_capture_moves_temp_and_saved_files_and_restores_them() {
+        let root = std::env::temp_dir().join(format!(
+            "chalkak-storage-trash-{}-{}",
+            std::process::id(),
+            SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        let service = StorageService::with_paths(root.join("runtime"), root.join("Pictures"))
+            .with_trash_dir(root.join("Trash"));
+        std::fs::create_dir_all(root.join("runtime")).unwrap();
+        std::fs::create_dir_all(root.join("Pictures")).unwrap();
+        let temp_path = service.temp_path_for_capture("trash-1").unwrap();
+        let saved_path = service.allocate_target_path("trash-1").unwrap();
+        std::fs::write(&temp_path, b"temp").unwrap();
+        std::fs::write(&saved_path, b"saved").unwrap();
+
+        let trashed = service.trash_capture("trash-1").unwrap();
+        assert_eq!(trashed.files.len(), 2);
+        assert!(!temp_path.exists());
+        assert!(!saved_path.exists());
+        assert!(root.join("Trash/files/capture_trash-1.png").exists());
+        assert!(root.join("Trash/info/trash-1.png.trashinfo").exists());
+
+        service.restore_trashed_capture(&trashed).unwrap();
+        assert_eq!(std::fs::read(&temp_path).unwrap(), b"temp");
+        assert_eq!(std::fs::read(&saved_path).unwrap(), b"saved");
+        assert!(!root.join("Trash/info/trash-1.png.trashinfo").exists());
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn trash_capture_without_files_returns_empty_record() {
+        let root =
+            std::env::temp_dir().join(format!("chalkak-storage-empty-{}", std::process::id()));
+        let service = StorageService::with_paths(root.join("runtime"), root.join("Pictures"))
+            .with_trash_dir(root.join("Trash"));
+
+        let trashed = service.trash_capture("missing").unwrap();
+        assert!(trashed.is_empty());
+        let _ = std::fs::remove_dir_all(root);
+    }
 }
@@ -0,0 +1,352 @@
+//! Minimal freedesktop.org Trash support (home trash only).
+//!
+//! Files are moved into `$XDG_DATA_HOME/Trash/files` and described by a
+//! matching `.trashinfo` entry in `$XDG_DATA_HOME/Trash/info`, so file
+//! managers can list and restore them like any other trashed file.
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use super::{StorageError, StorageResult};
+
+const TRASH_SUBDIR: &str = "Trash";
+const TRASH_FILES_SUBDIR: &str = "files";
+const TRASH_INFO_SUBDIR: &str = "info";
+const TRASH_INFO_EXTENSION: &str = "trashinfo";
+const MAX_NAME_ATTEMPTS: u32 = 10_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrashedFile {
+    pub original_path: PathBuf,
+    pub trashed_path: PathBuf,
+    pub info_path: PathBuf,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TrashedCapture {
+    pub files: Vec<TrashedFile>,
+}
+
+impl TrashedCapture {
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TrashDir {
+    files_dir: PathBuf,
+    info_dir: PathBuf,
+}
+
+impl TrashDir {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        let root = root.into();
+        Self {
+            files_dir: root.join(TRASH_FILES_SUBDIR),
+            info_dir: root.join(TRASH_INFO_SUBDIR),
+        }
+    }
+
+    pub fn files_dir(&self) -> &Path {
+        &self.files_dir
+    }
+
+    pub fn info_dir(&self) -> &Path {
+        &self.info_dir
+    }
+
+    pub fn trash_file(&self, path: &Path) -> StorageResult<TrashedFile> {
+        let original_path = absolute_path(path)?;
+        let file_name = original_path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| {
+                StorageError::Io(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("cannot trash path without file name: {}", path.display()),
+                ))
+            })?
+            .to_string();
+
+        fs::create_dir_all(&self.files_dir)?;
+        fs::create_dir_all(&self.info_dir)?;
+
+        let info_contents = trash_info_contents(&original_path, SystemTime::now());
+        let (trashed_name, info_path) = self.reserve_info_entry(&file_name, &info_contents)?;
+        let trashed_path = self.files_dir.join(&trashed_name);
+
+        if let Err(err) = move_path(&original_path, &trashed_path) {
+            let _ = fs::remove_file(&info_path);
+            return Err(StorageError::Io(err));
+        }
+
+        Ok(TrashedFile {
+            original_path,
+            trashed_path,
+            info_path,
+        })
+    }
+
+    pub fn restore(&self, entry: &TrashedFile) -> StorageResult<()> {
+        if entry.original_path.exists() {
+            return Err(StorageError::Io(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!(
+                    "restore target already exists: {}",
+                    entry.original_path.display()
+                ),
+            )));
+        }
+        if let Some(parent) = entry.original_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        move_path(&entry.trashed_path, &entry.original_path)?;
+        match fs::remove_file(&entry.info_path) {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(StorageError::Io(err)),
+        }
+    }
+
+    /// Creates the `.trashinfo` file with `create_new`, which is the spec's
+    /// atomic way to claim a name in `files/`.
+    fn reserve_info_entry(
+        &self,
+        file_name: &str,
+        info_contents: &str,
+    ) -> StorageResult<(String, PathBuf)> {
+        for attempt in 0..MAX_NAME_ATTEMPTS {
+            let candidate = candidate_trash_name(file_name, attempt);
+            if self.files_dir.join(&candidate).exists() {
+                continue;
+            }
+            let info_path = self
+                .info_dir
+                .join(format!("{candidate}.{TRASH_INFO_EXTENSION}"));
+            match fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&info_path)
+            {
+                Ok(mut file) => {
+                    if let Err(err) = file.write_all(info_contents.as_bytes()) {
+                        let _ = fs::remove_file(&info_path);
+                        return Err(StorageError::Io(err));
+                    }
+                    return Ok((candidate, info_path));
+                }
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(err) => return Err(StorageError::Io(err)),
+            }
+        }
+
+        Err(StorageError::Io(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("no free trash entry name for {file_name}"),
+        )))
+    }
+}
+
+pub fn default_trash_dir() -> StorageResult<TrashDir> {
+    let data_home = std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute());
+    let data_home = match data_home {
+        Some(path) => path,
+        None => {
+            let home = std::env::var("HOME").map_err(|_| StorageError::MissingHomeDirectory)?;
+            PathBuf::from(home).join(".local").join("share")
+        }
+    };
+    Ok(TrashDir::new(data_home.join(TRASH_SUBDIR)))
+}
+
+fn absolute_path(path: &Path) -> StorageResult<PathBuf> {
+    if path.is_absolute() {
+        return Ok(path.to_path_buf());
+    }
+    Ok(std::env::current_dir()?.join(path))
+}
+
+fn candidate_trash_name(file_name: &str, attempt: u32) -> String {
+    if attempt == 0 {
+        return file_name.to_string();
+    }
+    match file_name.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => format!("{stem}.{attempt}.{extension}"),
+        _ => format!("{file_name}.{attempt}"),
+    }
+}
+
+/// Renames when possible and falls back to copy + remove, because the
+/// runtime temp dir usually lives on a different filesystem than the trash.
+fn move_path(source: &Path, destination: &Path) -> io::Result<()> {
+    match fs::rename(source, destination) {
+        Ok(()) => Ok(()),
+        Err(rename_err) => {
+            if !source.is_file() {
+                return Err(rename_err);
+            }
+            if let Err(copy_err) = fs::copy(source, destination) {
+                let _ = fs::remove_file(destination);
+                return Err(copy_err);
+            }
+            fs::remove_file(source)
+        }
+    }
+}
+
+fn trash_info_contents(original_path: &Path, deleted_at: SystemTime) -> String {
+    format!(
+        "[Trash Info]\nPath={}\nDeletionDate={}\n",
+        encode_trash_info_path(original_path),
+        format_deletion_date(deleted_at)
+    )
+}
+
+fn encode_trash_info_path(path: &Path) -> String {
+    use std::os::unix::ffi::OsStrExt;
+
+    let mut encoded = String::new();
+    for &byte in path.as_os_str().as_bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'/' | b'-' | b'_' | b'.' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+/// Formats `YYYY-MM-DDThh:mm:ss` in local time, as the Trash spec asks.
+fn format_deletion_date(time: SystemTime) -> String {
+    format_deletion_date_in(time, &gtk4::glib::TimeZone::local())
+}
+
+fn format_deletion_date_in(time: SystemTime, zone: &gtk4::glib::TimeZone) -> String {
+    let seconds = time
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|duration| i64::try_from(duration.as_secs()).unwrap_or(i64::MAX))
+        .unwrap_or(0);
+    gtk4::glib::DateTime::from_unix_utc(seconds)
+        .and_then(|date| date.to_timezone(zone))
+        .and_then(|date| date.format("%Y-%m-%dT%H:%M:%S"))
+        .map(|text| text.to_string())
+        .unwrap_or_else(|err| {
+            tracing::warn!(?err, "failed to format trash deletion date");
+            "1970-01-01T00:00:00".to_string()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "chalkak-trash-{name}-{}-{}",
+            std::process::id(),
+            SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn trash_file_moves_file_and_writes_info_entry() {
+        let dir = scratch_dir("move");
+        let source = dir.join("capture_1.png");
+        fs::write(&source, b"png").unwrap();
+        let trash = TrashDir::new(dir.join("Trash"));
+
+        let entry = trash.trash_file(&source).unwrap();
+
+        assert!(!source.exists());
+        assert_eq!(entry.trashed_path, dir.join("Trash/files/capture_1.png"));
+        assert_eq!(fs::read(&entry.trashed_path).unwrap(), b"png");
+        let info = fs::read_to_string(&entry.info_path).unwrap();
+        assert!(info.starts_with("[Trash Info]\n"));
+        assert!(info.contains(&format!("Path={}\n", source.display())));
+        assert!(info.contains("DeletionDate="));
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn trash_file_picks_unique_name_when_entry_exists() {
+        let dir = scratch_dir("unique");
+        let trash = TrashDir::new(dir.join("Trash"));
+        let first = dir.join("a").join("shot.png");
+        let second = dir.join("b").join("shot.png");
+        for path in [&first, &second] {
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, b"png").unwrap();
+        }
+
+        let first_entry = trash.trash_file(&first).unwrap();
+        let second_entry = trash.trash_file(&second).unwrap();
+
+        assert_eq!(first_entry.trashed_path.file_name().unwrap(), "shot.png");
+        assert_eq!(second_entry.trashed_path.file_name().unwrap(), "shot.1.png");
+        assert!(second_entry
+            .info_path
+            .ends_with("Trash/info/shot.1.png.trashinfo"));
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn restore_moves_file_back_and_removes_info_entry() {
+        let dir = scratch_dir("restore");
+        let source = dir.join("capture_2.png");
+        fs::write(&source, b"png").unwrap();
+        let trash = TrashDir::new(dir.join("Trash"));
+        let entry = trash.trash_file(&source).unwrap();
+
+        trash.restore(&entry).unwrap();
+
+        assert_eq!(fs::read(&source).unwrap(), b"png");
+        assert!(!entry.trashed_path.exists());
+        assert!(!entry.info_path.exists());
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn restore_refuses_to_overwrite_existing_file() {
+        let dir = scratch_dir("conflict");
+        let source = dir.join("capture_3.png");
+        fs::write(&source, b"old").unwrap();
+        let trash = TrashDir::new(dir.join("Trash"));
+        let entry = trash.trash_file(&source).unwrap();
+        fs::write(&source, b"new").unwrap();
+
+        assert!(trash.restore(&entry).is_err());
+        assert_eq!(fs::read(&source).unwrap(), b"new");
+        assert!(entry.trashed_path.exists());
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn encode_trash_info_path_escapes_reserved_bytes() {
+        let encoded = encode_trash_info_path(Path::new("/home/me/My Shots/100%.png"));
+        assert_eq!(encoded, "/home/me/My%20Shots/100%25.png");
+    }
+
+    #[test]
+    fn format_deletion_date_writes_wall_clock_of_zone() {
+        let time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_093_991_528);
+        let seoul = gtk4::glib::TimeZone::new(Some("+09:00"));
+        let new_york = gtk4::glib::TimeZone::new(Some("-05:00"));
+
+        assert_eq!(format_deletion_date_in(time, &seoul), "2004-09-01T07:32:08");
+        assert_eq!(
+            format_deletion_date_in(time, &new_york),
+            "2004-08-31T17:32:08"
+        );
+    }
+}
@@ -3,4 +3,4 @@ pub mod widgets;
 
 pub use crate::theme::{default_color_tokens, tokens_for, ColorTokens};
 pub use style::{StyleTokens, LAYOUT_TOKENS};
-pub use widgets::{icon_button, icon_toggle_button, install_lucide_icon_theme};
+pub use widgets::{icon_button, icon_menu_button, icon_toggle_button, install_lucide_icon_theme};
@@ -1,7 +1,7 @@
 use std::sync::Once;
 
 use gtk4::prelude::*;
-use gtk4::{gio, Button, ToggleButton};
+use gtk4::{gio, Button, MenuButton, Orientation, Popover, ToggleButton};
 
 const LUCIDE_ICON_RESOURCE_PATH: &str = "/com/github/bityoungjae/chalkak/icons/hicolor";
 
@@ -67,3 +67,45 @@ pub fn icon_toggle_button(
     button.set_size_request(control_size, control_size);
     button
 }
+
+/// Icon button that opens a popover with one flat button per label. The
+/// popover closes when any item is clicked; callers connect the returned
+/// buttons to their actions.
+pub fn icon_menu_button(
+    icon_name: &str,
+    tooltip: &str,
+    control_size: i32,
+    extra_classes: &[&str],
+    item_labels: &[&str],
+) -> (MenuButton, Vec<Button>) {
+    let menu_button = MenuButton::new();
+    menu_button.set_icon_name(icon_name);
+    menu_button.set_focus_on_click(false);
+    menu_button.set_tooltip_text(Some(tooltip));
+    menu_button.add_css_class("flat");
+    menu_button.add_css_class("icon-button");
+    for css_class in extra_classes {
+        menu_button.add_css_class(css_class);
+    }
+    menu_button.set_size_request(control_size, control_size);
+
+    let popover = Popover::new();
+    popover.add_css_class("menu-popover");
+    let items_box = gtk4::Box::new(Orientation::Vertical, 0);
+    let items = item_labels
+        .iter()
+        .map(|label| {
+            let item = Button::with_label(label);
+            item.add_css_class("flat");
+            item.add_css_class("menu-popover-item");
+            item.set_focus_on_click(false);
+            let popover = popover.clone();
+            item.connect_clicked(move |_| popover.popdown());
+            items_box.append(&item);
+            item
+        })
+        .collect::<Vec<_>>();
+    popover.set_child(Some(&items_box));
+    menu_button.set_popover(Some(&popover));
+    (menu_button, items)
+}
@@ -41,3 +41,89 @@ fn cli_long_help_flag() {
     assert!(stdout.contains("Usage:"));
     assert!(stdout.contains("--version"));
 }
+
+#[test]
+fn cli_help_lists_ocr_command() {
+    let output = chalkak().arg("--help").output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("chalkak ocr"));
+}
+
+#[test]
+fn cli_ocr_help_flag() {
+    let output = chalkak().args(["ocr", "--help"]).output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success());
+    assert!(stdout.contains("--format"));
+    assert!(stdout.contains("Exit status:"));
+}
+
+#[test]
+fn cli_ocr_rejects_unknown_format_with_usage_exit_code() {
+    let output = chalkak()
+        .args(["ocr", "--format", "xml", "image.png"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr.contains("unknown format: xml"));
+}
+
+#[test]
+fn cli_ocr_reports_missing_model_dir_with_distinct_exit_code() {
+    if std::path::Path::new("/usr/share/chalkak/models").is_dir() {
+        // System models are installed; the missing-models path is unreachable.
+        return;
+    }
+    let output = chalkak()
+        .args(["ocr", "image.png"])
+        .env("XDG_DATA_HOME", "/tmp/chalkak-test-nonexistent-dir")
+        .env("HOME", "/tmp/chalkak-test-nonexistent-home")
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(output.status.code(), Some(3));
+    assert!(stderr.contains("model directory not found"));
+}
+
+#[test]
+fn cli_ocr_rejects_export_from_stdin_with_usage_exit_code() {
+    let output = chalkak()
+        .args(["ocr", "--export", "pdf", "-"])
+        .stdin(std::process::Stdio::null())
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr.contains("cannot be used with stdin"));
+}
+
+#[test]
+fn cli_help_lists_models_command() {
+    let output = chalkak().arg("--help").output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("chalkak models"));
+}
+
+#[test]
+fn cli_models_requires_a_command() {
+    let output = chalkak().arg("models").output().unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr.contains("missing command"));
+}
+
+#[test]
+fn cli_models_install_reports_unreadable_archive() {
+    let output = chalkak()
+        .args(["models", "install", "/tmp/chalkak-test-nonexistent.tar.gz"])
+        .env(
+            "XDG_DATA_HOME",
+            std::env::temp_dir().join("chalkak-test-models-cli"),
+        )
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(output.status.code(), Some(4));
+    assert!(stderr.contains("chalkak-test-nonexistent.tar.gz"));
+}